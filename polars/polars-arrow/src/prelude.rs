@@ -1,7 +1,8 @@
 pub use crate::array::default_arrays::*;
 pub use crate::array::*;
-use arrow::array::{ListArray, Utf8Array};
+use arrow::array::{BinaryArray, ListArray, Utf8Array};
 
 pub type AlignedVec<T> = arrow::buffer::MutableBuffer<T>;
 pub type LargeStringArray = Utf8Array<i64>;
+pub type LargeBinaryArray = BinaryArray<i64>;
 pub type LargeListArray = ListArray<i64>;
@@ -0,0 +1,50 @@
+use super::*;
+
+pub struct BinaryChunkedBuilder {
+    pub builder: MutableBinaryArray<i64>,
+    pub capacity: usize,
+    field: Field,
+}
+
+impl BinaryChunkedBuilder {
+    /// Create a new BinaryChunkedBuilder
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of elements in the final array.
+    pub fn new(name: &str, capacity: usize) -> Self {
+        BinaryChunkedBuilder {
+            builder: MutableBinaryArray::<i64>::with_capacity(capacity),
+            capacity,
+            field: Field::new(name, DataType::Binary),
+        }
+    }
+
+    /// Appends a value of type `T` into the builder
+    #[inline]
+    pub fn append_value<S: AsRef<[u8]>>(&mut self, v: S) {
+        self.builder.push(Some(v.as_ref()));
+    }
+
+    /// Appends a null slot into the builder
+    #[inline]
+    pub fn append_null(&mut self) {
+        self.builder.push::<&[u8]>(None);
+    }
+
+    #[inline]
+    pub fn append_option<S: AsRef<[u8]>>(&mut self, opt: Option<S>) {
+        self.builder.push(opt);
+    }
+
+    pub fn finish(self) -> BinaryChunked {
+        let arr = self.builder.into_arc();
+        ChunkedArray {
+            field: Arc::new(self.field),
+            chunks: vec![arr],
+            phantom: PhantomData,
+            categorical_map: None,
+            ..Default::default()
+        }
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-binary")]
+mod binary;
 mod boolean;
 mod from;
 mod list;
@@ -9,6 +11,8 @@ use crate::{
     utils::{get_iter_capacity, NoNull},
 };
 use arrow::{array::*, bitmap::Bitmap};
+#[cfg(feature = "dtype-binary")]
+pub use binary::*;
 use std::borrow::Cow;
 use std::iter::FromIterator;
 use std::marker::PhantomData;
@@ -166,6 +170,39 @@ where
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl<S> NewChunkedArray<BinaryType, S> for BinaryChunked
+where
+    S: AsRef<[u8]>,
+{
+    fn new_from_slice(name: &str, v: &[S]) -> Self {
+        let mut builder = BinaryChunkedBuilder::new(name, v.len());
+        v.iter().for_each(|val| builder.append_value(val));
+        builder.finish()
+    }
+
+    fn new_from_opt_slice(name: &str, opt_v: &[Option<S>]) -> Self {
+        let mut builder = BinaryChunkedBuilder::new(name, opt_v.len());
+        opt_v
+            .iter()
+            .for_each(|opt| builder.append_option(opt.as_ref()));
+        builder.finish()
+    }
+
+    fn new_from_opt_iter(name: &str, it: impl Iterator<Item = Option<S>>) -> Self {
+        let mut builder = BinaryChunkedBuilder::new(name, get_iter_capacity(&it));
+        it.for_each(|opt| builder.append_option(opt));
+        builder.finish()
+    }
+
+    /// Create a new ChunkedArray from an iterator.
+    fn new_from_iter(name: &str, it: impl Iterator<Item = S>) -> Self {
+        let mut builder = BinaryChunkedBuilder::new(name, get_iter_capacity(&it));
+        it.for_each(|v| builder.append_value(v));
+        builder.finish()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -19,7 +19,11 @@ fn cast_impl(name: &str, chunks: &[ArrayRef], dtype: &DataType) -> Result<Series
     let out = Series::try_from((name, chunks))?;
     use DataType::*;
     let out = match dtype {
-        Date | Datetime => out.into_date(),
+        Date => out.into_date(),
+        #[cfg(feature = "dtype-datetime")]
+        Datetime(tu, tz) => out.into_datetime(*tu, tz.clone()),
+        #[cfg(feature = "dtype-duration")]
+        Duration(tu) => out.into_duration(*tu),
         #[cfg(feature = "dtype-time")]
         Time => out.into_time(),
         _ => out,
@@ -75,6 +79,10 @@ where
                     .set_state(self);
                 Ok(ca.into_series())
             }
+            #[cfg(feature = "dtype-decimal")]
+            (_, DataType::Decimal(precision, scale)) => {
+                DecimalChunked::from_numeric(self, *precision, *scale).map(|ca| ca.into_series())
+            }
             _ => cast_impl(self.name(), &self.chunks, data_type),
         }
     }
@@ -91,11 +99,55 @@ impl ChunkCast for Utf8Chunked {
                 let ca = builder.finish();
                 Ok(ca.into_series())
             }
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => {
+                let mut ca: BinaryChunked = self
+                    .into_iter()
+                    .map(|opt_s| opt_s.map(|s| s.as_bytes()))
+                    .collect();
+                ca.rename(self.name());
+                Ok(ca.into_series())
+            }
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(precision, scale) => {
+                DecimalChunked::from_utf8(self, *precision, *scale).map(|ca| ca.into_series())
+            }
             _ => cast_impl(self.name(), &self.chunks, data_type),
         }
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkCast for BinaryChunked {
+    fn cast(&self, data_type: &DataType) -> Result<Series> {
+        match data_type {
+            DataType::Binary => Ok(self.clone().into_series()),
+            DataType::Utf8 => {
+                let mut builder = Utf8ChunkedBuilder::new(self.name(), self.len(), self.len() * 5);
+                for opt_v in self.into_iter() {
+                    match opt_v.map(std::str::from_utf8) {
+                        Some(Ok(s)) => builder.append_value(s),
+                        None => builder.append_null(),
+                        Some(Err(_)) => {
+                            return Err(PolarsError::ComputeError(
+                                format!(
+                                    "cannot cast {:?} to utf8; not all values are valid UTF-8",
+                                    self.name()
+                                )
+                                .into(),
+                            ))
+                        }
+                    }
+                }
+                Ok(builder.finish().into_series())
+            }
+            _ => Err(PolarsError::InvalidOperation(
+                format!("cannot cast binary to {:?}", data_type).into(),
+            )),
+        }
+    }
+}
+
 fn boolean_to_utf8(ca: &BooleanChunked) -> Utf8Chunked {
     ca.into_iter()
         .map(|opt_b| match opt_b {
@@ -172,4 +224,46 @@ mod test {
         let out = out.cast(&DataType::Categorical).unwrap();
         assert_eq!(out.dtype(), &DataType::Categorical)
     }
+
+    #[test]
+    #[cfg(feature = "dtype-binary")]
+    fn test_cast_binary() -> Result<()> {
+        let ca = Utf8Chunked::new_from_opt_slice("foo", &[Some("bar"), None]);
+        let out = ca.cast(&DataType::Binary)?;
+        assert_eq!(out.dtype(), &DataType::Binary);
+        assert_eq!(Vec::from(out.binary()?), &[Some(&b"bar"[..]), None]);
+
+        let out = out.cast(&DataType::Utf8)?;
+        assert!(out.series_equal_missing(&ca.into_series()));
+
+        let invalid = BinaryChunked::new_from_slice("foo", &[&[0xffu8, 0xfe][..]]);
+        assert!(invalid.cast(&DataType::Utf8).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_cast_decimal() -> Result<()> {
+        let dtype = DataType::Decimal(5, 2);
+        let ca =
+            Float64Chunked::new_from_opt_slice("a", &[Some(1.125), None, Some(-2.5), Some(1e6)]);
+        let out = ca.cast(&dtype)?;
+        assert_eq!(out.dtype(), &dtype);
+        assert_eq!(out.get(0).to_string(), "1.13");
+        assert_eq!(out.get(2).to_string(), "-2.50");
+        // doesn't fit the precision
+        assert_eq!(out.null_count(), 2);
+
+        let ca = Int32Chunked::new_from_slice("a", &[3, -7]);
+        let out = ca.cast(&dtype)?;
+        assert_eq!(out.get(1).to_string(), "-7.00");
+
+        let ca = Utf8Chunked::new_from_opt_slice("a", &[Some("12.345"), Some("foo"), None]);
+        let out = ca.cast(&dtype)?;
+        assert_eq!(out.get(0).to_string(), "12.34");
+        assert_eq!(out.null_count(), 2);
+
+        assert!(ca.cast(&DataType::Decimal(40, 2)).is_err());
+        Ok(())
+    }
 }
@@ -596,6 +596,121 @@ impl ChunkCompare<&ListChunked> for ListChunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+macro_rules! impl_cmp_binary {
+    ($self:ident, $rhs:ident, $operand:tt) => {{
+        // broadcast
+        if $rhs.len() == 1 {
+            match $rhs.get(0) {
+                Some(value) => $self
+                    .into_iter()
+                    .map(|opt_v| opt_v.map(|v| v $operand value))
+                    .collect(),
+                None => BooleanChunked::full("", false, $self.len()),
+            }
+        } else {
+            apply_operand_on_chunkedarray_by_iter!($self, $rhs, $operand)
+        }
+    }};
+}
+
+/// Bytes are compared lexicographically.
+#[cfg(feature = "dtype-binary")]
+impl ChunkCompare<&BinaryChunked> for BinaryChunked {
+    fn eq_missing(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        impl_eq_missing!(self, rhs)
+    }
+
+    fn eq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        impl_cmp_binary!(self, rhs, ==)
+    }
+
+    fn neq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        impl_cmp_binary!(self, rhs, !=)
+    }
+
+    fn gt(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        impl_cmp_binary!(self, rhs, >)
+    }
+
+    fn gt_eq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        impl_cmp_binary!(self, rhs, >=)
+    }
+
+    fn lt(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        impl_cmp_binary!(self, rhs, <)
+    }
+
+    fn lt_eq(&self, rhs: &BinaryChunked) -> BooleanChunked {
+        impl_cmp_binary!(self, rhs, <=)
+    }
+}
+
+#[cfg(feature = "dtype-decimal")]
+macro_rules! impl_cmp_decimal {
+    ($self:ident, $rhs:ident, $operand:tt) => {{
+        let (lhs, rhs) = $self
+            .align_scales($rhs)
+            .expect("cannot compare decimals; values overflow the common scale");
+        // broadcast
+        if rhs.len() == 1 {
+            match rhs.get(0) {
+                Some(value) => lhs
+                    .into_iter()
+                    .map(|opt_v| opt_v.map(|v| v $operand value))
+                    .collect(),
+                None => BooleanChunked::full("", false, lhs.len()),
+            }
+        } else {
+            lhs.into_iter()
+                .zip(rhs.into_iter())
+                .map(|(opt_l, opt_r)| match (opt_l, opt_r) {
+                    (Some(l), Some(r)) => Some(l $operand r),
+                    _ => None,
+                })
+                .collect()
+        }
+    }};
+}
+
+/// The values are compared at the largest scale of both arrays.
+#[cfg(feature = "dtype-decimal")]
+impl ChunkCompare<&DecimalChunked> for DecimalChunked {
+    fn eq_missing(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        let (lhs, rhs) = self
+            .align_scales(rhs)
+            .expect("cannot compare decimals; values overflow the common scale");
+        lhs.into_iter()
+            .zip(rhs.into_iter())
+            .map(|(opt_l, opt_r)| opt_l == opt_r)
+            .collect()
+    }
+
+    fn eq(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        impl_cmp_decimal!(self, rhs, ==)
+    }
+
+    fn neq(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        impl_cmp_decimal!(self, rhs, !=)
+    }
+
+    fn gt(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        impl_cmp_decimal!(self, rhs, >)
+    }
+
+    fn gt_eq(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        impl_cmp_decimal!(self, rhs, >=)
+    }
+
+    fn lt(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        impl_cmp_decimal!(self, rhs, <)
+    }
+
+    fn lt_eq(&self, rhs: &DecimalChunked) -> BooleanChunked {
+        impl_cmp_decimal!(self, rhs, <=)
+    }
+}
+
 impl Not for &BooleanChunked {
     type Output = BooleanChunked;
 
@@ -212,6 +212,92 @@ impl Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl<'a> IntoIterator for &'a BinaryChunked {
+    type Item = Option<&'a [u8]>;
+    type IntoIter = Box<dyn PolarsIterator<Item = Self::Item> + 'a>;
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.downcast_iter().flatten().trust_my_length(self.len()))
+    }
+}
+
+/// The no null iterator for a BinaryArray
+#[cfg(feature = "dtype-binary")]
+pub struct BinaryIterNoNull<'a> {
+    array: &'a BinaryArray<i64>,
+    current: usize,
+    current_end: usize,
+}
+
+#[cfg(feature = "dtype-binary")]
+impl<'a> BinaryIterNoNull<'a> {
+    /// create a new iterator
+    pub fn new(array: &'a BinaryArray<i64>) -> Self {
+        BinaryIterNoNull {
+            array,
+            current: 0,
+            current_end: array.len(),
+        }
+    }
+}
+
+#[cfg(feature = "dtype-binary")]
+impl<'a> Iterator for BinaryIterNoNull<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == self.current_end {
+            None
+        } else {
+            let old = self.current;
+            self.current += 1;
+            unsafe { Some(self.array.value_unchecked(old)) }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (
+            self.array.len() - self.current,
+            Some(self.array.len() - self.current),
+        )
+    }
+}
+
+#[cfg(feature = "dtype-binary")]
+impl<'a> DoubleEndedIterator for BinaryIterNoNull<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.current_end == self.current {
+            None
+        } else {
+            self.current_end -= 1;
+            unsafe { Some(self.array.value_unchecked(self.current_end)) }
+        }
+    }
+}
+
+/// all arrays have known size.
+#[cfg(feature = "dtype-binary")]
+impl<'a> ExactSizeIterator for BinaryIterNoNull<'a> {}
+
+#[cfg(feature = "dtype-binary")]
+impl BinaryChunked {
+    #[allow(clippy::wrong_self_convention)]
+    pub fn into_no_null_iter<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = &'a [u8]>
+           + '_
+           + Send
+           + Sync
+           + ExactSizeIterator
+           + DoubleEndedIterator
+           + TrustedLen {
+        self.downcast_iter()
+            .map(BinaryIterNoNull::new)
+            .flatten()
+            .trust_my_length(self.len())
+    }
+}
+
 impl<'a> IntoIterator for &'a ListChunked {
     type Item = Option<Series>;
     type IntoIter = Box<dyn PolarsIterator<Item = Self::Item> + 'a>;
@@ -1,11 +1,13 @@
 //! macros that define kernels for extracting
 //! `week`, `weekday`, `year`, `hour` etc. from primitive arrays.
+#[cfg(feature = "dtype-datetime")]
+use crate::chunked_array::temporal::conversion::timestamp_to_datetime_fn;
 use crate::prelude::*;
 use arrow::array::{ArrayRef, PrimitiveArray};
 use arrow::compute::arity::unary;
+use arrow::temporal_conversions::date32_to_datetime;
 #[cfg(feature = "dtype-time")]
 use arrow::temporal_conversions::time64ns_to_time;
-use arrow::temporal_conversions::{date32_to_datetime, timestamp_ms_to_datetime};
 use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
 use std::sync::Arc;
 
@@ -46,6 +48,23 @@ macro_rules! to_temporal_unit {
         }
     };
 }
+// Datetime kernels take the time unit of the timestamps at runtime.
+#[cfg(feature = "dtype-datetime")]
+macro_rules! datetime_to_temporal_unit {
+    ($name: ident, $chrono_method:ident, $dtype_out:expr) => {
+        pub(crate) fn $name(arr: &PrimitiveArray<i64>, tu: TimeUnit) -> ArrayRef {
+            let to_datetime = timestamp_to_datetime_fn(tu);
+            Arc::new(unary(
+                arr,
+                |value| {
+                    let dt = to_datetime(value);
+                    dt.$chrono_method()
+                },
+                $dtype_out,
+            )) as ArrayRef
+        }
+    };
+}
 #[cfg(feature = "dtype-date")]
 to_temporal_unit!(
     date_to_week,
@@ -95,85 +114,25 @@ to_temporal_unit!(
     ArrowDataType::UInt32
 );
 #[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_week,
-    week,
-    timestamp_ms_to_datetime,
-    i64,
-    ArrowDataType::UInt32
-);
+datetime_to_temporal_unit!(datetime_to_week, week, ArrowDataType::UInt32);
 #[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_weekday,
-    p_weekday,
-    timestamp_ms_to_datetime,
-    i64,
-    ArrowDataType::UInt32
-);
+datetime_to_temporal_unit!(datetime_to_weekday, p_weekday, ArrowDataType::UInt32);
 #[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_year,
-    year,
-    timestamp_ms_to_datetime,
-    i64,
-    ArrowDataType::Int32
-);
+datetime_to_temporal_unit!(datetime_to_year, year, ArrowDataType::Int32);
 #[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_month,
-    month,
-    timestamp_ms_to_datetime,
-    i64,
-    ArrowDataType::UInt32
-);
+datetime_to_temporal_unit!(datetime_to_month, month, ArrowDataType::UInt32);
 #[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_day,
-    day,
-    timestamp_ms_to_datetime,
-    i64,
-    ArrowDataType::UInt32
-);
+datetime_to_temporal_unit!(datetime_to_day, day, ArrowDataType::UInt32);
 #[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_hour,
-    hour,
-    timestamp_ms_to_datetime,
-    i64,
-    ArrowDataType::UInt32
-);
+datetime_to_temporal_unit!(datetime_to_hour, hour, ArrowDataType::UInt32);
 #[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_minute,
-    minute,
-    timestamp_ms_to_datetime,
-    i64,
-    ArrowDataType::UInt32
-);
+datetime_to_temporal_unit!(datetime_to_minute, minute, ArrowDataType::UInt32);
 #[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_second,
-    second,
-    timestamp_ms_to_datetime,
-    i64,
-    ArrowDataType::UInt32
-);
+datetime_to_temporal_unit!(datetime_to_second, second, ArrowDataType::UInt32);
 #[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_nanosecond,
-    nanosecond,
-    timestamp_ms_to_datetime,
-    i64,
-    ArrowDataType::UInt32
-);
+datetime_to_temporal_unit!(datetime_to_nanosecond, nanosecond, ArrowDataType::UInt32);
 #[cfg(feature = "dtype-datetime")]
-to_temporal_unit!(
-    datetime_to_ordinal,
-    ordinal,
-    timestamp_ms_to_datetime,
-    i64,
-    ArrowDataType::UInt32
-);
+datetime_to_temporal_unit!(datetime_to_ordinal, ordinal, ArrowDataType::UInt32);
 
 #[cfg(feature = "dtype-time")]
 to_temporal_unit!(
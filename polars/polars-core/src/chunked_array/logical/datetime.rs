@@ -5,23 +5,87 @@ pub type DatetimeChunked = Logical<DatetimeType, Int64Type>;
 
 impl From<Int64Chunked> for DatetimeChunked {
     fn from(ca: Int64Chunked) -> Self {
-        DatetimeChunked::new(ca)
+        ca.into_date()
     }
 }
 
 impl Int64Chunked {
+    /// Interpret the values as naive millisecond timestamps.
     pub fn into_date(self) -> DatetimeChunked {
-        DatetimeChunked::new(self)
+        self.into_datetime(TimeUnit::Milliseconds, None)
+    }
+
+    pub fn into_datetime(self, tu: TimeUnit, tz: Option<TimeZone>) -> DatetimeChunked {
+        let mut dt = DatetimeChunked::new(self);
+        dt.2 = Some(DataType::Datetime(tu, tz));
+        dt
     }
 }
 
 impl LogicalType for DatetimeChunked {
-    fn dtype(&self) -> &'static DataType {
-        &DataType::Datetime
+    fn dtype(&self) -> &DataType {
+        self.2.as_ref().unwrap()
     }
 
-    #[cfg(feature = "dtype-date")]
+    #[cfg(feature = "dtype-datetime")]
     fn get_any_value(&self, i: usize) -> AnyValue<'_> {
-        self.0.get_any_value(i).into_date()
+        self.0
+            .get_any_value(i)
+            .into_datetime(self.time_unit(), self.time_zone())
+    }
+}
+
+impl DatetimeChunked {
+    /// The unit of the underlying timestamps.
+    pub fn time_unit(&self) -> TimeUnit {
+        match self.2.as_ref().unwrap() {
+            DataType::Datetime(tu, _) => *tu,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The time zone of this array, `None` for naive datetimes.
+    pub fn time_zone(&self) -> &Option<TimeZone> {
+        match self.2.as_ref().unwrap() {
+            DataType::Datetime(_, tz) => tz,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Change the time zone metadata without touching the underlying timestamps.
+    pub(crate) fn set_time_zone(&mut self, tz: Option<TimeZone>) {
+        self.2 = Some(DataType::Datetime(self.time_unit(), tz))
+    }
+
+    /// Convert the timestamps to another [`TimeUnit`]. Going to a coarser unit truncates.
+    pub fn cast_time_unit(&self, tu: TimeUnit) -> Self {
+        let current = self.time_unit();
+        let out = convert_time_unit(&self.0, current, tu);
+        out.into_datetime(tu, self.time_zone().clone())
+    }
+}
+
+/// Rescale integer values expressed in `from` to `to`.
+/// Values that do not fit in an `i64` in the finer unit become null.
+pub(crate) fn convert_time_unit(ca: &Int64Chunked, from: TimeUnit, to: TimeUnit) -> Int64Chunked {
+    let (from, to) = (from.ticks_per_second(), to.ticks_per_second());
+    if from == to {
+        ca.clone()
+    } else if from > to {
+        ca / (from / to)
+    } else {
+        let factor = to / from;
+        ca.apply_on_opt(|opt_v| opt_v.and_then(|v| v.checked_mul(factor)))
+    }
+}
+
+/// Rescale a single value expressed in `from` to `to`.
+/// Returns `None` if the value does not fit in an `i64` in the finer unit.
+pub(crate) fn convert_time_unit_value(v: i64, from: TimeUnit, to: TimeUnit) -> Option<i64> {
+    let (from, to) = (from.ticks_per_second(), to.ticks_per_second());
+    if from >= to {
+        Some(v / (from / to))
+    } else {
+        v.checked_mul(to / from)
     }
 }
@@ -0,0 +1,781 @@
+use crate::chunked_array::ChunkIdIter;
+use crate::prelude::*;
+use crate::utils::{slice_offsets, NoNull};
+use arrow::array::PrimitiveArray;
+use arrow::compute::{concat::concatenate, filter::filter as filter_fn, take::take};
+use num::ToPrimitive;
+use std::collections::HashSet;
+
+/// The maximum number of digits of a decimal, i.e. the digits that always fit an `i128`.
+pub const MAX_DECIMAL_PRECISION: usize = 38;
+
+type DecimalArray = PrimitiveArray<i128>;
+
+/// Fixed-precision decimal numbers.
+///
+/// Every value is stored as an `i128` scaled by `10^scale`, so `1.25` with scale `2` is stored
+/// as `125`. Additions, subtractions and multiplications are exact; results that don't fit the
+/// precision are an error.
+#[derive(Clone)]
+pub struct DecimalChunked {
+    field: Field,
+    chunks: Vec<ArrayRef>,
+}
+
+fn check_precision(precision: usize, scale: usize) -> Result<()> {
+    if precision == 0 || precision > MAX_DECIMAL_PRECISION || scale > precision {
+        Err(PolarsError::InvalidOperation(
+            format!(
+                "invalid decimal precision and scale: ({}, {}); the precision must be in 1..=38 \
+                and the scale cannot exceed it",
+                precision, scale
+            )
+            .into(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[inline]
+fn pow10(exp: usize) -> i128 {
+    10i128.pow(exp as u32)
+}
+
+#[inline]
+fn fits_precision(v: i128, precision: usize) -> bool {
+    // 10^38 still fits an i128, so this never overflows for a valid precision
+    v.unsigned_abs() < pow10(precision) as u128
+}
+
+/// Format a scaled integer as a decimal string without losing digits.
+pub(crate) fn format_decimal(v: i128, scale: usize) -> String {
+    let sign = if v < 0 { "-" } else { "" };
+    let abs = v.unsigned_abs();
+    if scale == 0 {
+        format!("{}{}", sign, abs)
+    } else {
+        let factor = pow10(scale) as u128;
+        format!(
+            "{}{}.{:0width$}",
+            sign,
+            abs / factor,
+            abs % factor,
+            width = scale
+        )
+    }
+}
+
+/// Parse a decimal string like `"-12.50"` to an integer scaled by `10^scale`.
+/// Fractional digits beyond the scale are truncated.
+pub(crate) fn parse_decimal(s: &str, scale: usize) -> Option<i128> {
+    let s = s.trim();
+    let (negative, s) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (int_part, frac_part) = match s.find('.') {
+        Some(idx) => (&s[..idx], &s[idx + 1..]),
+        None => (s, ""),
+    };
+    if (int_part.is_empty() && frac_part.is_empty())
+        || !int_part
+            .bytes()
+            .chain(frac_part.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let mut v: i128 = 0;
+    let frac_digits = frac_part.bytes().chain(std::iter::repeat(b'0')).take(scale);
+    for b in int_part.bytes().chain(frac_digits) {
+        v = v.checked_mul(10)?.checked_add((b - b'0') as i128)?;
+    }
+    Some(if negative { -v } else { v })
+}
+
+impl DecimalChunked {
+    /// Create a new `DecimalChunked` from integers that are already scaled by `10^scale`.
+    pub fn new_from_opt_iter<I>(name: &str, iter: I, precision: usize, scale: usize) -> Result<Self>
+    where
+        I: IntoIterator<Item = Option<i128>>,
+    {
+        check_precision(precision, scale)?;
+        let out = Self::from_values(name, iter, precision, scale);
+        if out
+            .into_iter()
+            .flatten()
+            .all(|v| fits_precision(v, precision))
+        {
+            Ok(out)
+        } else {
+            Err(PolarsError::ComputeError(
+                format!("values don't fit in decimal[{},{}]", precision, scale).into(),
+            ))
+        }
+    }
+
+    /// Create a new `DecimalChunked` from a slice of integers scaled by `10^scale`.
+    pub fn new_from_opt_slice(
+        name: &str,
+        v: &[Option<i128>],
+        precision: usize,
+        scale: usize,
+    ) -> Result<Self> {
+        Self::new_from_opt_iter(name, v.iter().copied(), precision, scale)
+    }
+
+    /// The Arrow chunks must be `PrimitiveArray<i128>`.
+    pub(crate) fn new_from_chunks(
+        name: &str,
+        chunks: Vec<ArrayRef>,
+        precision: usize,
+        scale: usize,
+    ) -> Self {
+        DecimalChunked {
+            field: Field::new(name, DataType::Decimal(precision, scale)),
+            chunks,
+        }
+    }
+
+    /// Doesn't check if the values fit the precision.
+    pub(crate) fn from_values<I>(name: &str, iter: I, precision: usize, scale: usize) -> Self
+    where
+        I: IntoIterator<Item = Option<i128>>,
+    {
+        let arr = iter
+            .into_iter()
+            .collect::<DecimalArray>()
+            .to(ArrowDataType::Decimal(precision, scale));
+        Self::new_from_chunks(name, vec![Arc::new(arr)], precision, scale)
+    }
+
+    /// Cast numeric values to decimals. Floats are rounded to the scale, values that don't
+    /// fit the precision become null.
+    pub(crate) fn from_numeric<T>(
+        ca: &ChunkedArray<T>,
+        precision: usize,
+        scale: usize,
+    ) -> Result<Self>
+    where
+        T: PolarsNumericType,
+    {
+        check_precision(precision, scale)?;
+        let is_float = matches!(ca.dtype(), DataType::Float32 | DataType::Float64);
+        let factor = pow10(scale);
+        let iter = ca.into_iter().map(|opt_v| {
+            opt_v
+                .and_then(|v| {
+                    if is_float {
+                        v.to_f64()
+                            .and_then(|v| (v * 10f64.powi(scale as i32)).round().to_i128())
+                    } else {
+                        v.to_i128().and_then(|v| v.checked_mul(factor))
+                    }
+                })
+                .filter(|v| fits_precision(*v, precision))
+        });
+        Ok(Self::from_values(ca.name(), iter, precision, scale))
+    }
+
+    /// Parse strings to decimals. Invalid strings and values that don't fit the precision
+    /// become null.
+    pub(crate) fn from_utf8(ca: &Utf8Chunked, precision: usize, scale: usize) -> Result<Self> {
+        check_precision(precision, scale)?;
+        let iter = ca.into_iter().map(|opt_s| {
+            opt_s
+                .and_then(|s| parse_decimal(s, scale))
+                .filter(|v| fits_precision(*v, precision))
+        });
+        Ok(Self::from_values(ca.name(), iter, precision, scale))
+    }
+
+    /// The total number of digits.
+    pub fn precision(&self) -> usize {
+        match self.dtype() {
+            DataType::Decimal(precision, _) => *precision,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The number of digits after the decimal point.
+    pub fn scale(&self) -> usize {
+        match self.dtype() {
+            DataType::Decimal(_, scale) => *scale,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.chunks.iter().map(|arr| arr.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn null_count(&self) -> usize {
+        self.chunks.iter().map(|arr| arr.null_count()).sum()
+    }
+
+    pub fn name(&self) -> &str {
+        self.field.name()
+    }
+
+    pub fn rename(&mut self, name: &str) {
+        self.field = Field::new(name, self.field.data_type().clone())
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        self.field.data_type()
+    }
+
+    pub fn ref_field(&self) -> &Field {
+        &self.field
+    }
+
+    pub fn chunks(&self) -> &Vec<ArrayRef> {
+        &self.chunks
+    }
+
+    pub fn chunk_id(&self) -> ChunkIdIter {
+        self.chunks.iter().map(|chunk| chunk.len())
+    }
+
+    fn copy_with_chunks(&self, chunks: Vec<ArrayRef>) -> Self {
+        DecimalChunked {
+            field: self.field.clone(),
+            chunks,
+        }
+    }
+
+    pub(crate) fn downcast_iter(
+        &self,
+    ) -> impl Iterator<Item = &DecimalArray> + DoubleEndedIterator {
+        self.chunks
+            .iter()
+            .map(|arr| arr.as_any().downcast_ref::<DecimalArray>().unwrap())
+    }
+
+    /// Get the scaled integer at `index`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn get(&self, mut index: usize) -> Option<i128> {
+        for arr in self.downcast_iter() {
+            if index < arr.len() {
+                return if arr.is_valid(index) {
+                    Some(arr.value(index))
+                } else {
+                    None
+                };
+            }
+            index -= arr.len();
+        }
+        panic!("index out of bounds")
+    }
+
+    pub fn get_any_value(&self, index: usize) -> AnyValue {
+        match self.get(index) {
+            Some(v) => AnyValue::Decimal(v, self.scale()),
+            None => AnyValue::Null,
+        }
+    }
+
+    /// Slice the array. The semantics are those of [`ChunkedArray::slice`].
+    pub fn slice(&self, offset: i64, length: usize) -> Self {
+        let (mut remaining_offset, mut remaining_length) =
+            slice_offsets(offset, length, self.len());
+        let mut new_chunks = vec![];
+
+        for chunk in &self.chunks {
+            let chunk_len = chunk.len();
+            if remaining_offset >= chunk_len {
+                remaining_offset -= chunk_len;
+                continue;
+            }
+            let take_len = std::cmp::min(remaining_length, chunk_len - remaining_offset);
+            new_chunks.push(chunk.slice(remaining_offset, take_len).into());
+            remaining_length -= take_len;
+            remaining_offset = 0;
+            if remaining_length == 0 {
+                break;
+            }
+        }
+        self.copy_with_chunks(new_chunks)
+    }
+
+    pub fn head(&self, length: Option<usize>) -> Self {
+        self.slice(0, std::cmp::min(length.unwrap_or(10), self.len()))
+    }
+
+    pub fn tail(&self, length: Option<usize>) -> Self {
+        let len = std::cmp::min(length.unwrap_or(10), self.len());
+        self.slice(-(len as i64), len)
+    }
+
+    pub fn rechunk(&self) -> Self {
+        if self.chunks.len() == 1 {
+            return self.clone();
+        }
+        let arrays = self.chunks.iter().map(|arr| &**arr).collect::<Vec<_>>();
+        let arr = concatenate(&arrays).unwrap();
+        self.copy_with_chunks(vec![arr.into()])
+    }
+
+    /// Append the values of `other`. Both arrays must have the same precision and scale.
+    pub fn append(&mut self, other: &DecimalChunked) -> Result<()> {
+        if self.dtype() != other.dtype() {
+            return Err(PolarsError::DataTypeMisMatch(
+                "cannot append Series; data types don't match".into(),
+            ));
+        }
+        self.chunks.extend(other.chunks.iter().cloned());
+        Ok(())
+    }
+
+    pub fn filter(&self, filter: &BooleanChunked) -> Result<Self> {
+        // broadcast
+        if filter.len() == 1 {
+            return match filter.get(0) {
+                Some(true) => Ok(self.clone()),
+                _ => Ok(self.slice(0, 0)),
+            };
+        }
+        if self.len() != filter.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "filter's length: {} differs from that of the Series: {}",
+                    filter.len(),
+                    self.len()
+                )
+                .into(),
+            ));
+        }
+        let ca = self.rechunk();
+        let mask = filter.rechunk();
+        let arr = filter_fn(ca.chunks[0].as_ref(), mask.downcast_iter().next().unwrap())?;
+        Ok(self.copy_with_chunks(vec![arr.into()]))
+    }
+
+    pub fn is_null(&self) -> BooleanChunked {
+        let mut ca: BooleanChunked = self.into_iter().map(|opt_v| opt_v.is_none()).collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    pub fn is_not_null(&self) -> BooleanChunked {
+        let mut ca: BooleanChunked = self.into_iter().map(|opt_v| opt_v.is_some()).collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    pub fn reverse(&self) -> Self {
+        let idx: NoNull<UInt32Chunked> = (0..self.len() as u32).rev().collect();
+        // Safety: the indices are in bounds
+        unsafe { self.take_unchecked((&idx.into_inner()).into()) }
+    }
+
+    pub fn shift(&self, periods: i64) -> Self {
+        let len = self.len();
+        let n = std::cmp::min(periods.unsigned_abs() as usize, len);
+        let nulls = std::iter::repeat(None).take(n);
+        let values: Vec<_> = if periods >= 0 {
+            let kept = self.slice(0, len - n);
+            nulls.chain(kept.into_iter()).collect()
+        } else {
+            let kept = self.slice(n as i64, len - n);
+            kept.into_iter().chain(nulls).collect()
+        };
+        Self::from_values(self.name(), values, self.precision(), self.scale())
+    }
+
+    pub fn expand_at_index(&self, index: usize, length: usize) -> Self {
+        let v = self.get(index);
+        Self::from_values(
+            self.name(),
+            std::iter::repeat(v).take(length),
+            self.precision(),
+            self.scale(),
+        )
+    }
+
+    /// Nulls are sorted first, or last when `reverse` is set.
+    pub fn argsort(&self, reverse: bool) -> UInt32Chunked {
+        let mut vals = self.into_iter().enumerate().collect::<Vec<_>>();
+        if reverse {
+            vals.sort_by(|a, b| b.1.cmp(&a.1));
+        } else {
+            vals.sort_by(|a, b| a.1.cmp(&b.1));
+        }
+        let mut ca: NoNull<UInt32Chunked> = vals.into_iter().map(|(idx, _)| idx as u32).collect();
+        ca.rename(self.name());
+        ca.into_inner()
+    }
+
+    pub fn sort(&self, reverse: bool) -> Self {
+        // Safety: argsort only returns indices that are in bounds
+        unsafe { self.take_unchecked((&self.argsort(reverse)).into()) }
+    }
+
+    /// Indices of the first occurrence of every unique value.
+    pub fn arg_unique(&self) -> UInt32Chunked {
+        let mut seen = HashSet::with_capacity(self.len());
+        let mut ca: NoNull<UInt32Chunked> = self
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, opt_v)| seen.insert(opt_v).then(|| idx as u32))
+            .collect();
+        ca.rename(self.name());
+        ca.into_inner()
+    }
+
+    pub fn unique(&self) -> Self {
+        // Safety: arg_unique only returns indices that are in bounds
+        unsafe { self.take_unchecked((&self.arg_unique()).into()) }
+    }
+
+    pub fn n_unique(&self) -> usize {
+        self.into_iter().collect::<HashSet<_>>().len()
+    }
+
+    /// The exact sum. Returns `None` if all values are null and an error if the sum doesn't
+    /// fit the maximum precision.
+    pub fn sum(&self) -> Result<Option<i128>> {
+        sum_opt_iter(self.into_iter())
+    }
+
+    pub fn mean(&self) -> Result<Option<f64>> {
+        let count = self.len() - self.null_count();
+        Ok(self
+            .sum()?
+            .map(|sum| to_f64(sum, self.scale()) / count as f64))
+    }
+
+    /// Get a new array with the values scaled to `scale`. Values that don't fit the
+    /// precision become null, a lower scale truncates the fractional digits.
+    fn rescale(&self, precision: usize, scale: usize) -> Self {
+        let current = self.scale();
+        let iter = self.into_iter().map(|opt_v| {
+            opt_v
+                .and_then(|v| {
+                    if scale >= current {
+                        v.checked_mul(pow10(scale - current))
+                    } else {
+                        Some(v / pow10(current - scale))
+                    }
+                })
+                .filter(|v| fits_precision(*v, precision))
+        });
+        Self::from_values(self.name(), iter, precision, scale)
+    }
+
+    fn to_float(&self) -> Float64Chunked {
+        let scale = self.scale();
+        let mut ca: Float64Chunked = self
+            .into_iter()
+            .map(|opt_v| opt_v.map(|v| to_f64(v, scale)))
+            .collect();
+        ca.rename(self.name());
+        ca
+    }
+
+    pub fn cast(&self, data_type: &DataType) -> Result<Series> {
+        use DataType::*;
+        match data_type {
+            Decimal(precision, scale) => {
+                check_precision(*precision, *scale)?;
+                Ok(self.rescale(*precision, *scale).into_series())
+            }
+            Float32 | Float64 => self.to_float().cast(data_type),
+            Utf8 => {
+                let scale = self.scale();
+                let mut ca: Utf8Chunked = self
+                    .into_iter()
+                    .map(|opt_v| opt_v.map(|v| format_decimal(v, scale)))
+                    .collect();
+                ca.rename(self.name());
+                Ok(ca.into_series())
+            }
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 => {
+                // the fractional digits are truncated
+                let factor = pow10(self.scale());
+                let mut ca: Int64Chunked = self
+                    .into_iter()
+                    .map(|opt_v| opt_v.and_then(|v| (v / factor).to_i64()))
+                    .collect();
+                ca.rename(self.name());
+                ca.cast(data_type)
+            }
+            _ => Err(PolarsError::InvalidOperation(
+                format!("cannot cast {:?} to {:?}", self.dtype(), data_type).into(),
+            )),
+        }
+    }
+
+    fn arithmetic<F>(
+        &self,
+        rhs: &DecimalChunked,
+        precision: usize,
+        scale: usize,
+        op: F,
+    ) -> Result<Self>
+    where
+        F: Fn(i128, i128) -> Option<i128>,
+    {
+        let apply = |l: Option<i128>, r: Option<i128>| -> Result<Option<i128>> {
+            match (l, r) {
+                (Some(l), Some(r)) => match op(l, r) {
+                    Some(v) if fits_precision(v, precision) => Ok(Some(v)),
+                    _ => Err(PolarsError::ComputeError(
+                        format!(
+                            "decimal overflow; result doesn't fit decimal[{},{}]",
+                            precision, scale
+                        )
+                        .into(),
+                    )),
+                },
+                _ => Ok(None),
+            }
+        };
+
+        let values = if rhs.len() == 1 {
+            let r = rhs.get(0);
+            self.into_iter()
+                .map(|l| apply(l, r))
+                .collect::<Result<Vec<_>>>()?
+        } else if self.len() == 1 {
+            let l = self.get(0);
+            rhs.into_iter()
+                .map(|r| apply(l, r))
+                .collect::<Result<Vec<_>>>()?
+        } else if self.len() == rhs.len() {
+            self.into_iter()
+                .zip(rhs.into_iter())
+                .map(|(l, r)| apply(l, r))
+                .collect::<Result<Vec<_>>>()?
+        } else {
+            return Err(PolarsError::ShapeMisMatch(
+                "cannot apply arithmetic on decimals of different lengths".into(),
+            ));
+        };
+        Ok(Self::from_values(self.name(), values, precision, scale))
+    }
+
+    /// Bring both arrays to the largest scale of the two.
+    pub(crate) fn align_scales(&self, rhs: &DecimalChunked) -> Result<(Self, Self)> {
+        let scale = std::cmp::max(self.scale(), rhs.scale());
+        let rescale = |ca: &DecimalChunked| {
+            let precision =
+                std::cmp::min(ca.precision() + scale - ca.scale(), MAX_DECIMAL_PRECISION);
+            let out = ca.rescale(precision, scale);
+            if out.null_count() == ca.null_count() {
+                Ok(out)
+            } else {
+                Err(PolarsError::ComputeError(
+                    format!(
+                        "decimal overflow; cannot rescale {:?} to scale {}",
+                        ca.name(),
+                        scale
+                    )
+                    .into(),
+                ))
+            }
+        };
+        Ok((rescale(self)?, rescale(rhs)?))
+    }
+
+    /// Exact addition. The result has the largest scale of both arrays.
+    pub fn add(&self, rhs: &DecimalChunked) -> Result<Self> {
+        let (lhs, rhs) = self.align_scales(rhs)?;
+        let scale = lhs.scale();
+        let precision = std::cmp::min(
+            std::cmp::max(lhs.precision(), rhs.precision()) + 1,
+            MAX_DECIMAL_PRECISION,
+        );
+        lhs.arithmetic(&rhs, precision, scale, i128::checked_add)
+    }
+
+    /// Exact subtraction. The result has the largest scale of both arrays.
+    pub fn sub(&self, rhs: &DecimalChunked) -> Result<Self> {
+        let (lhs, rhs) = self.align_scales(rhs)?;
+        let scale = lhs.scale();
+        let precision = std::cmp::min(
+            std::cmp::max(lhs.precision(), rhs.precision()) + 1,
+            MAX_DECIMAL_PRECISION,
+        );
+        lhs.arithmetic(&rhs, precision, scale, i128::checked_sub)
+    }
+
+    /// Exact multiplication. The scale of the result is the sum of both scales.
+    pub fn mul(&self, rhs: &DecimalChunked) -> Result<Self> {
+        let scale = self.scale() + rhs.scale();
+        let precision = std::cmp::min(self.precision() + rhs.precision(), MAX_DECIMAL_PRECISION);
+        check_precision(precision, scale)?;
+        self.arithmetic(rhs, precision, scale, i128::checked_mul)
+    }
+}
+
+/// Iterate over the scaled integers.
+impl<'a> IntoIterator for &'a DecimalChunked {
+    type Item = Option<i128>;
+    type IntoIter = Box<dyn Iterator<Item = Option<i128>> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(
+            self.downcast_iter()
+                .flat_map(|arr| arr.iter().map(|opt_v| opt_v.copied())),
+        )
+    }
+}
+
+#[inline]
+pub(crate) fn to_f64(v: i128, scale: usize) -> f64 {
+    v as f64 / 10f64.powi(scale as i32)
+}
+
+/// Returns `None` if all values are null and an error if the sum doesn't fit the maximum
+/// precision.
+pub(crate) fn sum_opt_iter<I: Iterator<Item = Option<i128>>>(iter: I) -> Result<Option<i128>> {
+    let mut sum = None;
+    for v in iter.flatten() {
+        match sum.unwrap_or(0i128).checked_add(v) {
+            Some(v) if fits_precision(v, MAX_DECIMAL_PRECISION) => sum = Some(v),
+            _ => {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "decimal overflow; sum doesn't fit {} digits",
+                        MAX_DECIMAL_PRECISION
+                    )
+                    .into(),
+                ))
+            }
+        }
+    }
+    Ok(sum)
+}
+
+impl ChunkTake for DecimalChunked {
+    unsafe fn take_unchecked<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Self
+    where
+        Self: std::marker::Sized,
+        I: TakeIterator,
+        INulls: TakeIteratorNulls,
+    {
+        // there is no random access over many chunks, so we take from a single chunk
+        if self.chunks.len() > 1 {
+            return self.rechunk().take_unchecked(indices);
+        }
+        match indices {
+            TakeIdx::Array(array) => {
+                if self.is_empty() {
+                    let nulls = std::iter::repeat(None).take(array.len());
+                    return Self::from_values(self.name(), nulls, self.precision(), self.scale());
+                }
+                let arr = take(self.chunks[0].as_ref(), array).unwrap();
+                self.copy_with_chunks(vec![arr.into()])
+            }
+            TakeIdx::Iter(iter) => {
+                let idx: NoNull<UInt32Chunked> = iter.map(|v| v as u32).collect();
+                self.take_unchecked((&idx.into_inner()).into())
+            }
+            TakeIdx::IterNulls(iter) => {
+                let idx: UInt32Chunked = iter.map(|v| v.map(|v| v as u32)).collect();
+                self.take_unchecked((&idx).into())
+            }
+        }
+    }
+
+    fn take<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Result<Self>
+    where
+        Self: std::marker::Sized,
+        I: TakeIterator,
+        INulls: TakeIteratorNulls,
+    {
+        indices.check_bounds(self.len())?;
+        // Safety:
+        // just checked bounds
+        Ok(unsafe { self.take_unchecked(indices) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn values(ca: &DecimalChunked) -> Vec<Option<i128>> {
+        ca.into_iter().collect()
+    }
+
+    #[test]
+    fn test_decimal_parse_format() {
+        assert_eq!(parse_decimal("12.5", 2), Some(1250));
+        assert_eq!(parse_decimal("-0.129", 2), Some(-12));
+        assert_eq!(parse_decimal("+3", 1), Some(30));
+        assert_eq!(parse_decimal(".5", 1), Some(5));
+        assert_eq!(parse_decimal("1.2.3", 1), None);
+        assert_eq!(parse_decimal("-", 1), None);
+        assert_eq!(parse_decimal("abc", 1), None);
+
+        assert_eq!(format_decimal(1250, 2), "12.50");
+        assert_eq!(format_decimal(-5, 2), "-0.05");
+        assert_eq!(format_decimal(42, 0), "42");
+    }
+
+    #[test]
+    fn test_decimal_arithmetic() -> Result<()> {
+        let a = DecimalChunked::new_from_opt_slice("a", &[Some(125), None, Some(-300)], 5, 2)?;
+        let b = DecimalChunked::new_from_opt_slice("b", &[Some(5), Some(1), Some(1)], 3, 1)?;
+
+        let out = a.add(&b)?;
+        assert_eq!(out.dtype(), &DataType::Decimal(6, 2));
+        assert_eq!(values(&out), &[Some(175), None, Some(-290)]);
+
+        let out = a.sub(&b)?;
+        assert_eq!(values(&out), &[Some(75), None, Some(-310)]);
+
+        let out = a.mul(&b)?;
+        assert_eq!(out.dtype(), &DataType::Decimal(8, 3));
+        assert_eq!(values(&out), &[Some(625), None, Some(-300)]);
+
+        // broadcasting
+        let one = DecimalChunked::new_from_opt_slice("one", &[Some(100)], 3, 2)?;
+        let out = a.add(&one)?;
+        assert_eq!(values(&out), &[Some(225), None, Some(-200)]);
+
+        let max = DecimalChunked::new_from_opt_slice("max", &[Some(pow10(38) - 1)], 38, 0)?;
+        assert!(max.add(&max).is_err());
+        assert!(DecimalChunked::new_from_opt_slice("a", &[Some(1000)], 3, 0).is_err());
+        assert!(DecimalChunked::new_from_opt_slice("a", &[Some(1)], 39, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_sum_mean_cast() -> Result<()> {
+        let ca = DecimalChunked::new_from_opt_slice("a", &[Some(125), None, Some(250)], 5, 2)?;
+        assert_eq!(ca.sum()?, Some(375));
+        assert_eq!(ca.mean()?, Some(1.875));
+
+        // the sum doesn't fit 38 digits
+        let max = DecimalChunked::new_from_opt_slice("max", &[Some(pow10(38) - 1); 2], 38, 0)?;
+        assert!(max.sum().is_err());
+        assert!(max.mean().is_err());
+        let groups = vec![(0, vec![0]), (0, vec![0, 1])];
+        assert!(max.agg_sum(&groups).is_err());
+        assert!(max.into_series().try_sum_as_series().is_err());
+
+        let out = ca.cast(&DataType::Utf8)?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("1.25"), None, Some("2.50")]);
+        let out = ca.cast(&DataType::Float64)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(1.25), None, Some(2.5)]);
+        let out = ca.cast(&DataType::Int32)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), None, Some(2)]);
+
+        let out = ca.cast(&DataType::Decimal(6, 3))?;
+        assert_eq!(values(out.decimal()?), &[Some(1250), None, Some(2500)]);
+        assert!(ca.cast(&DataType::Boolean).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use super::*;
+use crate::prelude::*;
+
+pub type DurationChunked = Logical<DurationType, Int64Type>;
+
+impl From<Int64Chunked> for DurationChunked {
+    fn from(ca: Int64Chunked) -> Self {
+        ca.into_duration(TimeUnit::Milliseconds)
+    }
+}
+
+impl Int64Chunked {
+    pub fn into_duration(self, tu: TimeUnit) -> DurationChunked {
+        let mut dt = DurationChunked::new(self);
+        dt.2 = Some(DataType::Duration(tu));
+        dt
+    }
+}
+
+impl LogicalType for DurationChunked {
+    fn dtype(&self) -> &DataType {
+        self.2.as_ref().unwrap()
+    }
+
+    #[cfg(feature = "dtype-duration")]
+    fn get_any_value(&self, i: usize) -> AnyValue<'_> {
+        self.0.get_any_value(i).into_duration(self.time_unit())
+    }
+}
+
+impl DurationChunked {
+    /// The unit of the underlying integers.
+    pub fn time_unit(&self) -> TimeUnit {
+        match self.2.as_ref().unwrap() {
+            DataType::Duration(tu) => *tu,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Convert the values to another [`TimeUnit`]. Going to a coarser unit truncates.
+    pub fn cast_time_unit(&self, tu: TimeUnit) -> Self {
+        convert_time_unit(&self.0, self.time_unit(), tu).into_duration(tu)
+    }
+}
@@ -1,8 +1,17 @@
 mod date;
 mod datetime;
+#[cfg(feature = "dtype-decimal")]
+mod decimal;
+mod duration;
+#[cfg(feature = "dtype-struct")]
+mod struct_;
 mod time;
 
-pub use {date::*, datetime::*, time::*};
+#[cfg(feature = "dtype-decimal")]
+pub use decimal::*;
+#[cfg(feature = "dtype-struct")]
+pub use struct_::*;
+pub use {date::*, datetime::*, duration::*, time::*};
 
 use crate::prelude::*;
 use std::marker::PhantomData;
@@ -10,11 +19,18 @@ use std::ops::{Deref, DerefMut};
 
 /// Maps a logical type to a a chunked array implementation of the physical type.
 /// This saves a lot of compiler bloat and allows us to reuse functionality.
-pub struct Logical<K: PolarsDataType, T: PolarsDataType>(pub ChunkedArray<T>, PhantomData<K>);
+///
+/// The last field stores the full data type for logical types that carry metadata,
+/// e.g. the time zone of a `Datetime`.
+pub struct Logical<K: PolarsDataType, T: PolarsDataType>(
+    pub ChunkedArray<T>,
+    PhantomData<K>,
+    pub Option<DataType>,
+);
 
 impl<K: PolarsDataType, T: PolarsDataType> Clone for Logical<K, T> {
     fn clone(&self) -> Self {
-        Logical::<K, _>::new(self.0.clone())
+        Logical(self.0.clone(), PhantomData, self.2.clone())
     }
 }
 
@@ -34,13 +50,13 @@ impl<K: PolarsDataType, T: PolarsDataType> DerefMut for Logical<K, T> {
 
 impl<K: PolarsDataType, T: PolarsDataType> Logical<K, T> {
     pub fn new<J: PolarsDataType>(ca: ChunkedArray<T>) -> Logical<J, T> {
-        Logical(ca, PhantomData)
+        Logical(ca, PhantomData, None)
     }
 }
 
 pub trait LogicalType {
     /// Get data type of ChunkedArray.
-    fn dtype(&self) -> &'static DataType;
+    fn dtype(&self) -> &DataType;
 
     fn get_any_value(&self, _i: usize) -> AnyValue<'_> {
         unimplemented!()
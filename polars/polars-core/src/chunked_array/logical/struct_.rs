@@ -0,0 +1,387 @@
+use crate::chunked_array::ChunkIdIter;
+use crate::prelude::*;
+
+/// A collection of equally long [`Series`] grouped under a single name.
+///
+/// The fields are kept as separate `Series`; the Arrow `StructArray` chunks are assembled from
+/// them so that the column can be handed to Arrow based readers and writers.
+///
+/// A struct row can be null as a whole. The fields still have a (usually null) value at the
+/// positions of the null rows.
+#[derive(Clone)]
+pub struct StructChunked {
+    fields: Vec<Series>,
+    field: Field,
+    /// The valid rows; `None` if all rows are valid.
+    validity: Option<BooleanChunked>,
+    chunks: Vec<ArrayRef>,
+}
+
+impl StructChunked {
+    /// Create a new `StructChunked` from `Series` with equal lengths and unique names.
+    pub fn new(name: &str, fields: &[Series]) -> Result<Self> {
+        // the same invariants as the columns of a DataFrame
+        let df = DataFrame::new(fields.to_vec())?;
+        Ok(Self::new_unchecked(name, df.columns))
+    }
+
+    pub(crate) fn new_unchecked(name: &str, fields: Vec<Series>) -> Self {
+        Self::new_with_validity_unchecked(name, fields, None)
+    }
+
+    /// Mark the rows where `validity` is `false` or null as null rows.
+    pub fn with_validity(self, validity: Option<BooleanChunked>) -> Result<Self> {
+        if let Some(validity) = &validity {
+            if validity.len() != self.len() {
+                return Err(PolarsError::ShapeMisMatch(
+                    "the validity of a struct must have the length of its fields".into(),
+                ));
+            }
+        }
+        let name = self.name().to_string();
+        Ok(Self::new_with_validity_unchecked(
+            &name,
+            self.fields,
+            validity,
+        ))
+    }
+
+    fn new_with_validity_unchecked(
+        name: &str,
+        fields: Vec<Series>,
+        validity: Option<BooleanChunked>,
+    ) -> Self {
+        let dtype = DataType::Struct(fields.iter().map(|s| s.field().into_owned()).collect());
+        // a null in the validity, e.g. introduced by a shift, is a null row
+        let validity = validity
+            .filter(|v| v.into_iter().any(|valid| valid != Some(true)))
+            .map(|v| {
+                v.into_iter()
+                    .map(|valid| valid == Some(true))
+                    .collect::<BooleanChunked>()
+            });
+        let mut out = StructChunked {
+            fields,
+            field: Field::new(name, dtype),
+            validity,
+            chunks: vec![],
+        };
+        out.update_chunks();
+        out
+    }
+
+    /// Assemble the Arrow chunks from the fields and the validity. The fields are rechunked
+    /// if their chunk boundaries don't line up.
+    fn update_chunks(&mut self) {
+        let aligned = match self.fields.first() {
+            // the validity always has a single chunk
+            Some(first) if self.validity.is_some() => first.n_chunks() == 1,
+            Some(first) => self
+                .fields
+                .iter()
+                .all(|s| s.chunk_lengths().eq(first.chunk_lengths())),
+            None => true,
+        };
+        if !aligned {
+            self.fields = self.fields.iter().map(|s| s.rechunk()).collect();
+        }
+
+        let arrow_fields = self
+            .fields
+            .iter()
+            .map(|s| s.field().to_arrow())
+            .collect::<Vec<_>>();
+        let n_chunks = self.fields.first().map(|s| s.n_chunks()).unwrap_or(0);
+
+        let validity = self
+            .validity
+            .as_ref()
+            .map(|v| v.downcast_iter().next().unwrap().values().clone());
+
+        self.chunks = (0..n_chunks)
+            .map(|chunk_idx| {
+                let values = self.fields.iter().map(|s| s.to_arrow(chunk_idx)).collect();
+                Arc::new(StructArray::from_data(
+                    arrow_fields.clone(),
+                    values,
+                    validity.clone(),
+                )) as ArrayRef
+            })
+            .collect();
+    }
+
+    /// The valid rows; `None` if all rows are valid.
+    pub fn validity(&self) -> Option<&BooleanChunked> {
+        self.validity.as_ref()
+    }
+
+    /// The number of null rows.
+    pub fn null_count(&self) -> usize {
+        self.validity
+            .as_ref()
+            .map(|v| v.len() - v.into_no_null_iter().filter(|valid| *valid).count())
+            .unwrap_or(0)
+    }
+
+    /// Whether the rows are valid, i.e. not null.
+    pub fn is_not_null(&self) -> BooleanChunked {
+        let mut out = match &self.validity {
+            Some(validity) => validity.clone(),
+            None => BooleanChunked::full("", true, self.len()),
+        };
+        out.rename(self.name());
+        out
+    }
+
+    /// Get the fields of this struct.
+    pub fn fields(&self) -> &[Series] {
+        &self.fields
+    }
+
+    /// Get a field of this struct by name.
+    pub fn field_by_name(&self, name: &str) -> Result<Series> {
+        self.fields
+            .iter()
+            .find(|s| s.name() == name)
+            .cloned()
+            .ok_or_else(|| PolarsError::NotFound(name.into()))
+    }
+
+    /// Convert the fields of this struct to the columns of a [`DataFrame`].
+    pub fn unnest(self) -> DataFrame {
+        DataFrame::new_no_checks(self.fields)
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields.first().map(|s| s.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn name(&self) -> &str {
+        self.field.name()
+    }
+
+    pub fn rename(&mut self, name: &str) {
+        self.field = Field::new(name, self.field.data_type().clone())
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        self.field.data_type()
+    }
+
+    pub fn ref_field(&self) -> &Field {
+        &self.field
+    }
+
+    pub fn chunks(&self) -> &Vec<ArrayRef> {
+        &self.chunks
+    }
+
+    pub fn chunk_id(&self) -> ChunkIdIter {
+        self.chunks.iter().map(|chunk| chunk.len())
+    }
+
+    /// Append the fields of `other` to the fields of `self`.
+    pub fn append(&mut self, other: &StructChunked) -> Result<()> {
+        if self.dtype() != other.dtype() {
+            return Err(PolarsError::DataTypeMisMatch(
+                "cannot append Series; struct fields don't match".into(),
+            ));
+        }
+        let validity = match (&self.validity, &other.validity) {
+            (None, None) => None,
+            _ => {
+                let mut validity = self.is_not_null();
+                validity.append(&other.is_not_null());
+                Some(validity)
+            }
+        };
+        let mut fields = self.fields.clone();
+        for (lhs, rhs) in fields.iter_mut().zip(other.fields()) {
+            lhs.append(rhs)?;
+        }
+        *self = Self::new_with_validity_unchecked(self.name(), fields, validity);
+        Ok(())
+    }
+
+    /// Apply a function that selects or moves rows, e.g. a `take` or a `slice`, to every field
+    /// and to the validity, and collect the results in a new struct.
+    pub(crate) fn apply_fields<F>(&self, mut func: F) -> Self
+    where
+        F: FnMut(&Series) -> Series,
+    {
+        let fields = self.fields.iter().map(&mut func).collect();
+        let validity = self
+            .validity
+            .as_ref()
+            .map(|v| func(&v.clone().into_series()).bool().unwrap().clone());
+        Self::new_with_validity_unchecked(self.name(), fields, validity)
+    }
+
+    /// Apply a fallible function that selects or moves rows to every field and to the
+    /// validity, and collect the results in a new struct.
+    pub(crate) fn try_apply_fields<F>(&self, mut func: F) -> Result<Self>
+    where
+        F: FnMut(&Series) -> Result<Series>,
+    {
+        let fields = self.fields.iter().map(&mut func).collect::<Result<_>>()?;
+        let validity = match &self.validity {
+            Some(v) => Some(func(&v.clone().into_series())?.bool()?.clone()),
+            None => None,
+        };
+        Ok(Self::new_with_validity_unchecked(
+            self.name(),
+            fields,
+            validity,
+        ))
+    }
+
+    /// Replace the fields by fields of the same length. The validity is kept.
+    pub(crate) fn with_fields(&self, fields: Vec<Series>) -> Self {
+        Self::new_with_validity_unchecked(self.name(), fields, self.validity.clone())
+    }
+
+    fn struct_fields(&self) -> &[Field] {
+        match self.dtype() {
+            DataType::Struct(fields) => fields,
+            _ => unreachable!(),
+        }
+    }
+
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity
+            .as_ref()
+            .map(|v| v.get(index) == Some(true))
+            .unwrap_or(true)
+    }
+
+    pub fn get_any_value(&self, index: usize) -> AnyValue {
+        if !self.is_valid(index) {
+            return AnyValue::Null;
+        }
+        let values = self.fields.iter().map(|s| s.get(index)).collect();
+        AnyValue::Struct(values, self.struct_fields())
+    }
+
+    /// Get a single value. Does not do any bounds checking.
+    ///
+    /// # Safety
+    /// `index` must be in bounds.
+    pub unsafe fn get_any_value_unchecked(&self, index: usize) -> AnyValue {
+        if !self.is_valid(index) {
+            return AnyValue::Null;
+        }
+        let values = self.fields.iter().map(|s| s.get_unchecked(index)).collect();
+        AnyValue::Struct(values, self.struct_fields())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_struct_new() -> Result<()> {
+        let a = Series::new("a", &[1, 2, 3]);
+        let b = Series::new("b", &["foo", "bar", "ham"]);
+        let ca = StructChunked::new("s", &[a.clone(), b.clone()])?;
+
+        assert_eq!(ca.len(), 3);
+        assert_eq!(
+            ca.dtype(),
+            &DataType::Struct(vec![
+                Field::new("a", DataType::Int32),
+                Field::new("b", DataType::Utf8)
+            ])
+        );
+        assert!(ca.field_by_name("b")?.series_equal(&b));
+        assert!(ca.field_by_name("c").is_err());
+
+        let df = ca.unnest();
+        assert_eq!(df.get_column_names(), &["a", "b"]);
+        assert!(df.column("a")?.series_equal(&a));
+
+        // fields must have the same length and unique names
+        let c = Series::new("c", &[1, 2]);
+        assert!(StructChunked::new("s", &[a.clone(), c]).is_err());
+        assert!(StructChunked::new("s", &[a.clone(), a]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_series_ops() -> Result<()> {
+        let a = Series::new("a", &[Some(1), None, Some(3)]);
+        let b = Series::new("b", &["foo", "bar", "ham"]);
+        let s = StructChunked::new("s", &[a, b])?.into_series();
+
+        assert_eq!(s.dtype().to_string(), "struct[2]");
+        assert_eq!(s.get(0).to_string(), "{1,\"foo\"}");
+        assert_eq!(s.get(1).to_string(), "{null,\"bar\"}");
+
+        let out = s.slice(1, 2);
+        assert_eq!(out.len(), 2);
+        assert_eq!(out.get(0).to_string(), "{null,\"bar\"}");
+
+        let mask = BooleanChunked::new_from_slice("", &[true, false, true]);
+        let out = s.filter(&mask)?;
+        let ca = out.struct_()?;
+        assert_eq!(
+            Vec::from(ca.field_by_name("a")?.i32()?),
+            &[Some(1), Some(3)]
+        );
+
+        let mut appended = s.clone();
+        appended.append(&s)?;
+        assert_eq!(appended.len(), 6);
+        assert_eq!(appended.n_chunks(), appended.struct_()?.chunks().len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_null_rows() -> Result<()> {
+        let a = Series::new("a", &[1, 2, 3]);
+        let b = Series::new("b", &["foo", "bar", "ham"]);
+        let validity = BooleanChunked::new_from_slice("", &[true, false, true]);
+        let s = StructChunked::new("s", &[a.clone(), b.clone()])?
+            .with_validity(Some(validity))?
+            .into_series();
+
+        assert_eq!(s.null_count(), 1);
+        assert_eq!(s.get(1), AnyValue::Null);
+        assert_eq!(
+            Vec::from(&s.is_null()),
+            &[Some(false), Some(true), Some(false)]
+        );
+        assert_eq!(s.chunks()[0].null_count(), 1);
+
+        // the null row moves with the fields
+        assert_eq!(s.slice(1, 2).null_count(), 1);
+        assert_eq!(s.reverse().get(1), AnyValue::Null);
+        let mask = BooleanChunked::new_from_slice("", &[true, false, true]);
+        assert_eq!(s.filter(&mask)?.null_count(), 0);
+
+        let valid = StructChunked::new("s", &[a, b])?.into_series();
+        let mut appended = valid.clone();
+        appended.append(&s)?;
+        assert_eq!(appended.null_count(), 1);
+        assert_eq!(appended.get(4), AnyValue::Null);
+
+        // the validity must have the length of the fields
+        let validity = BooleanChunked::new_from_slice("", &[true]);
+        assert!(valid
+            .struct_()?
+            .clone()
+            .with_validity(Some(validity))
+            .is_err());
+
+        // arrow arrays keep the validity
+        let out = Series::try_from(("s", s.chunks().clone()))?;
+        assert!(out.series_equal_missing(&s));
+        assert_eq!(out.null_count(), 1);
+        Ok(())
+    }
+}
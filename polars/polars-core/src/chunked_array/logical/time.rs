@@ -16,7 +16,7 @@ impl Int64Chunked {
 }
 
 impl LogicalType for TimeChunked {
-    fn dtype(&self) -> &'static DataType {
+    fn dtype(&self) -> &DataType {
         &DataType::Time
     }
 
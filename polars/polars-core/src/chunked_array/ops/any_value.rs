@@ -11,7 +11,7 @@ unsafe fn arr_to_any_value<'a>(
     arr: &'a dyn Array,
     idx: usize,
     categorical_map: &'a Option<Arc<RevMapping>>,
-    dtype: &DataType,
+    dtype: &'a DataType,
 ) -> AnyValue<'a> {
     if arr.is_null(idx) {
         return AnyValue::Null;
@@ -33,6 +33,8 @@ unsafe fn arr_to_any_value<'a>(
     // TODO: insert types
     match dtype {
         DataType::Utf8 => downcast_and_pack!(LargeStringArray, Utf8),
+        #[cfg(feature = "dtype-binary")]
+        DataType::Binary => downcast_and_pack!(LargeBinaryArray, Binary),
         DataType::Boolean => downcast_and_pack!(BooleanArray, Boolean),
         DataType::UInt8 => downcast_and_pack!(UInt8Array, UInt8),
         DataType::UInt16 => downcast_and_pack!(UInt16Array, UInt16),
@@ -47,7 +49,9 @@ unsafe fn arr_to_any_value<'a>(
         #[cfg(feature = "dtype-date")]
         DataType::Date => downcast_and_pack!(Int32Array, Date),
         #[cfg(feature = "dtype-datetime")]
-        DataType::Datetime => downcast_and_pack!(Int64Array, Datetime),
+        DataType::Datetime(tu, tz) => AnyValue::Datetime(downcast!(Int64Array), *tu, tz),
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(tu) => AnyValue::Duration(downcast!(Int64Array), *tu),
         DataType::List(dt) => {
             let v: ArrayRef = downcast!(LargeListArray).into();
             let mut s = Series::try_from(("", v)).unwrap();
@@ -129,6 +133,18 @@ impl ChunkAnyValue for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkAnyValue for BinaryChunked {
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, index: usize) -> AnyValue {
+        get_any_value_unchecked!(self, index)
+    }
+
+    fn get_any_value(&self, index: usize) -> AnyValue {
+        get_any_value!(self, index)
+    }
+}
+
 impl ChunkAnyValue for ListChunked {
     #[inline]
     unsafe fn get_any_value_unchecked(&self, index: usize) -> AnyValue {
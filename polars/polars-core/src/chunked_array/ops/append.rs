@@ -33,6 +33,14 @@ impl Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl BinaryChunked {
+    pub fn append(&mut self, other: &Self) {
+        let len = self.len();
+        new_chunks(&mut self.chunks, &other.chunks, len);
+    }
+}
+
 impl ListChunked {
     pub fn append(&mut self, other: &Self) {
         let len = self.len();
@@ -2,6 +2,8 @@
 //! Used to speed up PartialEq and PartialOrd of elements within an array
 //!
 
+#[cfg(feature = "dtype-binary")]
+use crate::chunked_array::ops::take::take_random::{BinaryTakeRandom, BinaryTakeRandomSingleChunk};
 use crate::chunked_array::ops::take::take_random::{
     BoolTakeRandom, BoolTakeRandomSingleChunk, NumTakeRandomChunked, NumTakeRandomCont,
     NumTakeRandomSingleChunk, Utf8TakeRandom, Utf8TakeRandomSingleChunk,
@@ -68,6 +70,10 @@ macro_rules! impl_traits {
 
 impl_traits!(Utf8TakeRandom<'_>);
 impl_traits!(Utf8TakeRandomSingleChunk<'_>);
+#[cfg(feature = "dtype-binary")]
+impl_traits!(BinaryTakeRandom<'_>);
+#[cfg(feature = "dtype-binary")]
+impl_traits!(BinaryTakeRandomSingleChunk<'_>);
 impl_traits!(BoolTakeRandom<'_>);
 impl_traits!(BoolTakeRandomSingleChunk<'_>);
 impl_traits!(NumTakeRandomSingleChunk<'_, T>, T);
@@ -139,6 +145,27 @@ impl<'a> IntoPartialEqInner<'a> for &'a Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl<'a> IntoPartialEqInner<'a> for &'a BinaryChunked {
+    fn into_partial_eq_inner(self) -> Box<dyn PartialEqInner + 'a> {
+        match self.chunks.len() {
+            1 => {
+                let arr = self.downcast_iter().next().unwrap();
+                let t = BinaryTakeRandomSingleChunk { arr };
+                Box::new(t)
+            }
+            _ => {
+                let chunks = self.downcast_chunks();
+                let t = BinaryTakeRandom {
+                    chunks,
+                    chunk_lens: self.chunks.iter().map(|a| a.len() as u32).collect(),
+                };
+                Box::new(t)
+            }
+        }
+    }
+}
+
 impl<'a> IntoPartialEqInner<'a> for &'a BooleanChunked {
     fn into_partial_eq_inner(self) -> Box<dyn PartialEqInner + 'a> {
         match self.chunks.len() {
@@ -252,6 +279,27 @@ impl<'a> IntoPartialOrdInner<'a> for &'a Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl<'a> IntoPartialOrdInner<'a> for &'a BinaryChunked {
+    fn into_partial_ord_inner(self) -> Box<dyn PartialOrdInner + 'a> {
+        match self.chunks.len() {
+            1 => {
+                let arr = self.downcast_iter().next().unwrap();
+                let t = BinaryTakeRandomSingleChunk { arr };
+                Box::new(t)
+            }
+            _ => {
+                let chunks = self.downcast_chunks();
+                let t = BinaryTakeRandom {
+                    chunks,
+                    chunk_lens: self.chunks.iter().map(|a| a.len() as u32).collect(),
+                };
+                Box::new(t)
+            }
+        }
+    }
+}
+
 impl<'a> IntoPartialOrdInner<'a> for &'a BooleanChunked {
     fn into_partial_ord_inner(self) -> Box<dyn PartialOrdInner + 'a> {
         match self.chunks.len() {
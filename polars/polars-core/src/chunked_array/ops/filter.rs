@@ -93,6 +93,28 @@ impl ChunkFilter<Utf8Type> for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkFilter<BinaryType> for BinaryChunked {
+    fn filter(&self, filter: &BooleanChunked) -> Result<ChunkedArray<BinaryType>> {
+        // broadcast
+        if filter.len() == 1 {
+            return match filter.get(0) {
+                Some(true) => Ok(self.clone()),
+                _ => Ok(self.slice(0, 0)),
+            };
+        }
+        check_filter_len!(self, filter);
+        let (left, filter) = align_chunks_binary(self, filter);
+
+        let chunks = left
+            .downcast_iter()
+            .zip(filter.downcast_iter())
+            .map(|(left, mask)| filter_fn(left, mask).unwrap().into())
+            .collect::<Vec<_>>();
+        Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
+    }
+}
+
 #[cfg(feature = "dtype-categorical")]
 impl ChunkFilter<CategoricalType> for CategoricalChunked {
     fn filter(&self, filter: &BooleanChunked) -> Result<ChunkedArray<CategoricalType>>
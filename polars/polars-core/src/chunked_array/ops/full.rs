@@ -63,6 +63,29 @@ impl ChunkFullNull for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl<'a> ChunkFull<&'a [u8]> for BinaryChunked {
+    fn full(name: &str, value: &'a [u8], length: usize) -> Self {
+        let mut builder = BinaryChunkedBuilder::new(name, length);
+
+        for _ in 0..length {
+            builder.append_value(value);
+        }
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "dtype-binary")]
+impl ChunkFullNull for BinaryChunked {
+    fn full_null(name: &str, length: usize) -> Self {
+        let mut ca = (0..length)
+            .map::<Option<Vec<u8>>, _>(|_| None)
+            .collect::<Self>();
+        ca.rename(name);
+        ca
+    }
+}
+
 impl ChunkFull<&Series> for ListChunked {
     fn full(name: &str, value: &Series, length: usize) -> ListChunked {
         let mut builder = get_list_builder(value.dtype(), value.len() * length, length, name);
@@ -146,6 +146,52 @@ impl IsIn for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl IsIn for BinaryChunked {
+    fn is_in(&self, other: &Series) -> Result<BooleanChunked> {
+        match other.dtype() {
+            DataType::List(dt) if self.dtype() == &**dt => {
+                let ca: BooleanChunked = self
+                    .into_iter()
+                    .zip(other.list()?.into_iter())
+                    .map(|(value, series)| match (value, series) {
+                        (val, Some(series)) => {
+                            let ca = series.unpack::<BinaryType>().unwrap();
+                            ca.into_iter().any(|a| a == val)
+                        }
+                        _ => false,
+                    })
+                    .collect_trusted();
+                Ok(ca)
+            }
+            DataType::Binary => {
+                let mut set = HashSet::with_capacity(other.len());
+
+                let other = other.binary()?;
+                other.downcast_iter().for_each(|iter| {
+                    iter.into_iter().for_each(|opt_val| {
+                        set.insert(opt_val);
+                    })
+                });
+                let mut ca: BooleanChunked = self
+                    .into_iter()
+                    .map(|opt_val| set.contains(&opt_val))
+                    .collect_trusted();
+                ca.rename(self.name());
+                Ok(ca)
+            }
+            _ => Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "cannot do is_in operation with left a dtype: {:?} and right a dtype {:?}",
+                    self.dtype(),
+                    other.dtype()
+                )
+                .into(),
+            )),
+        }
+    }
+}
+
 impl IsIn for BooleanChunked {
     fn is_in(&self, other: &Series) -> Result<BooleanChunked> {
         match other.dtype() {
@@ -630,6 +630,13 @@ impl ChunkExpandAtIndex<Utf8Type> for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkExpandAtIndex<BinaryType> for BinaryChunked {
+    fn expand_at_index(&self, index: usize, length: usize) -> BinaryChunked {
+        impl_chunk_expand!(self, length, index)
+    }
+}
+
 #[cfg(feature = "dtype-categorical")]
 impl ChunkExpandAtIndex<CategoricalType> for CategoricalChunked {
     fn expand_at_index(&self, index: usize, length: usize) -> CategoricalChunked {
@@ -393,6 +393,34 @@ impl ChunkSort<Utf8Type> for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkSort<BinaryType> for BinaryChunked {
+    fn sort(&self, reverse: bool) -> BinaryChunked {
+        let mut v = Vec::from_iter(self);
+        sort_branch(
+            v.as_mut_slice(),
+            reverse,
+            order_default_null,
+            order_reverse_null,
+        );
+
+        let mut builder = BinaryChunkedBuilder::new(self.name(), self.len());
+        v.into_iter().for_each(|opt_v| builder.append_option(opt_v));
+        let mut ca = builder.finish();
+        ca.set_sorted(reverse);
+        ca
+    }
+
+    fn sort_in_place(&mut self, reverse: bool) {
+        let sorted = self.sort(reverse);
+        self.chunks = sorted.chunks;
+    }
+
+    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+        argsort!(self, reverse)
+    }
+}
+
 #[cfg(feature = "dtype-categorical")]
 impl ChunkSort<CategoricalType> for CategoricalChunked {
     fn sort(&self, reverse: bool) -> Self {
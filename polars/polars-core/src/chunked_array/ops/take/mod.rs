@@ -318,6 +318,51 @@ impl ChunkTake for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkTake for BinaryChunked {
+    unsafe fn take_unchecked<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Self
+    where
+        Self: std::marker::Sized,
+        I: TakeIterator,
+        INulls: TakeIteratorNulls,
+    {
+        // there is no random access over many chunks, so we take from a single chunk
+        if self.chunks.len() > 1 {
+            return self.rechunk().take_unchecked(indices);
+        }
+        match indices {
+            TakeIdx::Array(array) => {
+                if array.null_count() == array.len() || self.is_empty() {
+                    return Self::full_null(self.name(), array.len());
+                }
+                let arr = self.downcast_iter().next().unwrap();
+                let array = take::take(arr, array).unwrap().into();
+                self.copy_with_chunks(vec![array])
+            }
+            TakeIdx::Iter(iter) => {
+                let idx: NoNull<UInt32Chunked> = iter.map(|v| v as u32).collect();
+                self.take_unchecked((&idx.into_inner()).into())
+            }
+            TakeIdx::IterNulls(iter) => {
+                let idx: UInt32Chunked = iter.map(|v| v.map(|v| v as u32)).collect();
+                self.take_unchecked((&idx).into())
+            }
+        }
+    }
+
+    fn take<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Result<Self>
+    where
+        Self: std::marker::Sized,
+        I: TakeIterator,
+        INulls: TakeIteratorNulls,
+    {
+        indices.check_bounds(self.len())?;
+        // Safety:
+        // just checked bounds
+        Ok(unsafe { self.take_unchecked(indices) })
+    }
+}
+
 impl ChunkTake for ListChunked {
     unsafe fn take_unchecked<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Self
     where
@@ -37,6 +37,17 @@ impl ChunkTakeEvery<Utf8Type> for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkTakeEvery<BinaryType> for BinaryChunked {
+    fn take_every(&self, n: usize) -> BinaryChunked {
+        if self.null_count() == 0 {
+            self.into_no_null_iter().step_by(n).collect()
+        } else {
+            self.into_iter().step_by(n).collect()
+        }
+    }
+}
+
 impl ChunkTakeEvery<ListType> for ListChunked {
     fn take_every(&self, n: usize) -> ListChunked {
         if self.null_count() == 0 {
@@ -138,6 +138,23 @@ impl<'a> TakeRandomUtf8 for &'a Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl<'a> TakeRandom for &'a BinaryChunked {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<Self::Item> {
+        // Safety:
+        // Out of bounds is checked and downcast is of correct type
+        unsafe { impl_take_random_get!(self, index, LargeBinaryArray) }
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> Option<Self::Item> {
+        impl_take_random_get_unchecked!(self, index, LargeBinaryArray)
+    }
+}
+
 #[cfg(feature = "object")]
 impl<'a, T: PolarsObject> TakeRandom for &'a ObjectChunked<T> {
     type Item = &'a T;
@@ -262,6 +262,39 @@ impl ChunkUnique<Utf8Type> for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl ChunkUnique<BinaryType> for BinaryChunked {
+    fn unique(&self) -> Result<Self> {
+        let set = fill_set(self.into_iter());
+        Ok(BinaryChunked::new_from_opt_iter(
+            self.name(),
+            set.iter().copied(),
+        ))
+    }
+
+    fn arg_unique(&self) -> Result<UInt32Chunked> {
+        Ok(UInt32Chunked::new_from_aligned_vec(
+            self.name(),
+            arg_unique_ca!(self),
+        ))
+    }
+
+    fn is_unique(&self) -> Result<BooleanChunked> {
+        is_unique_duplicated!(self, false)
+    }
+    fn is_duplicated(&self) -> Result<BooleanChunked> {
+        is_unique_duplicated!(self, true)
+    }
+
+    fn value_counts(&self) -> Result<DataFrame> {
+        impl_value_counts!(self)
+    }
+
+    fn n_unique(&self) -> Result<usize> {
+        Ok(fill_set(self.into_iter()).len())
+    }
+}
+
 #[cfg(feature = "dtype-categorical")]
 impl ChunkUnique<CategoricalType> for CategoricalChunked {
     fn unique(&self) -> Result<Self> {
@@ -88,6 +88,32 @@ impl DataFrame {
     }
 }
 
+#[cfg(feature = "dtype-struct")]
+impl StructChunked {
+    /// Sample n rows from this StructChunked.
+    pub fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Self> {
+        if !with_replacement && n > self.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "n is larger than the number of elements in this array".into(),
+            ));
+        }
+        // all fields should use the same indices.
+        let idx: UInt32Chunked = match with_replacement {
+            true => create_rand_index_with_replacement(n, self.len()).1,
+            false => create_rand_index_no_replacement(n, self.len()).1,
+        };
+        // Safety:
+        // indices are within bounds
+        self.try_apply_fields(|s| unsafe { s.take_unchecked(&idx) })
+    }
+
+    /// Sample a fraction between 0.0-1.0 of this StructChunked.
+    pub fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Self> {
+        let n = (self.len() as f64 * frac) as usize;
+        self.sample_n(n, with_replacement)
+    }
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType,
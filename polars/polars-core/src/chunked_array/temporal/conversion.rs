@@ -1,14 +1,16 @@
 use super::*;
-use crate::prelude::AnyValue;
+use crate::prelude::{AnyValue, TimeUnit};
 #[cfg(feature = "dtype-time")]
 use arrow::temporal_conversions::time64ns_to_time;
-use arrow::temporal_conversions::timestamp_ms_to_datetime;
+use arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
 use chrono::{NaiveDateTime, NaiveTime};
 
 /// Number of seconds in a day
 pub(crate) const SECONDS_IN_DAY: i64 = 86_400;
 /// Number of milliseconds in a second
-const MILLISECONDS_IN_SECOND: i64 = 1_000;
+pub(crate) const MILLISECONDS_IN_SECOND: i64 = 1_000;
 
 impl From<&AnyValue<'_>> for NaiveDateTime {
     fn from(v: &AnyValue) -> Self {
@@ -16,12 +18,24 @@ impl From<&AnyValue<'_>> for NaiveDateTime {
             #[cfg(feature = "dtype-date")]
             AnyValue::Date(v) => NaiveDateTime::from_timestamp(*v as i64 * SECONDS_IN_DAY, 0),
             #[cfg(feature = "dtype-datetime")]
-            AnyValue::Datetime(v) => timestamp_ms_to_datetime(*v),
+            AnyValue::Datetime(v, tu, _) => timestamp_to_datetime_fn(*tu)(*v),
             _ => panic!("can only convert date/datetime to NaiveDateTime"),
         }
     }
 }
 
+#[cfg(feature = "dtype-duration")]
+impl From<&AnyValue<'_>> for chrono::Duration {
+    fn from(v: &AnyValue) -> Self {
+        match v {
+            AnyValue::Duration(v, TimeUnit::Nanoseconds) => chrono::Duration::nanoseconds(*v),
+            AnyValue::Duration(v, TimeUnit::Microseconds) => chrono::Duration::microseconds(*v),
+            AnyValue::Duration(v, TimeUnit::Milliseconds) => chrono::Duration::milliseconds(*v),
+            _ => panic!("can only convert duration to chrono::Duration"),
+        }
+    }
+}
+
 impl From<&AnyValue<'_>> for NaiveTime {
     fn from(v: &AnyValue) -> Self {
         match v {
@@ -32,6 +46,28 @@ impl From<&AnyValue<'_>> for NaiveTime {
     }
 }
 
+/// The function that turns a timestamp in the given unit into a `NaiveDateTime`.
+#[cfg(feature = "dtype-datetime")]
+pub(crate) fn timestamp_to_datetime_fn(tu: TimeUnit) -> fn(i64) -> NaiveDateTime {
+    match tu {
+        TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
+        TimeUnit::Microseconds => timestamp_us_to_datetime,
+        TimeUnit::Milliseconds => timestamp_ms_to_datetime,
+    }
+}
+
+/// The inverse of [`timestamp_to_datetime_fn`].
+#[cfg(feature = "dtype-datetime")]
+pub(crate) fn datetime_to_timestamp_fn(tu: TimeUnit) -> fn(&NaiveDateTime) -> i64 {
+    match tu {
+        TimeUnit::Nanoseconds => NaiveDateTime::timestamp_nanos,
+        TimeUnit::Microseconds => {
+            |v| v.timestamp() * 1_000_000 + v.timestamp_subsec_micros() as i64
+        }
+        TimeUnit::Milliseconds => NaiveDateTime::timestamp_millis,
+    }
+}
+
 // datetime is number of milliseconds since the Unix Epoch
 // Used by lazy for literal conversion
 #[cfg(feature = "private")]
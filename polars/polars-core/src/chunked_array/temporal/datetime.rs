@@ -1,15 +1,124 @@
 use super::*;
 use crate::prelude::*;
-use arrow::temporal_conversions::timestamp_ms_to_datetime;
+use arrow::array::{ArrayRef, PrimitiveArray};
+#[cfg(feature = "timezones")]
+use chrono::{LocalResult, TimeZone as _};
+#[cfg(feature = "timezones")]
+use chrono_tz::Tz;
+use std::borrow::Cow;
+use std::ops::Deref;
+
+#[cfg(feature = "timezones")]
+fn parse_time_zone(tz: &str) -> Result<Tz> {
+    tz.parse::<Tz>().map_err(|_| {
+        PolarsError::ComputeError(format!("unable to parse time zone: '{}'", tz).into())
+    })
+}
 
 impl DatetimeChunked {
+    /// The timestamps shifted to the wall clock time of the time zone of this array.
+    /// Naive datetimes (and time zones that cannot be parsed) are returned as is.
+    fn local_timestamps(&self) -> Cow<'_, Int64Chunked> {
+        #[cfg(feature = "timezones")]
+        if let Some(tz) = self
+            .time_zone()
+            .as_deref()
+            .and_then(|tz| tz.parse::<Tz>().ok())
+        {
+            let to_datetime = timestamp_to_datetime_fn(self.time_unit());
+            let to_timestamp = datetime_to_timestamp_fn(self.time_unit());
+            let ca = self.deref().apply(|v| {
+                let ndt = to_datetime(v);
+                to_timestamp(&tz.from_utc_datetime(&ndt).naive_local())
+            });
+            return Cow::Owned(ca);
+        }
+        Cow::Borrowed(self.deref())
+    }
+
+    /// Apply a temporal kernel on the local timestamps.
+    fn apply_temporal_kernel<S: PolarsDataType>(
+        &self,
+        kernel: fn(&PrimitiveArray<i64>, TimeUnit) -> ArrayRef,
+    ) -> ChunkedArray<S> {
+        let tu = self.time_unit();
+        self.local_timestamps()
+            .apply_kernel_cast(|arr| kernel(arr, tu))
+    }
+
+    /// Convert the timestamps to another time zone. This only changes the time zone metadata;
+    /// the instants in time stay the same, but are displayed and decomposed in the new zone.
+    #[cfg(feature = "timezones")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timezones")))]
+    pub fn convert_time_zone(&self, tz: &str) -> Result<Self> {
+        if self.time_zone().is_none() {
+            return Err(PolarsError::ComputeError(
+                "cannot convert a naive datetime to another time zone; use replace_time_zone first"
+                    .into(),
+            ));
+        }
+        parse_time_zone(tz)?;
+        let mut out = self.clone();
+        out.set_time_zone(Some(tz.to_string()));
+        Ok(out)
+    }
+
+    /// Replace the time zone, keeping the wall clock time. The underlying timestamps change
+    /// such that e.g. `10:00` in the old time zone becomes `10:00` in the new one.
+    /// `None` turns the array into naive datetimes.
+    ///
+    /// Ambiguous local times resolve to the earliest instant. Local times that do not exist
+    /// in the new time zone (e.g. in a DST gap) return an error.
+    #[cfg(feature = "timezones")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timezones")))]
+    pub fn replace_time_zone(&self, tz: Option<&str>) -> Result<Self> {
+        let tu = self.time_unit();
+        let local = self.local_timestamps();
+        let mut out = match tz {
+            None => local.into_owned().into_datetime(tu, None),
+            Some(tz_name) => {
+                let tz = parse_time_zone(tz_name)?;
+                let to_datetime = timestamp_to_datetime_fn(tu);
+                let to_timestamp = datetime_to_timestamp_fn(tu);
+                let ca: Int64Chunked = local
+                    .deref()
+                    .into_iter()
+                    .map(|opt_v| {
+                        opt_v
+                            .map(|v| {
+                                let ndt = to_datetime(v);
+                                match tz.from_local_datetime(&ndt) {
+                                    LocalResult::Single(dt) => Ok(to_timestamp(&dt.naive_utc())),
+                                    LocalResult::Ambiguous(earliest, _) => {
+                                        Ok(to_timestamp(&earliest.naive_utc()))
+                                    }
+                                    LocalResult::None => Err(PolarsError::ComputeError(
+                                        format!(
+                                            "datetime '{}' does not exist in time zone '{}'",
+                                            ndt, tz_name
+                                        )
+                                        .into(),
+                                    )),
+                                }
+                            })
+                            .transpose()
+                    })
+                    .collect::<Result<_>>()?;
+                ca.into_datetime(tu, Some(tz_name.to_string()))
+            }
+        };
+        out.rename(self.name());
+        Ok(out)
+    }
+
     pub fn as_datetime_iter(
         &self,
     ) -> impl Iterator<Item = Option<NaiveDateTime>> + TrustedLen + '_ {
+        let to_datetime = timestamp_to_datetime_fn(self.time_unit());
         self.downcast_iter()
-            .map(|iter| {
+            .map(move |iter| {
                 iter.into_iter()
-                    .map(|opt_v| opt_v.copied().map(timestamp_ms_to_datetime))
+                    .map(move |opt_v| opt_v.copied().map(to_datetime))
             })
             .flatten()
             .trust_my_length(self.len())
@@ -18,7 +127,7 @@ impl DatetimeChunked {
     /// Extract month from underlying NaiveDateTime representation.
     /// Returns the year number in the calendar date.
     pub fn year(&self) -> Int32Chunked {
-        self.apply_kernel_cast::<_, Int32Type>(datetime_to_year)
+        self.apply_temporal_kernel::<Int32Type>(datetime_to_year)
     }
 
     /// Extract month from underlying NaiveDateTime representation.
@@ -26,19 +135,19 @@ impl DatetimeChunked {
     ///
     /// The return value ranges from 1 to 12.
     pub fn month(&self) -> UInt32Chunked {
-        self.apply_kernel_cast::<_, UInt32Type>(datetime_to_month)
+        self.apply_temporal_kernel::<UInt32Type>(datetime_to_month)
     }
 
     /// Extract weekday from underlying NaiveDateTime representation.
     /// Returns the weekday number where monday = 0 and sunday = 6
     pub fn weekday(&self) -> UInt32Chunked {
-        self.apply_kernel_cast::<_, UInt32Type>(datetime_to_weekday)
+        self.apply_temporal_kernel::<UInt32Type>(datetime_to_weekday)
     }
 
     /// Returns the ISO week number starting from 1.
     /// The return value ranges from 1 to 53. (The last week of year differs by years.)
     pub fn week(&self) -> UInt32Chunked {
-        self.apply_kernel_cast::<_, UInt32Type>(datetime_to_week)
+        self.apply_temporal_kernel::<UInt32Type>(datetime_to_week)
     }
 
     /// Extract day from underlying NaiveDateTime representation.
@@ -46,47 +155,70 @@ impl DatetimeChunked {
     ///
     /// The return value ranges from 1 to 31. (The last day of month differs by months.)
     pub fn day(&self) -> UInt32Chunked {
-        self.apply_kernel_cast::<_, UInt32Type>(datetime_to_day)
+        self.apply_temporal_kernel::<UInt32Type>(datetime_to_day)
     }
 
     /// Extract hour from underlying NaiveDateTime representation.
     /// Returns the hour number from 0 to 23.
     pub fn hour(&self) -> UInt32Chunked {
-        self.apply_kernel_cast::<_, UInt32Type>(datetime_to_hour)
+        self.apply_temporal_kernel::<UInt32Type>(datetime_to_hour)
     }
 
     /// Extract minute from underlying NaiveDateTime representation.
     /// Returns the minute number from 0 to 59.
     pub fn minute(&self) -> UInt32Chunked {
-        self.apply_kernel_cast::<_, UInt32Type>(datetime_to_minute)
+        self.apply_temporal_kernel::<UInt32Type>(datetime_to_minute)
     }
 
     /// Extract second from underlying NaiveDateTime representation.
     /// Returns the second number from 0 to 59.
     pub fn second(&self) -> UInt32Chunked {
-        self.apply_kernel_cast::<_, UInt32Type>(datetime_to_second)
+        self.apply_temporal_kernel::<UInt32Type>(datetime_to_second)
     }
 
     /// Extract second from underlying NaiveDateTime representation.
     /// Returns the number of nanoseconds since the whole non-leap second.
     /// The range from 1,000,000,000 to 1,999,999,999 represents the leap second.
     pub fn nanosecond(&self) -> UInt32Chunked {
-        self.apply_kernel_cast::<_, UInt32Type>(datetime_to_nanosecond)
+        self.apply_temporal_kernel::<UInt32Type>(datetime_to_nanosecond)
     }
 
     /// Returns the day of year starting from 1.
     ///
     /// The return value ranges from 1 to 366. (The last day of year differs by years.)
     pub fn ordinal(&self) -> UInt32Chunked {
-        self.apply_kernel_cast::<_, UInt32Type>(datetime_to_ordinal)
+        self.apply_temporal_kernel::<UInt32Type>(datetime_to_ordinal)
     }
 
     /// Format Datetimewith a `fmt` rule. See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    /// Time zone aware datetimes are formatted in their local time, and support `%z`/`%Z`.
     pub fn strftime(&self, fmt: &str) -> Utf8Chunked {
+        let to_datetime = timestamp_to_datetime_fn(self.time_unit());
+        #[cfg(feature = "timezones")]
+        if let Some(tz) = self
+            .time_zone()
+            .as_deref()
+            .and_then(|tz| tz.parse::<Tz>().ok())
+        {
+            let mut ca: Utf8Chunked = self.apply_kernel_cast(|arr| {
+                let arr: Utf8Array<i64> = arr
+                    .into_iter()
+                    .map(|opt| {
+                        opt.map(|v| {
+                            let ndt = to_datetime(*v);
+                            format!("{}", tz.from_utc_datetime(&ndt).format(fmt))
+                        })
+                    })
+                    .collect();
+                Arc::new(arr)
+            });
+            ca.rename(self.name());
+            return ca;
+        }
         let mut ca: Utf8Chunked = self.apply_kernel_cast(|arr| {
             let arr: Utf8Array<i64> = arr
                 .into_iter()
-                .map(|opt| opt.map(|v| format!("{}", timestamp_ms_to_datetime(*v).format(fmt))))
+                .map(|opt| opt.map(|v| format!("{}", to_datetime(*v).format(fmt))))
                 .collect();
             Arc::new(arr)
         });
@@ -139,4 +271,84 @@ mod test {
             dt.cont_slice().unwrap()
         );
     }
+
+    #[test]
+    fn test_cast_time_unit_overflow() {
+        let v = i64::MAX / 1_000;
+        let dt = Int64Chunked::new_from_slice("dt", &[v, v + 1, -v, 1]).into_date();
+
+        let us = dt.cast_time_unit(TimeUnit::Microseconds);
+        assert_eq!(
+            Vec::from(&us.0),
+            &[Some(v * 1_000), None, Some(-v * 1_000), Some(1_000)]
+        );
+        let ns = dt.cast_time_unit(TimeUnit::Nanoseconds);
+        assert_eq!(Vec::from(&ns.0), &[None, None, None, Some(1_000_000)]);
+        // back to a coarser unit
+        let ms = us.cast_time_unit(TimeUnit::Milliseconds);
+        assert_eq!(Vec::from(&ms.0), &[Some(v), None, Some(-v), Some(1)]);
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn test_time_zones() -> Result<()> {
+        // 2021-03-28 00:30:00 UTC and 2021-07-01 12:00:00 UTC
+        let dt = Int64Chunked::new_from_slice("dt", &[1616891400000, 1625140800000]).into_date();
+        assert!(dt.convert_time_zone("Europe/Amsterdam").is_err());
+
+        let utc = dt.replace_time_zone(Some("UTC"))?;
+        assert_eq!(utc.cont_slice().unwrap(), dt.cont_slice().unwrap());
+        assert_eq!(
+            utc.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, Some("UTC".into()))
+        );
+
+        let ams = utc.convert_time_zone("Europe/Amsterdam")?;
+        // same instants, local components
+        assert_eq!(ams.cont_slice().unwrap(), dt.cont_slice().unwrap());
+        assert_eq!(Vec::from(&ams.hour()), &[Some(1), Some(14)]);
+        assert_eq!(
+            Vec::from(&ams.strftime("%H:%M %Z")),
+            &[Some("01:30 CET"), Some("14:00 CEST")]
+        );
+
+        // keep the wall clock time, change the instant
+        let naive = ams.replace_time_zone(None)?;
+        assert_eq!(naive.time_zone(), &None);
+        assert_eq!(Vec::from(&naive.hour()), &[Some(1), Some(14)]);
+        let ny = naive.replace_time_zone(Some("America/New_York"))?;
+        assert_eq!(Vec::from(&ny.hour()), &[Some(1), Some(14)]);
+        assert_eq!(
+            ny.cont_slice().unwrap()[1] - naive.cont_slice().unwrap()[1],
+            4 * 3600 * 1000
+        );
+
+        assert!(utc.convert_time_zone("Mars/Olympus_Mons").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_time_units() {
+        // 2021-07-01 12:00:00.123456789 UTC
+        let ns = Int64Chunked::new_from_slice("dt", &[1625140800123456789])
+            .into_datetime(TimeUnit::Nanoseconds, None);
+        assert_eq!(Vec::from(&ns.hour()), &[Some(12)]);
+        assert_eq!(Vec::from(&ns.nanosecond()), &[Some(123456789)]);
+        assert_eq!(
+            Vec::from(&ns.strftime("%Y-%m-%d %H:%M:%S%.f")),
+            &[Some("2021-07-01 12:00:00.123456789")]
+        );
+
+        let us = ns.cast_time_unit(TimeUnit::Microseconds);
+        assert_eq!(us.cont_slice().unwrap(), &[1625140800123456]);
+        assert_eq!(Vec::from(&us.nanosecond()), &[Some(123456000)]);
+        let ms = us.cast_time_unit(TimeUnit::Milliseconds);
+        assert_eq!(ms.cont_slice().unwrap(), &[1625140800123]);
+        assert_eq!(Vec::from(&ms.day()), &[Some(1)]);
+
+        // year 3000 does not fit in nanoseconds, but does in milliseconds
+        let far = Int64Chunked::new_from_slice("dt", &[32503680000000])
+            .into_datetime(TimeUnit::Milliseconds, None);
+        assert_eq!(Vec::from(&far.year()), &[Some(3000)]);
+    }
 }
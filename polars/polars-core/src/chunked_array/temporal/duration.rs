@@ -0,0 +1,94 @@
+use super::*;
+use crate::prelude::*;
+use chrono::Duration as ChronoDuration;
+use std::ops::Deref;
+
+/// Number of seconds in a minute
+const SECONDS_IN_MINUTE: i64 = 60;
+/// Number of seconds in an hour
+const SECONDS_IN_HOUR: i64 = 60 * SECONDS_IN_MINUTE;
+
+impl DurationChunked {
+    pub fn as_duration_iter(
+        &self,
+    ) -> impl Iterator<Item = Option<ChronoDuration>> + TrustedLen + '_ {
+        let to_duration = match self.time_unit() {
+            TimeUnit::Nanoseconds => ChronoDuration::nanoseconds,
+            TimeUnit::Microseconds => ChronoDuration::microseconds,
+            TimeUnit::Milliseconds => ChronoDuration::milliseconds,
+        };
+        self.downcast_iter()
+            .map(move |iter| {
+                iter.into_iter()
+                    .map(move |opt_v| opt_v.copied().map(to_duration))
+            })
+            .flatten()
+            .trust_my_length(self.len())
+    }
+
+    /// Number of ticks of the time unit in the given number of seconds.
+    fn ticks(&self, seconds: i64) -> i64 {
+        seconds * self.time_unit().ticks_per_second()
+    }
+
+    /// Extract the number of whole days of the `Duration`.
+    pub fn days(&self) -> Int64Chunked {
+        self.deref() / self.ticks(SECONDS_IN_DAY)
+    }
+
+    /// Extract the number of whole hours of the `Duration`.
+    pub fn hours(&self) -> Int64Chunked {
+        self.deref() / self.ticks(SECONDS_IN_HOUR)
+    }
+
+    /// Extract the number of whole minutes of the `Duration`.
+    pub fn minutes(&self) -> Int64Chunked {
+        self.deref() / self.ticks(SECONDS_IN_MINUTE)
+    }
+
+    /// Extract the number of whole seconds of the `Duration`.
+    pub fn seconds(&self) -> Int64Chunked {
+        self.deref() / self.ticks(1)
+    }
+
+    /// Extract the number of whole milliseconds of the `Duration`.
+    pub fn milliseconds(&self) -> Int64Chunked {
+        self.cast_time_unit(TimeUnit::Milliseconds).deref().clone()
+    }
+
+    pub fn new_from_duration(name: &str, v: &[ChronoDuration]) -> Self {
+        let vals = v
+            .iter()
+            .map(|v| v.num_milliseconds())
+            .collect_trusted::<AlignedVec<_>>();
+        Int64Chunked::new_from_aligned_vec(name, vals).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use chrono::Duration as ChronoDuration;
+
+    #[test]
+    fn test_duration_components() {
+        let durations = [
+            ChronoDuration::days(2) + ChronoDuration::hours(3),
+            ChronoDuration::minutes(90),
+            ChronoDuration::milliseconds(1500),
+        ];
+        let ca = DurationChunked::new_from_duration("d", &durations);
+
+        assert_eq!(Vec::from(&ca.days()), &[Some(2), Some(0), Some(0)]);
+        assert_eq!(Vec::from(&ca.hours()), &[Some(51), Some(1), Some(0)]);
+        assert_eq!(Vec::from(&ca.minutes()), &[Some(3060), Some(90), Some(0)]);
+        assert_eq!(
+            Vec::from(&ca.seconds()),
+            &[Some(183600), Some(5400), Some(1)]
+        );
+        assert_eq!(
+            Vec::from(&ca.milliseconds()),
+            &[Some(183600000), Some(5400000), Some(1500)]
+        );
+    }
+}
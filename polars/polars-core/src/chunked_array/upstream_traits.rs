@@ -5,6 +5,8 @@ use crate::chunked_array::object::ObjectArray;
 use crate::prelude::*;
 use crate::utils::NoNull;
 use crate::utils::{get_iter_capacity, CustomIterTools};
+#[cfg(feature = "dtype-binary")]
+use arrow::array::BinaryArray;
 use arrow::array::{BooleanArray, PrimitiveArray, Utf8Array};
 use arrow::buffer::MutableBuffer;
 use polars_arrow::utils::TrustMyLength;
@@ -130,6 +132,39 @@ where
     }
 }
 
+// FromIterator for BinaryChunked variants.
+
+#[cfg(feature = "dtype-binary")]
+impl<Ptr> FromIterator<Option<Ptr>> for BinaryChunked
+where
+    Ptr: AsRef<[u8]>,
+{
+    fn from_iter<I: IntoIterator<Item = Option<Ptr>>>(iter: I) -> Self {
+        let arr = BinaryArray::<i64>::from_iter(iter);
+        Self::new_from_chunks("", vec![Arc::new(arr)])
+    }
+}
+
+#[cfg(feature = "dtype-binary")]
+impl PolarsAsRef<[u8]> for Vec<u8> {}
+#[cfg(feature = "dtype-binary")]
+impl PolarsAsRef<[u8]> for &[u8] {}
+#[cfg(feature = "dtype-binary")]
+impl PolarsAsRef<[u8]> for &&[u8] {}
+#[cfg(feature = "dtype-binary")]
+impl<'a> PolarsAsRef<[u8]> for Cow<'a, [u8]> {}
+
+#[cfg(feature = "dtype-binary")]
+impl<Ptr> FromIterator<Ptr> for BinaryChunked
+where
+    Ptr: PolarsAsRef<[u8]>,
+{
+    fn from_iter<I: IntoIterator<Item = Ptr>>(iter: I) -> Self {
+        let arr = BinaryArray::<i64>::from_iter(iter.into_iter().map(Some));
+        Self::new_from_chunks("", vec![Arc::new(arr)])
+    }
+}
+
 impl<Ptr> FromIterator<Ptr> for ListChunked
 where
     Ptr: Borrow<Series>,
@@ -375,6 +410,13 @@ impl From<Utf8Chunked> for Vec<Option<String>> {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl<'a> From<&'a BinaryChunked> for Vec<Option<&'a [u8]>> {
+    fn from(ca: &'a BinaryChunked) -> Self {
+        ca.into_iter().collect()
+    }
+}
+
 impl<'a> From<&'a BooleanChunked> for Vec<Option<bool>> {
     fn from(ca: &'a BooleanChunked) -> Self {
         ca.into_iter().collect()
@@ -14,7 +14,7 @@ use crate::chunked_array::object::PolarsObjectSafe;
 use crate::prelude::*;
 use ahash::RandomState;
 use arrow::compute::comparison::Simd8;
-pub use arrow::datatypes::{DataType as ArrowDataType, TimeUnit};
+pub use arrow::datatypes::{DataType as ArrowDataType, TimeUnit as ArrowTimeUnit};
 use arrow::types::simd::Simd;
 use arrow::types::NativeType;
 use num::{Bounded, FromPrimitive, Num, NumCast, Zero};
@@ -24,8 +24,14 @@ use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::ops::{Add, AddAssign, Div, Mul, Rem, Sub};
 
+/// An IANA time zone name, e.g. "Europe/Amsterdam".
+pub type TimeZone = String;
+
 pub struct Utf8Type {}
 
+#[cfg(feature = "dtype-binary")]
+pub struct BinaryType {}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ListType {}
 
@@ -60,7 +66,23 @@ impl_polars_datatype!(Int64Type, Int64, i64);
 impl_polars_datatype!(Float32Type, Float32, f32);
 impl_polars_datatype!(Float64Type, Float64, f64);
 impl_polars_datatype!(DateType, Date, i32);
-impl_polars_datatype!(DatetimeType, Datetime, i64);
+
+pub struct DatetimeType {}
+
+impl PolarsDataType for DatetimeType {
+    fn get_dtype() -> DataType {
+        DataType::Datetime(TimeUnit::Milliseconds, None)
+    }
+}
+
+pub struct DurationType {}
+
+impl PolarsDataType for DurationType {
+    fn get_dtype() -> DataType {
+        DataType::Duration(TimeUnit::Milliseconds)
+    }
+}
+
 impl_polars_datatype!(TimeType, Time, i64);
 
 impl PolarsDataType for Utf8Type {
@@ -69,6 +91,13 @@ impl PolarsDataType for Utf8Type {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl PolarsDataType for BinaryType {
+    fn get_dtype() -> DataType {
+        DataType::Binary
+    }
+}
+
 pub struct BooleanType {}
 
 impl PolarsDataType for BooleanType {
@@ -111,6 +140,9 @@ impl<T> PolarsSingleType for T where T: NativeType + PolarsDataType {}
 
 impl PolarsSingleType for Utf8Type {}
 
+#[cfg(feature = "dtype-binary")]
+impl PolarsSingleType for BinaryType {}
+
 pub type ListChunked = ChunkedArray<ListType>;
 pub type BooleanChunked = ChunkedArray<BooleanType>;
 pub type UInt8Chunked = ChunkedArray<UInt8Type>;
@@ -124,6 +156,8 @@ pub type Int64Chunked = ChunkedArray<Int64Type>;
 pub type Float32Chunked = ChunkedArray<Float32Type>;
 pub type Float64Chunked = ChunkedArray<Float64Type>;
 pub type Utf8Chunked = ChunkedArray<Utf8Type>;
+#[cfg(feature = "dtype-binary")]
+pub type BinaryChunked = ChunkedArray<BinaryType>;
 pub type CategoricalChunked = ChunkedArray<CategoricalType>;
 
 pub trait NumericNative:
@@ -211,6 +245,9 @@ pub enum AnyValue<'a> {
     Boolean(bool),
     /// A UTF8 encoded string type.
     Utf8(&'a str),
+    /// A slice of raw bytes.
+    #[cfg(feature = "dtype-binary")]
+    Binary(&'a [u8]),
     /// An unsigned 8-bit integer number.
     UInt8(u8),
     /// An unsigned 16-bit integer number.
@@ -231,14 +268,20 @@ pub enum AnyValue<'a> {
     Float32(f32),
     /// A 64-bit floating point number.
     Float64(f64),
+    /// A 128-bit integer scaled by `10^scale`, followed by that scale.
+    #[cfg(feature = "dtype-decimal")]
+    Decimal(i128, usize),
     /// A 32-bit date representing the elapsed time since UNIX epoch (1970-01-01)
     /// in days (32 bits).
     #[cfg(feature = "dtype-date")]
     Date(i32),
     /// A 64-bit date representing the elapsed time since UNIX epoch (1970-01-01)
-    /// in milliseconds (64 bits).
+    /// in the given time unit (64 bits), with an optional time zone.
     #[cfg(feature = "dtype-datetime")]
-    Datetime(i64),
+    Datetime(i64, TimeUnit, &'a Option<TimeZone>),
+    /// A 64-bit integer representing difference between date-times in the given time unit
+    #[cfg(feature = "dtype-duration")]
+    Duration(i64, TimeUnit),
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     #[cfg(feature = "dtype-time")]
     Time(i64),
@@ -249,6 +292,9 @@ pub enum AnyValue<'a> {
     #[cfg(feature = "object")]
     /// Can be used to fmt and implements Any, so can be downcasted to the proper value type.
     Object(&'a dyn PolarsObjectSafe),
+    /// The values of the fields of a single struct row, in the order of the given fields.
+    #[cfg(feature = "dtype-struct")]
+    Struct(Vec<AnyValue<'a>>, &'a [Field]),
 }
 
 impl From<f64> for AnyValue<'_> {
@@ -320,13 +366,20 @@ impl<'a> AnyValue<'a> {
         match self {
             #[cfg(feature = "dtype-date")]
             AnyValue::Int32(v) => AnyValue::Date(v),
-            #[cfg(feature = "dtype-datetime")]
-            AnyValue::Int64(v) => AnyValue::Datetime(v),
             AnyValue::Null => AnyValue::Null,
             dt => panic!("cannot create date from other type. dtype: {}", dt),
         }
     }
 
+    #[cfg(feature = "dtype-datetime")]
+    pub(crate) fn into_datetime(self, tu: TimeUnit, tz: &'a Option<TimeZone>) -> Self {
+        match self {
+            AnyValue::Int64(v) => AnyValue::Datetime(v, tu, tz),
+            AnyValue::Null => AnyValue::Null,
+            dt => panic!("cannot create datetime from other type. dtype: {}", dt),
+        }
+    }
+
     #[cfg(feature = "dtype-time")]
     pub(crate) fn into_time(self) -> Self {
         match self {
@@ -336,6 +389,15 @@ impl<'a> AnyValue<'a> {
         }
     }
 
+    #[cfg(feature = "dtype-duration")]
+    pub(crate) fn into_duration(self, tu: TimeUnit) -> Self {
+        match self {
+            AnyValue::Int64(v) => AnyValue::Duration(v, tu),
+            AnyValue::Null => AnyValue::Null,
+            dt => panic!("cannot create duration from other type. dtype: {}", dt),
+        }
+    }
+
     pub fn add<'b>(&self, rhs: &AnyValue<'b>) -> Self {
         use AnyValue::*;
         match (self, rhs) {
@@ -380,14 +442,24 @@ impl Display for DataType {
             DataType::Int64 => "i64",
             DataType::Float32 => "f32",
             DataType::Float64 => "f64",
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(precision, scale) => {
+                return write!(f, "decimal[{},{}]", precision, scale)
+            }
             DataType::Utf8 => "str",
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => "binary",
             DataType::Date => "date",
-            DataType::Datetime => "datetime",
+            DataType::Datetime(tu, None) => return write!(f, "datetime[{}]", tu),
+            DataType::Datetime(tu, Some(tz)) => return write!(f, "datetime[{}, {}]", tu, tz),
+            DataType::Duration(tu) => return write!(f, "duration[{}]", tu),
             DataType::Time => "time",
             DataType::List(tp) => return write!(f, "list [{}]", tp),
             #[cfg(feature = "object")]
             DataType::Object(s) => s,
             DataType::Categorical => "cat",
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
         };
         f.write_str(s)
     }
@@ -399,6 +471,8 @@ impl PartialEq for AnyValue<'_> {
         use AnyValue::*;
         match (self, other) {
             (Utf8(l), Utf8(r)) => l == r,
+            #[cfg(feature = "dtype-binary")]
+            (Binary(l), Binary(r)) => l == r,
             (UInt8(l), UInt8(r)) => l == r,
             (UInt16(l), UInt16(r)) => l == r,
             (UInt32(l), UInt32(r)) => l == r,
@@ -409,16 +483,24 @@ impl PartialEq for AnyValue<'_> {
             (Int64(l), Int64(r)) => l == r,
             (Float32(l), Float32(r)) => l == r,
             (Float64(l), Float64(r)) => l == r,
+            #[cfg(feature = "dtype-decimal")]
+            (Decimal(l, scale_l), Decimal(r, scale_r)) => l == r && scale_l == scale_r,
             #[cfg(feature = "dtype-time")]
             (Time(l), Time(r)) => l == r,
             #[cfg(all(feature = "dtype-datetime", feature = "dtype-date"))]
             (Date(l), Date(r)) => l == r,
             #[cfg(all(feature = "dtype-datetime", feature = "dtype-date"))]
-            (Datetime(l), Datetime(r)) => l == r,
+            (Datetime(l, tu_l, tz_l), Datetime(r, tu_r, tz_r)) => {
+                l == r && tu_l == tu_r && tz_l == tz_r
+            }
+            #[cfg(feature = "dtype-duration")]
+            (Duration(l, tu_l), Duration(r, tu_r)) => l == r && tu_l == tu_r,
             (Boolean(l), Boolean(r)) => l == r,
             (List(_), List(_)) => panic!("eq between list series not supported"),
             #[cfg(feature = "object")]
             (Object(_), Object(_)) => panic!("eq between object not supported"),
+            #[cfg(feature = "dtype-struct")]
+            (Struct(l, _), Struct(r, _)) => l == r,
             // should it?
             (Null, Null) => true,
             #[cfg(feature = "dtype-categorical")]
@@ -456,7 +538,57 @@ impl PartialOrd for AnyValue<'_> {
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TimeUnit {
+    Nanoseconds,
+    Microseconds,
+    Milliseconds,
+}
+
+impl Display for TimeUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            TimeUnit::Nanoseconds => "ns",
+            TimeUnit::Microseconds => "μs",
+            TimeUnit::Milliseconds => "ms",
+        };
+        f.write_str(s)
+    }
+}
+
+impl TimeUnit {
+    pub fn to_arrow(self) -> ArrowTimeUnit {
+        match self {
+            TimeUnit::Nanoseconds => ArrowTimeUnit::Nanosecond,
+            TimeUnit::Microseconds => ArrowTimeUnit::Microsecond,
+            TimeUnit::Milliseconds => ArrowTimeUnit::Millisecond,
+        }
+    }
+
+    /// Number of ticks of this unit in a second.
+    pub fn ticks_per_second(self) -> i64 {
+        match self {
+            TimeUnit::Nanoseconds => 1_000_000_000,
+            TimeUnit::Microseconds => 1_000_000,
+            TimeUnit::Milliseconds => 1_000,
+        }
+    }
+}
+
+impl From<&ArrowTimeUnit> for TimeUnit {
+    /// Arrow's `Second` has no Polars counterpart and is widened to milliseconds.
+    fn from(tu: &ArrowTimeUnit) -> Self {
+        match tu {
+            ArrowTimeUnit::Nanosecond => TimeUnit::Nanoseconds,
+            ArrowTimeUnit::Microsecond => TimeUnit::Microseconds,
+            ArrowTimeUnit::Millisecond | ArrowTimeUnit::Second => TimeUnit::Milliseconds,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DataType {
     Boolean,
     UInt8,
@@ -469,23 +601,38 @@ pub enum DataType {
     Int64,
     Float32,
     Float64,
+    /// Fixed-precision decimal numbers stored as 128-bit integers: (precision, scale).
+    /// The precision is the total number of digits, the scale the number of digits
+    /// after the decimal point.
+    #[cfg(feature = "dtype-decimal")]
+    Decimal(usize, usize),
     /// String data
     Utf8,
+    /// Raw bytes, e.g. for payloads that aren't valid UTF-8
+    #[cfg(feature = "dtype-binary")]
+    Binary,
     /// A 32-bit date representing the elapsed time since UNIX epoch (1970-01-01)
     /// in days (32 bits).
     Date,
     /// A 64-bit date representing the elapsed time since UNIX epoch (1970-01-01)
-    /// in milliseconds (64 bits).
-    Datetime,
+    /// in the given [`TimeUnit`] (64 bits). The optional IANA time zone (e.g. "Europe/Amsterdam")
+    /// determines the local time in which the timestamps are displayed and decomposed.
+    Datetime(TimeUnit, Option<TimeZone>),
+    /// A 64-bit integer representing difference between date-times in the given [`TimeUnit`]
+    Duration(TimeUnit),
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     Time,
     List(Box<DataType>),
     #[cfg(feature = "object")]
     /// A generic type that can be used in a `Series`
     /// &'static str can be used to determine/set inner type
+    #[cfg_attr(feature = "serde", serde(skip))]
     Object(&'static str),
     Null,
     Categorical,
+    /// A nested type of named fields, e.g. a JSON object or a Parquet group
+    #[cfg(feature = "dtype-struct")]
+    Struct(Vec<Field>),
 }
 
 impl DataType {
@@ -494,7 +641,8 @@ impl DataType {
         use DataType::*;
         match self {
             Date => Int32,
-            Datetime => Int64,
+            Datetime(_, _) => Int64,
+            Duration(_) => Int64,
             Time => Int64,
             Categorical => UInt32,
             _ => self.clone(),
@@ -516,10 +664,15 @@ impl DataType {
             Int64 => ArrowDataType::Int64,
             Float32 => ArrowDataType::Float32,
             Float64 => ArrowDataType::Float64,
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(precision, scale) => ArrowDataType::Decimal(*precision, *scale),
             Utf8 => ArrowDataType::LargeUtf8,
+            #[cfg(feature = "dtype-binary")]
+            Binary => ArrowDataType::LargeBinary,
             Date => ArrowDataType::Date32,
-            Datetime => ArrowDataType::Timestamp(TimeUnit::Millisecond, None),
-            Time => ArrowDataType::Time64(TimeUnit::Nanosecond),
+            Datetime(tu, tz) => ArrowDataType::Timestamp(tu.to_arrow(), tz.clone()),
+            Duration(tu) => ArrowDataType::Duration(tu.to_arrow()),
+            Time => ArrowDataType::Time64(ArrowTimeUnit::Nanosecond),
             List(dt) => ArrowDataType::LargeList(Box::new(arrow::datatypes::Field::new(
                 "",
                 dt.to_arrow(),
@@ -529,6 +682,8 @@ impl DataType {
             #[cfg(feature = "object")]
             Object(_) => panic!("cannot convert object to arrow"),
             Categorical => ArrowDataType::UInt32,
+            #[cfg(feature = "dtype-struct")]
+            Struct(fields) => ArrowDataType::Struct(fields.iter().map(|f| f.to_arrow()).collect()),
         }
     }
 }
@@ -540,7 +695,8 @@ impl PartialEq<ArrowDataType> for DataType {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Field {
     name: String,
     data_type: DataType,
@@ -571,6 +727,7 @@ impl Field {
 }
 
 #[derive(Clone, Debug, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Schema {
     fields: Vec<Field>,
 }
@@ -723,14 +880,27 @@ impl From<&ArrowDataType> for DataType {
             ArrowDataType::Boolean => DataType::Boolean,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
+            #[cfg(feature = "dtype-decimal")]
+            ArrowDataType::Decimal(precision, scale) => DataType::Decimal(*precision, *scale),
             ArrowDataType::LargeList(f) => DataType::List(Box::new(f.data_type().into())),
             ArrowDataType::List(f) => DataType::List(Box::new(f.data_type().into())),
             ArrowDataType::Date32 => DataType::Date,
-            ArrowDataType::Timestamp(_, _) | ArrowDataType::Date64 => DataType::Datetime,
+            // an empty time zone means no time zone
+            ArrowDataType::Timestamp(tu, tz) => {
+                DataType::Datetime(tu.into(), tz.as_ref().filter(|tz| !tz.is_empty()).cloned())
+            }
+            ArrowDataType::Date64 => DataType::Datetime(TimeUnit::Milliseconds, None),
+            ArrowDataType::Duration(tu) => DataType::Duration(tu.into()),
             ArrowDataType::LargeUtf8 => DataType::Utf8,
             ArrowDataType::Utf8 => DataType::Utf8,
+            #[cfg(feature = "dtype-binary")]
+            ArrowDataType::LargeBinary | ArrowDataType::Binary => DataType::Binary,
             ArrowDataType::Time64(_) | ArrowDataType::Time32(_) => DataType::Time,
             ArrowDataType::Dictionary(_, _) => DataType::Categorical,
+            #[cfg(feature = "dtype-struct")]
+            ArrowDataType::Struct(fields) => {
+                DataType::Struct(fields.iter().map(|fld| fld.into()).collect())
+            }
             dt => panic!("Arrow datatype {:?} not supported by Polars", dt),
         }
     }
@@ -770,37 +940,73 @@ mod test {
     #[test]
     fn test_arrow_dtypes_to_polars() {
         let dtypes = [
-            (ArrowDataType::Date64, DataType::Datetime),
             (
-                ArrowDataType::Timestamp(TimeUnit::Nanosecond, None),
-                DataType::Datetime,
+                ArrowDataType::Date64,
+                DataType::Datetime(TimeUnit::Milliseconds, None),
+            ),
+            (
+                ArrowDataType::Timestamp(ArrowTimeUnit::Nanosecond, None),
+                DataType::Datetime(TimeUnit::Nanoseconds, None),
+            ),
+            (
+                ArrowDataType::Timestamp(ArrowTimeUnit::Microsecond, None),
+                DataType::Datetime(TimeUnit::Microseconds, None),
+            ),
+            (
+                ArrowDataType::Timestamp(ArrowTimeUnit::Millisecond, None),
+                DataType::Datetime(TimeUnit::Milliseconds, None),
             ),
             (
-                ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
-                DataType::Datetime,
+                ArrowDataType::Timestamp(ArrowTimeUnit::Second, None),
+                DataType::Datetime(TimeUnit::Milliseconds, None),
             ),
             (
-                ArrowDataType::Timestamp(TimeUnit::Millisecond, None),
-                DataType::Datetime,
+                ArrowDataType::Timestamp(ArrowTimeUnit::Second, Some("".to_string())),
+                DataType::Datetime(TimeUnit::Milliseconds, None),
             ),
             (
-                ArrowDataType::Timestamp(TimeUnit::Second, None),
-                DataType::Datetime,
+                ArrowDataType::Timestamp(
+                    ArrowTimeUnit::Millisecond,
+                    Some("Europe/Amsterdam".to_string()),
+                ),
+                DataType::Datetime(TimeUnit::Milliseconds, Some("Europe/Amsterdam".to_string())),
+            ),
+            (
+                ArrowDataType::Duration(ArrowTimeUnit::Millisecond),
+                DataType::Duration(TimeUnit::Milliseconds),
             ),
             (
-                ArrowDataType::Timestamp(TimeUnit::Second, Some("".to_string())),
-                DataType::Datetime,
+                ArrowDataType::Duration(ArrowTimeUnit::Nanosecond),
+                DataType::Duration(TimeUnit::Nanoseconds),
             ),
             (ArrowDataType::LargeUtf8, DataType::Utf8),
             (ArrowDataType::Utf8, DataType::Utf8),
-            (ArrowDataType::Time64(TimeUnit::Nanosecond), DataType::Time),
-            (ArrowDataType::Time64(TimeUnit::Millisecond), DataType::Time),
-            (ArrowDataType::Time64(TimeUnit::Microsecond), DataType::Time),
-            (ArrowDataType::Time64(TimeUnit::Second), DataType::Time),
-            (ArrowDataType::Time32(TimeUnit::Nanosecond), DataType::Time),
-            (ArrowDataType::Time32(TimeUnit::Millisecond), DataType::Time),
-            (ArrowDataType::Time32(TimeUnit::Microsecond), DataType::Time),
-            (ArrowDataType::Time32(TimeUnit::Second), DataType::Time),
+            (
+                ArrowDataType::Time64(ArrowTimeUnit::Nanosecond),
+                DataType::Time,
+            ),
+            (
+                ArrowDataType::Time64(ArrowTimeUnit::Millisecond),
+                DataType::Time,
+            ),
+            (
+                ArrowDataType::Time64(ArrowTimeUnit::Microsecond),
+                DataType::Time,
+            ),
+            (ArrowDataType::Time64(ArrowTimeUnit::Second), DataType::Time),
+            (
+                ArrowDataType::Time32(ArrowTimeUnit::Nanosecond),
+                DataType::Time,
+            ),
+            (
+                ArrowDataType::Time32(ArrowTimeUnit::Millisecond),
+                DataType::Time,
+            ),
+            (
+                ArrowDataType::Time32(ArrowTimeUnit::Microsecond),
+                DataType::Time,
+            ),
+            (ArrowDataType::Time32(ArrowTimeUnit::Second), DataType::Time),
             (
                 ArrowDataType::List(Box::new(ArrowField::new(
                     "item",
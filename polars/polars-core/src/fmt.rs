@@ -1,7 +1,9 @@
 use crate::prelude::*;
 
-#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
-use arrow::temporal_conversions::{date32_to_date, timestamp_ms_to_datetime};
+#[cfg(feature = "dtype-datetime")]
+use crate::chunked_array::temporal::conversion::timestamp_to_datetime_fn;
+#[cfg(feature = "dtype-date")]
+use arrow::temporal_conversions::date32_to_date;
 use num::{Num, NumCast};
 use std::{
     fmt,
@@ -132,6 +134,14 @@ impl Debug for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl Debug for BinaryChunked {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let limit = set_limit!(self);
+        format_array!(limit, f, self, "binary", self.name(), "ChunkedArray")
+    }
+}
+
 impl Debug for ListChunked {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let limit = set_limit!(self);
@@ -147,6 +157,22 @@ impl Debug for CategoricalChunked {
     }
 }
 
+#[cfg(feature = "dtype-struct")]
+impl Debug for StructChunked {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let limit = set_limit!(self);
+        format_array!(limit, f, self, self.dtype(), self.name(), "ChunkedArray")
+    }
+}
+
+#[cfg(feature = "dtype-decimal")]
+impl Debug for DecimalChunked {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let limit = set_limit!(self);
+        format_array!(limit, f, self, self.dtype(), self.name(), "ChunkedArray")
+    }
+}
+
 #[cfg(feature = "object")]
 impl<T> Debug for ObjectChunked<T>
 where
@@ -206,6 +232,15 @@ impl Debug for Series {
             DataType::Utf8 => {
                 format_array!(limit, f, self.utf8().unwrap(), "str", self.name(), "Series")
             }
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => format_array!(
+                limit,
+                f,
+                self.binary().unwrap(),
+                "binary",
+                self.name(),
+                "Series"
+            ),
             DataType::UInt8 => {
                 format_array!(limit, f, self.u8().unwrap(), "u8", self.name(), "Series")
             }
@@ -244,11 +279,20 @@ impl Debug for Series {
                 self.name(),
                 "Series"
             ),
-            DataType::Datetime => format_array!(
+            DataType::Datetime(_, _) => format_array!(
                 limit,
                 f,
                 self.datetime().unwrap(),
-                "datetime",
+                self.dtype(),
+                self.name(),
+                "Series"
+            ),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(_) => format_array!(
+                limit,
+                f,
+                self.duration().unwrap(),
+                self.dtype(),
                 self.name(),
                 "Series"
             ),
@@ -273,6 +317,24 @@ impl Debug for Series {
                 self.name(),
                 "Series"
             ),
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(_) => format_array!(
+                limit,
+                f,
+                self.struct_().unwrap(),
+                self.dtype(),
+                self.name(),
+                "Series"
+            ),
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => format_array!(
+                limit,
+                f,
+                self.decimal().unwrap(),
+                self.dtype(),
+                self.name(),
+                "Series"
+            ),
             dt => panic!("{:?} not impl", dt),
         }
     }
@@ -479,6 +541,64 @@ fn fmt_float<T: Num + NumCast>(f: &mut Formatter<'_>, width: usize, v: T) -> fmt
     }
 }
 
+#[cfg(feature = "dtype-datetime")]
+fn fmt_datetime(f: &mut Formatter<'_>, v: i64, tu: TimeUnit, tz: &Option<TimeZone>) -> fmt::Result {
+    let ndt = timestamp_to_datetime_fn(tu)(v);
+    match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => match tz.parse::<chrono_tz::Tz>() {
+            Ok(tz) => {
+                use chrono::TimeZone as _;
+                write!(f, "{}", tz.from_utc_datetime(&ndt))
+            }
+            Err(_) => write!(f, "{}", ndt),
+        },
+        _ => write!(f, "{}", ndt),
+    }
+}
+
+/// Format a duration as e.g. `1d 2h 3m 4s 5ms`, down to the precision of its time unit.
+#[cfg(feature = "dtype-duration")]
+fn fmt_duration(f: &mut Formatter<'_>, v: i64, tu: TimeUnit) -> fmt::Result {
+    if v == 0 {
+        return write!(f, "0{}", tu);
+    }
+    if v < 0 {
+        write!(f, "-")?;
+    }
+    let v = v.unsigned_abs();
+    let ticks = tu.ticks_per_second() as u64;
+    let subsec = v % ticks;
+    let mut parts = vec![
+        (v / (86_400 * ticks), "d"),
+        (v / (3_600 * ticks) % 24, "h"),
+        (v / (60 * ticks) % 60, "m"),
+        (v / ticks % 60, "s"),
+    ];
+    match tu {
+        TimeUnit::Milliseconds => parts.push((subsec, "ms")),
+        TimeUnit::Microseconds => {
+            parts.extend_from_slice(&[(subsec / 1_000, "ms"), (subsec % 1_000, "μs")])
+        }
+        TimeUnit::Nanoseconds => parts.extend_from_slice(&[
+            (subsec / 1_000_000, "ms"),
+            (subsec / 1_000 % 1_000, "μs"),
+            (subsec % 1_000, "ns"),
+        ]),
+    }
+    let mut first = true;
+    for (value, unit) in parts.iter() {
+        if *value > 0 {
+            if !first {
+                write!(f, " ")?;
+            }
+            write!(f, "{}{}", value, unit)?;
+            first = false;
+        }
+    }
+    Ok(())
+}
+
 impl Display for AnyValue<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let width = 0;
@@ -496,10 +616,22 @@ impl Display for AnyValue<'_> {
             AnyValue::Float64(v) => fmt_float(f, width, *v),
             AnyValue::Boolean(v) => write!(f, "{}", *v),
             AnyValue::Utf8(v) => write!(f, "{}", format!("\"{}\"", v)),
+            #[cfg(feature = "dtype-decimal")]
+            AnyValue::Decimal(v, scale) => write!(f, "{}", format_decimal(*v, *scale)),
+            #[cfg(feature = "dtype-binary")]
+            AnyValue::Binary(v) => {
+                write!(f, "b\"")?;
+                for b in v.iter().flat_map(|b| std::ascii::escape_default(*b)) {
+                    write!(f, "{}", b as char)?;
+                }
+                write!(f, "\"")
+            }
             #[cfg(feature = "dtype-date")]
             AnyValue::Date(v) => write!(f, "{}", date32_to_date(*v)),
             #[cfg(feature = "dtype-datetime")]
-            AnyValue::Datetime(v) => write!(f, "{}", timestamp_ms_to_datetime(*v)),
+            AnyValue::Datetime(v, tu, tz) => fmt_datetime(f, *v, *tu, tz),
+            #[cfg(feature = "dtype-duration")]
+            AnyValue::Duration(v, tu) => fmt_duration(f, *v, *tu),
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(_) => {
                 let nt: chrono::NaiveTime = self.into();
@@ -513,6 +645,17 @@ impl Display for AnyValue<'_> {
             AnyValue::List(s) => write!(f, "{}", s.fmt_list()),
             #[cfg(feature = "object")]
             AnyValue::Object(_) => write!(f, "object"),
+            #[cfg(feature = "dtype-struct")]
+            AnyValue::Struct(vals, _) => {
+                write!(f, "{{")?;
+                for (i, v) in vals.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", v)?;
+                }
+                write!(f, "}}")
+            }
         }
     }
 }
@@ -565,6 +708,13 @@ impl FmtList for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl FmtList for BinaryChunked {
+    fn fmt_list(&self) -> String {
+        impl_fmt_list!(self)
+    }
+}
+
 impl FmtList for ListChunked {
     fn fmt_list(&self) -> String {
         impl_fmt_list!(self)
@@ -578,6 +728,20 @@ impl FmtList for CategoricalChunked {
     }
 }
 
+#[cfg(feature = "dtype-struct")]
+impl FmtList for StructChunked {
+    fn fmt_list(&self) -> String {
+        impl_fmt_list!(self)
+    }
+}
+
+#[cfg(feature = "dtype-decimal")]
+impl FmtList for DecimalChunked {
+    fn fmt_list(&self) -> String {
+        impl_fmt_list!(self)
+    }
+}
+
 #[cfg(feature = "dtype-date")]
 impl FmtList for DateChunked {
     fn fmt_list(&self) -> String {
@@ -592,6 +756,13 @@ impl FmtList for DatetimeChunked {
     }
 }
 
+#[cfg(feature = "dtype-duration")]
+impl FmtList for DurationChunked {
+    fn fmt_list(&self) -> String {
+        impl_fmt_list!(self)
+    }
+}
+
 #[cfg(feature = "dtype-time")]
 impl FmtList for TimeChunked {
     fn fmt_list(&self) -> String {
@@ -652,7 +823,7 @@ Series: 'Date' [date]
             .into_date();
         assert_eq!(
             r#"shape: (3,)
-Series: '' [datetime]
+Series: '' [datetime[ms]]
 [
 	1970-01-01 00:00:00.001
 	null
@@ -662,6 +833,90 @@ Series: '' [datetime]
         );
     }
 
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn test_fmt_datetime_tz() {
+        let s = Int64Chunked::new_from_opt_slice("", &[Some(1_000_000_000_000), None])
+            .into_datetime(TimeUnit::Milliseconds, Some("Asia/Kathmandu".into()));
+        assert_eq!(
+            r#"shape: (2,)
+Series: '' [datetime[ms, Asia/Kathmandu]]
+[
+	2001-09-09 07:31:40 +0545
+	null
+]"#,
+            format!("{:?}", s.into_series())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-duration")]
+    fn test_fmt_duration() {
+        let s = Int64Chunked::new_from_slice("", &[90_061_001_002_003, 0])
+            .into_duration(TimeUnit::Nanoseconds);
+        assert_eq!(
+            r#"shape: (2,)
+Series: '' [duration[ns]]
+[
+	1d 1h 1m 1s 1ms 2μs 3ns
+	0ns
+]"#,
+            format!("{:?}", s.into_series())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_fmt_struct() -> Result<()> {
+        let a = Series::new("a", &[Some(1), None]);
+        let b = Series::new("b", &["foo", "bar"]);
+        let s = StructChunked::new("s", &[a, b])?.into_series();
+        assert_eq!(
+            r#"shape: (2,)
+Series: 's' [struct[2]]
+[
+	{1,"foo"}
+	{null,"bar"}
+]"#,
+            format!("{:?}", s)
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-binary")]
+    fn test_fmt_binary() {
+        let ca =
+            BinaryChunked::new_from_opt_slice("", &[Some(&b"ab\n"[..]), None, Some(&b"\xff"[..])]);
+        assert_eq!(
+            r#"shape: (3,)
+Series: '' [binary]
+[
+	b"ab\n"
+	null
+	b"\xff"
+]"#,
+            format!("{:?}", ca.into_series())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_fmt_decimal() -> Result<()> {
+        let ca = DecimalChunked::new_from_opt_slice("d", &[Some(-5), None, Some(12345)], 5, 2)?;
+        assert_eq!(
+            r#"shape: (3,)
+Series: 'd' [decimal[5,2]]
+[
+	-0.05
+	null
+	123.45
+]"#,
+            format!("{:?}", ca.into_series())
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_fmt_chunkedarray() {
         let ca = Int32Chunked::new_from_opt_slice("Date", &[Some(1), None, Some(3)]);
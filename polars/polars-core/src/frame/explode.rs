@@ -118,6 +118,27 @@ impl DataFrame {
         Ok(df)
     }
 
+    /// Replace the given struct columns by their fields.
+    #[cfg(feature = "dtype-struct")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "dtype-struct")))]
+    pub fn unnest<'a, J, S: Selection<'a, J>>(&self, columns: S) -> Result<DataFrame> {
+        let names = columns.to_selection_vec();
+        // check that all columns exist and are structs
+        for name in &names {
+            self.column(name)?.struct_()?;
+        }
+
+        let mut new_cols = Vec::with_capacity(self.width());
+        for s in &self.columns {
+            if names.contains(&s.name()) {
+                new_cols.extend_from_slice(s.struct_()?.fields());
+            } else {
+                new_cols.push(s.clone());
+            }
+        }
+        DataFrame::new(new_cols)
+    }
+
     ///
     /// Unpivot a `DataFrame` from wide to long format.
     ///
@@ -278,4 +299,18 @@ mod test {
             &[Some(10), Some(11), Some(12), Some(2), Some(4), Some(6)]
         )
     }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_unnest() -> Result<()> {
+        let a = Series::new("a", &[1, 2]);
+        let b = Series::new("b", &["foo", "bar"]);
+        let s = StructChunked::new("s", &[a, b])?.into_series();
+        let df = DataFrame::new(vec![Series::new("x", &[1.0, 2.0]), s])?;
+
+        let out = df.unnest(&["s"])?;
+        assert_eq!(out.get_column_names(), &["x", "a", "b"]);
+        assert!(df.unnest(&["x"]).is_err());
+        Ok(())
+    }
 }
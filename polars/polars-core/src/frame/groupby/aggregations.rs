@@ -379,6 +379,58 @@ where
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+impl DecimalChunked {
+    /// The sums get the maximum precision. Returns an error if a group sum doesn't fit it.
+    pub(crate) fn agg_sum(&self, groups: &[(u32, Vec<u32>)]) -> Result<Series> {
+        let values = self.into_iter().collect::<Vec<_>>();
+        let sums = groups
+            .iter()
+            .map(|(_, idx)| sum_opt_iter(idx.iter().map(|i| values[*i as usize])))
+            .collect::<Result<Vec<_>>>()?;
+        let ca = DecimalChunked::from_values(
+            self.name(),
+            sums.into_iter(),
+            MAX_DECIMAL_PRECISION,
+            self.scale(),
+        );
+        Ok(ca.into_series())
+    }
+
+    pub(crate) fn agg_mean(&self, groups: &[(u32, Vec<u32>)]) -> Result<Series> {
+        let values = self.into_iter().collect::<Vec<_>>();
+        let scale = self.scale();
+        let mut ca: Float64Chunked = groups
+            .iter()
+            .map(|(_, idx)| {
+                let group = idx.iter().map(|i| values[*i as usize]);
+                let count = group.clone().flatten().count();
+                Ok(sum_opt_iter(group)?.map(|sum| to_f64(sum, scale) / count as f64))
+            })
+            .collect::<Result<_>>()?;
+        ca.rename(self.name());
+        Ok(ca.into_series())
+    }
+
+    pub(crate) fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        let iter = groups.iter().map(|(first, idx)| {
+            if idx.is_empty() {
+                None
+            } else {
+                Some(*first as usize)
+            }
+        });
+        unsafe { self.take_unchecked(iter.into()) }.into_series()
+    }
+
+    pub(crate) fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        let iter = groups
+            .iter()
+            .map(|(_, idx)| idx.last().map(|i| *i as usize));
+        unsafe { self.take_unchecked(iter.into()) }.into_series()
+    }
+}
+
 pub(crate) trait AggNUnique {
     fn agg_n_unique(&self, _groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
         None
@@ -457,6 +509,13 @@ impl AggNUnique for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl AggNUnique for BinaryChunked {
+    fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+        Some(impl_agg_n_unique!(self, groups, NoNull<UInt32Chunked>))
+    }
+}
+
 pub trait AggList {
     fn agg_list(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
         None
@@ -7,6 +7,8 @@ use crate::utils::Wrap;
 use crate::utils::{
     accumulate_dataframes_vertical, copy_from_slice_unchecked, set_partition_size, split_ca, NoNull,
 };
+#[cfg(feature = "dtype-binary")]
+use crate::vector_hasher::BytesHash;
 use crate::vector_hasher::{get_null_hash_value, AsU64, StrHash};
 use crate::POOL;
 use ahash::{CallHasher, RandomState};
@@ -175,6 +177,50 @@ impl IntoGroupTuples for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl IntoGroupTuples for BinaryChunked {
+    fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
+        let hb = RandomState::default();
+        let null_h = get_null_hash_value(hb.clone());
+
+        if multithreaded {
+            let n_partitions = set_partition_size();
+
+            let split = split_ca(self, n_partitions).unwrap();
+
+            let byte_hashes = POOL.install(|| {
+                split
+                    .par_iter()
+                    .map(|ca| {
+                        ca.into_iter()
+                            .map(|opt_b| {
+                                let hash = match opt_b {
+                                    Some(b) => <[u8]>::get_hash(b, &hb),
+                                    None => null_h,
+                                };
+                                BytesHash::new(opt_b, hash)
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            });
+            groupby_threaded_num(byte_hashes, 0, n_partitions as u64)
+        } else {
+            let byte_hashes = self
+                .into_iter()
+                .map(|opt_b| {
+                    let hash = match opt_b {
+                        Some(b) => <[u8]>::get_hash(b, &hb),
+                        None => null_h,
+                    };
+                    BytesHash::new(opt_b, hash)
+                })
+                .collect::<Vec<_>>();
+            groupby(byte_hashes.iter())
+        }
+    }
+}
+
 #[cfg(feature = "dtype-categorical")]
 impl IntoGroupTuples for CategoricalChunked {
     fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
@@ -607,7 +653,7 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
 
         for agg_col in agg_cols {
             let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Mean);
-            let opt_agg = agg_col.agg_mean(&self.groups);
+            let opt_agg = agg_col.try_agg_mean(&self.groups)?;
             if let Some(mut agg) = opt_agg {
                 agg.rename(&new_name);
                 cols.push(agg);
@@ -646,7 +692,7 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
 
         for agg_col in agg_cols {
             let new_name = fmt_groupby_column(agg_col.name(), GroupByMethod::Sum);
-            let opt_agg = agg_col.agg_sum(&self.groups);
+            let opt_agg = agg_col.try_agg_sum(&self.groups)?;
             if let Some(mut agg) = opt_agg {
                 agg.rename(&new_name);
                 cols.push(agg);
@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-datetime")]
+use crate::chunked_array::temporal::conversion::datetime_to_timestamp_fn;
 use crate::frame::groupby::GroupBy;
 use crate::prelude::*;
 use crate::utils::chrono::{Datelike, NaiveDate};
@@ -77,6 +79,10 @@ impl DateChunked {
 impl DatetimeChunked {
     pub fn round(&self, rule: SampleRule) -> DatetimeChunked {
         use SampleRule::*;
+        let tu = self.time_unit();
+        let to_timestamp = datetime_to_timestamp_fn(tu);
+        // number of ticks of the time unit in `n` seconds
+        let ticks = |n: u32| n as i64 * tu.ticks_per_second();
         let mut out = match rule {
             Month(n) => {
                 let year = self.year();
@@ -85,7 +91,7 @@ impl DatetimeChunked {
                     .zip(month.into_iter())
                     .map(|(yr, month)| match (yr, month) {
                         (Some(yr), Some(month)) => NaiveDate::from_ymd_opt(yr, month, 1)
-                            .map(|nd| nd.and_hms(0, 0, 0).timestamp_millis()),
+                            .map(|nd| to_timestamp(&nd.and_hms(0, 0, 0))),
                         _ => None,
                     })
                     .collect()
@@ -106,7 +112,7 @@ impl DatetimeChunked {
                                 .num_days_from_monday();
 
                             NaiveDate::from_yo_opt(yr, od + offset)
-                                .map(|nd| nd.and_hms(0, 0, 0).timestamp_millis())
+                                .map(|nd| to_timestamp(&nd.and_hms(0, 0, 0)))
                         }
                         _ => None,
                     })
@@ -114,24 +120,24 @@ impl DatetimeChunked {
             }
             Day(n) => {
                 // just floor divide to create a bucket
-                let fact = 1000 * 3600 * 24 * n;
+                let fact = ticks(3600 * 24 * n);
                 self.deref() / fact * fact
             }
             Hour(n) => {
-                let fact = 1000 * 3600 * n;
+                let fact = ticks(3600 * n);
                 self.deref() / fact * fact
             }
             Minute(n) => {
-                let fact = 1000 * 60 * n;
+                let fact = ticks(60 * n);
                 self.deref() / fact * fact
             }
             Second(n) => {
-                let fact = 1000 * n;
+                let fact = ticks(n);
                 self.deref() / fact * fact
             }
         };
         out.rename(self.name());
-        out.into()
+        out.into_datetime(tu, self.time_zone().clone())
     }
 }
 
@@ -324,8 +330,8 @@ impl DataFrame {
                         key_phys = key_phys / n;
                         key_phys = key_phys * n;
                     }
-                    DataType::Datetime => {
-                        let fact = 1000 * 3600 * 24 * n;
+                    DataType::Datetime(tu, _) => {
+                        let fact = n as i64 * 3600 * 24 * tu.ticks_per_second();
                         // round to lower bucket
                         key_phys = key_phys / fact;
                         key_phys = key_phys * fact;
@@ -346,8 +352,8 @@ impl DataFrame {
                 df.hstack_mut(&[year, day, hour])?;
 
                 match key_dtype {
-                    DataType::Datetime => {
-                        let fact = 1000 * 3600 * n;
+                    DataType::Datetime(tu, _) => {
+                        let fact = n as i64 * 3600 * tu.ticks_per_second();
                         // round to lower bucket
                         key_phys = key_phys / fact;
                         key_phys = key_phys * fact;
@@ -370,8 +376,8 @@ impl DataFrame {
                 df.hstack_mut(&[year, day, hour, minute])?;
 
                 match key_dtype {
-                    DataType::Datetime => {
-                        let fact = 1000 * 60 * n;
+                    DataType::Datetime(tu, _) => {
+                        let fact = n as i64 * 60 * tu.ticks_per_second();
                         // round to lower bucket
                         key_phys = key_phys / fact;
                         key_phys = key_phys * fact;
@@ -397,8 +403,8 @@ impl DataFrame {
                 df.hstack_mut(&[year, day, hour, minute, second])?;
 
                 match key_dtype {
-                    DataType::Datetime => {
-                        let fact = 1000 * n;
+                    DataType::Datetime(tu, _) => {
+                        let fact = n as i64 * tu.ticks_per_second();
                         // round to lower bucket
                         key_phys = key_phys / fact;
                         key_phys = key_phys * fact;
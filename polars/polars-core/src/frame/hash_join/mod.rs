@@ -7,6 +7,8 @@ use crate::frame::hash_join::multiple_keys::{
 use crate::frame::select::Selection;
 use crate::prelude::*;
 use crate::utils::{set_partition_size, split_ca};
+#[cfg(feature = "dtype-binary")]
+use crate::vector_hasher::BytesHash;
 use crate::vector_hasher::{
     create_hash_and_keys_threaded_vectorized, prepare_hashed_relation_threaded, this_partition,
     AsU64, StrHash,
@@ -17,6 +19,8 @@ use hashbrown::hash_map::{Entry, RawEntryMut};
 use hashbrown::HashMap;
 use itertools::Itertools;
 use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::hash::{BuildHasher, Hash, Hasher};
@@ -60,6 +64,7 @@ macro_rules! det_hash_prone_order {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum JoinType {
     Left,
     Inner,
@@ -894,6 +899,72 @@ impl HashJoin<Utf8Type> for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+fn prepare_bytes<'a>(been_split: &'a [BinaryChunked], hb: &RandomState) -> Vec<Vec<BytesHash<'a>>> {
+    POOL.install(|| {
+        been_split
+            .par_iter()
+            .map(|ca| {
+                ca.into_iter()
+                    .map(|opt_b| {
+                        let mut state = hb.build_hasher();
+                        opt_b.hash(&mut state);
+                        let hash = state.finish();
+                        BytesHash::new(opt_b, hash)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    })
+}
+
+#[cfg(feature = "dtype-binary")]
+impl HashJoin<BinaryType> for BinaryChunked {
+    fn hash_join_inner(&self, other: &BinaryChunked) -> Vec<(u32, u32)> {
+        let n_threads = POOL.current_num_threads();
+
+        let (a, b, swap) = det_hash_prone_order!(self, other);
+
+        let hb = RandomState::default();
+        let splitted_a = split_ca(a, n_threads).unwrap();
+        let splitted_b = split_ca(b, n_threads).unwrap();
+
+        let byte_hashes_a = prepare_bytes(&splitted_a, &hb);
+        let byte_hashes_b = prepare_bytes(&splitted_b, &hb);
+        hash_join_tuples_inner(byte_hashes_a, byte_hashes_b, swap)
+    }
+
+    fn hash_join_left(&self, other: &BinaryChunked) -> Vec<(u32, Option<u32>)> {
+        let n_threads = POOL.current_num_threads();
+
+        let hb = RandomState::default();
+        let splitted_a = split_ca(self, n_threads).unwrap();
+        let splitted_b = split_ca(other, n_threads).unwrap();
+
+        let byte_hashes_a = prepare_bytes(&splitted_a, &hb);
+        let byte_hashes_b = prepare_bytes(&splitted_b, &hb);
+        hash_join_tuples_left(byte_hashes_a, byte_hashes_b)
+    }
+
+    fn hash_join_outer(&self, other: &BinaryChunked) -> Vec<(Option<u32>, Option<u32>)> {
+        let (a, b, swap) = det_hash_prone_order!(self, other);
+
+        let n_partitions = set_partition_size();
+        let splitted_a = split_ca(a, n_partitions).unwrap();
+        let splitted_b = split_ca(b, n_partitions).unwrap();
+
+        let iters_a = splitted_a
+            .iter()
+            .map(|ca| ca.into_iter())
+            .collect::<Vec<_>>();
+        let iters_b = splitted_b
+            .iter()
+            .map(|ca| ca.into_iter())
+            .collect::<Vec<_>>();
+        hash_join_tuples_outer(iters_a, iters_b, swap)
+    }
+}
+
 pub trait ZipOuterJoinColumn {
     fn zip_outer_join_column(
         &self,
@@ -969,6 +1040,8 @@ macro_rules! impl_zip_outer_join {
 }
 impl_zip_outer_join!(BooleanChunked);
 impl_zip_outer_join!(Utf8Chunked);
+#[cfg(feature = "dtype-binary")]
+impl_zip_outer_join!(BinaryChunked);
 
 impl ZipOuterJoinColumn for Float32Chunked {
     fn zip_outer_join_column(
@@ -202,7 +202,9 @@ impl<'a> From<&AnyValue<'a>> for Field {
             #[cfg(feature = "dtype-date")]
             Date(_) => Field::new("", DataType::Date),
             #[cfg(feature = "dtype-datetime")]
-            Datetime(_) => Field::new("", DataType::Datetime),
+            Datetime(_, tu, tz) => Field::new("", DataType::Datetime(*tu, (*tz).clone())),
+            #[cfg(feature = "dtype-duration")]
+            Duration(_, tu) => Field::new("", DataType::Duration(*tu)),
             #[cfg(feature = "dtype-time")]
             Time(_) => Field::new("", DataType::Time),
             _ => unimplemented!(),
@@ -235,7 +237,13 @@ pub(crate) enum Buffer {
     #[cfg(feature = "dtype-date")]
     Date(PrimitiveChunkedBuilder<Int32Type>),
     #[cfg(feature = "dtype-datetime")]
-    Datetime(PrimitiveChunkedBuilder<Int64Type>),
+    Datetime(
+        PrimitiveChunkedBuilder<Int64Type>,
+        TimeUnit,
+        Option<TimeZone>,
+    ),
+    #[cfg(feature = "dtype-duration")]
+    Duration(PrimitiveChunkedBuilder<Int64Type>, TimeUnit),
     #[cfg(feature = "dtype-time")]
     Time(PrimitiveChunkedBuilder<Int64Type>),
     Float32(PrimitiveChunkedBuilder<Float32Type>),
@@ -255,7 +263,9 @@ impl Debug for Buffer {
             #[cfg(feature = "dtype-date")]
             Date(_) => f.write_str("Date"),
             #[cfg(feature = "dtype-datetime")]
-            Datetime(_) => f.write_str("datetime"),
+            Datetime(_, _, _) => f.write_str("datetime"),
+            #[cfg(feature = "dtype-duration")]
+            Duration(_, _) => f.write_str("duration"),
             #[cfg(feature = "dtype-time")]
             Time(_) => f.write_str("time"),
             Float32(_) => f.write_str("f32"),
@@ -282,7 +292,17 @@ impl Buffer {
             #[cfg(feature = "dtype-date")]
             (Date(builder), AnyValue::Null) => builder.append_null(),
             #[cfg(feature = "dtype-datetime")]
-            (Datetime(builder), AnyValue::Datetime(v)) => builder.append_value(v),
+            (Datetime(builder, tu, _), AnyValue::Datetime(v, tu_v, _)) => {
+                builder.append_option(convert_time_unit_value(v, tu_v, *tu))
+            }
+            #[cfg(feature = "dtype-datetime")]
+            (Datetime(builder, _, _), AnyValue::Null) => builder.append_null(),
+            #[cfg(feature = "dtype-duration")]
+            (Duration(builder, tu), AnyValue::Duration(v, tu_v)) => {
+                builder.append_option(convert_time_unit_value(v, tu_v, *tu))
+            }
+            #[cfg(feature = "dtype-duration")]
+            (Duration(builder, _), AnyValue::Null) => builder.append_null(),
             #[cfg(feature = "dtype-time")]
             (Time(builder), AnyValue::Time(v)) => builder.append_value(v),
             (Float32(builder), AnyValue::Null) => builder.append_null(),
@@ -306,7 +326,9 @@ impl Buffer {
             #[cfg(feature = "dtype-date")]
             Date(b) => b.finish().into_date().into_series(),
             #[cfg(feature = "dtype-datetime")]
-            Datetime(b) => b.finish().into_date().into_series(),
+            Datetime(b, tu, tz) => b.finish().into_datetime(tu, tz).into_series(),
+            #[cfg(feature = "dtype-duration")]
+            Duration(b, tu) => b.finish().into_duration(tu).into_series(),
             #[cfg(feature = "dtype-time")]
             Time(b) => b.finish().into_date().into_series(),
             Float32(b) => b.finish().into_series(),
@@ -330,7 +352,11 @@ impl From<(&DataType, usize)> for Buffer {
             #[cfg(feature = "dtype-date")]
             Date => Buffer::Date(PrimitiveChunkedBuilder::new("", len)),
             #[cfg(feature = "dtype-datetime")]
-            Datetime => Buffer::Datetime(PrimitiveChunkedBuilder::new("", len)),
+            Datetime(tu, tz) => {
+                Buffer::Datetime(PrimitiveChunkedBuilder::new("", len), *tu, tz.clone())
+            }
+            #[cfg(feature = "dtype-duration")]
+            Duration(tu) => Buffer::Duration(PrimitiveChunkedBuilder::new("", len), *tu),
             #[cfg(feature = "dtype-time")]
             Time => Buffer::Time(PrimitiveChunkedBuilder::new("", len)),
             Float32 => Buffer::Float32(PrimitiveChunkedBuilder::new("", len)),
@@ -28,10 +28,12 @@ pub use crate::{
 };
 pub(crate) use arrow::array::*;
 pub use arrow::datatypes::{Field as ArrowField, Schema as ArrowSchema};
-pub use polars_arrow::prelude::{AlignedVec, LargeListArray, LargeStringArray};
+pub use polars_arrow::prelude::{AlignedVec, LargeBinaryArray, LargeListArray, LargeStringArray};
 pub(crate) use polars_arrow::trusted_len::TrustedLen;
 pub use std::sync::Arc;
 
+#[cfg(feature = "dtype-binary")]
+pub use crate::chunked_array::builder::BinaryChunkedBuilder;
 #[cfg(feature = "object")]
 pub use crate::chunked_array::object::PolarsObject;
 #[cfg(feature = "temporal")]
@@ -1,8 +1,9 @@
 use super::DeDataType;
 use crate::prelude::*;
-use serde::ser::SerializeMap;
+use serde::ser::{Error, SerializeMap};
 use serde::{Serialize, Serializer};
 use std::cell::RefCell;
+use std::convert::TryFrom;
 
 pub struct IterSer<I>
 where
@@ -54,7 +55,7 @@ where
 {
     let mut state = serializer.serialize_map(Some(3))?;
     state.serialize_entry("name", name)?;
-    let dtype: DeDataType = dtype.into();
+    let dtype = DeDataType::try_from(dtype).map_err(S::Error::custom)?;
     state.serialize_entry("datatype", &dtype)?;
     state.serialize_entry("values", &IterSer::new(ca.into_iter()))?;
     state.end()
@@ -105,7 +106,7 @@ macro_rules! impl_serialize {
             {
                 let mut state = serializer.serialize_map(Some(3))?;
                 state.serialize_entry("name", self.name())?;
-                let dtype: DeDataType = self.dtype().into();
+                let dtype = DeDataType::try_from(self.dtype()).map_err(S::Error::custom)?;
                 state.serialize_entry("datatype", &dtype)?;
                 state.serialize_entry("values", &IterSer::new(self.into_iter()))?;
                 state.end()
@@ -127,7 +128,12 @@ impl Serialize for CategoricalChunked {
     where
         S: Serializer,
     {
-        let ca = self.cast(&DataType::Utf8).unwrap();
-        ca.serialize(serializer)
+        // the categories are written as strings
+        let s = self.cast(&DataType::Utf8).map_err(S::Error::custom)?;
+        let mut state = serializer.serialize_map(Some(3))?;
+        state.serialize_entry("name", self.name())?;
+        state.serialize_entry("datatype", &DeDataType::Categorical)?;
+        state.serialize_entry("values", &IterSer::new(s.utf8().unwrap().into_iter()))?;
+        state.end()
     }
 }
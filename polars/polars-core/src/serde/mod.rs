@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
 
 use crate::prelude::*;
 
@@ -9,7 +10,7 @@ pub mod series;
 // remote data structure. The `remote` attribute gives the path to the actual
 // type we intend to derive code for.
 #[derive(Serialize, Deserialize, Debug)]
-#[serde(remote = "TimeUnit")]
+#[serde(remote = "ArrowTimeUnit")]
 enum TimeUnitDef {
     /// Time in seconds.
     Second,
@@ -38,34 +39,49 @@ enum DeDataType<'a> {
     Float64,
     Utf8,
     Date,
-    Datetime,
+    Datetime(TimeUnit, Option<TimeZone>),
+    Duration(TimeUnit),
+    Time,
     #[serde(with = "TimeUnitDef")]
-    Time64(TimeUnit),
+    Time64(ArrowTimeUnit),
     List,
     Object(&'a str),
     Null,
     Categorical,
 }
 
-impl From<&DataType> for DeDataType<'_> {
-    fn from(dt: &DataType) -> Self {
-        match dt {
+impl TryFrom<&DataType> for DeDataType<'_> {
+    type Error = PolarsError;
+
+    fn try_from(dt: &DataType) -> Result<Self> {
+        Ok(match dt {
+            DataType::Int8 => DeDataType::Int8,
+            DataType::UInt8 => DeDataType::UInt8,
+            DataType::Int16 => DeDataType::Int16,
+            DataType::UInt16 => DeDataType::UInt16,
             DataType::Int32 => DeDataType::Int32,
             DataType::UInt32 => DeDataType::UInt32,
             DataType::Int64 => DeDataType::Int64,
             DataType::UInt64 => DeDataType::UInt64,
             DataType::Date => DeDataType::Date,
-            DataType::Datetime => DeDataType::Datetime,
+            DataType::Datetime(tu, tz) => DeDataType::Datetime(*tu, tz.clone()),
+            DataType::Duration(tu) => DeDataType::Duration(*tu),
+            DataType::Time => DeDataType::Time,
             DataType::Float32 => DeDataType::Float32,
             DataType::Float64 => DeDataType::Float64,
             DataType::Utf8 => DeDataType::Utf8,
             DataType::Boolean => DeDataType::Boolean,
             DataType::Null => DeDataType::Null,
             DataType::List(_) => DeDataType::List,
+            DataType::Categorical => DeDataType::Categorical,
             #[cfg(feature = "object")]
             DataType::Object(s) => DeDataType::Object(s),
-            _ => unimplemented!(),
-        }
+            dt => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("serializing a Series of dtype {:?} is not supported", dt).into(),
+                ))
+            }
+        })
     }
 }
 
@@ -119,6 +135,40 @@ mod test {
         assert!(df.frame_equal_missing(&out));
     }
 
+    #[test]
+    #[cfg(all(
+        feature = "dtype-i8",
+        feature = "dtype-u8",
+        feature = "dtype-i16",
+        feature = "dtype-u16",
+        feature = "dtype-categorical"
+    ))]
+    fn test_serde_dtypes() -> Result<()> {
+        let df = DataFrame::new(vec![
+            Series::new("i8", &[Some(1i8), None]),
+            Series::new("u8", &[Some(1u8), None]),
+            Series::new("i16", &[Some(1i16), None]),
+            Series::new("u16", &[Some(1u16), None]),
+            Series::new("cat", &[Some("a"), None]).cast(&DataType::Categorical)?,
+        ])?;
+
+        let json = serde_json::to_string(&df).unwrap();
+        let out = serde_json::from_str::<DataFrame>(&json).unwrap();
+        assert_eq!(out.dtypes(), df.dtypes());
+        assert!(df.frame_equal_missing(&out));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-struct", feature = "dtype-decimal"))]
+    fn test_serde_unsupported_dtype() -> Result<()> {
+        let s = StructChunked::new("s", &[Series::new("a", &[1, 2])])?.into_series();
+        assert!(serde_json::to_string(&s).is_err());
+        let s = DecimalChunked::new_from_opt_slice("d", &[Some(1), None], 5, 2)?.into_series();
+        assert!(serde_json::to_string(&s).is_err());
+        Ok(())
+    }
+
     /// test using the `DeserializedOwned` trait
     #[test]
     fn test_serde_df_owned() {
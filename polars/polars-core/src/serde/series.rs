@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use crate::serde::DeDataType;
 use serde::de::{MapAccess, Visitor};
-use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
 use std::borrow::Cow;
 use std::fmt::Formatter;
 
@@ -13,7 +13,15 @@ impl Serialize for Series {
     where
         S: Serializer,
     {
-        if let Ok(ca) = self.i32() {
+        if let Ok(ca) = self.i8() {
+            ca.serialize(serializer)
+        } else if let Ok(ca) = self.u8() {
+            ca.serialize(serializer)
+        } else if let Ok(ca) = self.i16() {
+            ca.serialize(serializer)
+        } else if let Ok(ca) = self.u16() {
+            ca.serialize(serializer)
+        } else if let Ok(ca) = self.i32() {
             ca.serialize(serializer)
         } else if let Ok(ca) = self.u32() {
             ca.serialize(serializer)
@@ -29,24 +37,25 @@ impl Serialize for Series {
             ca.serialize(serializer)
         } else if let Ok(ca) = self.datetime() {
             ca.serialize(serializer)
+        } else if let Ok(ca) = self.duration() {
+            ca.serialize(serializer)
+        } else if let Ok(ca) = self.time() {
+            ca.serialize(serializer)
         } else if let Ok(ca) = self.utf8() {
             ca.serialize(serializer)
         } else if let Ok(ca) = self.bool() {
             ca.serialize(serializer)
-        } else if let Ok(ca) = self.categorical() {
-            #[cfg(feature = "dtype-categorical")]
-            {
-                ca.serialize(serializer)
-            }
-            #[cfg(not(feature = "dtype-categorical"))]
-            {
-                panic!("activate dtype-categorical");
-            }
         } else if let Ok(ca) = self.list() {
             ca.serialize(serializer)
         } else {
-            // cast small integers to i32
-            self.cast(&DataType::Int32).unwrap().serialize(serializer)
+            #[cfg(feature = "dtype-categorical")]
+            if let Ok(ca) = self.categorical() {
+                return ca.serialize(serializer);
+            }
+            Err(ser::Error::custom(format!(
+                "serializing a Series of dtype {:?} is not supported",
+                self.dtype()
+            )))
         }
     }
 }
@@ -118,6 +127,16 @@ impl<'de> Deserialize<'de> for Series {
                         let values: Vec<Option<u8>> = map.next_value()?;
                         Ok(Series::new(&name, values))
                     }
+                    #[cfg(feature = "dtype-i16")]
+                    DeDataType::Int16 => {
+                        let values: Vec<Option<i16>> = map.next_value()?;
+                        Ok(Series::new(&name, values))
+                    }
+                    #[cfg(feature = "dtype-u16")]
+                    DeDataType::UInt16 => {
+                        let values: Vec<Option<u16>> = map.next_value()?;
+                        Ok(Series::new(&name, values))
+                    }
                     DeDataType::Int32 => {
                         let values: Vec<Option<i32>> = map.next_value()?;
                         Ok(Series::new(&name, values))
@@ -140,12 +159,33 @@ impl<'de> Deserialize<'de> for Series {
                         Ok(Series::new(&name, values).cast(&DataType::Date).unwrap())
                     }
                     #[cfg(feature = "dtype-datetime")]
-                    DeDataType::Datetime => {
+                    DeDataType::Datetime(tu, tz) => {
                         let values: Vec<Option<i64>> = map.next_value()?;
                         Ok(Series::new(&name, values)
-                            .cast(&DataType::Datetime)
+                            .cast(&DataType::Datetime(tu, tz))
                             .unwrap())
                     }
+                    #[cfg(feature = "dtype-duration")]
+                    DeDataType::Duration(tu) => {
+                        let values: Vec<Option<i64>> = map.next_value()?;
+                        Ok(Series::new(&name, values)
+                            .cast(&DataType::Duration(tu))
+                            .unwrap())
+                    }
+                    #[cfg(feature = "dtype-time")]
+                    DeDataType::Time => {
+                        let values: Vec<Option<i64>> = map.next_value()?;
+                        Series::new(&name, values)
+                            .cast(&DataType::Time)
+                            .map_err(de::Error::custom)
+                    }
+                    #[cfg(feature = "dtype-categorical")]
+                    DeDataType::Categorical => {
+                        let values: Vec<Option<&str>> = map.next_value()?;
+                        Series::new(&name, values)
+                            .cast(&DataType::Categorical)
+                            .map_err(de::Error::custom)
+                    }
                     DeDataType::Boolean => {
                         let values: Vec<Option<bool>> = map.next_value()?;
                         Ok(Series::new(&name, values))
@@ -166,9 +206,10 @@ impl<'de> Deserialize<'de> for Series {
                         let values: Vec<Series> = map.next_value()?;
                         Ok(Series::new(&name, values))
                     }
-                    dt => {
-                        panic!("{:?} dtype deserialization not yet implemented", dt)
-                    }
+                    dt => Err(de::Error::custom(format!(
+                        "deserializing a Series of dtype {:?} is not supported",
+                        dt
+                    ))),
                 }
             }
         }
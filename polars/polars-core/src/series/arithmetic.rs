@@ -344,10 +344,25 @@ pub(crate) fn coerce_lhs_rhs<'a>(
     Ok((left, right))
 }
 
+/// Arithmetic between a `Duration` and another temporal type is resolved by the logical
+/// types themselves. Coercing to a supertype first would lose the semantics of the `Duration`.
+#[cfg(feature = "dtype-duration")]
+fn is_duration_arithmetic(lhs: &DataType, rhs: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        (lhs, rhs),
+        (Date | Datetime(_, _) | Duration(_), Duration(_)) | (Duration(_), Date | Datetime(_, _))
+    )
+}
+
 impl ops::Sub for &Series {
     type Output = Series;
 
     fn sub(self, rhs: Self) -> Self::Output {
+        #[cfg(feature = "dtype-duration")]
+        if is_duration_arithmetic(self.dtype(), rhs.dtype()) {
+            return self.subtract(rhs).expect("data types don't match");
+        }
         let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
         lhs.subtract(rhs.as_ref()).expect("data types don't match")
     }
@@ -357,6 +372,10 @@ impl ops::Add for &Series {
     type Output = Series;
 
     fn add(self, rhs: Self) -> Self::Output {
+        #[cfg(feature = "dtype-duration")]
+        if is_duration_arithmetic(self.dtype(), rhs.dtype()) {
+            return self.add_to(rhs).expect("data types don't match");
+        }
         let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
         lhs.add_to(rhs.as_ref()).expect("data types don't match")
     }
@@ -422,7 +441,11 @@ where
 
         let out = match_arrow_data_type_apply_macro_ca_logical_num!(s, sub);
         match self.dtype() {
-            DataType::Datetime | DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(tu, tz) => out.into_datetime(*tu, tz.clone()),
+            DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(tu) => out.into_duration(*tu),
             #[cfg(feature = "dtype-time")]
             DataType::Time => out.into_time(),
             _ => out,
@@ -456,7 +479,11 @@ where
         }
         let out = match_arrow_data_type_apply_macro_ca_logical_num!(s, add);
         match self.dtype() {
-            DataType::Datetime | DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(tu, tz) => out.into_datetime(*tu, tz.clone()),
+            DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(tu) => out.into_duration(*tu),
             #[cfg(feature = "dtype-time")]
             DataType::Time => out.into_time(),
             _ => out,
@@ -491,7 +518,11 @@ where
 
         let out = match_arrow_data_type_apply_macro_ca_logical_num!(s, div);
         match self.dtype() {
-            DataType::Datetime | DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(tu, tz) => out.into_datetime(*tu, tz.clone()),
+            DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(tu) => out.into_duration(*tu),
             #[cfg(feature = "dtype-time")]
             DataType::Time => out.into_time(),
             _ => out,
@@ -525,7 +556,11 @@ where
         }
         let out = match_arrow_data_type_apply_macro_ca_logical_num!(s, mul);
         match self.dtype() {
-            DataType::Datetime | DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(tu, tz) => out.into_datetime(*tu, tz.clone()),
+            DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(tu) => out.into_duration(*tu),
             #[cfg(feature = "dtype-time")]
             DataType::Time => out.into_time(),
             _ => out,
@@ -560,7 +595,11 @@ where
         let out = match_arrow_data_type_apply_macro_ca_logical_num!(s, rem);
 
         match self.dtype() {
-            DataType::Datetime | DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(tu, tz) => out.into_datetime(*tu, tz.clone()),
+            DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(tu) => out.into_duration(*tu),
             #[cfg(feature = "dtype-time")]
             DataType::Time => out.into_time(),
             _ => out,
@@ -636,7 +675,11 @@ where
         let out = match_arrow_data_type_apply_macro_ca_logical_num!(s, sub);
 
         match rhs.dtype() {
-            DataType::Datetime | DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(tu, tz) => out.into_datetime(*tu, tz.clone()),
+            DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(tu) => out.into_duration(*tu),
             #[cfg(feature = "dtype-time")]
             DataType::Time => out.into_time(),
             _ => out,
@@ -652,7 +695,11 @@ where
         let out = match_arrow_data_type_apply_macro_ca_logical_num!(s, div);
 
         match rhs.dtype() {
-            DataType::Datetime | DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(tu, tz) => out.into_datetime(*tu, tz.clone()),
+            DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(tu) => out.into_duration(*tu),
             #[cfg(feature = "dtype-time")]
             DataType::Time => out.into_time(),
             _ => out,
@@ -673,7 +720,11 @@ where
         let out = match_arrow_data_type_apply_macro_ca_logical_num!(s, rem);
 
         match rhs.dtype() {
-            DataType::Datetime | DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(tu, tz) => out.into_datetime(*tu, tz.clone()),
+            DataType::Date => out.into_date(),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(tu) => out.into_duration(*tu),
             #[cfg(feature = "dtype-time")]
             DataType::Time => out.into_time(),
             _ => out,
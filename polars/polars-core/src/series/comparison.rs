@@ -14,6 +14,10 @@ macro_rules! impl_compare {
         match lhs.dtype() {
             DataType::Boolean => lhs.bool().unwrap().$method(rhs.bool().unwrap()),
             DataType::Utf8 => lhs.utf8().unwrap().$method(rhs.utf8().unwrap()),
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => lhs.binary().unwrap().$method(rhs.binary().unwrap()),
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => lhs.decimal().unwrap().$method(rhs.decimal().unwrap()),
             DataType::UInt8 => lhs.u8().unwrap().$method(rhs.u8().unwrap()),
             DataType::UInt16 => lhs.u16().unwrap().$method(rhs.u16().unwrap()),
             DataType::UInt32 => lhs.u32().unwrap().$method(rhs.u32().unwrap()),
@@ -29,10 +33,15 @@ macro_rules! impl_compare {
             #[cfg(feature = "dtype-time")]
             DataType::Time => lhs.time().unwrap().$method(rhs.time().unwrap().deref()),
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime => lhs
+            DataType::Datetime(_, _) => lhs
                 .datetime()
                 .unwrap()
                 .$method(rhs.datetime().unwrap().deref()),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(_) => lhs
+                .duration()
+                .unwrap()
+                .$method(rhs.duration().unwrap().deref()),
             DataType::List(_) => lhs.list().unwrap().$method(rhs.list().unwrap()),
             #[cfg(feature = "dtype-categorical")]
             DataType::Categorical => lhs
@@ -130,6 +130,19 @@ impl std::convert::TryFrom<(&str, Vec<ArrayRef>)> for Series {
                 let chunks = cast_chunks(&chunks, &DataType::Utf8).unwrap();
                 Ok(Utf8Chunked::new_from_chunks(name, chunks).into_series())
             }
+            #[cfg(feature = "dtype-binary")]
+            ArrowDataType::LargeBinary => {
+                Ok(BinaryChunked::new_from_chunks(name, chunks).into_series())
+            }
+            #[cfg(feature = "dtype-binary")]
+            ArrowDataType::Binary => {
+                let chunks = cast_chunks(&chunks, &DataType::Binary)?;
+                Ok(BinaryChunked::new_from_chunks(name, chunks).into_series())
+            }
+            #[cfg(feature = "dtype-decimal")]
+            ArrowDataType::Decimal(precision, scale) => {
+                Ok(DecimalChunked::new_from_chunks(name, chunks, *precision, *scale).into_series())
+            }
             ArrowDataType::List(fld) => {
                 let chunks = chunks
                     .iter()
@@ -180,22 +193,26 @@ impl std::convert::TryFrom<(&str, Vec<ArrayRef>)> for Series {
             }
             #[cfg(feature = "dtype-datetime")]
             ArrowDataType::Timestamp(tu, tz) => {
-                let s = if tz.is_none() || tz == &Some("".to_string()) {
-                    let chunks = cast_chunks(&chunks, &DataType::Int64).unwrap();
-                    Int64Chunked::new_from_chunks(name, chunks)
-                        .into_date()
-                        .into_series()
-                } else {
-                    return Err(PolarsError::InvalidOperation(
-                        "Cannot create polars series timestamp with timezone".into(),
-                    ));
+                // an empty time zone string is treated as a naive timestamp
+                let tz = tz.clone().filter(|tz| !tz.is_empty());
+                let chunks = cast_chunks(&chunks, &DataType::Int64).unwrap();
+                let ca = Int64Chunked::new_from_chunks(name, chunks);
+                // seconds are the only unit we cannot represent, the others are kept as is
+                let ca = match tu {
+                    ArrowTimeUnit::Second => &ca * 1000,
+                    _ => ca,
                 };
-                Ok(match tu {
-                    TimeUnit::Second => &s * 1000,
-                    TimeUnit::Millisecond => s,
-                    TimeUnit::Microsecond => &s / 1000,
-                    TimeUnit::Nanosecond => &s / 1000000,
-                })
+                Ok(ca.into_datetime(tu.into(), tz).into_series())
+            }
+            #[cfg(feature = "dtype-duration")]
+            ArrowDataType::Duration(tu) => {
+                let chunks = cast_chunks(&chunks, &DataType::Int64).unwrap();
+                let ca = Int64Chunked::new_from_chunks(name, chunks);
+                let ca = match tu {
+                    ArrowTimeUnit::Second => &ca * 1000,
+                    _ => ca,
+                };
+                Ok(ca.into_duration(tu.into()).into_series())
             }
             #[cfg(feature = "dtype-time")]
             ArrowDataType::Time64(tu) | ArrowDataType::Time32(tu) => {
@@ -204,10 +221,10 @@ impl std::convert::TryFrom<(&str, Vec<ArrayRef>)> for Series {
                     .into_time()
                     .into_series();
                 Ok(match tu {
-                    TimeUnit::Second => &s * 1000000000,
-                    TimeUnit::Millisecond => &s * 1000000,
-                    TimeUnit::Microsecond => &s * 1000,
-                    TimeUnit::Nanosecond => s,
+                    ArrowTimeUnit::Second => &s * 1000000000,
+                    ArrowTimeUnit::Millisecond => &s * 1000000,
+                    ArrowTimeUnit::Microsecond => &s * 1000,
+                    ArrowTimeUnit::Nanosecond => s,
                 })
             }
             ArrowDataType::LargeList(fld) => {
@@ -217,6 +234,44 @@ impl std::convert::TryFrom<(&str, Vec<ArrayRef>)> for Series {
                     .collect();
                 Ok(ListChunked::new_from_chunks(name, chunks).into_series())
             }
+            #[cfg(feature = "dtype-struct")]
+            ArrowDataType::Struct(fields) => {
+                use arrow::bitmap::Bitmap;
+                // every field becomes a Series of the child arrays at that position
+                let fields = fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, fld)| {
+                        let field_chunks = chunks
+                            .iter()
+                            .map(|arr| {
+                                let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+                                arr.values()[i].clone()
+                            })
+                            .collect();
+                        Series::try_from((fld.name().as_str(), field_chunks))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                let validity = if chunks.iter().any(|arr| arr.null_count() > 0) {
+                    let chunks = chunks
+                        .iter()
+                        .map(|arr| {
+                            let bitmap = arr
+                                .validity()
+                                .cloned()
+                                .unwrap_or_else(|| !(&Bitmap::new_zeroed(arr.len())));
+                            Arc::new(BooleanArray::from_data_default(bitmap, None)) as ArrayRef
+                        })
+                        .collect();
+                    Some(BooleanChunked::new_from_chunks(name, chunks))
+                } else {
+                    None
+                };
+                Ok(StructChunked::new(name, &fields)?
+                    .with_validity(validity)?
+                    .into_series())
+            }
             ArrowDataType::Null => {
                 // we don't support null types yet so we use a small digit type filled with nulls
                 let len = chunks.iter().fold(0, |acc, array| acc + array.len());
@@ -413,6 +468,13 @@ impl From<DatetimeChunked> for Series {
     }
 }
 
+#[cfg(feature = "dtype-duration")]
+impl From<DurationChunked> for Series {
+    fn from(a: DurationChunked) -> Self {
+        a.into_series()
+    }
+}
+
 #[cfg(feature = "dtype-time")]
 impl From<TimeChunked> for Series {
     fn from(a: TimeChunked) -> Self {
@@ -0,0 +1,378 @@
+use super::private;
+use super::IntoSeries;
+use super::SeriesTrait;
+use crate::chunked_array::{
+    ops::compare_inner::{
+        IntoPartialEqInner, IntoPartialOrdInner, PartialEqInner, PartialOrdInner,
+    },
+    ChunkIdIter,
+};
+use crate::fmt::FmtList;
+use crate::frame::groupby::*;
+use crate::frame::hash_join::{HashJoin, ZipOuterJoinColumn};
+use crate::prelude::*;
+use crate::series::implementations::SeriesWrap;
+use ahash::RandomState;
+use arrow::array::ArrayRef;
+#[cfg(feature = "object")]
+use std::any::Any;
+use std::borrow::Cow;
+
+impl IntoSeries for BinaryChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<BinaryChunked> {}
+
+impl private::PrivateSeries for SeriesWrap<BinaryChunked> {
+    fn _field(&self) -> Cow<Field> {
+        Cow::Borrowed(self.0.ref_field())
+    }
+    fn _dtype(&self) -> &DataType {
+        self.0.ref_field().data_type()
+    }
+
+    fn set_sorted(&mut self, reverse: bool) {
+        self.0.set_sorted(reverse)
+    }
+
+    fn into_partial_eq_inner<'a>(&'a self) -> Box<dyn PartialEqInner + 'a> {
+        (&self.0).into_partial_eq_inner()
+    }
+    fn into_partial_ord_inner<'a>(&'a self) -> Box<dyn PartialOrdInner + 'a> {
+        (&self.0).into_partial_ord_inner()
+    }
+
+    fn vec_hash(&self, random_state: RandomState) -> AlignedVec<u64> {
+        self.0.vec_hash(random_state)
+    }
+
+    fn vec_hash_combine(&self, build_hasher: RandomState, hashes: &mut [u64]) {
+        self.0.vec_hash_combine(build_hasher, hashes)
+    }
+
+    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        self.0.agg_first(groups)
+    }
+
+    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        self.0.agg_last(groups)
+    }
+
+    fn agg_n_unique(&self, groups: &[(u32, Vec<u32>)]) -> Option<UInt32Chunked> {
+        self.0.agg_n_unique(groups)
+    }
+
+    #[cfg(feature = "lazy")]
+    fn agg_valid_count(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        self.0.agg_valid_count(groups)
+    }
+
+    fn hash_join_inner(&self, other: &Series) -> Vec<(u32, u32)> {
+        HashJoin::hash_join_inner(&self.0, other.as_ref().as_ref())
+    }
+    fn hash_join_left(&self, other: &Series) -> Vec<(u32, Option<u32>)> {
+        HashJoin::hash_join_left(&self.0, other.as_ref().as_ref())
+    }
+    fn hash_join_outer(&self, other: &Series) -> Vec<(Option<u32>, Option<u32>)> {
+        HashJoin::hash_join_outer(&self.0, other.as_ref().as_ref())
+    }
+    fn zip_outer_join_column(
+        &self,
+        right_column: &Series,
+        opt_join_tuples: &[(Option<u32>, Option<u32>)],
+    ) -> Series {
+        ZipOuterJoinColumn::zip_outer_join_column(&self.0, right_column, opt_join_tuples)
+    }
+    fn group_tuples(&self, multithreaded: bool) -> GroupTuples {
+        IntoGroupTuples::group_tuples(&self.0, multithreaded)
+    }
+
+    fn str_value(&self, index: usize) -> Cow<str> {
+        Cow::Owned(format!("{}", self.get(index)))
+    }
+}
+
+impl SeriesTrait for SeriesWrap<BinaryChunked> {
+    #[cfg(feature = "interpolate")]
+    fn interpolate(&self) -> Series {
+        self.0.clone().into_series()
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkIdIter {
+        self.0.chunk_id()
+    }
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    fn binary(&self) -> Result<&BinaryChunked> {
+        unsafe { Ok(&*(self as *const dyn SeriesTrait as *const BinaryChunked)) }
+    }
+
+    fn append_array(&mut self, other: ArrayRef) -> Result<()> {
+        self.0.append_array(other)
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_series()
+    }
+
+    fn append(&mut self, other: &Series) -> Result<()> {
+        if self.0.dtype() == other.dtype() {
+            self.0.append(other.as_ref().as_ref());
+            Ok(())
+        } else {
+            Err(PolarsError::DataTypeMisMatch(
+                "cannot append Series; data types don't match".into(),
+            ))
+        }
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
+        ChunkFilter::filter(&self.0, filter).map(|ca| ca.into_series())
+    }
+
+    fn take(&self, indices: &UInt32Chunked) -> Result<Series> {
+        let indices = if indices.chunks.len() > 1 {
+            Cow::Owned(indices.rechunk())
+        } else {
+            Cow::Borrowed(indices)
+        };
+        Ok(ChunkTake::take(&self.0, (&*indices).into())?.into_series())
+    }
+
+    fn take_iter(&self, iter: &mut dyn TakeIterator) -> Result<Series> {
+        Ok(ChunkTake::take(&self.0, iter.into())?.into_series())
+    }
+
+    fn take_every(&self, n: usize) -> Series {
+        self.0.take_every(n).into_series()
+    }
+
+    unsafe fn take_iter_unchecked(&self, iter: &mut dyn TakeIterator) -> Series {
+        ChunkTake::take_unchecked(&self.0, iter.into()).into_series()
+    }
+
+    unsafe fn take_unchecked(&self, idx: &UInt32Chunked) -> Result<Series> {
+        let idx = if idx.chunks.len() > 1 {
+            Cow::Owned(idx.rechunk())
+        } else {
+            Cow::Borrowed(idx)
+        };
+        Ok(ChunkTake::take_unchecked(&self.0, (&*idx).into()).into_series())
+    }
+
+    unsafe fn take_opt_iter_unchecked(&self, iter: &mut dyn TakeIteratorNulls) -> Series {
+        ChunkTake::take_unchecked(&self.0, iter.into()).into_series()
+    }
+
+    #[cfg(feature = "take_opt_iter")]
+    fn take_opt_iter(&self, iter: &mut dyn TakeIteratorNulls) -> Result<Series> {
+        Ok(ChunkTake::take(&self.0, iter.into())?.into_series())
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        ChunkOps::rechunk(&self.0).into_series()
+    }
+
+    fn head(&self, length: Option<usize>) -> Series {
+        self.0.head(length).into_series()
+    }
+
+    fn tail(&self, length: Option<usize>) -> Series {
+        self.0.tail(length).into_series()
+    }
+
+    fn expand_at_index(&self, index: usize, length: usize) -> Series {
+        ChunkExpandAtIndex::expand_at_index(&self.0, index, length).into_series()
+    }
+
+    fn cast(&self, data_type: &DataType) -> Result<Series> {
+        self.0.cast(data_type)
+    }
+
+    fn value_counts(&self) -> Result<DataFrame> {
+        ChunkUnique::value_counts(&self.0)
+    }
+
+    fn get(&self, index: usize) -> AnyValue {
+        self.0.get_any_value(index)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_in_place(&mut self, reverse: bool) {
+        ChunkSort::sort_in_place(&mut self.0, reverse);
+    }
+
+    fn sort(&self, reverse: bool) -> Series {
+        ChunkSort::sort(&self.0, reverse).into_series()
+    }
+
+    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+        ChunkSort::argsort(&self.0, reverse)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn unique(&self) -> Result<Series> {
+        ChunkUnique::unique(&self.0).map(|ca| ca.into_series())
+    }
+
+    fn n_unique(&self) -> Result<usize> {
+        ChunkUnique::n_unique(&self.0)
+    }
+
+    fn arg_unique(&self) -> Result<UInt32Chunked> {
+        ChunkUnique::arg_unique(&self.0)
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn is_unique(&self) -> Result<BooleanChunked> {
+        ChunkUnique::is_unique(&self.0)
+    }
+
+    fn is_duplicated(&self) -> Result<BooleanChunked> {
+        ChunkUnique::is_duplicated(&self.0)
+    }
+
+    fn reverse(&self) -> Series {
+        ChunkReverse::reverse(&self.0).into_series()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        ChunkShift::shift(&self.0, periods).into_series()
+    }
+
+    fn fmt_list(&self) -> String {
+        FmtList::fmt_list(&self.0)
+    }
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
+        self.0
+            .sample_n(n, with_replacement)
+            .map(|ca| ca.into_series())
+    }
+
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
+        self.0
+            .sample_frac(frac, with_replacement)
+            .map(|ca| ca.into_series())
+    }
+
+    #[cfg(feature = "is_in")]
+    fn is_in(&self, other: &Series) -> Result<BooleanChunked> {
+        IsIn::is_in(&self.0, other)
+    }
+
+    #[cfg(feature = "object")]
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    fn bytes() -> Series {
+        BinaryChunked::new_from_opt_slice(
+            "a",
+            &[
+                Some(&b"foo"[..]),
+                None,
+                Some(&b"\x00\xff"[..]),
+                Some(&b"foo"[..]),
+            ],
+        )
+        .into_series()
+    }
+
+    #[test]
+    fn test_binary_series_ops() -> Result<()> {
+        let s = bytes();
+        assert_eq!(s.dtype(), &DataType::Binary);
+        assert_eq!(s.null_count(), 1);
+
+        let idx = UInt32Chunked::new_from_slice("", &[2, 0]);
+        let out = s.take(&idx)?;
+        assert_eq!(
+            Vec::from(out.binary()?),
+            &[Some(&b"\x00\xff"[..]), Some(&b"foo"[..])]
+        );
+
+        let mask = BooleanChunked::new_from_slice("", &[false, true, true, false]);
+        let out = s.filter(&mask)?;
+        assert_eq!(Vec::from(out.binary()?), &[None, Some(&b"\x00\xff"[..])]);
+
+        let out = s.sort(false);
+        assert_eq!(
+            Vec::from(out.binary()?),
+            &[
+                None,
+                Some(&b"\x00\xff"[..]),
+                Some(&b"foo"[..]),
+                Some(&b"foo"[..])
+            ]
+        );
+
+        assert_eq!(s.n_unique()?, 3);
+        assert!(s.series_equal_missing(&s.rechunk()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary_groupby_join() -> Result<()> {
+        let df = DataFrame::new(vec![bytes(), Series::new("b", &[1, 2, 3, 4])])?;
+        let out = df.groupby("a")?.select("b").sum()?.sort("b_sum", false)?;
+        assert_eq!(
+            Vec::from(out.column("b_sum")?.i32()?),
+            &[Some(2), Some(3), Some(5)]
+        );
+
+        let right = DataFrame::new(vec![
+            BinaryChunked::new_from_slice("a", &[&b"foo"[..]]).into_series(),
+            Series::new("c", &[true]),
+        ])?;
+        let out = df.inner_join(&right, "a", "a")?;
+        assert_eq!(Vec::from(out.column("b")?.i32()?), &[Some(1), Some(4)]);
+        Ok(())
+    }
+}
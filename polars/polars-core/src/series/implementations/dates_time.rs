@@ -26,8 +26,11 @@ use std::any::Any;
 use std::borrow::Cow;
 use std::ops::{Deref, DerefMut};
 
+// Every `$meta` names a method on the logical array whose output is passed on to
+// `$into_logical`, so that metadata like the time unit and time zone of a `Datetime`
+// survives the round trip.
 macro_rules! impl_dyn_series {
-    ($ca: ident, $into_logical: ident) => {
+    ($ca: ident, $into_logical: ident $(, $meta: ident)*) => {
         impl IntoSeries for $ca {
             fn into_series(self) -> Series {
                 Series(Arc::new(SeriesWrap(self)))
@@ -45,18 +48,18 @@ macro_rules! impl_dyn_series {
             fn explode_by_offsets(&self, offsets: &[i64]) -> Series {
                 self.0
                     .explode_by_offsets(offsets)
-                    .$into_logical()
+                    .$into_logical($(self.0.$meta().to_owned()),*)
                     .into_series()
             }
 
             #[cfg(feature = "cum_agg")]
             fn _cummax(&self, reverse: bool) -> Series {
-                self.0.cummax(reverse).$into_logical().into_series()
+                self.0.cummax(reverse).$into_logical($(self.0.$meta().to_owned()),*).into_series()
             }
 
             #[cfg(feature = "cum_agg")]
             fn _cummin(&self, reverse: bool) -> Series {
-                self.0.cummin(reverse).$into_logical().into_series()
+                self.0.cummin(reverse).$into_logical($(self.0.$meta().to_owned()),*).into_series()
             }
 
             #[cfg(feature = "cum_agg")]
@@ -88,7 +91,7 @@ macro_rules! impl_dyn_series {
                 let other = other.to_physical_repr().into_owned();
                 self.0
                     .zip_with(mask, &other.as_ref().as_ref())
-                    .map(|ca| ca.$into_logical().into_series())
+                    .map(|ca| ca.$into_logical($(self.0.$meta().to_owned()),*).into_series())
             }
 
             fn vec_hash(&self, random_state: RandomState) -> AlignedVec<u64> {
@@ -107,26 +110,31 @@ macro_rules! impl_dyn_series {
             fn agg_min(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
                 self.0
                     .agg_min(groups)
-                    .map(|ca| ca.$into_logical().into_series())
+                    .map(|ca| ca.$into_logical($(self.0.$meta().to_owned()),*).into_series())
             }
 
             fn agg_max(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
                 self.0
                     .agg_max(groups)
-                    .map(|ca| ca.$into_logical().into_series())
+                    .map(|ca| ca.$into_logical($(self.0.$meta().to_owned()),*).into_series())
             }
 
-            fn agg_sum(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
-                // does not make sense on logical
-                None
+            fn agg_sum(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+                match self.dtype() {
+                    // durations are the only logical type that can be summed
+                    #[cfg(feature = "dtype-duration")]
+                    DataType::Duration(tu) => self.0.agg_sum(groups).map(|s| s.into_duration(*tu)),
+                    // does not make sense on other logical types
+                    _ => None,
+                }
             }
 
             fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
-                self.0.agg_first(groups).$into_logical().into_series()
+                self.0.agg_first(groups).$into_logical($(self.0.$meta().to_owned()),*).into_series()
             }
 
             fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
-                self.0.agg_last(groups).$into_logical().into_series()
+                self.0.agg_last(groups).$into_logical($(self.0.$meta().to_owned()),*).into_series()
             }
 
             fn agg_std(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
@@ -154,13 +162,13 @@ macro_rules! impl_dyn_series {
             fn agg_quantile(&self, groups: &[(u32, Vec<u32>)], quantile: f64) -> Option<Series> {
                 self.0
                     .agg_quantile(groups, quantile)
-                    .map(|s| s.$into_logical().into_series())
+                    .map(|s| s.$into_logical($(self.0.$meta().to_owned()),*).into_series())
             }
 
             fn agg_median(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
                 self.0
                     .agg_median(groups)
-                    .map(|s| s.$into_logical().into_series())
+                    .map(|s| s.$into_logical($(self.0.$meta().to_owned()),*).into_series())
             }
             #[cfg(feature = "lazy")]
             fn agg_valid_count(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
@@ -207,20 +215,67 @@ macro_rules! impl_dyn_series {
                 let right_column = right_column.to_physical_repr().into_owned();
                 self.0
                     .zip_outer_join_column(&right_column, opt_join_tuples)
-                    .$into_logical()
+                    .$into_logical($(self.0.$meta().to_owned()),*)
                     .into_series()
             }
             fn subtract(&self, rhs: &Series) -> Result<Series> {
                 match (self.dtype(), rhs.dtype()) {
+                    #[cfg(feature = "dtype-duration")]
+                    (DataType::Date, DataType::Date) => {
+                        let dt = DataType::Datetime(TimeUnit::Milliseconds, None);
+                        let lhs = self.cast(&dt)?;
+                        let rhs = rhs.cast(&dt)?;
+                        lhs.subtract(&rhs)
+                    }
+                    #[cfg(not(feature = "dtype-duration"))]
                     (DataType::Date, DataType::Date) => {
                         let lhs = self.cast(&DataType::Int32).unwrap();
                         let rhs = rhs.cast(&DataType::Int32).unwrap();
-                        Ok(lhs.subtract(&rhs)?.$into_logical().into_series())
+                        Ok(lhs
+                            .subtract(&rhs)?
+                            .$into_logical($(self.0.$meta().to_owned()),*)
+                            .into_series())
+                    }
+                    #[cfg(feature = "dtype-duration")]
+                    (DataType::Datetime(tu, _), DataType::Datetime(_, _)) => {
+                        let lhs = self.cast(&DataType::Int64).unwrap();
+                        let rhs = rhs
+                            .cast(&DataType::Datetime(*tu, None))?
+                            .cast(&DataType::Int64)
+                            .unwrap();
+                        Ok(lhs.subtract(&rhs)?.into_duration(*tu))
                     }
-                    (DataType::Datetime, DataType::Datetime) => {
+                    #[cfg(not(feature = "dtype-duration"))]
+                    (DataType::Datetime(_, _), DataType::Datetime(_, _)) => {
                         let lhs = self.cast(&DataType::Int64).unwrap();
                         let rhs = rhs.cast(&DataType::Int64).unwrap();
-                        Ok(lhs.subtract(&rhs)?.$into_logical().into_series())
+                        Ok(lhs
+                            .subtract(&rhs)?
+                            .$into_logical($(self.0.$meta().to_owned()),*)
+                            .into_series())
+                    }
+                    #[cfg(feature = "dtype-duration")]
+                    (DataType::Date, DataType::Duration(tu)) => {
+                        let lhs = self.cast(&DataType::Datetime(*tu, None))?;
+                        lhs.subtract(rhs)
+                    }
+                    #[cfg(feature = "dtype-duration")]
+                    (DataType::Datetime(tu, tz), DataType::Duration(_)) => {
+                        let lhs = self.cast(&DataType::Int64).unwrap();
+                        let rhs = rhs
+                            .cast(&DataType::Duration(*tu))?
+                            .cast(&DataType::Int64)
+                            .unwrap();
+                        Ok(lhs.subtract(&rhs)?.into_datetime(*tu, tz.clone()))
+                    }
+                    #[cfg(feature = "dtype-duration")]
+                    (DataType::Duration(tu), DataType::Duration(_)) => {
+                        let lhs = self.cast(&DataType::Int64).unwrap();
+                        let rhs = rhs
+                            .cast(&DataType::Duration(*tu))?
+                            .cast(&DataType::Int64)
+                            .unwrap();
+                        Ok(lhs.subtract(&rhs)?.into_duration(*tu))
                     }
                     (dtl, dtr) => Err(PolarsError::ComputeError(
                         format!(
@@ -231,6 +286,47 @@ macro_rules! impl_dyn_series {
                     )),
                 }
             }
+            #[cfg(feature = "dtype-duration")]
+            fn add_to(&self, rhs: &Series) -> Result<Series> {
+                match (self.dtype(), rhs.dtype()) {
+                    (DataType::Date, DataType::Duration(tu)) => {
+                        let lhs = self.cast(&DataType::Datetime(*tu, None))?;
+                        lhs.add_to(rhs)
+                    }
+                    (DataType::Duration(tu), DataType::Date) => {
+                        let rhs = rhs.cast(&DataType::Datetime(*tu, None))?;
+                        self.add_to(&rhs)
+                    }
+                    // the result takes the unit of the datetime operand
+                    (DataType::Datetime(tu, tz), DataType::Duration(_)) => {
+                        let lhs = self.cast(&DataType::Int64).unwrap();
+                        let rhs = rhs
+                            .cast(&DataType::Duration(*tu))?
+                            .cast(&DataType::Int64)
+                            .unwrap();
+                        Ok(lhs.add_to(&rhs)?.into_datetime(*tu, tz.clone()))
+                    }
+                    (DataType::Duration(_), DataType::Datetime(_, _)) => {
+                        rhs.add_to(&self.0.clone().into_series())
+                    }
+                    (DataType::Duration(tu), DataType::Duration(_)) => {
+                        let lhs = self.cast(&DataType::Int64).unwrap();
+                        let rhs = rhs
+                            .cast(&DataType::Duration(*tu))?
+                            .cast(&DataType::Int64)
+                            .unwrap();
+                        Ok(lhs.add_to(&rhs)?.into_duration(*tu))
+                    }
+                    (dtl, dtr) => Err(PolarsError::ComputeError(
+                        format!(
+                            "cannot do addition on these date types: {:?}, {:?}",
+                            dtl, dtr
+                        )
+                        .into(),
+                    )),
+                }
+            }
+            #[cfg(not(feature = "dtype-duration"))]
             fn add_to(&self, _rhs: &Series) -> Result<Series> {
                 Err(PolarsError::ComputeError(
                     "cannot do addition on logical".into(),
@@ -268,7 +364,7 @@ macro_rules! impl_dyn_series {
         impl SeriesTrait for SeriesWrap<$ca> {
             #[cfg(feature = "interpolate")]
             fn interpolate(&self) -> Series {
-                self.0.interpolate().$into_logical().into_series()
+                self.0.interpolate().$into_logical($(self.0.$meta().to_owned()),*).into_series()
             }
 
             fn rename(&mut self, name: &str) {
@@ -321,7 +417,7 @@ macro_rules! impl_dyn_series {
             }
 
             fn datetime(&self) -> Result<&DatetimeChunked> {
-                if matches!(self.0.dtype(), DataType::Datetime) {
+                if matches!(self.0.dtype(), DataType::Datetime(_, _)) {
                     unsafe { Ok(&*(self as *const dyn SeriesTrait as *const DatetimeChunked)) }
                 } else {
                     Err(PolarsError::DataTypeMisMatch(
@@ -335,12 +431,27 @@ macro_rules! impl_dyn_series {
                 }
             }
 
+            fn duration(&self) -> Result<&DurationChunked> {
+                if matches!(self.0.dtype(), DataType::Duration(_)) {
+                    unsafe { Ok(&*(self as *const dyn SeriesTrait as *const DurationChunked)) }
+                } else {
+                    Err(PolarsError::DataTypeMisMatch(
+                        format!(
+                            "cannot unpack Series: {:?} of type {:?} into duration",
+                            self.name(),
+                            self.dtype(),
+                        )
+                        .into(),
+                    ))
+                }
+            }
+
             fn append_array(&mut self, other: ArrayRef) -> Result<()> {
                 self.0.append_array(other)
             }
 
             fn slice(&self, offset: i64, length: usize) -> Series {
-                self.0.slice(offset, length).$into_logical().into_series()
+                self.0.slice(offset, length).$into_logical($(self.0.$meta().to_owned()),*).into_series()
             }
 
             fn mean(&self) -> Option<f64> {
@@ -366,45 +477,45 @@ macro_rules! impl_dyn_series {
             fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
                 self.0
                     .filter(filter)
-                    .map(|ca| ca.$into_logical().into_series())
+                    .map(|ca| ca.$into_logical($(self.0.$meta().to_owned()),*).into_series())
             }
 
             fn take(&self, indices: &UInt32Chunked) -> Result<Series> {
                 ChunkTake::take(self.0.deref(), indices.into())
-                    .map(|ca| ca.$into_logical().into_series())
+                    .map(|ca| ca.$into_logical($(self.0.$meta().to_owned()),*).into_series())
             }
 
             fn take_iter(&self, iter: &mut dyn TakeIterator) -> Result<Series> {
                 ChunkTake::take(self.0.deref(), iter.into())
-                    .map(|ca| ca.$into_logical().into_series())
+                    .map(|ca| ca.$into_logical($(self.0.$meta().to_owned()),*).into_series())
             }
 
             fn take_every(&self, n: usize) -> Series {
-                self.0.take_every(n).$into_logical().into_series()
+                self.0.take_every(n).$into_logical($(self.0.$meta().to_owned()),*).into_series()
             }
 
             unsafe fn take_iter_unchecked(&self, iter: &mut dyn TakeIterator) -> Series {
                 ChunkTake::take_unchecked(self.0.deref(), iter.into())
-                    .$into_logical()
+                    .$into_logical($(self.0.$meta().to_owned()),*)
                     .into_series()
             }
 
             unsafe fn take_unchecked(&self, idx: &UInt32Chunked) -> Result<Series> {
                 Ok(ChunkTake::take_unchecked(self.0.deref(), idx.into())
-                    .$into_logical()
+                    .$into_logical($(self.0.$meta().to_owned()),*)
                     .into_series())
             }
 
             unsafe fn take_opt_iter_unchecked(&self, iter: &mut dyn TakeIteratorNulls) -> Series {
                 ChunkTake::take_unchecked(self.0.deref(), iter.into())
-                    .$into_logical()
+                    .$into_logical($(self.0.$meta().to_owned()),*)
                     .into_series()
             }
 
             #[cfg(feature = "take_opt_iter")]
             fn take_opt_iter(&self, iter: &mut dyn TakeIteratorNulls) -> Result<Series> {
                 ChunkTake::take(self.0.deref(), iter.into())
-                    .map(|ca| ca.$into_logical().into_series())
+                    .map(|ca| ca.$into_logical($(self.0.$meta().to_owned()),*).into_series())
             }
 
             fn len(&self) -> usize {
@@ -412,39 +523,55 @@ macro_rules! impl_dyn_series {
             }
 
             fn rechunk(&self) -> Series {
-                self.0.rechunk().$into_logical().into_series()
+                self.0.rechunk().$into_logical($(self.0.$meta().to_owned()),*).into_series()
             }
 
             fn head(&self, length: Option<usize>) -> Series {
-                self.0.head(length).$into_logical().into_series()
+                self.0.head(length).$into_logical($(self.0.$meta().to_owned()),*).into_series()
             }
 
             fn tail(&self, length: Option<usize>) -> Series {
-                self.0.tail(length).$into_logical().into_series()
+                self.0.tail(length).$into_logical($(self.0.$meta().to_owned()),*).into_series()
             }
 
             fn expand_at_index(&self, index: usize, length: usize) -> Series {
                 self.0
                     .expand_at_index(index, length)
-                    .$into_logical()
+                    .$into_logical($(self.0.$meta().to_owned()),*)
                     .into_series()
             }
 
             fn cast(&self, data_type: &DataType) -> Result<Series> {
-                const MS_IN_DAY: i64 = 86400000;
+                const SECONDS_IN_DAY: i64 = 86400;
                 use DataType::*;
                 let ca = match (self.dtype(), data_type) {
                     #[cfg(feature = "dtype-datetime")]
-                    (Date, Datetime) => {
-                        let casted = self.0.cast(data_type)?;
-                        let casted = casted.datetime().unwrap();
-                        return Ok((casted.deref() * MS_IN_DAY).into_date().into_series());
+                    (Date, Datetime(tu, tz)) => {
+                        let casted = self.0.cast(&Int64)?;
+                        let casted = casted.i64().unwrap();
+                        return Ok((casted * (SECONDS_IN_DAY * tu.ticks_per_second()))
+                            .into_datetime(*tu, tz.clone())
+                            .into_series());
                     }
                     #[cfg(feature = "dtype-date")]
-                    (Datetime, Date) => {
-                        let ca = self.0.deref() / MS_IN_DAY;
+                    (Datetime(tu, _), Date) => {
+                        let ca = self.0.deref() / (SECONDS_IN_DAY * tu.ticks_per_second());
                         Cow::Owned(ca)
                     }
+                    #[cfg(feature = "dtype-datetime")]
+                    (Datetime(from, _), Datetime(to, tz)) => {
+                        let ca = self.0.cast(&Int64)?;
+                        return Ok(convert_time_unit(ca.i64().unwrap(), *from, *to)
+                            .into_datetime(*to, tz.clone())
+                            .into_series());
+                    }
+                    #[cfg(feature = "dtype-duration")]
+                    (Duration(from), Duration(to)) => {
+                        let ca = self.0.cast(&Int64)?;
+                        return Ok(convert_time_unit(ca.i64().unwrap(), *from, *to)
+                            .into_duration(*to)
+                            .into_series());
+                    }
                     _ => Cow::Borrowed(self.0.deref()),
                 };
                 ca.cast(data_type)
@@ -464,16 +591,18 @@ macro_rules! impl_dyn_series {
 
             #[inline]
             unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
-                self.0.get_any_value_unchecked(index).$into_logical()
+                self.0
+                    .get_any_value_unchecked(index)
+                    .$into_logical($(self.0.$meta()),*)
             }
 
             fn sort_in_place(&mut self, reverse: bool) {
                 let ca = self.0.deref().sort(reverse);
-                self.0 = ca.$into_logical();
+                self.0 = ca.$into_logical($(self.0.$meta().to_owned()),*);
             }
 
             fn sort(&self, reverse: bool) -> Series {
-                self.0.sort(reverse).$into_logical().into_series()
+                self.0.sort(reverse).$into_logical($(self.0.$meta().to_owned()),*).into_series()
             }
 
             fn argsort(&self, reverse: bool) -> UInt32Chunked {
@@ -485,7 +614,7 @@ macro_rules! impl_dyn_series {
             }
 
             fn unique(&self) -> Result<Series> {
-                self.0.unique().map(|ca| ca.$into_logical().into_series())
+                self.0.unique().map(|ca| ca.$into_logical($(self.0.$meta().to_owned()),*).into_series())
             }
 
             fn n_unique(&self) -> Result<usize> {
@@ -521,7 +650,7 @@ macro_rules! impl_dyn_series {
             }
 
             fn reverse(&self) -> Series {
-                self.0.reverse().$into_logical().into_series()
+                self.0.reverse().$into_logical($(self.0.$meta().to_owned()),*).into_series()
             }
 
             fn as_single_ptr(&mut self) -> Result<usize> {
@@ -529,26 +658,29 @@ macro_rules! impl_dyn_series {
             }
 
             fn shift(&self, periods: i64) -> Series {
-                self.0.shift(periods).$into_logical().into_series()
+                self.0.shift(periods).$into_logical($(self.0.$meta().to_owned()),*).into_series()
             }
 
             fn fill_null(&self, strategy: FillNullStrategy) -> Result<Series> {
                 self.0
                     .fill_null(strategy)
-                    .map(|ca| ca.$into_logical().into_series())
+                    .map(|ca| ca.$into_logical($(self.0.$meta().to_owned()),*).into_series())
             }
 
             fn sum_as_series(&self) -> Series {
-                Int32Chunked::full_null(self.name(), 1)
-                    .cast(self.dtype())
-                    .unwrap()
-                    .into()
+                match self.dtype() {
+                    #[cfg(feature = "dtype-duration")]
+                    DataType::Duration(tu) => self.0.sum_as_series().into_duration(*tu),
+                    _ => Int32Chunked::full_null(self.name(), 1)
+                        .cast(self.dtype())
+                        .unwrap(),
+                }
             }
             fn max_as_series(&self) -> Series {
-                self.0.max_as_series().$into_logical()
+                self.0.max_as_series().$into_logical($(self.0.$meta().to_owned()),*)
             }
             fn min_as_series(&self) -> Series {
-                self.0.min_as_series().$into_logical()
+                self.0.min_as_series().$into_logical($(self.0.$meta().to_owned()),*)
             }
             fn mean_as_series(&self) -> Series {
                 Int32Chunked::full_null(self.name(), 1)
@@ -594,7 +726,7 @@ macro_rules! impl_dyn_series {
             fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
                 self.0
                     .sample_n(n, with_replacement)
-                    .map(|s| s.$into_logical().into_series())
+                    .map(|s| s.$into_logical($(self.0.$meta().to_owned()),*).into_series())
             }
 
             #[cfg(feature = "random")]
@@ -602,7 +734,7 @@ macro_rules! impl_dyn_series {
             fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
                 self.0
                     .sample_frac(frac, with_replacement)
-                    .map(|s| s.$into_logical().into_series())
+                    .map(|s| s.$into_logical($(self.0.$meta().to_owned()),*).into_series())
             }
 
             fn pow(&self, _exponent: f64) -> Result<Series> {
@@ -633,10 +765,10 @@ macro_rules! impl_dyn_series {
                         .list()
                         .unwrap()
                         .clone(),
-                    DataType::Datetime => self
+                    DataType::Datetime(_, _) | DataType::Duration(_) => self
                         .0
                         .repeat_by(by)
-                        .cast(&DataType::List(Box::new(DataType::Datetime)))
+                        .cast(&DataType::List(Box::new(self.dtype().clone())))
                         .unwrap()
                         .list()
                         .unwrap()
@@ -656,7 +788,7 @@ macro_rules! impl_dyn_series {
 
             #[cfg(feature = "mode")]
             fn mode(&self) -> Result<Series> {
-                self.0.mode().map(|ca| ca.$into_logical().into_series())
+                self.0.mode().map(|ca| ca.$into_logical($(self.0.$meta().to_owned()),*).into_series())
             }
         }
     };
@@ -665,7 +797,9 @@ macro_rules! impl_dyn_series {
 #[cfg(feature = "dtype-date")]
 impl_dyn_series!(DateChunked, into_date);
 #[cfg(feature = "dtype-datetime")]
-impl_dyn_series!(DatetimeChunked, into_date);
+impl_dyn_series!(DatetimeChunked, into_datetime, time_unit, time_zone);
+#[cfg(feature = "dtype-duration")]
+impl_dyn_series!(DurationChunked, into_duration, time_unit);
 #[cfg(feature = "dtype-time")]
 impl_dyn_series!(TimeChunked, into_time);
 
@@ -673,11 +807,10 @@ macro_rules! impl_dyn_series_numeric {
     ($ca: ident) => {
         impl private::PrivateSeriesNumeric for SeriesWrap<$ca> {
             fn bit_repr_is_large(&self) -> bool {
-                if let DataType::Datetime = self.dtype() {
-                    true
-                } else {
-                    false
-                }
+                matches!(
+                    self.dtype(),
+                    DataType::Datetime(_, _) | DataType::Duration(_)
+                )
             }
             fn bit_repr_large(&self) -> UInt64Chunked {
                 self.0.bit_repr_large()
@@ -693,6 +826,8 @@ macro_rules! impl_dyn_series_numeric {
 impl_dyn_series_numeric!(DateChunked);
 #[cfg(feature = "dtype-datetime")]
 impl_dyn_series_numeric!(DatetimeChunked);
+#[cfg(feature = "dtype-duration")]
+impl_dyn_series_numeric!(DurationChunked);
 #[cfg(feature = "dtype-time")]
 impl_dyn_series_numeric!(TimeChunked);
 
@@ -704,13 +839,13 @@ mod test {
     #[cfg(feature = "dtype-datetime")]
     fn test_agg_list_type() -> Result<()> {
         let s = Series::new("foo", &[1, 2, 3]);
-        let s = s.cast(&DataType::Datetime)?;
+        let s = s.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
 
         let l = s.agg_list(&[(0, vec![0, 1, 2])]).unwrap();
 
         match l.dtype() {
             DataType::List(inner) => {
-                assert!(matches!(&**inner, DataType::Datetime))
+                assert!(matches!(&**inner, DataType::Datetime(_, _)))
             }
             _ => assert!(false),
         }
@@ -723,19 +858,28 @@ mod test {
     #[cfg_attr(miri, ignore)]
     fn test_datelike_join() -> Result<()> {
         let s = Series::new("foo", &[1, 2, 3]);
-        let mut s1 = s.cast(&DataType::Datetime)?;
+        let mut s1 = s.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
         s1.rename("bar");
 
         let df = DataFrame::new(vec![s, s1])?;
 
         let out = df.left_join(&df.clone(), "bar", "bar")?;
-        assert!(matches!(out.column("bar")?.dtype(), DataType::Datetime));
+        assert!(matches!(
+            out.column("bar")?.dtype(),
+            DataType::Datetime(_, _)
+        ));
 
         let out = df.inner_join(&df.clone(), "bar", "bar")?;
-        assert!(matches!(out.column("bar")?.dtype(), DataType::Datetime));
+        assert!(matches!(
+            out.column("bar")?.dtype(),
+            DataType::Datetime(_, _)
+        ));
 
         let out = df.outer_join(&df.clone(), "bar", "bar")?;
-        assert!(matches!(out.column("bar")?.dtype(), DataType::Datetime));
+        assert!(matches!(
+            out.column("bar")?.dtype(),
+            DataType::Datetime(_, _)
+        ));
         Ok(())
     }
 
@@ -743,10 +887,13 @@ mod test {
     #[cfg(feature = "dtype-datetime")]
     fn test_datelike_methods() -> Result<()> {
         let s = Series::new("foo", &[1, 2, 3]);
-        let s = s.cast(&DataType::Datetime)?;
+        let s = s.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
 
         let out = s.subtract(&s)?;
-        assert!(matches!(out.dtype(), DataType::Datetime));
+        #[cfg(feature = "dtype-duration")]
+        assert!(matches!(out.dtype(), DataType::Duration(_)));
+        #[cfg(not(feature = "dtype-duration"))]
+        assert!(matches!(out.dtype(), DataType::Datetime(_, _)));
 
         let mut a = s.clone();
         a.append(&s).unwrap();
@@ -755,6 +902,46 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "dtype-duration")]
+    fn test_duration_arithmetic() -> Result<()> {
+        let a = Int64Chunked::new_from_slice("a", &[1000, 2000, 3000])
+            .into_date()
+            .into_series();
+        let b = Int64Chunked::new_from_slice("b", &[0, 500, 1000])
+            .into_date()
+            .into_series();
+
+        let diff = &a - &b;
+        assert_eq!(diff.dtype(), &DataType::Duration(TimeUnit::Milliseconds));
+        assert_eq!(
+            Vec::from(diff.duration()?.deref()),
+            &[Some(1000), Some(1500), Some(2000)]
+        );
+
+        let out = &b + &diff;
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
+        assert!(out.series_equal(&a.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?));
+
+        let out = &a - &diff;
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
+        assert!(out.series_equal(&b));
+
+        let out = &diff + &diff;
+        assert_eq!(out.dtype(), &DataType::Duration(TimeUnit::Milliseconds));
+        assert_eq!(
+            out.sum_as_series().dtype(),
+            &DataType::Duration(TimeUnit::Milliseconds)
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_arithmetic_dispatch() {
         let s = Int64Chunked::new_from_slice("", &[1, 2, 3])
@@ -763,25 +950,55 @@ mod test {
 
         // check if we don't panic.
         let out = &s * 100;
-        assert_eq!(out.dtype(), &DataType::Datetime);
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
         let out = &s / 100;
-        assert_eq!(out.dtype(), &DataType::Datetime);
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
         let out = &s + 100;
-        assert_eq!(out.dtype(), &DataType::Datetime);
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
         let out = &s - 100;
-        assert_eq!(out.dtype(), &DataType::Datetime);
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
         let out = &s % 100;
-        assert_eq!(out.dtype(), &DataType::Datetime);
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
 
         let out = 100.mul(&s);
-        assert_eq!(out.dtype(), &DataType::Datetime);
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
         let out = 100.div(&s);
-        assert_eq!(out.dtype(), &DataType::Datetime);
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
         let out = 100.sub(&s);
-        assert_eq!(out.dtype(), &DataType::Datetime);
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
         let out = 100.add(&s);
-        assert_eq!(out.dtype(), &DataType::Datetime);
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
         let out = 100.rem(&s);
-        assert_eq!(out.dtype(), &DataType::Datetime);
+        assert_eq!(
+            out.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
     }
 }
@@ -0,0 +1,307 @@
+use super::private;
+use super::IntoSeries;
+use super::SeriesTrait;
+use crate::chunked_array::ChunkIdIter;
+use crate::fmt::FmtList;
+use crate::prelude::*;
+use crate::series::implementations::SeriesWrap;
+use crate::utils::NoNull;
+use arrow::array::ArrayRef;
+#[cfg(feature = "object")]
+use std::any::Any;
+use std::borrow::Cow;
+
+impl IntoSeries for DecimalChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<DecimalChunked> {}
+
+impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
+    fn _field(&self) -> Cow<Field> {
+        Cow::Borrowed(self.0.ref_field())
+    }
+
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    fn agg_sum(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        Some(self.0.agg_sum(groups).unwrap())
+    }
+
+    fn agg_mean(&self, groups: &[(u32, Vec<u32>)]) -> Option<Series> {
+        Some(self.0.agg_mean(groups).unwrap())
+    }
+
+    fn try_agg_sum(&self, groups: &[(u32, Vec<u32>)]) -> Result<Option<Series>> {
+        self.0.agg_sum(groups).map(Some)
+    }
+
+    fn try_agg_mean(&self, groups: &[(u32, Vec<u32>)]) -> Result<Option<Series>> {
+        self.0.agg_mean(groups).map(Some)
+    }
+
+    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        self.0.agg_first(groups)
+    }
+
+    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        self.0.agg_last(groups)
+    }
+
+    fn subtract(&self, rhs: &Series) -> Result<Series> {
+        self.0.sub(rhs.decimal()?).map(|ca| ca.into_series())
+    }
+
+    fn add_to(&self, rhs: &Series) -> Result<Series> {
+        self.0.add(rhs.decimal()?).map(|ca| ca.into_series())
+    }
+
+    fn multiply(&self, rhs: &Series) -> Result<Series> {
+        self.0.mul(rhs.decimal()?).map(|ca| ca.into_series())
+    }
+
+    fn str_value(&self, index: usize) -> Cow<str> {
+        Cow::Owned(format!("{}", self.get(index)))
+    }
+}
+
+impl SeriesTrait for SeriesWrap<DecimalChunked> {
+    fn rename(&mut self, name: &str) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkIdIter {
+        self.0.chunk_id()
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+
+    fn decimal(&self) -> Result<&DecimalChunked> {
+        unsafe { Ok(&*(self as *const dyn SeriesTrait as *const DecimalChunked)) }
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_series()
+    }
+
+    fn append(&mut self, other: &Series) -> Result<()> {
+        self.0.append(other.decimal()?)
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
+        self.0.filter(filter).map(|ca| ca.into_series())
+    }
+
+    fn take(&self, indices: &UInt32Chunked) -> Result<Series> {
+        let indices = if indices.chunks.len() > 1 {
+            Cow::Owned(indices.rechunk())
+        } else {
+            Cow::Borrowed(indices)
+        };
+        Ok(ChunkTake::take(&self.0, (&*indices).into())?.into_series())
+    }
+
+    fn take_iter(&self, iter: &mut dyn TakeIterator) -> Result<Series> {
+        Ok(ChunkTake::take(&self.0, iter.into())?.into_series())
+    }
+
+    fn take_every(&self, n: usize) -> Series {
+        let idx: NoNull<UInt32Chunked> = (0..self.len() as u32).step_by(n).collect();
+        // Safety: the indices are in bounds
+        unsafe { ChunkTake::take_unchecked(&self.0, (&idx.into_inner()).into()) }.into_series()
+    }
+
+    unsafe fn take_iter_unchecked(&self, iter: &mut dyn TakeIterator) -> Series {
+        ChunkTake::take_unchecked(&self.0, iter.into()).into_series()
+    }
+
+    unsafe fn take_unchecked(&self, idx: &UInt32Chunked) -> Result<Series> {
+        let idx = if idx.chunks.len() > 1 {
+            Cow::Owned(idx.rechunk())
+        } else {
+            Cow::Borrowed(idx)
+        };
+        Ok(ChunkTake::take_unchecked(&self.0, (&*idx).into()).into_series())
+    }
+
+    unsafe fn take_opt_iter_unchecked(&self, iter: &mut dyn TakeIteratorNulls) -> Series {
+        ChunkTake::take_unchecked(&self.0, iter.into()).into_series()
+    }
+
+    #[cfg(feature = "take_opt_iter")]
+    fn take_opt_iter(&self, iter: &mut dyn TakeIteratorNulls) -> Result<Series> {
+        Ok(ChunkTake::take(&self.0, iter.into())?.into_series())
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0.rechunk().into_series()
+    }
+
+    fn head(&self, length: Option<usize>) -> Series {
+        self.0.head(length).into_series()
+    }
+
+    fn tail(&self, length: Option<usize>) -> Series {
+        self.0.tail(length).into_series()
+    }
+
+    fn expand_at_index(&self, index: usize, length: usize) -> Series {
+        self.0.expand_at_index(index, length).into_series()
+    }
+
+    fn cast(&self, data_type: &DataType) -> Result<Series> {
+        self.0.cast(data_type)
+    }
+
+    fn get(&self, index: usize) -> AnyValue {
+        self.0.get_any_value(index)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value(index)
+    }
+
+    fn sort(&self, reverse: bool) -> Series {
+        self.0.sort(reverse).into_series()
+    }
+
+    fn argsort(&self, reverse: bool) -> UInt32Chunked {
+        self.0.argsort(reverse)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn unique(&self) -> Result<Series> {
+        Ok(self.0.unique().into_series())
+    }
+
+    fn n_unique(&self) -> Result<usize> {
+        Ok(self.0.n_unique())
+    }
+
+    fn arg_unique(&self) -> Result<UInt32Chunked> {
+        Ok(self.0.arg_unique())
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.0.reverse().into_series()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.0.shift(periods).into_series()
+    }
+
+    fn sum_as_series(&self) -> Series {
+        self.try_sum_as_series().unwrap()
+    }
+
+    fn try_sum_as_series(&self) -> Result<Series> {
+        let sum = std::iter::once(self.0.sum()?);
+        Ok(
+            DecimalChunked::from_values(self.name(), sum, MAX_DECIMAL_PRECISION, self.0.scale())
+                .into_series(),
+        )
+    }
+
+    fn mean_as_series(&self) -> Series {
+        Float64Chunked::new_from_opt_slice(self.name(), &[self.0.mean().unwrap()]).into_series()
+    }
+
+    fn fmt_list(&self) -> String {
+        FmtList::fmt_list(&self.0)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    #[cfg(feature = "object")]
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    fn decimals(name: &str, values: &[Option<i128>]) -> Series {
+        DecimalChunked::new_from_opt_slice(name, values, 10, 2)
+            .unwrap()
+            .into_series()
+    }
+
+    #[test]
+    fn test_decimal_series_ops() -> Result<()> {
+        let s = decimals("a", &[Some(150), None, Some(-25), Some(150)]);
+        assert_eq!(s.dtype(), &DataType::Decimal(10, 2));
+        assert_eq!(s.get(0).to_string(), "1.50");
+        assert_eq!(s.get(2).to_string(), "-0.25");
+
+        let out = &s + &s;
+        assert_eq!(out.dtype(), &DataType::Decimal(11, 2));
+        assert_eq!(out.get(0).to_string(), "3.00");
+        let out = &s * &s;
+        assert_eq!(out.get(2).to_string(), "0.0625");
+
+        let out = s.sort(false);
+        assert_eq!(out.get(0), AnyValue::Null);
+        assert_eq!(out.get(1).to_string(), "-0.25");
+        assert_eq!(s.n_unique()?, 3);
+
+        let mut appended = s.clone();
+        appended.append(&s)?;
+        assert_eq!(appended.len(), 8);
+        assert!(appended.slice(4, 4).series_equal_missing(&s));
+
+        assert_eq!(s.sum_as_series().get(0).to_string(), "2.75");
+        assert_eq!(s.mean_as_series().f64()?.get(0), Some(2.75 / 3.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_groupby() -> Result<()> {
+        let df = DataFrame::new(vec![
+            Series::new("g", &["a", "b", "a", "b"]),
+            decimals("v", &[Some(110), Some(5), Some(-10), None]),
+        ])?;
+        let out = df
+            .groupby("g")?
+            .agg(&[("v", &["sum", "mean"])])?
+            .sort("g", false)?;
+        let sum = out.column("v_sum")?;
+        assert_eq!(sum.dtype(), &DataType::Decimal(38, 2));
+        assert_eq!(sum.get(0).to_string(), "1.00");
+        assert_eq!(sum.get(1).to_string(), "0.05");
+        assert_eq!(
+            Vec::from(out.column("v_mean")?.f64()?),
+            &[Some(0.5), Some(0.05)]
+        );
+        Ok(())
+    }
+}
@@ -1,16 +1,23 @@
+#[cfg(feature = "dtype-binary")]
+mod binary;
 mod boolean;
 #[cfg(feature = "dtype-categorical")]
 mod categorical;
 #[cfg(any(
     feature = "dtype-datetime",
     feature = "dtype-date",
+    feature = "dtype-duration",
     feature = "dtype-time"
 ))]
 mod dates_time;
+#[cfg(feature = "dtype-decimal")]
+mod decimal;
 mod floats;
 mod list;
 #[cfg(feature = "object")]
 mod object;
+#[cfg(feature = "dtype-struct")]
+mod struct_;
 mod utf8;
 
 #[cfg(feature = "object")]
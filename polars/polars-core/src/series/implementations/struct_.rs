@@ -0,0 +1,238 @@
+use super::private;
+use super::IntoSeries;
+use super::SeriesTrait;
+use crate::chunked_array::ChunkIdIter;
+use crate::fmt::FmtList;
+use crate::prelude::*;
+use crate::series::implementations::SeriesWrap;
+use crate::utils::NoNull;
+use arrow::array::ArrayRef;
+#[cfg(feature = "object")]
+use std::any::Any;
+use std::borrow::Cow;
+
+impl IntoSeries for StructChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<StructChunked> {}
+
+impl private::PrivateSeries for SeriesWrap<StructChunked> {
+    fn _field(&self) -> Cow<Field> {
+        Cow::Borrowed(self.0.ref_field())
+    }
+
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    fn agg_first(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        self.0.apply_fields(|s| s.agg_first(groups)).into_series()
+    }
+
+    fn agg_last(&self, groups: &[(u32, Vec<u32>)]) -> Series {
+        self.0.apply_fields(|s| s.agg_last(groups)).into_series()
+    }
+
+    fn str_value(&self, index: usize) -> Cow<str> {
+        Cow::Owned(format!("{}", self.get(index)))
+    }
+}
+
+impl SeriesTrait for SeriesWrap<StructChunked> {
+    #[cfg(feature = "interpolate")]
+    fn interpolate(&self) -> Series {
+        let fields = self.0.fields().iter().map(|s| s.interpolate()).collect();
+        self.0.with_fields(fields).into_series()
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkIdIter {
+        self.0.chunk_id()
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+
+    fn struct_(&self) -> Result<&StructChunked> {
+        unsafe { Ok(&*(self as *const dyn SeriesTrait as *const StructChunked)) }
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0
+            .apply_fields(|s| s.slice(offset, length))
+            .into_series()
+    }
+
+    fn append(&mut self, other: &Series) -> Result<()> {
+        self.0.append(other.struct_()?)
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> Result<Series> {
+        self.0
+            .try_apply_fields(|s| s.filter(filter))
+            .map(|ca| ca.into_series())
+    }
+
+    fn take(&self, indices: &UInt32Chunked) -> Result<Series> {
+        self.0
+            .try_apply_fields(|s| s.take(indices))
+            .map(|ca| ca.into_series())
+    }
+
+    fn take_iter(&self, iter: &mut dyn TakeIterator) -> Result<Series> {
+        // the iterator can only be consumed once, so we materialize the indices
+        let idx: NoNull<UInt32Chunked> = iter.map(|v| v as u32).collect();
+        self.take(&idx.into_inner())
+    }
+
+    unsafe fn take_iter_unchecked(&self, iter: &mut dyn TakeIterator) -> Series {
+        let idx: NoNull<UInt32Chunked> = iter.map(|v| v as u32).collect();
+        self.take_unchecked(&idx.into_inner()).unwrap()
+    }
+
+    unsafe fn take_unchecked(&self, idx: &UInt32Chunked) -> Result<Series> {
+        self.0
+            .try_apply_fields(|s| s.take_unchecked(idx))
+            .map(|ca| ca.into_series())
+    }
+
+    unsafe fn take_opt_iter_unchecked(&self, iter: &mut dyn TakeIteratorNulls) -> Series {
+        let idx: UInt32Chunked = iter.map(|opt_v| opt_v.map(|v| v as u32)).collect();
+        self.take_unchecked(&idx).unwrap()
+    }
+
+    #[cfg(feature = "take_opt_iter")]
+    fn take_opt_iter(&self, iter: &mut dyn TakeIteratorNulls) -> Result<Series> {
+        let idx: UInt32Chunked = iter.map(|opt_v| opt_v.map(|v| v as u32)).collect();
+        self.take(&idx)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0.apply_fields(|s| s.rechunk()).into_series()
+    }
+
+    fn head(&self, length: Option<usize>) -> Series {
+        self.0.apply_fields(|s| s.head(length)).into_series()
+    }
+
+    fn tail(&self, length: Option<usize>) -> Series {
+        self.0.apply_fields(|s| s.tail(length)).into_series()
+    }
+
+    fn take_every(&self, n: usize) -> Series {
+        self.0.apply_fields(|s| s.take_every(n)).into_series()
+    }
+
+    fn expand_at_index(&self, index: usize, length: usize) -> Series {
+        self.0
+            .apply_fields(|s| s.expand_at_index(index, length))
+            .into_series()
+    }
+
+    fn cast(&self, data_type: &DataType) -> Result<Series> {
+        match data_type {
+            DataType::Struct(fields) if fields.len() == self.0.fields().len() => {
+                let fields = self
+                    .0
+                    .fields()
+                    .iter()
+                    .zip(fields)
+                    .map(|(s, fld)| {
+                        let mut out = s.cast(fld.data_type())?;
+                        out.rename(fld.name());
+                        Ok(out)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                StructChunked::new(self.name(), &fields)?
+                    .with_validity(self.0.validity().cloned())
+                    .map(|ca| ca.into_series())
+            }
+            _ => Err(PolarsError::InvalidOperation(
+                format!("cannot cast {:?} to {:?}", self.dtype(), data_type).into(),
+            )),
+        }
+    }
+
+    fn get(&self, index: usize) -> AnyValue {
+        self.0.get_any_value(index)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        !self.0.is_not_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.0.apply_fields(|s| s.reverse()).into_series()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.0.apply_fields(|s| s.shift(periods)).into_series()
+    }
+
+    fn fill_null(&self, strategy: FillNullStrategy) -> Result<Series> {
+        let fields = self
+            .0
+            .fields()
+            .iter()
+            .map(|s| s.fill_null(strategy))
+            .collect::<Result<_>>()?;
+        Ok(self.0.with_fields(fields).into_series())
+    }
+
+    fn fmt_list(&self) -> String {
+        FmtList::fmt_list(&self.0)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
+        self.0
+            .sample_n(n, with_replacement)
+            .map(|ca| ca.into_series())
+    }
+
+    #[cfg(feature = "random")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
+    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
+        self.0
+            .sample_frac(frac, with_replacement)
+            .map(|ca| ca.into_series())
+    }
+
+    #[cfg(feature = "object")]
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+}
@@ -323,12 +323,13 @@ impl Series {
     ///
     /// * Date -> Int32
     /// * Datetime-> Int64
+    /// * Duration -> Int64
     ///
     pub fn to_physical_repr(&self) -> Cow<Series> {
         use DataType::*;
         match self.dtype() {
             Date => Cow::Owned(self.cast(&DataType::Int32).unwrap()),
-            Datetime => Cow::Owned(self.cast(&DataType::Int64).unwrap()),
+            Datetime(_, _) | Duration(_) => Cow::Owned(self.cast(&DataType::Int64).unwrap()),
             _ => Cow::Borrowed(self),
         }
     }
@@ -647,6 +648,15 @@ impl Series {
             .into_series()
     }
 
+    #[cfg(feature = "dtype-duration")]
+    pub(crate) fn into_duration(self, tu: TimeUnit) -> Series {
+        self.i64()
+            .expect("impl error")
+            .clone()
+            .into_duration(tu)
+            .into_series()
+    }
+
     pub(crate) fn into_date(self) -> Series {
         match self.dtype() {
             #[cfg(feature = "dtype-date")]
@@ -656,6 +666,15 @@ impl Series {
             _ => unreachable!(),
         }
     }
+
+    #[cfg(feature = "dtype-datetime")]
+    pub(crate) fn into_datetime(self, tu: TimeUnit, tz: Option<TimeZone>) -> Series {
+        self.i64()
+            .expect("impl error")
+            .clone()
+            .into_datetime(tu, tz)
+            .into_series()
+    }
 }
 
 impl Deref for Series {
@@ -167,6 +167,14 @@ pub(crate) mod private {
         fn agg_sum(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
             None
         }
+        /// `agg_sum` for dtypes where the sum can overflow, e.g. decimals.
+        fn try_agg_sum(&self, groups: &[(u32, Vec<u32>)]) -> Result<Option<Series>> {
+            Ok(self.agg_sum(groups))
+        }
+        /// `agg_mean` for dtypes where the sum can overflow, e.g. decimals.
+        fn try_agg_mean(&self, groups: &[(u32, Vec<u32>)]) -> Result<Option<Series>> {
+            Ok(self.agg_mean(groups))
+        }
         fn agg_std(&self, _groups: &[(u32, Vec<u32>)]) -> Option<Series> {
             None
         }
@@ -429,6 +437,14 @@ pub trait SeriesTrait:
         ))
     }
 
+    /// Unpack to ChunkedArray of dtype binary
+    #[cfg(feature = "dtype-binary")]
+    fn binary(&self) -> Result<&BinaryChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("Series dtype {:?} != binary", self.dtype()).into(),
+        ))
+    }
+
     /// Unpack to ChunkedArray of dtype Time
     fn time(&self) -> Result<&TimeChunked> {
         Err(PolarsError::DataTypeMisMatch(
@@ -450,6 +466,13 @@ pub trait SeriesTrait:
         ))
     }
 
+    /// Unpack to ChunkedArray of dtype duration
+    fn duration(&self) -> Result<&DurationChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("Series dtype {:?} != duration", self.dtype()).into(),
+        ))
+    }
+
     /// Unpack to ChunkedArray of dtype list
     fn list(&self) -> Result<&ListChunked> {
         Err(PolarsError::DataTypeMisMatch(
@@ -464,6 +487,24 @@ pub trait SeriesTrait:
         ))
     }
 
+    /// Unpack to StructChunked
+    #[cfg(feature = "dtype-struct")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "dtype-struct")))]
+    fn struct_(&self) -> Result<&StructChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("Series dtype {:?} != struct", self.dtype()).into(),
+        ))
+    }
+
+    /// Unpack to DecimalChunked
+    #[cfg(feature = "dtype-decimal")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "dtype-decimal")))]
+    fn decimal(&self) -> Result<&DecimalChunked> {
+        Err(PolarsError::DataTypeMisMatch(
+            format!("Series dtype {:?} != decimal", self.dtype()).into(),
+        ))
+    }
+
     /// Check if underlying physical data is numeric.
     ///
     /// Date types and Categoricals are also considered numeric.
@@ -474,6 +515,12 @@ pub trait SeriesTrait:
             DataType::Utf8 | DataType::List(_) | DataType::Boolean | DataType::Null => false,
             #[cfg(feature = "object")]
             DataType::Object(_) => false,
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(_) => false,
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => false,
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => false,
             _ => true,
         }
     }
@@ -487,11 +534,18 @@ pub trait SeriesTrait:
             | DataType::List(_)
             | DataType::Categorical
             | DataType::Date
-            | DataType::Datetime
+            | DataType::Datetime(_, _)
+            | DataType::Duration(_)
             | DataType::Boolean
             | DataType::Null => false,
             #[cfg(feature = "object")]
             DataType::Object(_) => false,
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(_) => false,
+            #[cfg(feature = "dtype-binary")]
+            DataType::Binary => false,
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => false,
             _ => true,
         }
     }
@@ -816,6 +870,11 @@ pub trait SeriesTrait:
     fn sum_as_series(&self) -> Series {
         invalid_operation_panic!(self)
     }
+    /// Get the sum of the Series as a new Series of length 1. Returns an error if the sum
+    /// overflows, e.g. for decimals.
+    fn try_sum_as_series(&self) -> Result<Series> {
+        Ok(self.sum_as_series())
+    }
     /// Get the max of the Series as a new Series of length 1.
     fn max_as_series(&self) -> Series {
         invalid_operation_panic!(self)
@@ -856,7 +915,7 @@ pub trait SeriesTrait:
     fn hour(&self) -> Result<UInt32Chunked> {
         match self.dtype() {
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime => self.datetime().map(|ca| ca.hour()),
+            DataType::Datetime(_, _) => self.datetime().map(|ca| ca.hour()),
             #[cfg(feature = "dtype-time")]
             DataType::Time => self.time().map(|ca| ca.hour()),
             _ => Err(PolarsError::InvalidOperation(
@@ -872,7 +931,7 @@ pub trait SeriesTrait:
     fn minute(&self) -> Result<UInt32Chunked> {
         match self.dtype() {
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime => self.datetime().map(|ca| ca.minute()),
+            DataType::Datetime(_, _) => self.datetime().map(|ca| ca.minute()),
             #[cfg(feature = "dtype-time")]
             DataType::Time => self.time().map(|ca| ca.minute()),
             _ => Err(PolarsError::InvalidOperation(
@@ -888,7 +947,7 @@ pub trait SeriesTrait:
     fn second(&self) -> Result<UInt32Chunked> {
         match self.dtype() {
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime => self.datetime().map(|ca| ca.second()),
+            DataType::Datetime(_, _) => self.datetime().map(|ca| ca.second()),
             #[cfg(feature = "dtype-time")]
             DataType::Time => self.time().map(|ca| ca.second()),
             _ => Err(PolarsError::InvalidOperation(
@@ -904,7 +963,7 @@ pub trait SeriesTrait:
     fn nanosecond(&self) -> Result<UInt32Chunked> {
         match self.dtype() {
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime => self.datetime().map(|ca| ca.nanosecond()),
+            DataType::Datetime(_, _) => self.datetime().map(|ca| ca.nanosecond()),
             #[cfg(feature = "dtype-time")]
             DataType::Time => self.time().map(|ca| ca.nanosecond()),
             _ => Err(PolarsError::InvalidOperation(
@@ -924,7 +983,7 @@ pub trait SeriesTrait:
             #[cfg(feature = "dtype-date")]
             DataType::Date => self.date().map(|ca| ca.day()),
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime => self.datetime().map(|ca| ca.day()),
+            DataType::Datetime(_, _) => self.datetime().map(|ca| ca.day()),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -938,7 +997,7 @@ pub trait SeriesTrait:
             #[cfg(feature = "dtype-date")]
             DataType::Date => self.date().map(|ca| ca.weekday()),
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime => self.datetime().map(|ca| ca.weekday()),
+            DataType::Datetime(_, _) => self.datetime().map(|ca| ca.weekday()),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -954,7 +1013,7 @@ pub trait SeriesTrait:
             #[cfg(feature = "dtype-date")]
             DataType::Date => self.date().map(|ca| ca.week()),
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime => self.datetime().map(|ca| ca.week()),
+            DataType::Datetime(_, _) => self.datetime().map(|ca| ca.week()),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -971,7 +1030,7 @@ pub trait SeriesTrait:
             #[cfg(feature = "dtype-date")]
             DataType::Date => self.date().map(|ca| ca.ordinal()),
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime => self.datetime().map(|ca| ca.ordinal()),
+            DataType::Datetime(_, _) => self.datetime().map(|ca| ca.ordinal()),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -989,7 +1048,7 @@ pub trait SeriesTrait:
             #[cfg(feature = "dtype-date")]
             DataType::Date => self.date().map(|ca| ca.month()),
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime => self.datetime().map(|ca| ca.month()),
+            DataType::Datetime(_, _) => self.datetime().map(|ca| ca.month()),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -1005,7 +1064,7 @@ pub trait SeriesTrait:
             #[cfg(feature = "dtype-date")]
             DataType::Date => self.date().map(|ca| ca.year()),
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime => self.datetime().map(|ca| ca.year()),
+            DataType::Datetime(_, _) => self.datetime().map(|ca| ca.year()),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -1020,7 +1079,7 @@ pub trait SeriesTrait:
             #[cfg(feature = "dtype-date")]
             DataType::Date => self.date().map(|ca| ca.strftime(fmt).into_series()),
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime => self.datetime().map(|ca| ca.strftime(fmt).into_series()),
+            DataType::Datetime(_, _) => self.datetime().map(|ca| ca.strftime(fmt).into_series()),
             #[cfg(feature = "dtype-time")]
             DataType::Time => self.time().map(|ca| ca.strftime(fmt).into_series()),
             _ => Err(PolarsError::InvalidOperation(
@@ -1039,7 +1098,9 @@ pub trait SeriesTrait:
                 .unwrap()
                 .datetime()
                 .map(|ca| (ca.deref() * 1000)),
-            DataType::Datetime => self.datetime().map(|ca| ca.deref().clone()),
+            DataType::Datetime(_, _) => self
+                .datetime()
+                .map(|ca| ca.cast_time_unit(TimeUnit::Milliseconds).deref().clone()),
             _ => Err(PolarsError::InvalidOperation(
                 format!("operation not supported on dtype {:?}", self.dtype()).into(),
             )),
@@ -32,6 +32,19 @@ impl Series {
         if self.len() != other.len() || self.null_count() != other.null_count() {
             return false;
         }
+        #[cfg(feature = "dtype-struct")]
+        if let (Ok(lhs), Ok(rhs)) = (self.struct_(), other.struct_()) {
+            return lhs.fields().len() == rhs.fields().len()
+                && lhs
+                    .is_not_null()
+                    .into_series()
+                    .series_equal(&rhs.is_not_null().into_series())
+                && lhs
+                    .fields()
+                    .iter()
+                    .zip(rhs.fields())
+                    .all(|(l, r)| l.name() == r.name() && l.series_equal_missing(r));
+        }
         if self.dtype() != other.dtype()
             && !(matches!(self.dtype(), DataType::Utf8 | DataType::Categorical)
                 || matches!(other.dtype(), DataType::Utf8 | DataType::Categorical))
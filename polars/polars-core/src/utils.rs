@@ -357,7 +357,7 @@ macro_rules! apply_method_all_arrow_series {
             DataType::Float32 => $self.f32().unwrap().$method($($args),*),
             DataType::Float64 => $self.f64().unwrap().$method($($args),*),
             DataType::Date => $self.date().unwrap().$method($($args),*),
-            DataType::Datetime=> $self.datetime().unwrap().$method($($args),*),
+            DataType::Datetime(_, _) => $self.datetime().unwrap().$method($($args),*),
             DataType::List(_) => $self.list().unwrap().$method($($args),*),
             dt => panic!("dtype {:?} not supported", dt)
         }
@@ -387,7 +387,7 @@ macro_rules! apply_method_numeric_series {
             #[cfg(feature = "dtype-date")]
             DataType::Date => $self.date().unwrap().$method($($args),*),
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime=> $self.datetime().unwrap().$method($($args),*),
+            DataType::Datetime(_, _) => $self.datetime().unwrap().$method($($args),*),
             _ => unimplemented!(),
         }
     }
@@ -455,6 +455,16 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Result<DataType> {
     }
 }
 
+/// The time unit that both time units can be cast to without losing precision.
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-duration"))]
+fn finer_time_unit(l: TimeUnit, r: TimeUnit) -> TimeUnit {
+    if l.ticks_per_second() >= r.ticks_per_second() {
+        l
+    } else {
+        r
+    }
+}
+
 /// Given two datatypes, determine the supertype that both types can safely be cast to
 fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
     use DataType::*;
@@ -546,7 +556,7 @@ fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
         #[cfg(feature = "dtype-date")]
         (Int32, Date) => Some(Int32),
         #[cfg(feature = "dtype-datetime")]
-        (Int32, Datetime) => Some(Int64),
+        (Int32, Datetime(_, _)) => Some(Int64),
         #[cfg(feature = "dtype-time")]
         (Int32, Time) => Some(Int64),
         (Int32, Boolean) => Some(Int32),
@@ -558,7 +568,7 @@ fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
         (Int64, Float32) => Some(Float32),
         (Int64, Float64) => Some(Float64),
         #[cfg(feature = "dtype-datetime")]
-        (Int64, Datetime) => Some(Int64),
+        (Int64, Datetime(_, _)) => Some(Int64),
         #[cfg(feature = "dtype-date")]
         (Int64, Date) => Some(Int32),
         #[cfg(feature = "dtype-time")]
@@ -570,7 +580,7 @@ fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
         #[cfg(feature = "dtype-date")]
         (Float32, Date) => Some(Float32),
         #[cfg(feature = "dtype-datetime")]
-        (Float32, Datetime) => Some(Float64),
+        (Float32, Datetime(_, _)) => Some(Float64),
         #[cfg(feature = "dtype-time")]
         (Float32, Time) => Some(Float64),
         (Float64, Float32) => Some(Float64),
@@ -578,7 +588,7 @@ fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
         #[cfg(feature = "dtype-date")]
         (Float64, Date) => Some(Float64),
         #[cfg(feature = "dtype-datetime")]
-        (Float64, Datetime) => Some(Float64),
+        (Float64, Datetime(_, _)) => Some(Float64),
         #[cfg(feature = "dtype-time")]
         (Float64, Time) => Some(Float64),
         (Float64, Boolean) => Some(Float64),
@@ -592,18 +602,18 @@ fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
         #[cfg(feature = "dtype-datetime")]
         (Date, Float64) => Some(Float64),
         #[cfg(feature = "dtype-datetime")]
-        (Date, Datetime) => Some(Datetime),
+        (Date, Datetime(tu, tz)) => Some(Datetime(*tu, tz.clone())),
 
         #[cfg(feature = "dtype-date")]
-        (Datetime, Int32) => Some(Int64),
+        (Datetime(_, _), Int32) => Some(Int64),
         #[cfg(feature = "dtype-date")]
-        (Datetime, Int64) => Some(Int64),
+        (Datetime(_, _), Int64) => Some(Int64),
         #[cfg(feature = "dtype-date")]
-        (Datetime, Float32) => Some(Float64),
+        (Datetime(_, _), Float32) => Some(Float64),
         #[cfg(feature = "dtype-date")]
-        (Datetime, Float64) => Some(Float64),
+        (Datetime(_, _), Float64) => Some(Float64),
         #[cfg(feature = "dtype-date")]
-        (Datetime, Date) => Some(Datetime),
+        (Datetime(tu, tz), Date) => Some(Datetime(*tu, tz.clone())),
 
         #[cfg(feature = "dtype-time")]
         (Time, Int32) => Some(Int64),
@@ -614,10 +624,36 @@ fn _get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
         #[cfg(feature = "dtype-time")]
         (Time, Float64) => Some(Float64),
 
+        // the same instants in time, only displayed in different time zones
+        // and/or stored with a different precision
+        #[cfg(feature = "dtype-datetime")]
+        (Datetime(tu_l, tz), Datetime(tu_r, _)) => {
+            Some(Datetime(finer_time_unit(*tu_l, *tu_r), tz.clone()))
+        }
+        #[cfg(feature = "dtype-duration")]
+        (Duration(tu_l), Duration(tu_r)) => Some(Duration(finer_time_unit(*tu_l, *tu_r))),
+
+        // keep all integer digits and the largest scale
+        #[cfg(feature = "dtype-decimal")]
+        (Decimal(p_l, s_l), Decimal(p_r, s_r)) => {
+            let scale = std::cmp::max(*s_l, *s_r);
+            let int_digits = std::cmp::max(p_l - s_l, p_r - s_r);
+            Some(Decimal(
+                std::cmp::min(int_digits + scale, MAX_DECIMAL_PRECISION),
+                scale,
+            ))
+        }
+        #[cfg(feature = "dtype-decimal")]
+        (Decimal(_, scale), UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64) => {
+            Some(Decimal(MAX_DECIMAL_PRECISION, *scale))
+        }
+        #[cfg(feature = "dtype-decimal")]
+        (Decimal(_, _), Float32 | Float64) => Some(Float64),
+
         #[cfg(all(feature = "dtype-time", feature = "dtype-datetime"))]
-        (Time, Datetime) => Some(Int64),
+        (Time, Datetime(_, _)) => Some(Int64),
         #[cfg(all(feature = "dtype-time", feature = "dtype-datetime"))]
-        (Datetime, Time) => Some(Int64),
+        (Datetime(_, _), Time) => Some(Int64),
         #[cfg(all(feature = "dtype-time", feature = "dtype-date"))]
         (Time, Date) => Some(Int64),
         #[cfg(all(feature = "dtype-time", feature = "dtype-date"))]
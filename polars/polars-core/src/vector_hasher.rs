@@ -144,6 +144,34 @@ impl VecHash for Utf8Chunked {
     }
 }
 
+#[cfg(feature = "dtype-binary")]
+impl VecHash for BinaryChunked {
+    fn vec_hash(&self, random_state: RandomState) -> AlignedVec<u64> {
+        let null_h = get_null_hash_value(random_state.clone());
+        let mut av = AlignedVec::with_capacity(self.len());
+        self.downcast_iter().for_each(|arr| {
+            av.extend(arr.into_iter().map(|opt_v| match opt_v {
+                Some(v) => <[u8]>::get_hash(v, &random_state),
+                None => null_h,
+            }))
+        });
+        av
+    }
+
+    fn vec_hash_combine(&self, random_state: RandomState, hashes: &mut [u64]) {
+        let null_h = get_null_hash_value(random_state.clone());
+        self.into_iter()
+            .zip(hashes.iter_mut())
+            .for_each(|(opt_v, h)| {
+                let l = match opt_v {
+                    Some(v) => <[u8]>::get_hash(v, &random_state),
+                    None => null_h,
+                };
+                *h = boost_hash_combine(l, *h)
+            })
+    }
+}
+
 impl VecHash for BooleanChunked {
     fn vec_hash(&self, random_state: RandomState) -> AlignedVec<u64> {
         let mut av = AlignedVec::with_capacity(self.len());
@@ -374,6 +402,42 @@ impl<'a> AsU64 for StrHash<'a> {
     }
 }
 
+/// The binary counterpart of [`StrHash`].
+#[cfg(feature = "dtype-binary")]
+#[derive(Eq, Copy, Clone)]
+pub(crate) struct BytesHash<'a> {
+    bytes: Option<&'a [u8]>,
+    hash: u64,
+}
+
+#[cfg(feature = "dtype-binary")]
+impl<'a> Hash for BytesHash<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash)
+    }
+}
+
+#[cfg(feature = "dtype-binary")]
+impl<'a> BytesHash<'a> {
+    pub(crate) fn new(b: Option<&'a [u8]>, hash: u64) -> Self {
+        Self { bytes: b, hash }
+    }
+}
+
+#[cfg(feature = "dtype-binary")]
+impl<'a> PartialEq for BytesHash<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+#[cfg(feature = "dtype-binary")]
+impl<'a> AsU64 for BytesHash<'a> {
+    fn as_u64(self) -> u64 {
+        self.hash
+    }
+}
+
 #[inline]
 /// For partitions that are a power of 2 we can use a bitshift instead of a modulo.
 pub(crate) fn this_partition(h: u64, thread_no: u64, n_partitions: u64) -> bool {
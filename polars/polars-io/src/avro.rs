@@ -0,0 +1,634 @@
+//! # (De)serializing Apache Avro files.
+//!
+//! Avro records are read as rows of a DataFrame. Unions of `null` and a type become nullable
+//! columns, arrays become `List` columns, nested records become `Struct` columns and enums become
+//! `Categorical` columns. The `date` and `timestamp-millis`/`timestamp-micros` logical types are
+//! read as `Date` and `Datetime`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use std::io::Cursor;
+//!
+//! let s0 = Series::new("days", &[0, 1, 2, 3, 4]);
+//! let s1 = Series::new("temp", &[22.1, 19.9, 7., 2., 3.]);
+//! let df = DataFrame::new(vec![s0, s1]).unwrap();
+//!
+//! let mut buf: Vec<u8> = Vec::new();
+//! AvroWriter::new(&mut buf)
+//!     .with_compression(Some(AvroCompression::Deflate))
+//!     .finish(&df)
+//!     .expect("avro writer");
+//!
+//! let df_read = AvroReader::new(Cursor::new(buf)).finish().unwrap();
+//! assert!(df.frame_equal(&df_read));
+//! ```
+use super::{finish_reader, ArrowReader, ArrowResult, RecordBatch};
+use crate::prelude::*;
+use arrow::datatypes::Schema as ArrowSchema;
+use arrow::io::avro::read;
+use polars_core::prelude::*;
+use serde_json::{json, Value};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::sync::Arc;
+
+/// Read Apache Avro files into a DataFrame
+///
+/// # Example
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::avro::AvroReader;
+/// use polars_io::SerReader;
+/// use std::fs::File;
+///
+/// fn example() -> Result<DataFrame> {
+///     let file = File::open("file.avro").expect("file not found");
+///
+///     AvroReader::new(file)
+///         .finish()
+/// }
+/// ```
+pub struct AvroReader<R> {
+    reader: R,
+    rechunk: bool,
+    stop_after_n_rows: Option<usize>,
+    projection: Option<Vec<usize>>,
+    columns: Option<Vec<String>>,
+}
+
+impl<R: Read + Seek> AvroReader<R> {
+    /// Get the schema of the Avro file. Only the header of the file is read.
+    pub fn schema(&mut self) -> Result<Schema> {
+        let position = self.reader.seek(SeekFrom::Current(0))?;
+        let (_, schema, _, _) = read::read_metadata(&mut self.reader)?;
+        self.reader.seek(SeekFrom::Start(position))?;
+        Ok((&schema).into())
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_stop_after_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.stop_after_n_rows = num_rows;
+        self
+    }
+
+    /// Only read the columns with these names. Takes precedence over `with_projection`.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Only read the columns at these indexes (counting from 0).
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+}
+
+/// Only keeps the projected columns of the decoded record batches, so that the other columns
+/// are never converted to Series.
+struct AvroBatchReader<R: Read> {
+    reader: read::Reader<R>,
+    projection: Option<Vec<usize>>,
+    schema: Arc<ArrowSchema>,
+}
+
+impl<R: Read> ArrowReader for AvroBatchReader<R> {
+    fn next_record_batch(&mut self) -> ArrowResult<Option<RecordBatch>> {
+        let batch = match self.reader.next() {
+            Some(batch) => batch?,
+            None => return Ok(None),
+        };
+        match &self.projection {
+            Some(projection) => {
+                let columns = projection
+                    .iter()
+                    .map(|&i| batch.column(i).clone())
+                    .collect();
+                RecordBatch::try_new(self.schema.clone(), columns).map(Some)
+            }
+            None => Ok(Some(batch)),
+        }
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        Arc::new((&*self.schema).into())
+    }
+}
+
+impl<R> SerReader<R> for AvroReader<R>
+where
+    R: Read + Seek,
+{
+    fn new(reader: R) -> Self {
+        AvroReader {
+            reader,
+            rechunk: true,
+            stop_after_n_rows: None,
+            projection: None,
+            columns: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> Result<DataFrame> {
+        let (avro_schemas, schema, codec, file_marker) = read::read_metadata(&mut self.reader)?;
+        let schema = Arc::new(schema);
+
+        let projection = match (self.columns, self.projection) {
+            (Some(columns), _) => {
+                let prj = columns
+                    .iter()
+                    .map(|name| {
+                        schema
+                            .fields()
+                            .iter()
+                            .position(|fld| fld.name() == name)
+                            .ok_or_else(|| PolarsError::NotFound(name.clone()))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Some(prj)
+            }
+            (None, Some(mut prj)) => {
+                // the columns are returned in the order of the file, like the other readers
+                prj.sort_unstable();
+                if let Some(&i) = prj.iter().find(|&&i| i >= schema.fields().len()) {
+                    return Err(PolarsError::OutOfBounds(
+                        format!(
+                            "projection index {} is out of bounds for a schema with {} columns",
+                            i,
+                            schema.fields().len()
+                        )
+                        .into(),
+                    ));
+                }
+                Some(prj)
+            }
+            (None, None) => None,
+        };
+        let projected_schema = match &projection {
+            Some(prj) => Arc::new(ArrowSchema::new(
+                prj.iter().map(|&i| schema.field(i).clone()).collect(),
+            )),
+            None => schema.clone(),
+        };
+
+        let reader = read::Reader::new(
+            read::Decompressor::new(
+                read::BlockStreamIterator::new(&mut self.reader, file_marker),
+                codec,
+            ),
+            avro_schemas,
+            schema,
+        );
+        let reader = AvroBatchReader {
+            reader,
+            projection,
+            schema: projected_schema,
+        };
+
+        let df = finish_reader(reader, self.rechunk, self.stop_after_n_rows, None, None)?;
+        match self.stop_after_n_rows {
+            Some(n) if n < df.height() => Ok(df.slice(0, n)),
+            _ => Ok(df),
+        }
+    }
+}
+
+/// The codec used to compress the blocks of an Avro file.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AvroCompression {
+    Deflate,
+    Snappy,
+}
+
+/// Number of rows that are encoded in a single block.
+const BLOCK_SIZE: usize = 4096;
+
+/// Write a DataFrame to Apache Avro files
+///
+/// Every column is written as a union of `null` and its type. `Categorical` columns are written
+/// as enums if all categories are valid Avro names, otherwise as strings. Avro has no nanosecond
+/// timestamps, so `Datetime` columns in nanoseconds are written in microseconds.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::avro::{AvroCompression, AvroWriter};
+/// use std::fs::File;
+/// use polars_io::SerWriter;
+///
+/// fn example(df: &DataFrame) -> Result<()> {
+///     let file = File::create("file.avro").expect("could not create file");
+///
+///     AvroWriter::new(file)
+///         .with_compression(Some(AvroCompression::Snappy))
+///         .finish(df)
+/// }
+/// ```
+pub struct AvroWriter<W> {
+    writer: W,
+    compression: Option<AvroCompression>,
+    name: String,
+}
+
+impl<W: Write> AvroWriter<W> {
+    /// Set the compression of the blocks. Defaults to no compression.
+    pub fn with_compression(mut self, compression: Option<AvroCompression>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the name of the Avro record that holds the rows. Defaults to `"record"`.
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+}
+
+impl<W> SerWriter<W> for AvroWriter<W>
+where
+    W: Write,
+{
+    fn new(writer: W) -> Self {
+        AvroWriter {
+            writer,
+            compression: None,
+            name: "record".to_string(),
+        }
+    }
+
+    fn finish(mut self, df: &DataFrame) -> Result<()> {
+        let mut columns = Vec::with_capacity(df.width());
+        let mut fields = Vec::with_capacity(df.width());
+        for s in df.get_columns() {
+            let (s, schema) = prepare_column(s)?;
+            fields.push(json!({
+                "name": s.name(),
+                "type": ["null", schema.avro_type],
+            }));
+            columns.push((s, schema.enum_symbols));
+        }
+        let schema = json!({
+            "type": "record",
+            "name": self.name,
+            "fields": fields,
+        });
+        let codec = match self.compression {
+            None => "null",
+            Some(AvroCompression::Deflate) => "deflate",
+            Some(AvroCompression::Snappy) => "snappy",
+        };
+
+        let hasher = RandomState::new();
+        let mut sync_marker = [0u8; 16];
+        for (i, chunk) in sync_marker.chunks_mut(8).enumerate() {
+            let mut h = hasher.build_hasher();
+            h.write_usize(i);
+            chunk.copy_from_slice(&h.finish().to_le_bytes());
+        }
+
+        // header: magic bytes, the metadata map and the sync marker
+        let mut buf = b"Obj\x01".to_vec();
+        encode_long(&mut buf, 2);
+        encode_bytes(&mut buf, b"avro.schema");
+        encode_bytes(&mut buf, schema.to_string().as_bytes());
+        encode_bytes(&mut buf, b"avro.codec");
+        encode_bytes(&mut buf, codec.as_bytes());
+        encode_long(&mut buf, 0);
+        buf.extend_from_slice(&sync_marker);
+        self.writer.write_all(&buf)?;
+
+        let mut offset = 0;
+        while offset < df.height() {
+            let n_rows = std::cmp::min(BLOCK_SIZE, df.height() - offset);
+            let mut data = vec![];
+            for row in offset..offset + n_rows {
+                for (s, symbols) in &columns {
+                    encode_value(&mut data, s.get(row), symbols.as_ref())?;
+                }
+            }
+            let data = compress(data, self.compression)?;
+
+            buf.clear();
+            encode_long(&mut buf, n_rows as i64);
+            encode_long(&mut buf, data.len() as i64);
+            self.writer.write_all(&buf)?;
+            self.writer.write_all(&data)?;
+            self.writer.write_all(&sync_marker)?;
+            offset += n_rows;
+        }
+        Ok(())
+    }
+}
+
+struct ColumnSchema {
+    avro_type: Value,
+    enum_symbols: Option<PlHashMap<String, i64>>,
+}
+
+/// Avro names start with a letter or underscore followed by letters, digits and underscores.
+fn is_avro_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+/// Get the Avro schema of a column. Categoricals are cast to `Utf8` and written as an enum of
+/// their unique values.
+fn prepare_column(s: &Series) -> Result<(Series, ColumnSchema)> {
+    if s.dtype() == &DataType::Categorical {
+        let s = s.cast(&DataType::Utf8)?;
+        let unique = s.utf8()?.unique()?;
+        let mut symbols = unique.into_iter().flatten().collect::<Vec<_>>();
+        symbols.sort_unstable();
+        if symbols.iter().all(|v| is_avro_name(v)) {
+            let avro_type = json!({
+                "type": "enum",
+                "name": s.name(),
+                "symbols": symbols,
+            });
+            let enum_symbols = symbols
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (v.to_string(), i as i64))
+                .collect();
+            return Ok((
+                s,
+                ColumnSchema {
+                    avro_type,
+                    enum_symbols: Some(enum_symbols),
+                },
+            ));
+        }
+        let avro_type = avro_type(s.name(), &DataType::Utf8)?;
+        return Ok((
+            s,
+            ColumnSchema {
+                avro_type,
+                enum_symbols: None,
+            },
+        ));
+    }
+    let avro_type = avro_type(s.name(), s.dtype())?;
+    Ok((
+        s.clone(),
+        ColumnSchema {
+            avro_type,
+            enum_symbols: None,
+        },
+    ))
+}
+
+/// Map a polars dtype onto an Avro type. `name` is used to name nested records, which Avro
+/// requires to be unique.
+fn avro_type(name: &str, dtype: &DataType) -> Result<Value> {
+    let avro_type = match dtype {
+        DataType::Boolean => json!("boolean"),
+        DataType::UInt8 | DataType::UInt16 | DataType::Int8 | DataType::Int16 | DataType::Int32 => {
+            json!("int")
+        }
+        DataType::UInt32 | DataType::UInt64 | DataType::Int64 => json!("long"),
+        DataType::Float32 => json!("float"),
+        DataType::Float64 => json!("double"),
+        DataType::Utf8 => json!("string"),
+        #[cfg(feature = "dtype-binary")]
+        DataType::Binary => json!("bytes"),
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => json!({"type": "int", "logicalType": "date"}),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(TimeUnit::Milliseconds, _) => {
+            json!({"type": "long", "logicalType": "timestamp-millis"})
+        }
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, _) => json!({"type": "long", "logicalType": "timestamp-micros"}),
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => json!({"type": "long", "logicalType": "time-micros"}),
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(_) => json!("long"),
+        DataType::List(inner) => json!({
+            "type": "array",
+            "items": ["null", avro_type(name, inner)?],
+        }),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => {
+            let fields = fields
+                .iter()
+                .map(|fld| {
+                    let name = format!("{}_{}", name, fld.name());
+                    Ok(json!({
+                        "name": fld.name(),
+                        "type": ["null", avro_type(&name, fld.data_type())?],
+                    }))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            json!({"type": "record", "name": name, "fields": fields})
+        }
+        dt => {
+            return Err(PolarsError::InvalidOperation(
+                format!("cannot write a column of dtype {:?} to Avro", dt).into(),
+            ))
+        }
+    };
+    Ok(avro_type)
+}
+
+/// Longs and ints are zigzag encoded variable length integers.
+fn encode_long(buf: &mut Vec<u8>, v: i64) {
+    let mut z = ((v << 1) ^ (v >> 63)) as u64;
+    while z >= 0x80 {
+        buf.push((z as u8) | 0x80);
+        z >>= 7;
+    }
+    buf.push(z as u8);
+}
+
+fn encode_bytes(buf: &mut Vec<u8>, v: &[u8]) {
+    encode_long(buf, v.len() as i64);
+    buf.extend_from_slice(v);
+}
+
+/// Encode a value of a `["null", T]` union: the index of the branch followed by the value.
+fn encode_value(
+    buf: &mut Vec<u8>,
+    value: AnyValue,
+    enum_symbols: Option<&PlHashMap<String, i64>>,
+) -> Result<()> {
+    if let AnyValue::Null = value {
+        encode_long(buf, 0);
+        return Ok(());
+    }
+    encode_long(buf, 1);
+    match value {
+        AnyValue::Boolean(v) => buf.push(v as u8),
+        AnyValue::Utf8(v) => match enum_symbols {
+            Some(symbols) => encode_long(buf, symbols[v]),
+            None => encode_bytes(buf, v.as_bytes()),
+        },
+        #[cfg(feature = "dtype-binary")]
+        AnyValue::Binary(v) => encode_bytes(buf, v),
+        AnyValue::UInt8(v) => encode_long(buf, v as i64),
+        AnyValue::UInt16(v) => encode_long(buf, v as i64),
+        AnyValue::UInt32(v) => encode_long(buf, v as i64),
+        AnyValue::UInt64(v) => {
+            if v > i64::MAX as u64 {
+                return Err(PolarsError::ComputeError(
+                    format!("value {} does not fit in an Avro long", v).into(),
+                ));
+            }
+            encode_long(buf, v as i64)
+        }
+        AnyValue::Int8(v) => encode_long(buf, v as i64),
+        AnyValue::Int16(v) => encode_long(buf, v as i64),
+        AnyValue::Int32(v) => encode_long(buf, v as i64),
+        AnyValue::Int64(v) => encode_long(buf, v),
+        AnyValue::Float32(v) => buf.extend_from_slice(&v.to_le_bytes()),
+        AnyValue::Float64(v) => buf.extend_from_slice(&v.to_le_bytes()),
+        #[cfg(feature = "dtype-date")]
+        AnyValue::Date(v) => encode_long(buf, v as i64),
+        #[cfg(feature = "dtype-datetime")]
+        AnyValue::Datetime(v, tu, _) => match tu {
+            TimeUnit::Nanoseconds => encode_long(buf, v / 1000),
+            TimeUnit::Microseconds | TimeUnit::Milliseconds => encode_long(buf, v),
+        },
+        #[cfg(feature = "dtype-time")]
+        AnyValue::Time(v) => encode_long(buf, v / 1000),
+        #[cfg(feature = "dtype-duration")]
+        AnyValue::Duration(v, _) => encode_long(buf, v),
+        AnyValue::List(s) => {
+            // arrays are written as a single block of items followed by an empty block
+            if !s.is_empty() {
+                encode_long(buf, s.len() as i64);
+                for i in 0..s.len() {
+                    encode_value(buf, s.get(i), None)?;
+                }
+            }
+            encode_long(buf, 0);
+        }
+        #[cfg(feature = "dtype-struct")]
+        AnyValue::Struct(values, _) => {
+            for v in values {
+                encode_value(buf, v, None)?;
+            }
+        }
+        v => {
+            return Err(PolarsError::InvalidOperation(
+                format!("cannot write value {:?} to Avro", v).into(),
+            ))
+        }
+    }
+    Ok(())
+}
+
+fn compress(data: Vec<u8>, compression: Option<AvroCompression>) -> Result<Vec<u8>> {
+    match compression {
+        None => Ok(data),
+        Some(AvroCompression::Deflate) => {
+            use flate2::{write::DeflateEncoder, Compression};
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&data)?;
+            Ok(encoder.finish()?)
+        }
+        Some(AvroCompression::Snappy) => {
+            let mut compressed = snap::raw::Encoder::new()
+                .compress_vec(&data)
+                .map_err(|e| PolarsError::ComputeError(format!("{}", e).into()))?;
+            // snappy blocks end with the big endian CRC32 checksum of the uncompressed data
+            compressed.extend_from_slice(&crc32fast::hash(&data).to_be_bytes());
+            Ok(compressed)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn write_and_read_avro() -> Result<()> {
+        let mut df = create_df();
+        df.with_column(Series::new(
+            "name",
+            &[Some("a"), None, Some("c"), Some("d"), None],
+        ))?;
+        df.with_column(
+            Series::new("kind", &["x", "y", "x", "x", "y"]).cast(&DataType::Categorical)?,
+        )?;
+
+        for compression in [
+            None,
+            Some(AvroCompression::Deflate),
+            Some(AvroCompression::Snappy),
+        ] {
+            let mut buf: Vec<u8> = Vec::new();
+            AvroWriter::new(&mut buf)
+                .with_compression(compression)
+                .finish(&df)?;
+
+            let df_read = AvroReader::new(Cursor::new(&buf)).finish()?;
+            assert_eq!(df_read.schema(), df.schema());
+            assert!(df_read.drop("kind")?.frame_equal_missing(&df.drop("kind")?));
+            assert_eq!(
+                Vec::from(df_read.column("kind")?.cast(&DataType::Utf8)?.utf8()?),
+                &[Some("x"), Some("y"), Some("x"), Some("x"), Some("y")]
+            );
+
+            let df_read = AvroReader::new(Cursor::new(&buf))
+                .with_columns(Some(vec!["name".to_string(), "days".to_string()]))
+                .with_stop_after_n_rows(Some(3))
+                .finish()?;
+            assert_eq!(df_read.get_column_names(), &["name", "days"]);
+            assert_eq!(df_read.height(), 3);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn write_and_read_avro_list_and_temporal() -> Result<()> {
+        let mut list = ListPrimitiveChunkedBuilder::<Int64Type>::new("list", 3, 4);
+        list.append_opt_series(Some(&Series::new("", &[Some(1i64), None])));
+        list.append_null();
+        list.append_opt_series(Some(&Series::new("", &[3i64, 4])));
+        let mut df = DataFrame::new(vec![list.finish().into_series()])?;
+        #[cfg(feature = "dtype-date")]
+        df.with_column(
+            Int32Chunked::new_from_opt_slice("date", &[Some(18_000), None, Some(19_000)])
+                .into_date()
+                .into_series(),
+        )?;
+        #[cfg(feature = "dtype-datetime")]
+        for (name, tu) in [
+            ("datetime_ms", TimeUnit::Milliseconds),
+            ("datetime_us", TimeUnit::Microseconds),
+        ] {
+            df.with_column(
+                Int64Chunked::new_from_opt_slice(
+                    name,
+                    &[Some(1_600_000_000_000), None, Some(1_700_000_000_000)],
+                )
+                .into_datetime(tu, None)
+                .into_series(),
+            )?;
+        }
+
+        let mut buf: Vec<u8> = Vec::new();
+        AvroWriter::new(&mut buf).finish(&df)?;
+        let df_read = AvroReader::new(Cursor::new(&buf)).finish()?;
+        assert_eq!(df_read.schema(), df.schema());
+        assert!(df_read.frame_equal_missing(&df));
+        Ok(())
+    }
+}
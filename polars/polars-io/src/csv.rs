@@ -41,13 +41,18 @@
 //! }
 //! ```
 //!
+pub use crate::csv_core::csv::BatchedCsvReader;
 use crate::csv_core::csv::CoreReader;
 use crate::csv_core::utils::get_reader_bytes;
+use crate::csv_core::write as csv_write;
+pub use crate::csv_core::write::{QuoteStyle, SerializeOptions};
 use crate::mmap::MmapBytesReader;
 use crate::utils::resolve_homedir;
 use crate::{PhysicalIoExpr, ScanAggregation, SerReader, SerWriter};
 pub use arrow::io::csv::write;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fs::File;
 use std::io::Write;
@@ -57,11 +62,9 @@ use std::path::PathBuf;
 pub struct CsvWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
-    /// Builds an Arrow CSV Writer
-    writer_builder: write::WriterBuilder,
-    /// arrow specific options
-    options: write::SerializeOptions,
+    options: SerializeOptions,
     header: bool,
+    batch_size: usize,
 }
 
 impl<W> SerWriter<W> for CsvWriter<W>
@@ -69,34 +72,19 @@ where
     W: Write,
 {
     fn new(buffer: W) -> Self {
-        // 6f because our precision is milliseconds
-        // no need for 3 traling zeros
-        let options = write::SerializeOptions {
-            // 9f: all nanoseconds
-            time64_format: "%T%.9f".to_string(),
-            // 6f: all milliseconds
-            timestamp_format: "%FT%H:%M:%S.%6f".to_string(),
-            ..Default::default()
-        };
-
         CsvWriter {
             buffer,
-            writer_builder: write::WriterBuilder::new(),
-            options,
+            options: SerializeOptions::default(),
             header: true,
+            batch_size: 1024,
         }
     }
 
-    fn finish(self, df: &DataFrame) -> Result<()> {
-        let mut writer = self.writer_builder.from_writer(self.buffer);
-        let iter = df.iter_record_batches();
+    fn finish(mut self, df: &DataFrame) -> Result<()> {
         if self.header {
-            write::write_header(&mut writer, &df.schema().to_arrow())?;
+            csv_write::write_header(&mut self.buffer, &df.get_column_names(), &self.options)?;
         }
-        for batch in iter {
-            write::write_batch(&mut writer, &batch, &self.options)?;
-        }
-        Ok(())
+        csv_write::write(&mut self.buffer, df, self.batch_size, &self.options)
     }
 }
 
@@ -112,39 +100,144 @@ where
 
     /// Set the CSV file's column delimiter as a byte character
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
-        self.writer_builder.delimiter(delimiter);
+        self.options.delimiter = delimiter;
         self
     }
 
     /// Set the CSV file's date format
     pub fn with_date_format(mut self, format: String) -> Self {
-        self.options.date32_format = format;
+        self.options.date_format = Some(format);
         self
     }
 
     /// Set the CSV file's time format
     pub fn with_time_format(mut self, format: String) -> Self {
-        self.options.time32_format = format.clone();
-        self.options.time64_format = format;
+        self.options.time_format = Some(format);
         self
     }
 
     /// Set the CSV file's timestamp format array in
     pub fn with_timestamp_format(mut self, format: String) -> Self {
-        self.options.timestamp_format = format;
+        self.options.datetime_format = Some(format);
+        self
+    }
+
+    /// Set the single byte character used for quoting. Defaults to `"`.
+    pub fn with_quoting_char(mut self, quote: u8) -> Self {
+        self.options.quote = quote;
+        self
+    }
+
+    /// Set when fields are quoted. Defaults to [`QuoteStyle::Necessary`].
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.options.quote_style = quote_style;
+        self
+    }
+
+    /// Set the string that is written for null values. Defaults to an empty string.
+    pub fn with_null_value(mut self, null_value: String) -> Self {
+        self.options.null = null_value;
+        self
+    }
+
+    /// Set the string that ends every line. Defaults to `\n`.
+    pub fn with_line_terminator(mut self, line_terminator: String) -> Self {
+        self.options.line_terminator = line_terminator;
+        self
+    }
+
+    /// Set the number of decimals that are written for floats.
+    pub fn with_float_precision(mut self, precision: Option<usize>) -> Self {
+        self.options.float_precision = precision;
         self
     }
+
+    /// Write floats in scientific notation, e.g. `1.5e3`.
+    pub fn with_float_scientific(mut self, scientific: bool) -> Self {
+        self.options.float_scientific = scientific;
+        self
+    }
+
+    /// Set all options of serializing the values at once.
+    pub fn with_serialize_options(mut self, options: SerializeOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set the number of rows that a thread serializes at once. A batch has at least one row.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Write DataFrames with the given schema one after the other, e.g. as they are produced
+    /// by a query. The header is written immediately.
+    pub fn batched(mut self, schema: &Schema) -> Result<BatchedCsvWriter<W>> {
+        if self.header {
+            let names = schema
+                .fields()
+                .iter()
+                .map(|fld| fld.name().as_str())
+                .collect::<Vec<_>>();
+            csv_write::write_header(&mut self.buffer, &names, &self.options)?;
+        }
+        Ok(BatchedCsvWriter {
+            writer: self,
+            schema: schema.clone(),
+        })
+    }
 }
 
-#[derive(Copy, Clone)]
+/// Writes DataFrames with the same schema incrementally to csv.
+///
+/// Created by [`CsvWriter::batched`].
+pub struct BatchedCsvWriter<W: Write> {
+    writer: CsvWriter<W>,
+    schema: Schema,
+}
+
+impl<W: Write> BatchedCsvWriter<W> {
+    /// Write the rows of `df`. The schema must be the schema the writer was created with.
+    pub fn write_batch(&mut self, df: &DataFrame) -> Result<()> {
+        if df.schema() != self.schema {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "cannot write a batch with schema {:?} to a csv writer with schema {:?}",
+                    df.schema(),
+                    self.schema
+                )
+                .into(),
+            ));
+        }
+        let writer = &mut self.writer;
+        csv_write::write(&mut writer.buffer, df, writer.batch_size, &writer.options)
+    }
+
+    /// Flush the underlying writer.
+    pub fn finish(mut self) -> Result<()> {
+        self.writer.buffer.flush()?;
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CsvEncoding {
     /// Utf8 encoding
     Utf8,
     /// Utf8 encoding and unknown bytes are replaced with �
     LossyUtf8,
+    /// ISO-8859-1, every byte is the code point of its character
+    Latin1,
+    /// Windows code page 1252, Latin-1 with printable characters in the range 0x80-0x9F
+    Windows1252,
+    /// UTF-16, the byte order is taken from the byte order mark. Without one little endian is
+    /// assumed. Invalid code units are replaced with �
+    Utf16,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NullValues {
     /// A single value that's used for all columns
     AllColumns(String),
@@ -396,6 +489,88 @@ where
     }
 }
 
+impl<'a, R> CsvReader<'a, R>
+where
+    R: 'a + MmapBytesReader,
+{
+    /// Split the dtypes of the schema overwrite in the schema the parser can read and the fields
+    /// that have to be cast after parsing.
+    fn parser_schema_overwrite(&self) -> (Option<Schema>, Vec<Field>) {
+        let schema = match self.schema_overwrite {
+            Some(schema) => schema,
+            None => return (None, vec![]),
+        };
+        let mut to_cast = Vec::with_capacity(schema.len());
+
+        let fields = schema
+            .fields()
+            .iter()
+            .filter_map(|fld| {
+                use DataType::*;
+                match fld.data_type() {
+                    // For categorical we first read as utf8 and later cast to categorical
+                    Categorical => {
+                        to_cast.push(fld.clone());
+                        Some(Field::new(fld.name(), DataType::Utf8))
+                    }
+                    Date | Datetime(_, _) => {
+                        to_cast.push(fld.clone());
+                        // let inference decide the column type
+                        None
+                    }
+                    Time => {
+                        to_cast.push(fld.clone());
+                        // let inference decide the column type
+                        None
+                    }
+                    Int8 | Int16 | UInt8 | UInt16 | Boolean => {
+                        // We have not compiled these buffers, so we cast them later.
+                        to_cast.push(fld.clone());
+                        // let inference decide the column type
+                        None
+                    }
+                    _ => Some(fld.clone()),
+                }
+            })
+            .collect();
+        (Some(Schema::new(fields)), to_cast)
+    }
+
+    /// Read the file in DataFrames of `batch_size` rows instead of a single DataFrame.
+    ///
+    /// The projection, dtypes, null values, comment and quote options are applied to every
+    /// batch. Dates are not parsed and the aggregation and predicate of a lazy scan are ignored.
+    pub fn batched(&mut self, batch_size: usize) -> Result<BatchedCsvReader<'_>> {
+        let (schema_overwrite, to_cast) = self.parser_schema_overwrite();
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let csv_reader = CoreReader::new(
+            reader_bytes,
+            self.stop_after_n_rows,
+            self.skip_rows,
+            self.projection.take(),
+            self.max_records,
+            self.delimiter,
+            self.has_header,
+            self.ignore_parser_errors,
+            self.schema,
+            self.columns.take(),
+            self.encoding,
+            self.n_threads,
+            schema_overwrite.as_ref().or(self.schema),
+            self.dtype_overwrite,
+            self.sample_size,
+            self.chunk_size,
+            self.low_memory,
+            self.comment_char,
+            self.quote_char,
+            self.null_values.take(),
+            None,
+            None,
+        )?;
+        csv_reader.batched(batch_size, to_cast)
+    }
+}
+
 impl<'a> CsvReader<'a, File> {
     /// This is the recommended way to create a csv reader as this allows for fastest parsing.
     pub fn from_path<P: Into<PathBuf>>(path: P) -> Result<Self> {
@@ -445,107 +620,38 @@ where
     fn finish(mut self) -> Result<DataFrame> {
         let rechunk = self.rechunk;
 
-        let mut df = if let Some(schema) = self.schema_overwrite {
-            // This branch we check if there are dtypes we cannot parse.
-            // We only support a few dtypes in the parser and later cast to the required dtype
-            let mut to_cast = Vec::with_capacity(schema.len());
-
-            let fields = schema
-                .fields()
-                .iter()
-                .filter_map(|fld| {
-                    use DataType::*;
-                    match fld.data_type() {
-                        // For categorical we first read as utf8 and later cast to categorical
-                        Categorical => {
-                            to_cast.push(fld);
-                            Some(Field::new(fld.name(), DataType::Utf8))
-                        }
-                        Date | Datetime => {
-                            to_cast.push(fld);
-                            // let inference decide the column type
-                            None
-                        }
-                        Time => {
-                            to_cast.push(fld);
-                            // let inference decide the column type
-                            None
-                        }
-                        Int8 | Int16 | UInt8 | UInt16 | Boolean => {
-                            // We have not compiled these buffers, so we cast them later.
-                            to_cast.push(fld);
-                            // let inference decide the column type
-                            None
-                        }
-                        _ => Some(fld.clone()),
-                    }
-                })
-                .collect();
-            let schema = Schema::new(fields);
-
-            // we cannot overwrite self, because the lifetime is already instantiated with `a, and
-            // the lifetime that accompanies this scope is shorter.
-            // So we just build_csv_reader from here
-            let reader_bytes = get_reader_bytes(&mut self.reader)?;
-            let mut csv_reader = CoreReader::new(
-                reader_bytes,
-                self.stop_after_n_rows,
-                self.skip_rows,
-                self.projection,
-                self.max_records,
-                self.delimiter,
-                self.has_header,
-                self.ignore_parser_errors,
-                self.schema,
-                self.columns,
-                self.encoding,
-                self.n_threads,
-                Some(&schema),
-                self.dtype_overwrite,
-                self.sample_size,
-                self.chunk_size,
-                self.low_memory,
-                self.comment_char,
-                self.quote_char,
-                self.null_values,
-                self.predicate,
-                self.aggregate,
-            )?;
-            let mut df = csv_reader.as_df()?;
-
-            // cast to the original dtypes in the schema
-            for fld in to_cast {
-                df.may_apply(fld.name(), |s| s.cast(fld.data_type()))?;
-            }
-            df
-        } else {
-            let reader_bytes = get_reader_bytes(&mut self.reader)?;
-            let mut csv_reader = CoreReader::new(
-                reader_bytes,
-                self.stop_after_n_rows,
-                self.skip_rows,
-                self.projection,
-                self.max_records,
-                self.delimiter,
-                self.has_header,
-                self.ignore_parser_errors,
-                self.schema,
-                self.columns,
-                self.encoding,
-                self.n_threads,
-                self.schema,
-                self.dtype_overwrite,
-                self.sample_size,
-                self.chunk_size,
-                self.low_memory,
-                self.comment_char,
-                self.quote_char,
-                self.null_values,
-                self.predicate,
-                self.aggregate,
-            )?;
-            csv_reader.as_df()?
-        };
+        let (schema_overwrite, to_cast) = self.parser_schema_overwrite();
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let mut csv_reader = CoreReader::new(
+            reader_bytes,
+            self.stop_after_n_rows,
+            self.skip_rows,
+            self.projection,
+            self.max_records,
+            self.delimiter,
+            self.has_header,
+            self.ignore_parser_errors,
+            self.schema,
+            self.columns,
+            self.encoding,
+            self.n_threads,
+            schema_overwrite.as_ref().or(self.schema),
+            self.dtype_overwrite,
+            self.sample_size,
+            self.chunk_size,
+            self.low_memory,
+            self.comment_char,
+            self.quote_char,
+            self.null_values,
+            self.predicate,
+            self.aggregate,
+        )?;
+        let mut df = csv_reader.as_df()?;
+
+        // cast to the original dtypes in the schema
+        for fld in to_cast {
+            df.may_apply(fld.name(), |s| s.cast(fld.data_type()))?;
+        }
 
         // Important that this rechunk is never done in parallel.
         // As that leads to great memory overhead.
@@ -635,6 +741,54 @@ mod test {
         assert_eq!("0,22.1\n1,19.9\n2,7.0\n3,2.0\n4,3.0\n", csv);
     }
 
+    #[test]
+    fn test_write_csv_options() -> Result<()> {
+        let df = df![
+            "a" => [Some("x,y"), None, Some("say \"hi\"")],
+            "b" => [1.5, 2.0, 1234.5678],
+            "c" => [1, 2, 3]
+        ]?;
+
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .with_null_value("NULL".to_string())
+            .with_float_precision(Some(2))
+            .finish(&df)?;
+        let csv = std::str::from_utf8(&buf).unwrap();
+        assert_eq!(
+            "a,b,c\n\"x,y\",1.50,1\nNULL,2.00,2\n\"say \"\"hi\"\"\",1234.57,3\n",
+            csv
+        );
+
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .has_header(false)
+            .with_quote_style(QuoteStyle::NonNumeric)
+            .with_quoting_char(b'\'')
+            .with_float_scientific(true)
+            .with_line_terminator("\r\n".to_string())
+            .with_batch_size(1)
+            .finish(&df.head(Some(2)))?;
+        let csv = std::str::from_utf8(&buf).unwrap();
+        assert_eq!("'x,y',1.5e0,1\r\n,2e0,2\r\n", csv);
+
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .has_header(false)
+            .with_quote_style(QuoteStyle::Always)
+            .finish(&df.select("c")?)?;
+        assert_eq!("\"1\"\n\"2\"\n\"3\"\n", std::str::from_utf8(&buf).unwrap());
+
+        // a batch has at least one row
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .has_header(false)
+            .with_batch_size(0)
+            .finish(&df.select("c")?)?;
+        assert_eq!("1\n2\n3\n", std::str::from_utf8(&buf).unwrap());
+        Ok(())
+    }
+
     #[test]
     fn test_read_csv_file() {
         let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods1.csv";
@@ -1005,7 +1159,7 @@ AUDCAD,1616455921,0.96212,0.95666,1
             .has_header(true)
             .with_dtypes(Some(&Schema::new(vec![Field::new(
                 "b",
-                DataType::Datetime,
+                DataType::Datetime(TimeUnit::Milliseconds, None),
             )])))
             .finish()?;
 
@@ -1013,7 +1167,7 @@ AUDCAD,1616455921,0.96212,0.95666,1
             df.dtypes(),
             &[
                 DataType::Utf8,
-                DataType::Datetime,
+                DataType::Datetime(TimeUnit::Milliseconds, None),
                 DataType::Float64,
                 DataType::Float64,
                 DataType::Int64
@@ -1174,7 +1328,10 @@ bar,bar";
         let df = CsvReader::new(file).with_parse_dates(true).finish()?;
 
         let ts = df.column("timestamp")?;
-        assert_eq!(ts.dtype(), &DataType::Datetime);
+        assert_eq!(
+            ts.dtype(),
+            &DataType::Datetime(TimeUnit::Milliseconds, None)
+        );
         assert_eq!(ts.null_count(), 0);
 
         Ok(())
@@ -1290,4 +1447,88 @@ A3,\"B4_\"\"with_embedded_double_quotes\"\"\",C4,4";
 
         Ok(())
     }
+
+    #[test]
+    fn test_csv_encodings() -> Result<()> {
+        let csv = b"a,b\ncaf\xe9,\x80 5\n";
+        let df = CsvReader::new(Cursor::new(&csv[..]))
+            .with_encoding(CsvEncoding::Windows1252)
+            .finish()?;
+        assert_eq!(Vec::from(df.column("a")?.utf8()?), &[Some("café")]);
+        assert_eq!(Vec::from(df.column("b")?.utf8()?), &[Some("€ 5")]);
+
+        let df = CsvReader::new(Cursor::new(&csv[..]))
+            .with_encoding(CsvEncoding::Latin1)
+            .finish()?;
+        assert_eq!(Vec::from(df.column("a")?.utf8()?), &[Some("café")]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "decompress")]
+    fn test_compressed_csv_encodings() -> Result<()> {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let csv = b"a,b\ncaf\xe9,\x80 5\n";
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(csv)?;
+        let compressed = encoder.finish()?;
+
+        // the bytes are decoded after decompression, with and without a schema
+        let df = CsvReader::new(Cursor::new(&compressed))
+            .with_encoding(CsvEncoding::Windows1252)
+            .finish()?;
+        assert_eq!(Vec::from(df.column("a")?.utf8()?), &[Some("café")]);
+        assert_eq!(Vec::from(df.column("b")?.utf8()?), &[Some("€ 5")]);
+
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Utf8),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let df = CsvReader::new(Cursor::new(&compressed))
+            .with_schema(&schema)
+            .with_encoding(CsvEncoding::Latin1)
+            .finish()?;
+        assert_eq!(Vec::from(df.column("a")?.utf8()?), &[Some("café")]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_batched_csv() -> Result<()> {
+        let csv = r"a,b,c
+1,x,true
+2,NA,false
+# a comment
+3,z,true
+4,x,false
+5,NA,true
+";
+        let schema = Schema::new(vec![Field::new("c", DataType::Boolean)]);
+        let mut reader = CsvReader::new(Cursor::new(csv))
+            .with_comment_char(Some(b'#'))
+            .with_null_values(Some(NullValues::AllColumns("NA".to_string())))
+            .with_columns(Some(vec!["b".to_string(), "c".to_string()]))
+            .with_dtypes(Some(&schema));
+        let batches = reader.batched(2)?.collect::<Result<Vec<_>>>()?;
+        assert_eq!(
+            batches.iter().map(|df| df.height()).collect::<Vec<_>>(),
+            &[2, 2, 1]
+        );
+        let df = &batches[1];
+        assert_eq!(df.get_column_names(), &["b", "c"]);
+        assert_eq!(Vec::from(df.column("b")?.utf8()?), &[Some("z"), Some("x")]);
+        assert_eq!(df.column("c")?.dtype(), &DataType::Boolean);
+        assert_eq!(batches[2].column("b")?.null_count(), 1);
+
+        let mut reader = CsvReader::new(Cursor::new(csv))
+            .with_comment_char(Some(b'#'))
+            .with_stop_after_n_rows(Some(3));
+        let n_rows = reader
+            .batched(2)?
+            .map(|df| df.map(|df| df.height()))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(n_rows, &[2, 1]);
+        Ok(())
+    }
 }
@@ -113,7 +113,7 @@ impl<'a> CoreReader<'a> {
         columns: Option<Vec<String>>,
         encoding: CsvEncoding,
         n_threads: Option<usize>,
-        schema_overwrite: Option<&'a Schema>,
+        schema_overwrite: Option<&Schema>,
         dtype_overwrite: Option<&'a [DataType]>,
         sample_size: usize,
         chunk_size: usize,
@@ -125,7 +125,17 @@ impl<'a> CoreReader<'a> {
         aggregate: Option<&'a [ScanAggregation]>,
     ) -> Result<CoreReader<'a>> {
         #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
-        let mut reader_bytes = reader_bytes;
+        let reader_bytes = match decompress(&reader_bytes) {
+            Some(b) => ReaderBytes::Owned(b),
+            None => reader_bytes,
+        };
+        // decode legacy encodings once, the parser only deals with utf8. This must happen after
+        // decompression, the compressed bytes are not encoded text.
+        let reader_bytes = transcode_to_utf8(reader_bytes, encoding);
+        let encoding = match encoding {
+            CsvEncoding::LossyUtf8 => CsvEncoding::LossyUtf8,
+            _ => CsvEncoding::Utf8,
+        };
 
         // check if schema should be inferred
         let delimiter = delimiter.unwrap_or(b',');
@@ -133,41 +143,17 @@ impl<'a> CoreReader<'a> {
         let mut schema = match schema {
             Some(schema) => Cow::Borrowed(schema),
             None => {
-                #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
-                {
-                    // We keep track of the inferred schema bool
-                    // In case the file is compressed this schema inference is wrong and has to be done
-                    // again after decompression.
-                    if let Some(b) = decompress(&reader_bytes) {
-                        reader_bytes = ReaderBytes::Owned(b);
-                    }
-
-                    let (inferred_schema, _) = infer_file_schema(
-                        &reader_bytes,
-                        delimiter,
-                        max_records,
-                        has_header,
-                        schema_overwrite,
-                        &mut skip_rows,
-                        comment_char,
-                        quote_char,
-                    )?;
-                    Cow::Owned(inferred_schema)
-                }
-                #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
-                {
-                    let (inferred_schema, _) = infer_file_schema(
-                        &reader_bytes,
-                        delimiter,
-                        max_records,
-                        has_header,
-                        schema_overwrite,
-                        &mut skip_rows,
-                        comment_char,
-                        quote_char,
-                    )?;
-                    Cow::Owned(inferred_schema)
-                }
+                let (inferred_schema, _) = infer_file_schema(
+                    &reader_bytes,
+                    delimiter,
+                    max_records,
+                    has_header,
+                    schema_overwrite,
+                    &mut skip_rows,
+                    comment_char,
+                    quote_char,
+                )?;
+                Cow::Owned(inferred_schema)
             }
         };
         if let Some(dtypes) = dtype_overwrite {
@@ -553,6 +539,54 @@ impl<'a> CoreReader<'a> {
         }
     }
 
+    /// Turn the reader into a [`BatchedCsvReader`]. The columns in `to_cast` are parsed in
+    /// another dtype and cast in every batch.
+    pub(crate) fn batched(
+        mut self,
+        batch_size: usize,
+        to_cast: Vec<Field>,
+    ) -> Result<BatchedCsvReader<'a>> {
+        if batch_size == 0 {
+            return Err(PolarsError::ValueError(
+                "the batch size should be larger than 0".into(),
+            ));
+        }
+        let reader_bytes = self.reader_bytes.take().unwrap();
+        let offset = reader_bytes.len() - self.find_starting_point(&reader_bytes)?.len();
+
+        let mut projection = self
+            .projection
+            .take()
+            .unwrap_or_else(|| (0..self.schema.len()).collect());
+        projection.sort_unstable();
+
+        let mut n_str_columns = 0;
+        for i in &projection {
+            let fld = self.schema.field(*i).ok_or_else(||
+                PolarsError::ValueError(
+                    format!("the given projection index: {} is out of bounds for csv schema with {} columns", i, self.schema.len()).into())
+                )?;
+            if fld.data_type() == &DataType::Utf8 {
+                n_str_columns += 1;
+            }
+        }
+        // assume 10 chars per str
+        let str_capacities = (0..n_str_columns)
+            .map(|_| RunningSize::new(batch_size * 10))
+            .collect();
+
+        Ok(BatchedCsvReader {
+            remaining_rows: self.n_rows,
+            reader: self,
+            reader_bytes,
+            offset,
+            projection,
+            str_capacities,
+            batch_size,
+            to_cast,
+        })
+    }
+
     /// Read the csv into a DataFrame. The predicate can come from a lazy physical plan.
     pub fn as_df(&mut self) -> Result<DataFrame> {
         let predicate = self.predicate.take();
@@ -581,3 +615,88 @@ impl<'a> CoreReader<'a> {
         Ok(df)
     }
 }
+
+/// Reads a csv file in DataFrames of a fixed number of rows, so that files larger than memory
+/// can be processed batch by batch. Created with [`CsvReader::batched`](crate::csv::CsvReader::batched).
+pub struct BatchedCsvReader<'a> {
+    reader: CoreReader<'a>,
+    reader_bytes: ReaderBytes<'a>,
+    /// Position of the next row in the bytes.
+    offset: usize,
+    projection: Vec<usize>,
+    str_capacities: Vec<RunningSize>,
+    batch_size: usize,
+    remaining_rows: Option<usize>,
+    to_cast: Vec<Field>,
+}
+
+impl<'a> BatchedCsvReader<'a> {
+    /// Parse the next `batch_size` rows. Returns `None` when the whole file is read.
+    pub fn next_batch(&mut self) -> Result<Option<DataFrame>> {
+        loop {
+            let n_lines = match self.remaining_rows {
+                Some(0) => return Ok(None),
+                Some(n) => std::cmp::min(n, self.batch_size),
+                None => self.batch_size,
+            };
+            let bytes = &self.reader_bytes[..];
+            if self.offset >= bytes.len() {
+                return Ok(None);
+            }
+
+            let reader = &self.reader;
+            let mut buffers = init_buffers(
+                &self.projection,
+                n_lines,
+                &reader.schema,
+                &self.str_capacities,
+                reader.quote_char,
+                reader.encoding,
+                reader.ignore_parser_errors,
+            )?;
+            let read = parse_lines(
+                &bytes[self.offset..],
+                self.offset,
+                reader.delimiter,
+                reader.comment_char,
+                reader.quote_char,
+                reader.null_values.as_ref(),
+                &self.projection,
+                &mut buffers,
+                reader.ignore_parser_errors,
+                // `parse_lines` parses one line more than `n_lines`
+                n_lines - 1,
+            )?;
+            if read == self.offset {
+                return Ok(None);
+            }
+            self.offset = read;
+
+            let mut df = DataFrame::new_no_checks(
+                buffers
+                    .into_iter()
+                    .map(|buf| buf.into_series())
+                    .collect::<Result<_>>()?,
+            );
+            // only comments were parsed
+            if df.height() == 0 {
+                continue;
+            }
+            if let Some(remaining) = &mut self.remaining_rows {
+                *remaining -= std::cmp::min(df.height(), *remaining);
+            }
+            for fld in &self.to_cast {
+                df.may_apply(fld.name(), |s| s.cast(fld.data_type()))?;
+            }
+            return Ok(Some(df));
+        }
+    }
+}
+
+impl<'a> Iterator for BatchedCsvReader<'a> {
+    type Item = Result<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_batch().transpose()
+    }
+}
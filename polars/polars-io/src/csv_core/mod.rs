@@ -1,6 +1,7 @@
 pub(crate) mod buffer;
 pub mod csv;
 pub(crate) mod parser;
+pub(crate) mod write;
 
 #[cfg(not(feature = "private"))]
 pub(crate) mod utils;
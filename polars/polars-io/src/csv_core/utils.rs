@@ -2,12 +2,14 @@ use crate::csv::CsvEncoding;
 use crate::csv_core::parser::{
     next_line_position, skip_bom, skip_line_ending, skip_whitespace, SplitFields, SplitLines,
 };
-use crate::mmap::{MmapBytesReader, ReaderBytes};
+pub use crate::mmap::get_reader_bytes;
+use crate::mmap::ReaderBytes;
 use lazy_static::lazy_static;
 use polars_core::datatypes::PlHashSet;
 use polars_core::prelude::*;
 use regex::{Regex, RegexBuilder};
 use std::borrow::Cow;
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 use std::io::Read;
 
 pub(crate) fn get_file_chunks(
@@ -46,30 +48,6 @@ pub(crate) fn get_file_chunks(
     offsets
 }
 
-pub fn get_reader_bytes<R: Read + MmapBytesReader>(reader: &mut R) -> Result<ReaderBytes<'_>> {
-    // we have a file so we can mmap
-    if let Some(file) = reader.to_file() {
-        let mmap = unsafe { memmap::Mmap::map(file)? };
-        Ok(ReaderBytes::Mapped(mmap))
-    } else {
-        // we can get the bytes for free
-        if reader.to_bytes().is_some() {
-            // duplicate .to_bytes() is necessary to satisfy the borrow checker
-            Ok(ReaderBytes::Borrowed(reader.to_bytes().unwrap()))
-        } else {
-            // we have to read to an owned buffer to get the bytes.
-            let mut bytes = Vec::with_capacity(1024 * 128);
-            reader.read_to_end(&mut bytes)?;
-            if !bytes.is_empty()
-                && (bytes[bytes.len() - 1] != b'\n' || bytes[bytes.len() - 1] != b'\r')
-            {
-                bytes.push(b'\n')
-            }
-            Ok(ReaderBytes::Owned(bytes))
-        }
-    }
-}
-
 lazy_static! {
     static ref FLOAT_RE: Regex = Regex::new(r"^\s*-?((\d*\.\d+)[eE]?[-\+]?\d*)|inf|NaN$").unwrap();
     static ref INTEGER_RE: Regex = Regex::new(r"^\s*-?(\d+)$").unwrap();
@@ -105,10 +83,61 @@ pub(crate) fn parse_bytes_with_encoding(bytes: &[u8], encoding: CsvEncoding) ->
             .map_err(anyhow::Error::from)?
             .into(),
         CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
+        // these are transcoded to utf8 before parsing
+        CsvEncoding::Latin1 | CsvEncoding::Windows1252 | CsvEncoding::Utf16 => {
+            simdutf8::basic::from_utf8(bytes)
+                .map_err(anyhow::Error::from)?
+                .into()
+        }
     };
     Ok(s)
 }
 
+/// The characters of the bytes 0x80-0x9F in Windows-1252. The bytes that are not assigned map
+/// to the control character of the same code point, like Latin-1.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{81}', '\u{201A}', '\u{192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2C6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8D}', '\u{17D}', '\u{8F}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2DC}', '\u{2122}', '\u{161}', '\u{203A}', '\u{153}', '\u{9D}', '\u{17E}', '\u{178}',
+];
+
+fn decode_utf16(bytes: &[u8]) -> String {
+    let (bytes, big_endian) = match bytes {
+        [0xFE, 0xFF, rest @ ..] => (rest, true),
+        [0xFF, 0xFE, rest @ ..] => (rest, false),
+        _ => (bytes, false),
+    };
+    let units = bytes.chunks_exact(2).map(|unit| {
+        if big_endian {
+            u16::from_be_bytes([unit[0], unit[1]])
+        } else {
+            u16::from_le_bytes([unit[0], unit[1]])
+        }
+    });
+    char::decode_utf16(units)
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+/// Transcode the bytes of a file in a non utf8 encoding to utf8, so that they can be parsed.
+/// Utf8 input is returned as is.
+pub fn transcode_to_utf8(reader_bytes: ReaderBytes, encoding: CsvEncoding) -> ReaderBytes {
+    let decoded: String = match encoding {
+        CsvEncoding::Utf8 | CsvEncoding::LossyUtf8 => return reader_bytes,
+        CsvEncoding::Latin1 => reader_bytes.iter().map(|&b| b as char).collect(),
+        CsvEncoding::Windows1252 => reader_bytes
+            .iter()
+            .map(|&b| match b {
+                0x80..=0x9F => WINDOWS_1252_HIGH[(b - 0x80) as usize],
+                _ => b as char,
+            })
+            .collect(),
+        CsvEncoding::Utf16 => decode_utf16(&reader_bytes),
+    };
+    ReaderBytes::Owned(decoded.into_bytes())
+}
+
 /// Infer the schema of a CSV file by reading through the first n records of the file,
 /// with `max_read_records` controlling the maximum number of records to read.
 ///
@@ -348,6 +377,27 @@ mod test {
         assert!(FLOAT_RE.is_match("inf"));
     }
 
+    #[test]
+    fn test_transcode_to_utf8() {
+        let transcode = |bytes: &[u8], encoding| {
+            let out = transcode_to_utf8(ReaderBytes::Borrowed(bytes), encoding);
+            String::from_utf8(out.to_vec()).unwrap()
+        };
+        assert_eq!(transcode(b"caf\xe9", CsvEncoding::Latin1), "café");
+        assert_eq!(
+            transcode(b"\x80 \x93a\x94 \xe9", CsvEncoding::Windows1252),
+            "€ “a” é"
+        );
+        assert_eq!(
+            transcode(&[0xFF, 0xFE, b'a', 0, b',', 0, 0xE9, 0], CsvEncoding::Utf16),
+            "a,é"
+        );
+        assert_eq!(
+            transcode(&[0xFE, 0xFF, 0, b'a', 0, b',', 0, 0xE9], CsvEncoding::Utf16),
+            "a,é"
+        );
+    }
+
     #[test]
     fn test_get_file_chunks() {
         let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods1.csv";
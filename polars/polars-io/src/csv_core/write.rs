@@ -0,0 +1,245 @@
+//! Serialize a DataFrame to csv directly from the `Series`, in parallel over chunks of rows.
+use crate::utils::{write_chunked, SerializedCells};
+use polars_core::prelude::*;
+use std::fmt::{Debug, Display, LowerExp};
+use std::io::Write;
+
+/// When to put a field between quotes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum QuoteStyle {
+    /// Quote every field.
+    Always,
+    /// Only quote fields that contain the delimiter, the quote char or a line break.
+    Necessary,
+    /// Quote all fields that are not numeric.
+    NonNumeric,
+    /// Never quote, even if the field contains special characters.
+    Never,
+}
+
+impl Default for QuoteStyle {
+    fn default() -> Self {
+        QuoteStyle::Necessary
+    }
+}
+
+/// Options to serialize the values of a DataFrame to csv.
+#[derive(Clone, Debug)]
+pub struct SerializeOptions {
+    /// Format of `Date` columns, defaults to `%Y-%m-%d`.
+    pub date_format: Option<String>,
+    /// Format of `Time` columns, defaults to `%T%.9f`.
+    pub time_format: Option<String>,
+    /// Format of `Datetime` columns, defaults to `%FT%H:%M:%S.%6f`.
+    pub datetime_format: Option<String>,
+    /// Number of decimals of floats.
+    pub float_precision: Option<usize>,
+    /// Write floats in scientific notation.
+    pub float_scientific: bool,
+    pub delimiter: u8,
+    pub quote: u8,
+    pub quote_style: QuoteStyle,
+    /// String that is written for null values.
+    pub null: String,
+    pub line_terminator: String,
+}
+
+impl Default for SerializeOptions {
+    fn default() -> Self {
+        SerializeOptions {
+            date_format: None,
+            time_format: None,
+            datetime_format: None,
+            float_precision: None,
+            float_scientific: false,
+            delimiter: b',',
+            quote: b'"',
+            quote_style: QuoteStyle::default(),
+            null: String::new(),
+            line_terminator: "\n".to_string(),
+        }
+    }
+}
+
+fn write_str(buf: &mut Vec<u8>, v: &str, options: &SerializeOptions) {
+    let quote = options.quote;
+    let needs_quotes = match options.quote_style {
+        QuoteStyle::Always | QuoteStyle::NonNumeric => true,
+        QuoteStyle::Necessary => {
+            // an empty string would be read as null
+            (v.is_empty() && options.null.is_empty())
+                || v.bytes()
+                    .any(|b| b == options.delimiter || b == quote || b == b'\n' || b == b'\r')
+        }
+        QuoteStyle::Never => false,
+    };
+    if needs_quotes {
+        buf.push(quote);
+        for b in v.bytes() {
+            // escape the quote char by doubling it
+            if b == quote {
+                buf.push(quote);
+            }
+            buf.push(b);
+        }
+        buf.push(quote);
+    } else {
+        buf.extend_from_slice(v.as_bytes());
+    }
+}
+
+fn write_number<T: Display>(buf: &mut Vec<u8>, v: T, options: &SerializeOptions) {
+    if options.quote_style == QuoteStyle::Always {
+        let quote = options.quote as char;
+        write!(buf, "{}{}{}", quote, v, quote).unwrap();
+    } else {
+        write!(buf, "{}", v).unwrap();
+    }
+}
+
+fn write_float<T: Debug + Display + LowerExp>(buf: &mut Vec<u8>, v: T, options: &SerializeOptions) {
+    let quoted = options.quote_style == QuoteStyle::Always;
+    if quoted {
+        buf.push(options.quote);
+    }
+    match (options.float_precision, options.float_scientific) {
+        (Some(precision), true) => write!(buf, "{:.*e}", precision, v),
+        (None, true) => write!(buf, "{:e}", v),
+        (Some(precision), false) => write!(buf, "{:.*}", precision, v),
+        // debug formatting keeps the decimal point of whole numbers, e.g. `7.0`
+        (None, false) => write!(buf, "{:?}", v),
+    }
+    .unwrap();
+    if quoted {
+        buf.push(options.quote);
+    }
+}
+
+/// Serialize a value of a column that has no typed serializer, e.g. a list.
+fn serialize_value(buf: &mut Vec<u8>, value: AnyValue, options: &SerializeOptions) {
+    match value {
+        AnyValue::Null => buf.extend_from_slice(options.null.as_bytes()),
+        #[cfg(feature = "dtype-decimal")]
+        v @ AnyValue::Decimal(_, _) => write_number(buf, v, options),
+        v => write_str(buf, &v.to_string(), options),
+    }
+}
+
+fn serialize_column(s: &Series, options: &SerializeOptions) -> Result<SerializedCells> {
+    let mut cells = SerializedCells::with_capacity(s.len());
+    let null = options.null.as_bytes();
+    macro_rules! numbers {
+        ($ca:expr) => {
+            cells.extend_primitive($ca, null, |buf, v| write_number(buf, v, options))
+        };
+    }
+    match s.dtype() {
+        DataType::Utf8 => {
+            let values = s.utf8()?.downcast_iter().flat_map(|arr| arr.iter());
+            cells.extend(values, null, |buf, v| write_str(buf, v, options))
+        }
+        DataType::Boolean => {
+            let values = s.bool()?.downcast_iter().flat_map(|arr| arr.iter());
+            cells.extend(values, null, |buf, v| {
+                write_str(buf, if v { "true" } else { "false" }, options)
+            })
+        }
+        DataType::UInt8 => numbers!(s.u8()?),
+        DataType::UInt16 => numbers!(s.u16()?),
+        DataType::UInt32 => numbers!(s.u32()?),
+        DataType::UInt64 => numbers!(s.u64()?),
+        DataType::Int8 => numbers!(s.i8()?),
+        DataType::Int16 => numbers!(s.i16()?),
+        DataType::Int32 => numbers!(s.i32()?),
+        DataType::Int64 => numbers!(s.i64()?),
+        DataType::Float32 => {
+            cells.extend_primitive(s.f32()?, null, |buf, v| write_float(buf, v, options))
+        }
+        DataType::Float64 => {
+            cells.extend_primitive(s.f64()?, null, |buf, v| write_float(buf, v, options))
+        }
+        _ => cells.extend((0..s.len()).map(|i| Some(s.get(i))), null, |buf, v| {
+            serialize_value(buf, v, options)
+        }),
+    }
+    Ok(cells)
+}
+
+/// Format the temporal columns as strings and convert categoricals to their values.
+fn prepare_column(s: &Series, options: &SerializeOptions) -> Result<Series> {
+    let out = match s.dtype() {
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => {
+            let fmt = options.date_format.as_deref().unwrap_or("%Y-%m-%d");
+            s.date()?.strftime(fmt).into_series()
+        }
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, _) => {
+            let fmt = options
+                .datetime_format
+                .as_deref()
+                .unwrap_or("%FT%H:%M:%S.%6f");
+            s.datetime()?.strftime(fmt).into_series()
+        }
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => {
+            let fmt = options.time_format.as_deref().unwrap_or("%T%.9f");
+            s.time()?.strftime(fmt).into_series()
+        }
+        DataType::Categorical => s.cast(&DataType::Utf8)?,
+        _ => return Ok(s.clone()),
+    };
+    Ok(out)
+}
+
+pub(crate) fn write_header<W: Write>(
+    writer: &mut W,
+    names: &[&str],
+    options: &SerializeOptions,
+) -> Result<()> {
+    let mut buf = vec![];
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 {
+            buf.push(options.delimiter);
+        }
+        write_str(&mut buf, name, options);
+    }
+    buf.extend_from_slice(options.line_terminator.as_bytes());
+    writer.write_all(&buf)?;
+    Ok(())
+}
+
+/// Write the rows of `df`, serialized in parallel in chunks of `chunk_size` rows.
+pub(crate) fn write<W: Write>(
+    writer: &mut W,
+    df: &DataFrame,
+    chunk_size: usize,
+    options: &SerializeOptions,
+) -> Result<()> {
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|s| prepare_column(s, options))
+        .collect::<Result<Vec<_>>>()?;
+    let df = DataFrame::new_no_checks(columns);
+
+    write_chunked(writer, &df, chunk_size, |chunk, _| {
+        let columns = chunk
+            .get_columns()
+            .iter()
+            .map(|s| serialize_column(s, options))
+            .collect::<Result<Vec<_>>>()?;
+        let size = columns.iter().map(|cells| cells.size()).sum::<usize>();
+        let mut buf = Vec::with_capacity(size + chunk.height() * (columns.len() + 1));
+        for row in 0..chunk.height() {
+            for (i, cells) in columns.iter().enumerate() {
+                if i > 0 {
+                    buf.push(options.delimiter);
+                }
+                buf.extend_from_slice(cells.get(row));
+            }
+            buf.extend_from_slice(options.line_terminator.as_bytes());
+        }
+        Ok(buf)
+    })
+}
@@ -32,11 +32,13 @@
 //! let df_read = IpcReader::new(buf).finish().unwrap();
 //! assert!(df.frame_equal(&df_read));
 //! ```
-use super::{finish_reader, ArrowReader, ArrowResult, RecordBatch};
+use super::{finish_reader, ArrowReader, ArrowResult, BatchedReader, RecordBatch};
 use crate::prelude::*;
+use crate::{PhysicalIoExpr, ScanAggregation};
 use arrow::io::ipc::{read, write};
 use polars_core::prelude::*;
-use std::io::{Read, Seek, Write};
+use std::fs::File;
+use std::io::{Cursor, Read, Seek, Write};
 use std::sync::Arc;
 
 /// Read Arrows IPC format into a DataFrame
@@ -60,6 +62,128 @@ pub struct IpcReader<R> {
     reader: R,
     /// Aggregates chunks afterwards to a single chunk.
     rechunk: bool,
+    stop_after_n_rows: Option<usize>,
+    projection: Option<Vec<usize>>,
+    columns: Option<Vec<String>>,
+}
+
+impl<R: Read + Seek> IpcReader<R> {
+    /// Get the schema of the IPC file. Only the file's footer is read.
+    pub fn schema(&mut self) -> Result<Schema> {
+        let metadata = read::read_file_metadata(&mut self.reader)?;
+        Ok((&*metadata.schema).into())
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_stop_after_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.stop_after_n_rows = num_rows;
+        self
+    }
+
+    /// Only read the columns with these names. Takes precedence over `with_projection`.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Only read the columns at these indexes (counting from 0).
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Read the file one record batch at a time. The columns of the batches are in the order
+    /// of the file, not in the order of the projection.
+    pub fn batched(mut self) -> Result<BatchedReader<read::FileReader<R>>> {
+        let metadata = read::read_file_metadata(&mut self.reader)?;
+        let projection = self.take_projection(&metadata)?.map(sorted);
+        let ipc_reader = read::FileReader::new(self.reader, metadata, projection);
+        Ok(BatchedReader::new(ipc_reader, self.stop_after_n_rows))
+    }
+
+    /// The indexes of the columns to read, in the requested order.
+    fn take_projection(&mut self, metadata: &read::FileMetadata) -> Result<Option<Vec<usize>>> {
+        let projection = match self.columns.take() {
+            Some(columns) => {
+                let schema: Schema = (&*metadata.schema).into();
+                let prj = columns
+                    .iter()
+                    .map(|name| schema.index_of(name))
+                    .collect::<Result<Vec<_>>>()?;
+                Some(prj)
+            }
+            None => self.projection.take(),
+        };
+        Ok(projection)
+    }
+
+    fn finish_impl(
+        mut self,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        aggregate: Option<&[ScanAggregation]>,
+    ) -> Result<DataFrame> {
+        let rechunk = self.rechunk;
+        let metadata = read::read_file_metadata(&mut self.reader)?;
+        let projection = self.take_projection(&metadata)?;
+        // the columns are read in the order of the file, so restore the requested order afterwards
+        let column_order = projection
+            .as_ref()
+            .filter(|prj| prj.windows(2).any(|w| w[0] > w[1]))
+            .map(|prj| {
+                prj.iter()
+                    .map(|&i| metadata.schema.fields()[i].name().to_string())
+                    .collect::<Vec<_>>()
+            });
+
+        let ipc_reader = read::FileReader::new(&mut self.reader, metadata, projection.map(sorted));
+        let mut df = finish_reader(
+            ipc_reader,
+            rechunk,
+            self.stop_after_n_rows,
+            predicate,
+            aggregate,
+        )?;
+        if let Some(names) = column_order {
+            df = df.select(names.iter().map(|s| s.as_str()).collect::<Vec<_>>())?;
+        }
+        match self.stop_after_n_rows {
+            Some(n) if n < df.height() => Ok(df.slice(0, n)),
+            _ => Ok(df),
+        }
+    }
+
+    #[cfg(feature = "lazy")]
+    // todo! hoist to lazy crate
+    pub fn finish_with_scan_ops(
+        self,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        aggregate: Option<&[ScanAggregation]>,
+        projection: Option<Vec<usize>>,
+    ) -> Result<DataFrame> {
+        self.with_projection(projection)
+            .finish_impl(predicate, aggregate)
+    }
+}
+
+impl IpcReader<File> {
+    /// Memory map the file and read from the mapped bytes instead of from the file handle.
+    /// The arrays are still copied out of the mapping.
+    pub fn memory_mapped(self) -> Result<IpcReader<Cursor<memmap::Mmap>>> {
+        // Safety: the file must not be modified while it is mapped
+        let mmap = unsafe { memmap::Mmap::map(&self.reader)? };
+        Ok(IpcReader {
+            reader: Cursor::new(mmap),
+            rechunk: self.rechunk,
+            stop_after_n_rows: self.stop_after_n_rows,
+            projection: self.projection,
+            columns: self.columns,
+        })
+    }
+}
+
+fn sorted(mut projection: Vec<usize>) -> Vec<usize> {
+    projection.sort_unstable();
+    projection
 }
 
 impl<R> ArrowReader for read::FileReader<R>
@@ -83,6 +207,9 @@ where
         IpcReader {
             reader,
             rechunk: true,
+            stop_after_n_rows: None,
+            projection: None,
+            columns: None,
         }
     }
     fn set_rechunk(mut self, rechunk: bool) -> Self {
@@ -90,11 +217,8 @@ where
         self
     }
 
-    fn finish(mut self) -> Result<DataFrame> {
-        let rechunk = self.rechunk;
-        let metadata = read::read_file_metadata(&mut self.reader)?;
-        let ipc_reader = read::FileReader::new(&mut self.reader, metadata, None);
-        finish_reader(ipc_reader, rechunk, None, None, None)
+    fn finish(self) -> Result<DataFrame> {
+        self.finish_impl(None, None)
     }
 }
 
@@ -118,6 +242,29 @@ where
 /// ```
 pub struct IpcWriter<W> {
     writer: W,
+    compression: Option<IpcCompression>,
+}
+
+/// The codec used to compress the buffers of the record batches.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum IpcCompression {
+    LZ4,
+    ZSTD,
+}
+
+/// Compressed files and streams can be read, but the arrow version polars builds on cannot
+/// write them.
+pub(crate) fn check_compression(compression: Option<IpcCompression>) -> Result<()> {
+    match compression {
+        None => Ok(()),
+        Some(compression) => Err(PolarsError::InvalidOperation(
+            format!(
+                "writing {:?} compressed IPC is not supported, compressed IPC can only be read",
+                compression
+            )
+            .into(),
+        )),
+    }
 }
 
 impl<W> SerWriter<W> for IpcWriter<W>
@@ -125,10 +272,14 @@ where
     W: Write,
 {
     fn new(writer: W) -> Self {
-        IpcWriter { writer }
+        IpcWriter {
+            writer,
+            compression: None,
+        }
     }
 
     fn finish(mut self, df: &DataFrame) -> Result<()> {
+        check_compression(self.compression)?;
         let mut ipc_writer = write::FileWriter::try_new(&mut self.writer, &df.schema().to_arrow())?;
 
         let iter = df.iter_record_batches();
@@ -141,9 +292,75 @@ where
     }
 }
 
+impl<W: Write> IpcWriter<W> {
+    /// Set the compression of the record batches. Defaults to no compression. Writing
+    /// compressed IPC is not supported yet, so any compression makes the writer return an error.
+    pub fn with_compression(mut self, compression: Option<IpcCompression>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Write DataFrames with the given schema one after the other, instead of a single DataFrame.
+    pub fn batched(self, schema: &Schema) -> Result<BatchedIpcWriter<W>> {
+        check_compression(self.compression)?;
+        let writer = write::FileWriter::try_new(self.writer, &schema.to_arrow())?;
+        Ok(BatchedIpcWriter {
+            writer: IpcFormatWriter::File(writer),
+            schema: schema.clone(),
+        })
+    }
+}
+
+pub(crate) enum IpcFormatWriter<W: Write> {
+    File(write::FileWriter<W>),
+    Stream(write::StreamWriter<W>),
+}
+
+/// Writes DataFrames with the same schema incrementally to the IPC file or stream format.
+///
+/// Created by [`IpcWriter::batched`] or
+/// [`IpcStreamWriter::batched`](crate::ipc_stream::IpcStreamWriter::batched).
+pub struct BatchedIpcWriter<W: Write> {
+    pub(crate) writer: IpcFormatWriter<W>,
+    pub(crate) schema: Schema,
+}
+
+impl<W: Write> BatchedIpcWriter<W> {
+    /// Write the rows of `df`. The schema must be the schema the writer was created with.
+    pub fn write_batch(&mut self, df: &DataFrame) -> Result<()> {
+        if df.schema() != self.schema {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "cannot write a batch with schema {:?} to an ipc writer with schema {:?}",
+                    df.schema(),
+                    self.schema
+                )
+                .into(),
+            ));
+        }
+        for batch in df.iter_record_batches() {
+            match &mut self.writer {
+                IpcFormatWriter::File(writer) => writer.write(&batch)?,
+                IpcFormatWriter::Stream(writer) => writer.write(&batch)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the footer of the file, or the end of stream marker.
+    pub fn finish(self) -> Result<()> {
+        match self.writer {
+            IpcFormatWriter::File(mut writer) => writer.finish()?,
+            IpcFormatWriter::Stream(mut writer) => writer.finish()?,
+        };
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
+    use polars_core::prelude::*;
     use std::io::Cursor;
 
     #[test]
@@ -160,4 +377,159 @@ mod test {
         let df_read = IpcReader::new(buf).finish().unwrap();
         assert!(df.frame_equal(&df_read));
     }
+
+    #[test]
+    fn test_read_ipc_with_projection() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let df = create_df();
+        IpcWriter::new(&mut buf).finish(&df)?;
+
+        buf.set_position(0);
+        let df_read = IpcReader::new(buf.clone())
+            .with_columns(Some(vec!["temp".to_string()]))
+            .with_stop_after_n_rows(Some(3))
+            .finish()?;
+        assert_eq!(df_read.shape(), (3, 1));
+        assert!(df_read
+            .column("temp")?
+            .series_equal(&df.column("temp")?.head(Some(3))));
+
+        let df_read = IpcReader::new(buf)
+            .with_projection(Some(vec![1, 0]))
+            .finish()?;
+        assert_eq!(df_read.get_column_names(), &["temp", "days"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_ipc_memory_mapped() -> Result<()> {
+        let path = std::env::temp_dir().join("polars_test_read_ipc_memory_mapped.ipc");
+        let df = create_df();
+        IpcWriter::new(std::fs::File::create(&path)?).finish(&df)?;
+
+        let df_read = IpcReader::new(std::fs::File::open(&path)?)
+            .memory_mapped()?
+            .finish()?;
+        assert!(df.frame_equal(&df_read));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-duration")]
+    fn write_and_read_ipc_duration() -> Result<()> {
+        use polars_core::df;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df![
+            "duration" => [Some(86_400_000i64), Some(-1500), None]
+        ]?;
+        let dtype = DataType::Duration(TimeUnit::Microseconds);
+        df.may_apply("duration", |s| s.cast(&dtype))?;
+
+        IpcWriter::new(&mut buf).finish(&df)?;
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish()?;
+        assert_eq!(df_read.column("duration")?.dtype(), &dtype);
+        assert!(df.frame_equal_missing(&df_read));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn write_and_read_ipc_time_zone() -> Result<()> {
+        use polars_core::df;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let dtype = DataType::Datetime(TimeUnit::Milliseconds, Some("Europe/Amsterdam".into()));
+        let mut df = df![
+            "datetime" => [Some(1616891400000i64), None, Some(1625140800000)]
+        ]?;
+        df.may_apply("datetime", |s| s.cast(&dtype))?;
+
+        IpcWriter::new(&mut buf).finish(&df)?;
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish()?;
+        assert_eq!(df_read.column("datetime")?.dtype(), &dtype);
+        assert!(df.frame_equal_missing(&df_read));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn write_and_read_ipc_struct() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let a = Series::new("a", &[Some(1i64), None, Some(3)]);
+        let b = Series::new("b", &[Some("foo"), Some("bar"), None]);
+        let inner = StructChunked::new("inner", &[b])?.into_series();
+        let validity = BooleanChunked::new_from_slice("", &[true, true, false]);
+        let s = StructChunked::new("struct", &[a, inner])?
+            .with_validity(Some(validity))?
+            .into_series();
+        let df = DataFrame::new(vec![s])?;
+
+        IpcWriter::new(&mut buf).finish(&df)?;
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish()?;
+        let s_read = df_read.column("struct")?;
+        assert_eq!(s_read.dtype(), df.column("struct")?.dtype());
+        assert_eq!(s_read.null_count(), 1);
+        assert_eq!(s_read.get(2), AnyValue::Null);
+        assert!(df.frame_equal_missing(&df_read));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-binary")]
+    fn write_and_read_ipc_binary() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let ca = BinaryChunked::new_from_opt_slice(
+            "bytes",
+            &[Some(&b"foo"[..]), None, Some(&b"\x00\xff"[..])],
+        );
+        let df = DataFrame::new(vec![ca.into_series()])?;
+
+        IpcWriter::new(&mut buf).finish(&df)?;
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish()?;
+        assert_eq!(df_read.column("bytes")?.dtype(), &DataType::Binary);
+        assert!(df.frame_equal_missing(&df_read));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn write_and_read_ipc_decimal() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let ca = DecimalChunked::new_from_opt_slice("price", &[Some(1999), None, Some(-5)], 10, 2)?;
+        let df = DataFrame::new(vec![ca.into_series()])?;
+
+        IpcWriter::new(&mut buf).finish(&df)?;
+        buf.set_position(0);
+
+        let df_read = IpcReader::new(buf).finish()?;
+        assert_eq!(df_read.column("price")?.dtype(), &DataType::Decimal(10, 2));
+        assert!(df.frame_equal_missing(&df_read));
+        Ok(())
+    }
+
+    #[test]
+    fn test_batched_ipc_writer() -> Result<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let df = create_df();
+
+        let mut writer = IpcWriter::new(&mut buf).batched(&df.schema())?;
+        writer.write_batch(&df.slice(0, 2))?;
+        writer.write_batch(&df.slice(2, 3))?;
+        assert!(writer.write_batch(&df.select("days")?).is_err());
+        writer.finish()?;
+
+        buf.set_position(0);
+        let df_read = IpcReader::new(buf).finish()?;
+        assert!(df.frame_equal(&df_read));
+        Ok(())
+    }
 }
@@ -0,0 +1,282 @@
+//! # (De)serializing Arrows Streaming IPC format.
+//!
+//! The streaming format is used to send DataFrames over pipes and sockets. Unlike the
+//! [file format](crate::ipc) it has no footer, so it can be read without seeking and written
+//! without knowing the number of record batches up front.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use std::io::Cursor;
+//!
+//!
+//! let s0 = Series::new("days", &[0, 1, 2, 3, 4]);
+//! let s1 = Series::new("temp", &[22.1, 19.9, 7., 2., 3.]);
+//! let df = DataFrame::new(vec![s0, s1]).unwrap();
+//!
+//! let mut buf: Vec<u8> = Vec::new();
+//! IpcStreamWriter::new(&mut buf).finish(&df).expect("ipc writer");
+//!
+//! let df_read = IpcStreamReader::new(Cursor::new(buf)).finish().unwrap();
+//! assert!(df.frame_equal(&df_read));
+//! ```
+use super::{finish_reader, ArrowReader, ArrowResult, RecordBatch};
+use crate::ipc::{check_compression, BatchedIpcWriter, IpcCompression, IpcFormatWriter};
+use crate::prelude::*;
+use arrow::io::ipc::{read, write};
+use polars_core::prelude::*;
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+/// Read Arrows Streaming IPC format into a DataFrame
+///
+/// The stream is read until the end-of-stream marker or, as the format allows, until the end of
+/// the input.
+///
+/// # Example
+/// ```
+/// use polars_core::prelude::*;
+/// use std::net::TcpStream;
+/// use polars_io::ipc_stream::IpcStreamReader;
+/// use polars_io::SerReader;
+///
+/// fn example() -> Result<DataFrame> {
+///     let stream = TcpStream::connect("127.0.0.1:8080").expect("could not connect");
+///
+///     IpcStreamReader::new(stream)
+///         .finish()
+/// }
+/// ```
+pub struct IpcStreamReader<R> {
+    /// File or Stream object
+    reader: R,
+    /// Aggregates chunks afterwards to a single chunk.
+    rechunk: bool,
+    stop_after_n_rows: Option<usize>,
+    projection: Option<Vec<usize>>,
+    columns: Option<Vec<String>>,
+}
+
+impl<R: Read> IpcStreamReader<R> {
+    /// Get the schema of the stream. Only the schema message at the start of the stream is read.
+    pub fn schema(&mut self) -> Result<Schema> {
+        let metadata = read::read_stream_metadata(&mut self.reader)?;
+        Ok((&*metadata.schema).into())
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_stop_after_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.stop_after_n_rows = num_rows;
+        self
+    }
+
+    /// Only keep the columns with these names. Takes precedence over `with_projection`.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Only keep the columns at these indexes (counting from 0).
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+}
+
+impl<R> ArrowReader for read::StreamReader<R>
+where
+    R: Read,
+{
+    fn next_record_batch(&mut self) -> ArrowResult<Option<RecordBatch>> {
+        match self.next() {
+            Some(Ok(read::StreamState::Some(batch))) => Ok(Some(batch)),
+            // the reader hit the end of the input before the end-of-stream marker, e.g. because
+            // the pipe was closed. The stream ends after the last complete message
+            Some(Ok(read::StreamState::Waiting)) | None => Ok(None),
+            Some(Err(e)) => Err(e),
+        }
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        Arc::new((&**self.schema()).into())
+    }
+}
+
+impl<R> SerReader<R> for IpcStreamReader<R>
+where
+    R: Read,
+{
+    fn new(reader: R) -> Self {
+        IpcStreamReader {
+            reader,
+            rechunk: true,
+            stop_after_n_rows: None,
+            projection: None,
+            columns: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> Result<DataFrame> {
+        let metadata = read::read_stream_metadata(&mut self.reader)?;
+        let schema: Schema = (&*metadata.schema).into();
+
+        // the stream messages always contain all columns, so we project after reading
+        let columns = match (self.columns, self.projection) {
+            (Some(columns), _) => Some(columns),
+            (None, Some(mut prj)) => {
+                // the columns are returned in the order of the stream, like the file reader
+                prj.sort_unstable();
+                let columns = prj
+                    .iter()
+                    .map(|&i| {
+                        schema.field(i).map(|fld| fld.name().clone()).ok_or_else(|| {
+                            PolarsError::OutOfBounds(
+                                format!(
+                                    "projection index {} is out of bounds for a schema with {} columns",
+                                    i,
+                                    schema.len()
+                                )
+                                .into(),
+                            )
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Some(columns)
+            }
+            (None, None) => None,
+        };
+
+        let ipc_reader = read::StreamReader::new(&mut self.reader, metadata);
+        let mut df = finish_reader(ipc_reader, self.rechunk, self.stop_after_n_rows, None, None)?;
+        if let Some(columns) = columns {
+            df = df.select(&columns)?;
+        }
+        match self.stop_after_n_rows {
+            Some(n) if n < df.height() => Ok(df.slice(0, n)),
+            _ => Ok(df),
+        }
+    }
+}
+
+/// Write a DataFrame to Arrow's Streaming IPC format
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::ipc_stream::IpcStreamWriter;
+/// use std::io::stdout;
+/// use polars_io::SerWriter;
+///
+/// fn example(df: &DataFrame) -> Result<()> {
+///     IpcStreamWriter::new(stdout())
+///         .finish(df)
+/// }
+/// ```
+pub struct IpcStreamWriter<W> {
+    writer: W,
+    compression: Option<IpcCompression>,
+}
+
+impl<W: Write> IpcStreamWriter<W> {
+    /// Set the compression of the record batches. Defaults to no compression. Writing
+    /// compressed IPC is not supported yet, so any compression makes the writer return an error.
+    pub fn with_compression(mut self, compression: Option<IpcCompression>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Write DataFrames with the given schema one after the other, e.g. as they arrive.
+    pub fn batched(self, schema: &Schema) -> Result<BatchedIpcWriter<W>> {
+        check_compression(self.compression)?;
+        let writer = write::StreamWriter::try_new(self.writer, &schema.to_arrow())?;
+        Ok(BatchedIpcWriter {
+            writer: IpcFormatWriter::Stream(writer),
+            schema: schema.clone(),
+        })
+    }
+}
+
+impl<W> SerWriter<W> for IpcStreamWriter<W>
+where
+    W: Write,
+{
+    fn new(writer: W) -> Self {
+        IpcStreamWriter {
+            writer,
+            compression: None,
+        }
+    }
+
+    fn finish(self, df: &DataFrame) -> Result<()> {
+        let mut writer = self.batched(&df.schema())?;
+        writer.write_batch(df)?;
+        writer.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use polars_core::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn write_and_read_ipc_stream() -> Result<()> {
+        let mut buf: Vec<u8> = Vec::new();
+        let df = create_df();
+
+        let mut writer = IpcStreamWriter::new(&mut buf).batched(&df.schema())?;
+        writer.write_batch(&df.slice(0, 3))?;
+        writer.write_batch(&df.slice(3, 2))?;
+        writer.finish()?;
+
+        let df_read = IpcStreamReader::new(Cursor::new(&buf)).finish()?;
+        assert!(df.frame_equal(&df_read));
+
+        let df_read = IpcStreamReader::new(Cursor::new(&buf))
+            .with_projection(Some(vec![1]))
+            .with_stop_after_n_rows(Some(4))
+            .finish()?;
+        assert_eq!(df_read.get_column_names(), &["temp"]);
+        assert_eq!(df_read.height(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn write_compressed_ipc_stream() {
+        let df = create_df();
+        for compression in [IpcCompression::LZ4, IpcCompression::ZSTD] {
+            let mut buf: Vec<u8> = Vec::new();
+            assert!(IpcStreamWriter::new(&mut buf)
+                .with_compression(Some(compression))
+                .finish(&df)
+                .is_err());
+            assert!(IpcWriter::new(&mut buf)
+                .with_compression(Some(compression))
+                .finish(&df)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn read_ipc_stream_without_eos_marker() -> Result<()> {
+        let mut buf: Vec<u8> = Vec::new();
+        let df = create_df();
+        IpcStreamWriter::new(&mut buf).finish(&df)?;
+        // the end-of-stream marker is a continuation token followed by a zero length
+        assert_eq!(&buf[buf.len() - 8..], &[255, 255, 255, 255, 0, 0, 0, 0]);
+        buf.truncate(buf.len() - 8);
+
+        let df_read = IpcStreamReader::new(Cursor::new(&buf)).finish()?;
+        assert!(df.frame_equal(&df_read));
+        Ok(())
+    }
+}
@@ -60,6 +60,7 @@
 //! +-----+--------+-------+--------+
 //! ```
 //!
+use crate::ndjson_core::write as json_write;
 use crate::prelude::*;
 use crate::{finish_reader, ArrowReader};
 pub use arrow::{
@@ -72,10 +73,60 @@ use std::io::Write;
 use std::io::{Read, Seek};
 use std::sync::Arc;
 
-// Write a DataFrame to JSON
+/// The layout of the JSON a DataFrame is written to.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum JsonFormat {
+    /// A single JSON array with an object per row, e.g. `[{"a":1,"b":"x"},{"a":2,"b":"y"}]`.
+    Json,
+    /// Newline delimited JSON, an object per row on its own line.
+    JsonLines,
+    /// A single object that maps every column name to an array of its values, e.g.
+    /// `{"a":[1,2],"b":["x","y"]}`.
+    JsonColumns,
+}
+
+impl Default for JsonFormat {
+    fn default() -> Self {
+        JsonFormat::JsonLines
+    }
+}
+
+/// Write a DataFrame to JSON
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+/// use std::fs::File;
+///
+/// fn example(df: &DataFrame) -> Result<()> {
+///     let mut file = File::create("example.json").expect("could not create file");
+///
+///     JsonWriter::new(&mut file)
+///         .with_json_format(JsonFormat::Json)
+///         .finish(df)
+/// }
+/// ```
 pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
+    format: JsonFormat,
+    batch_size: usize,
+}
+
+impl<W: Write> JsonWriter<W> {
+    /// Set the layout of the output. Defaults to [`JsonFormat::JsonLines`].
+    pub fn with_json_format(mut self, format: JsonFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Set the number of rows every thread serializes at once. A batch has at least one row.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -83,17 +134,15 @@ where
     W: Write,
 {
     fn new(buffer: W) -> Self {
-        JsonWriter { buffer }
+        JsonWriter {
+            buffer,
+            format: JsonFormat::default(),
+            batch_size: 1024,
+        }
     }
 
-    fn finish(self, df: &DataFrame) -> Result<()> {
-        let mut json_writer = arrow::io::json::LineDelimitedWriter::new(self.buffer);
-
-        let batches = df.as_record_batches()?;
-        json_writer.write_batches(&batches)?;
-        json_writer.finish()?;
-
-        Ok(())
+    fn finish(mut self, df: &DataFrame) -> Result<()> {
+        json_write::write(&mut self.buffer, df, self.batch_size, self.format)
     }
 }
 
@@ -206,4 +255,103 @@ mod test {
         assert_eq!("d", df.get_columns()[3].name());
         assert_eq!((12, 4), df.shape());
     }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn read_json_struct() -> Result<()> {
+        use polars_core::prelude::*;
+
+        let nested_json = r#"{"a":1, "b":{"c":2.0, "d":"foo"}}
+{"a":2, "b":{"c":null, "d":"bar"}}
+{"a":3, "b":{"c":0.5, "d":null}}"#;
+        let df = JsonReader::new(Cursor::new(nested_json))
+            .infer_schema(None)
+            .finish()?;
+
+        assert_eq!(
+            df.column("b")?.dtype(),
+            &DataType::Struct(vec![
+                Field::new("c", DataType::Float64),
+                Field::new("d", DataType::Utf8)
+            ])
+        );
+        let b = df.column("b")?.struct_()?;
+        assert_eq!(
+            Vec::from(b.field_by_name("c")?.f64()?),
+            &[Some(2.0), None, Some(0.5)]
+        );
+        assert_eq!(
+            Vec::from(b.field_by_name("d")?.utf8()?),
+            &[Some("foo"), Some("bar"), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn write_and_read_json_struct() -> Result<()> {
+        use polars_core::prelude::*;
+
+        let c = Series::new("c", &[Some(2.0), None, Some(0.5)]);
+        let d = Series::new("d", &[Some("foo"), Some("bar"), None]);
+        let s = StructChunked::new("b", &[c, d])?.into_series();
+        let df = DataFrame::new(vec![Series::new("a", &[1i64, 2, 3]), s])?;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        JsonWriter::new(&mut buf).finish(&df)?;
+        buf.set_position(0);
+
+        let df_read = JsonReader::new(buf).infer_schema(None).finish()?;
+        assert_eq!(df_read.column("b")?.dtype(), df.column("b")?.dtype());
+        assert!(df.frame_equal_missing(&df_read));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-date")]
+    fn write_json_formats() -> Result<()> {
+        use polars_core::prelude::*;
+
+        let df = df![
+            "a" => [Some(1i64), None],
+            "b" => [Some("x\"y"), None],
+            "c" => [Some(1.5), Some(f64::NAN)],
+        ]?;
+        let list = Series::new(
+            "l",
+            &[Series::new("", &[1i64, 2]), Series::new("", &[3i64])],
+        );
+        let date = Series::new("d", &[0i32, 18628]).cast(&DataType::Date)?;
+        let df = df.hstack(&[list, date])?;
+
+        let write = |format, batch_size| -> Result<String> {
+            let mut buf = Vec::new();
+            JsonWriter::new(&mut buf)
+                .with_json_format(format)
+                .with_batch_size(batch_size)
+                .finish(&df)?;
+            Ok(String::from_utf8(buf).unwrap())
+        };
+
+        let row_0 = r#"{"a":1,"b":"x\"y","c":1.5,"l":[1,2],"d":"1970-01-01"}"#;
+        let row_1 = r#"{"a":null,"b":null,"c":null,"l":[3],"d":"2021-01-01"}"#;
+        assert_eq!(
+            write(JsonFormat::JsonLines, 1)?,
+            format!("{}\n{}\n", row_0, row_1)
+        );
+        assert_eq!(
+            write(JsonFormat::Json, 1)?,
+            format!("[{},{}]", row_0, row_1)
+        );
+        // a batch has at least one row
+        assert_eq!(
+            write(JsonFormat::Json, 0)?,
+            format!("[{},{}]", row_0, row_1)
+        );
+        assert_eq!(
+            write(JsonFormat::JsonColumns, 1)?,
+            r#"{"a":[1,null],"b":["x\"y",null],"c":[1.5,null],"l":[[1,2],[3]],"d":["1970-01-01","2021-01-01"]}"#
+        );
+        Ok(())
+    }
 }
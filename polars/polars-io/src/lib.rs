@@ -1,5 +1,8 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "avro")]
+#[cfg_attr(docsrs, doc(cfg(feature = "avro")))]
+pub mod avro;
 #[cfg(feature = "csv-file")]
 #[cfg_attr(docsrs, doc(cfg(feature = "csv-file")))]
 pub mod csv;
@@ -9,14 +12,23 @@ pub mod csv_core;
 #[cfg(feature = "ipc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
 pub mod ipc;
+#[cfg(feature = "ipc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
+pub mod ipc_stream;
 #[cfg(feature = "json")]
 #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
 pub mod json;
-#[cfg(feature = "csv-file")]
+#[cfg(any(feature = "csv-file", feature = "json"))]
 pub mod mmap;
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+pub mod ndjson_core;
 #[cfg(feature = "parquet")]
 #[cfg_attr(docsrs, doc(cfg(feature = "feature")))]
 pub mod parquet;
+#[cfg(feature = "parquet")]
+#[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
+pub mod predicates;
 pub mod prelude;
 #[cfg(all(test, feature = "csv-file"))]
 mod tests;
@@ -25,16 +37,23 @@ pub(crate) mod utils;
 use arrow::{error::Result as ArrowResult, record_batch::RecordBatch};
 
 use polars_core::prelude::*;
-use std::io::{Read, Seek, Write};
+use std::io::{Read, Write};
 use std::sync::Arc;
 
 pub trait PhysicalIoExpr: Send + Sync {
     fn evaluate(&self, df: &DataFrame) -> Result<Series>;
+
+    /// Get an evaluator of the predicate on batch statistics, if the predicate supports it.
+    /// Readers use it to skip batches of rows, e.g. parquet row groups, without decoding them.
+    #[cfg(feature = "parquet")]
+    fn as_stats_evaluator(&self) -> Option<&dyn predicates::StatsEvaluator> {
+        None
+    }
 }
 
 pub trait SerReader<R>
 where
-    R: Read + Seek,
+    R: Read,
 {
     fn new(reader: R) -> Self;
 
@@ -64,7 +83,12 @@ pub trait ArrowReader {
     fn schema(&self) -> Arc<Schema>;
 }
 
-#[cfg(any(feature = "ipc", feature = "parquet", feature = "json"))]
+#[cfg(any(
+    feature = "ipc",
+    feature = "parquet",
+    feature = "json",
+    feature = "avro"
+))]
 pub(crate) fn finish_reader<R: ArrowReader>(
     mut reader: R,
     rechunk: bool,
@@ -108,7 +132,21 @@ pub(crate) fn finish_reader<R: ArrowReader>(
             }
         }
     }
-    let mut df = accumulate_dataframes_vertical(parsed_dfs)?;
+    let mut df = if parsed_dfs.is_empty() {
+        // all batches were skipped, e.g. parquet row groups pruned by their statistics
+        let columns = reader
+            .schema()
+            .fields()
+            .iter()
+            .map(|fld| {
+                let arr = arrow::array::new_empty_array(fld.data_type().to_arrow());
+                Series::try_from((fld.name().as_str(), arr))
+            })
+            .collect::<Result<_>>()?;
+        DataFrame::new_no_checks(columns)
+    } else {
+        accumulate_dataframes_vertical(parsed_dfs)?
+    };
 
     if let Some(aggregate) = aggregate {
         let cols = aggregate
@@ -124,6 +162,59 @@ pub(crate) fn finish_reader<R: ArrowReader>(
     }
 }
 
+/// Iterator over the record batches of an [`ArrowReader`], converted to DataFrames. Only one
+/// batch is held in memory at a time.
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+pub struct BatchedReader<R> {
+    reader: R,
+    stop_after_n_rows: Option<usize>,
+    n_rows: usize,
+}
+
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+impl<R: ArrowReader> BatchedReader<R> {
+    pub(crate) fn new(reader: R, stop_after_n_rows: Option<usize>) -> Self {
+        BatchedReader {
+            reader,
+            stop_after_n_rows,
+            n_rows: 0,
+        }
+    }
+
+    /// The schema of the batches.
+    pub fn schema(&self) -> Arc<Schema> {
+        self.reader.schema()
+    }
+}
+
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+impl<R: ArrowReader> Iterator for BatchedReader<R> {
+    type Item = Result<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use std::convert::TryFrom;
+
+        let remaining = self
+            .stop_after_n_rows
+            .map(|n| n.saturating_sub(self.n_rows));
+        if remaining == Some(0) {
+            return None;
+        }
+        let batch = match self.reader.next_record_batch() {
+            Ok(batch) => batch?,
+            Err(e) => return Some(Err(e.into())),
+        };
+        let df = DataFrame::try_from(batch).map(|df| match remaining {
+            Some(n) if n < df.height() => df.slice(0, n),
+            _ => df,
+        });
+        if let Ok(df) = &df {
+            self.n_rows += df.height();
+        }
+        Some(df)
+    }
+}
+
 pub enum ScanAggregation {
     Sum {
         column: String,
@@ -149,7 +240,12 @@ pub enum ScanAggregation {
 
 impl ScanAggregation {
     /// Evaluate the aggregations per batch.
-    #[cfg(any(feature = "ipc", feature = "parquet", feature = "json"))]
+    #[cfg(any(
+        feature = "ipc",
+        feature = "parquet",
+        feature = "json",
+        feature = "avro"
+    ))]
     pub(crate) fn evaluate_batch(&self, df: &DataFrame) -> Result<Series> {
         use ScanAggregation::*;
         let s = match self {
@@ -163,7 +259,7 @@ impl ScanAggregation {
     }
 
     /// After all batches are concatenated the aggregation is determined for the whole set.
-    pub(crate) fn finish(&self, df: &DataFrame) -> Result<Series> {
+    pub fn finish(&self, df: &DataFrame) -> Result<Series> {
         use ScanAggregation::*;
         match self {
             Sum { column, alias } => {
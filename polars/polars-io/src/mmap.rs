@@ -1,3 +1,4 @@
+use polars_core::prelude::*;
 use std::fs::File;
 use std::io::{Cursor, Read, Seek};
 
@@ -55,3 +56,27 @@ impl std::ops::Deref for ReaderBytes<'_> {
         }
     }
 }
+
+pub fn get_reader_bytes<R: Read + MmapBytesReader>(reader: &mut R) -> Result<ReaderBytes<'_>> {
+    // we have a file so we can mmap
+    if let Some(file) = reader.to_file() {
+        let mmap = unsafe { memmap::Mmap::map(file)? };
+        Ok(ReaderBytes::Mapped(mmap))
+    } else {
+        // we can get the bytes for free
+        if reader.to_bytes().is_some() {
+            // duplicate .to_bytes() is necessary to satisfy the borrow checker
+            Ok(ReaderBytes::Borrowed(reader.to_bytes().unwrap()))
+        } else {
+            // we have to read to an owned buffer to get the bytes.
+            let mut bytes = Vec::with_capacity(1024 * 128);
+            reader.read_to_end(&mut bytes)?;
+            if !bytes.is_empty()
+                && (bytes[bytes.len() - 1] != b'\n' || bytes[bytes.len() - 1] != b'\r')
+            {
+                bytes.push(b'\n')
+            }
+            Ok(ReaderBytes::Owned(bytes))
+        }
+    }
+}
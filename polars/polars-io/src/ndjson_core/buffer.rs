@@ -0,0 +1,95 @@
+use polars_core::prelude::*;
+use serde_json::Value;
+
+/// Buffer for the values of a single column.
+pub(crate) enum Buffer {
+    Boolean(BooleanChunkedBuilder),
+    Int64(PrimitiveChunkedBuilder<Int64Type>),
+    Float64(PrimitiveChunkedBuilder<Float64Type>),
+    Utf8(Utf8ChunkedBuilder),
+}
+
+impl Buffer {
+    pub(crate) fn new(field: &Field, capacity: usize) -> Self {
+        let name = field.name().as_str();
+        use DataType::*;
+        match field.data_type() {
+            Boolean => Buffer::Boolean(BooleanChunkedBuilder::new(name, capacity)),
+            Float32 | Float64 => Buffer::Float64(PrimitiveChunkedBuilder::new(name, capacity)),
+            // integers and the temporal types are read as i64 and cast afterwards
+            UInt8
+            | UInt16
+            | UInt32
+            | UInt64
+            | Int8
+            | Int16
+            | Int32
+            | Int64
+            | Date
+            | Datetime(_, _)
+            | Duration(_)
+            | Time => Buffer::Int64(PrimitiveChunkedBuilder::new(name, capacity)),
+            // everything else is read as a string, nested values as their JSON representation
+            _ => Buffer::Utf8(Utf8ChunkedBuilder::new(name, capacity, capacity * 10)),
+        }
+    }
+
+    /// Add a value. A value of the wrong JSON type is an error, or null if `ignore_errors` is set.
+    #[inline]
+    pub(crate) fn add(&mut self, value: Option<&Value>, ignore_errors: bool) -> Result<()> {
+        let value = value.filter(|v| !v.is_null());
+        match self {
+            Buffer::Boolean(builder) => {
+                builder.append_option(cast_value(value, Value::as_bool, ignore_errors, "boolean")?)
+            }
+            Buffer::Int64(builder) => {
+                builder.append_option(cast_value(value, Value::as_i64, ignore_errors, "integer")?)
+            }
+            Buffer::Float64(builder) => {
+                builder.append_option(cast_value(value, Value::as_f64, ignore_errors, "number")?)
+            }
+            Buffer::Utf8(builder) => match value {
+                None => builder.append_null(),
+                Some(Value::String(s)) => builder.append_value(s),
+                Some(v) => builder.append_value(v.to_string()),
+            },
+        }
+        Ok(())
+    }
+
+    pub(crate) fn into_series(self, dtype: &DataType) -> Result<Series> {
+        let s = match self {
+            Buffer::Boolean(builder) => builder.finish().into_series(),
+            Buffer::Int64(builder) => builder.finish().into_series(),
+            Buffer::Float64(builder) => builder.finish().into_series(),
+            Buffer::Utf8(builder) => builder.finish().into_series(),
+        };
+        if s.dtype() == dtype {
+            Ok(s)
+        } else {
+            s.cast(dtype)
+        }
+    }
+}
+
+fn cast_value<T>(
+    value: Option<&Value>,
+    cast: impl Fn(&Value) -> Option<T>,
+    ignore_errors: bool,
+    expected: &str,
+) -> Result<Option<T>> {
+    match value {
+        None => Ok(None),
+        Some(v) => match cast(v) {
+            Some(v) => Ok(Some(v)),
+            None if ignore_errors => Ok(None),
+            None => Err(PolarsError::ComputeError(
+                format!(
+                    "could not read the ndjson value {} as {}, set 'ignore_errors' to read it as null",
+                    v, expected
+                )
+                .into(),
+            )),
+        },
+    }
+}
@@ -0,0 +1,4 @@
+//! Multithreaded parsing and serialization of (newline delimited) JSON.
+pub(crate) mod buffer;
+pub mod ndjson;
+pub(crate) mod write;
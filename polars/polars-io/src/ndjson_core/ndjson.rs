@@ -0,0 +1,543 @@
+use crate::mmap::{get_reader_bytes, MmapBytesReader, ReaderBytes};
+use crate::ndjson_core::buffer::Buffer;
+use crate::prelude::*;
+use crate::{PhysicalIoExpr, ScanAggregation};
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_core::{prelude::*, POOL};
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// Read newline delimited JSON into a DataFrame. Every line must contain a single JSON object.
+///
+/// The file is split into chunks at line boundaries and the chunks are parsed in parallel.
+///
+/// # Example
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+/// use std::fs::File;
+///
+/// fn example() -> Result<DataFrame> {
+///     let file = File::open("logs.ndjson")?;
+///
+///     JsonLineReader::new(file)
+///         .infer_schema_len(Some(100))
+///         .with_stop_after_n_rows(Some(1000))
+///         .finish()
+/// }
+/// ```
+pub struct JsonLineReader<'a, R>
+where
+    R: MmapBytesReader,
+{
+    reader: R,
+    rechunk: bool,
+    stop_after_n_rows: Option<usize>,
+    infer_schema_len: Option<usize>,
+    schema: Option<&'a Schema>,
+    projection: Option<Vec<usize>>,
+    columns: Option<Vec<String>>,
+    n_threads: Option<usize>,
+    ignore_errors: bool,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    aggregate: Option<&'a [ScanAggregation]>,
+}
+
+impl<'a, R> JsonLineReader<'a, R>
+where
+    R: 'a + MmapBytesReader,
+{
+    /// Stop reading when `n` rows are read.
+    pub fn with_stop_after_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.stop_after_n_rows = num_rows;
+        self
+    }
+
+    /// Set the number of lines used to infer the schema. If `None`, all lines are used.
+    pub fn infer_schema_len(mut self, len: Option<usize>) -> Self {
+        self.infer_schema_len = len;
+        self
+    }
+
+    /// Set the schema of the file. This skips schema inference.
+    pub fn with_schema(mut self, schema: &'a Schema) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Only read the columns at these indexes (counting from 0).
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Only read the columns with these names. Takes precedence over `with_projection`.
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the number of threads used for parsing. Defaults to the size of the global thread pool.
+    pub fn with_n_threads(mut self, n: Option<usize>) -> Self {
+        self.n_threads = n;
+        self
+    }
+
+    /// Read values that do not match the type of their column as null instead of returning an
+    /// error.
+    pub fn with_ignore_errors(mut self, ignore: bool) -> Self {
+        self.ignore_errors = ignore;
+        self
+    }
+
+    #[cfg(feature = "private")]
+    pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.predicate = predicate;
+        self
+    }
+
+    pub fn with_aggregate(mut self, aggregate: Option<&'a [ScanAggregation]>) -> Self {
+        self.aggregate = aggregate;
+        self
+    }
+}
+
+impl<'a, R> SerReader<R> for JsonLineReader<'a, R>
+where
+    R: MmapBytesReader,
+{
+    fn new(reader: R) -> Self {
+        JsonLineReader {
+            reader,
+            rechunk: true,
+            stop_after_n_rows: None,
+            infer_schema_len: Some(100),
+            schema: None,
+            projection: None,
+            columns: None,
+            n_threads: None,
+            ignore_errors: false,
+            predicate: None,
+            aggregate: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> Result<DataFrame> {
+        let rechunk = self.rechunk;
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let mut json_reader = CoreJsonReader::new(
+            reader_bytes,
+            self.stop_after_n_rows,
+            self.schema,
+            self.infer_schema_len,
+            self.projection,
+            self.columns,
+            self.n_threads,
+            self.ignore_errors,
+            self.predicate,
+            self.aggregate,
+        )?;
+        let mut df = json_reader.as_df()?;
+        if rechunk && df.n_chunks()? > 1 {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+pub(crate) struct CoreJsonReader<'a> {
+    reader_bytes: Option<ReaderBytes<'a>>,
+    schema: Cow<'a, Schema>,
+    projection: Option<Vec<usize>>,
+    n_rows: Option<usize>,
+    n_threads: Option<usize>,
+    ignore_errors: bool,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    aggregate: Option<&'a [ScanAggregation]>,
+}
+
+impl<'a> CoreJsonReader<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        reader_bytes: ReaderBytes<'a>,
+        n_rows: Option<usize>,
+        schema: Option<&'a Schema>,
+        infer_schema_len: Option<usize>,
+        mut projection: Option<Vec<usize>>,
+        columns: Option<Vec<String>>,
+        n_threads: Option<usize>,
+        ignore_errors: bool,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        aggregate: Option<&'a [ScanAggregation]>,
+    ) -> Result<CoreJsonReader<'a>> {
+        let schema = match schema {
+            Some(schema) => Cow::Borrowed(schema),
+            None => Cow::Owned(infer_schema(&reader_bytes, infer_schema_len)?),
+        };
+        if let Some(cols) = columns {
+            let prj = cols
+                .iter()
+                .map(|name| schema.index_of(name))
+                .collect::<Result<Vec<_>>>()?;
+            projection = Some(prj);
+        }
+
+        Ok(CoreJsonReader {
+            reader_bytes: Some(reader_bytes),
+            schema,
+            projection,
+            n_rows,
+            n_threads,
+            ignore_errors,
+            predicate,
+            aggregate,
+        })
+    }
+
+    fn parse_json(
+        &mut self,
+        n_threads: usize,
+        bytes: &[u8],
+        predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+    ) -> Result<DataFrame> {
+        let bytes = match self.n_rows {
+            Some(n) => take_lines(bytes, n),
+            None => bytes,
+        };
+
+        // the columns are read in the order of the schema
+        let mut projection = self
+            .projection
+            .take()
+            .unwrap_or_else(|| (0..self.schema.len()).collect());
+        projection.sort_unstable();
+        let fields = projection
+            .iter()
+            .map(|i| {
+                self.schema.field(*i).ok_or_else(|| {
+                    PolarsError::ValueError(
+                        format!(
+                            "the given projection index: {} is out of bounds for json schema with {} columns",
+                            i,
+                            self.schema.len()
+                        )
+                        .into(),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let file_chunks = get_file_chunks(bytes, n_threads);
+        let ignore_errors = self.ignore_errors;
+
+        // If the number of threads given by the user is lower than our global thread pool we create
+        // new one.
+        let owned_pool;
+        let pool = if POOL.current_num_threads() != n_threads {
+            owned_pool = Some(
+                ThreadPoolBuilder::new()
+                    .num_threads(n_threads)
+                    .build()
+                    .unwrap(),
+            );
+            owned_pool.as_ref().unwrap()
+        } else {
+            &POOL
+        };
+
+        let dfs = pool.install(|| {
+            file_chunks
+                .into_par_iter()
+                .map(|(start, stop)| {
+                    let mut df = parse_lines(&bytes[start..stop], &fields, ignore_errors)?;
+                    if let Some(predicate) = predicate {
+                        let s = predicate.evaluate(&df)?;
+                        let mask = s.bool().expect("filter predicates was not of type boolean");
+                        df = df.filter(mask)?;
+                    }
+                    Ok(df)
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+        accumulate_dataframes_vertical(dfs)
+    }
+
+    /// Read the json into a DataFrame. The predicate can come from a lazy physical plan.
+    pub(crate) fn as_df(&mut self) -> Result<DataFrame> {
+        let predicate = self.predicate.take();
+        let aggregate = self.aggregate.take();
+        let n_threads = self
+            .n_threads
+            .unwrap_or_else(|| POOL.current_num_threads())
+            .max(1);
+
+        let reader_bytes = self.reader_bytes.take().unwrap();
+
+        let mut df = self.parse_json(n_threads, &reader_bytes, predicate.as_ref())?;
+
+        if let Some(aggregate) = aggregate {
+            let cols = aggregate
+                .iter()
+                .map(|scan_agg| scan_agg.finish(&df))
+                .collect::<Result<Vec<_>>>()?;
+            df = DataFrame::new_no_checks(cols)
+        }
+        Ok(df)
+    }
+}
+
+#[inline]
+fn is_blank(line: &[u8]) -> bool {
+    line.iter().all(u8::is_ascii_whitespace)
+}
+
+/// Iterate over the non-empty lines.
+fn lines(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
+    bytes.split(|b| *b == b'\n').filter(|line| !is_blank(line))
+}
+
+fn parse_line(line: &[u8]) -> Result<Map<String, Value>> {
+    match serde_json::from_slice(line) {
+        Ok(Value::Object(map)) => Ok(map),
+        Ok(_) => Err(PolarsError::ComputeError(
+            "expected a JSON object on every line of the ndjson file".into(),
+        )),
+        Err(e) => Err(PolarsError::ComputeError(
+            format!("could not parse ndjson line: {}", e).into(),
+        )),
+    }
+}
+
+/// Slice the bytes after the `n`th non-empty line.
+fn take_lines(bytes: &[u8], n: usize) -> &[u8] {
+    if n == 0 {
+        return &bytes[..0];
+    }
+    let mut start = 0;
+    let mut n_lines = 0;
+    for end in memchr::memchr_iter(b'\n', bytes) {
+        if !is_blank(&bytes[start..end]) {
+            n_lines += 1;
+            if n_lines == n {
+                return &bytes[..end + 1];
+            }
+        }
+        start = end + 1;
+    }
+    bytes
+}
+
+/// Split the bytes in (at most) `n_threads` ranges that end at a line boundary.
+fn get_file_chunks(bytes: &[u8], n_threads: usize) -> Vec<(usize, usize)> {
+    let n_threads = n_threads.max(1);
+    let mut last_pos = 0;
+    let total_len = bytes.len();
+    let chunk_size = total_len / n_threads;
+    let mut offsets = Vec::with_capacity(n_threads);
+    for _ in 0..n_threads {
+        let search_pos = last_pos + chunk_size;
+
+        if search_pos >= total_len {
+            break;
+        }
+
+        let end_pos = match memchr::memchr(b'\n', &bytes[search_pos..]) {
+            Some(pos) => search_pos + pos + 1,
+            None => {
+                break;
+            }
+        };
+        offsets.push((last_pos, end_pos));
+        last_pos = end_pos;
+    }
+    offsets.push((last_pos, total_len));
+    offsets
+}
+
+fn parse_lines(bytes: &[u8], fields: &[&Field], ignore_errors: bool) -> Result<DataFrame> {
+    let capacity = memchr::memchr_iter(b'\n', bytes).count() + 1;
+    let mut buffers = fields
+        .iter()
+        .map(|fld| Buffer::new(fld, capacity))
+        .collect::<Vec<_>>();
+
+    for line in lines(bytes) {
+        let map = parse_line(line)?;
+        for (buf, fld) in buffers.iter_mut().zip(fields) {
+            buf.add(map.get(fld.name().as_str()), ignore_errors)?;
+        }
+    }
+
+    let columns = buffers
+        .into_iter()
+        .zip(fields)
+        .map(|(buf, fld)| buf.into_series(fld.data_type()))
+        .collect::<Result<_>>()?;
+    Ok(DataFrame::new_no_checks(columns))
+}
+
+fn infer_dtype(value: &Value) -> DataType {
+    match value {
+        Value::Null => DataType::Null,
+        Value::Bool(_) => DataType::Boolean,
+        Value::Number(n) if n.is_i64() => DataType::Int64,
+        Value::Number(_) => DataType::Float64,
+        // nested values are read as their JSON representation
+        Value::String(_) | Value::Array(_) | Value::Object(_) => DataType::Utf8,
+    }
+}
+
+/// Determine the type of a column that has values of both `l` and `r`.
+fn coerce_dtype(l: &DataType, r: &DataType) -> DataType {
+    use DataType::*;
+    match (l, r) {
+        (l, r) if l == r => l.clone(),
+        (Null, dt) | (dt, Null) => dt.clone(),
+        (Int64, Float64) | (Float64, Int64) => Float64,
+        _ => Utf8,
+    }
+}
+
+/// Infer the schema of newline delimited JSON from the first `infer_schema_len` lines.
+/// If `infer_schema_len` is `None`, all lines are used.
+///
+/// The columns are ordered by first appearance. Columns with values of different types are
+/// read as `Utf8`, unless all values are numbers.
+pub fn infer_schema(bytes: &[u8], infer_schema_len: Option<usize>) -> Result<Schema> {
+    let mut names: Vec<String> = vec![];
+    let mut dtypes: PlHashMap<String, DataType> = PlHashMap::new();
+
+    for line in lines(bytes).take(infer_schema_len.unwrap_or(usize::MAX)) {
+        for (name, value) in parse_line(line)? {
+            let dtype = infer_dtype(&value);
+            match dtypes.get_mut(&name) {
+                Some(current) => *current = coerce_dtype(current, &dtype),
+                None => {
+                    names.push(name.clone());
+                    dtypes.insert(name, dtype);
+                }
+            }
+        }
+    }
+
+    let fields = names
+        .into_iter()
+        .map(|name| {
+            let dtype = match dtypes.remove(&name) {
+                // only nulls were seen
+                Some(DataType::Null) | None => DataType::Utf8,
+                Some(dtype) => dtype,
+            };
+            Field::new(&name, dtype)
+        })
+        .collect();
+    Ok(Schema::new(fields))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Cursor;
+
+    const LOGS: &str = r#"{"level":"info", "code":1, "latency":0.5, "ok":true}
+{"level":"warn", "code":2, "latency":1, "ok":false}
+
+{"level":"error", "code":3, "ok":null, "extra":{"a":1}}
+{"level":"info", "code":4, "latency":2.25, "ok":true}
+"#;
+
+    #[test]
+    fn test_infer_ndjson_schema() -> Result<()> {
+        let schema = infer_schema(LOGS.as_bytes(), None)?;
+        assert_eq!(
+            schema.fields(),
+            &[
+                Field::new("level", DataType::Utf8),
+                Field::new("code", DataType::Int64),
+                Field::new("latency", DataType::Float64),
+                Field::new("ok", DataType::Boolean),
+                Field::new("extra", DataType::Utf8),
+            ]
+        );
+
+        let schema = infer_schema(LOGS.as_bytes(), Some(1))?;
+        assert_eq!(schema.len(), 4);
+        assert_eq!(
+            schema.field_with_name("latency")?.data_type(),
+            &DataType::Float64
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_ndjson() -> Result<()> {
+        // every line ends up in its own chunk
+        let df = JsonLineReader::new(Cursor::new(LOGS))
+            .infer_schema_len(None)
+            .with_n_threads(Some(4))
+            .finish()?;
+        assert_eq!(df.shape(), (4, 5));
+        assert_eq!(
+            Vec::from(df.column("latency")?.f64()?),
+            &[Some(0.5), Some(1.0), None, Some(2.25)]
+        );
+        assert_eq!(
+            Vec::from(df.column("extra")?.utf8()?),
+            &[None, None, Some(r#"{"a":1}"#), None]
+        );
+
+        let df = JsonLineReader::new(Cursor::new(LOGS))
+            .with_columns(Some(vec!["ok".into(), "level".into()]))
+            .with_stop_after_n_rows(Some(3))
+            .finish()?;
+        assert_eq!(df.get_column_names(), &["level", "ok"]);
+        assert_eq!(
+            Vec::from(df.column("ok")?.bool()?),
+            &[Some(true), Some(false), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_ndjson_with_schema() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("code", DataType::Int32),
+            Field::new("level", DataType::Utf8),
+        ]);
+        let df = JsonLineReader::new(Cursor::new(LOGS))
+            .with_schema(&schema)
+            .finish()?;
+        assert_eq!(df.schema(), schema);
+        assert_eq!(df.column("code")?.sum::<i32>(), Some(10));
+
+        let invalid = "{\"a\":1}\n[1, 2]\n";
+        assert!(JsonLineReader::new(Cursor::new(invalid)).finish().is_err());
+
+        // "level" is not a number
+        let schema = Schema::new(vec![Field::new("level", DataType::Int64)]);
+        assert!(JsonLineReader::new(Cursor::new(LOGS))
+            .with_schema(&schema)
+            .finish()
+            .is_err());
+        let df = JsonLineReader::new(Cursor::new(LOGS))
+            .with_schema(&schema)
+            .with_ignore_errors(true)
+            .finish()?;
+        assert_eq!(df.column("level")?.null_count(), 4);
+
+        // no threads is read as a single thread
+        let df = JsonLineReader::new(Cursor::new(LOGS))
+            .with_n_threads(Some(0))
+            .finish()?;
+        assert_eq!(df.height(), 4);
+        Ok(())
+    }
+}
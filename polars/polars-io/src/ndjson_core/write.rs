@@ -0,0 +1,230 @@
+//! Serialize a DataFrame to JSON directly from the `Series`. Every column is serialized from its
+//! typed arrays, the rows are then assembled from the serialized values. Rows are serialized in
+//! parallel over chunks of rows, the column layout in parallel over the columns.
+use crate::json::JsonFormat;
+use crate::utils::{write_chunked, SerializedCells};
+#[cfg(feature = "dtype-date")]
+use arrow::temporal_conversions::date32_to_date;
+#[cfg(feature = "dtype-time")]
+use arrow::temporal_conversions::time64ns_to_time;
+#[cfg(feature = "dtype-datetime")]
+use arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
+use num::Float;
+use polars_core::{prelude::*, POOL};
+use rayon::prelude::*;
+use std::fmt::Debug;
+use std::io::Write;
+
+fn write_str(buf: &mut Vec<u8>, v: &str) {
+    // serde_json takes care of escaping quotes, backslashes and control characters
+    serde_json::to_writer(buf, v).unwrap()
+}
+
+fn write_float<T: Debug + Float>(buf: &mut Vec<u8>, v: T) {
+    // JSON has no representation of NaN and infinity. Whole numbers are written as e.g. `7.0`,
+    // so that they are read back as floats.
+    if v.is_finite() {
+        write!(buf, "{:?}", v).unwrap()
+    } else {
+        buf.extend_from_slice(b"null")
+    }
+}
+
+fn serialize_value(buf: &mut Vec<u8>, value: AnyValue) {
+    match value {
+        AnyValue::Null => buf.extend_from_slice(b"null"),
+        AnyValue::Boolean(v) => buf.extend_from_slice(if v { b"true" } else { b"false" }),
+        AnyValue::Utf8(v) => write_str(buf, v),
+        AnyValue::UInt8(v) => write!(buf, "{}", v).unwrap(),
+        AnyValue::UInt16(v) => write!(buf, "{}", v).unwrap(),
+        AnyValue::UInt32(v) => write!(buf, "{}", v).unwrap(),
+        AnyValue::UInt64(v) => write!(buf, "{}", v).unwrap(),
+        AnyValue::Int8(v) => write!(buf, "{}", v).unwrap(),
+        AnyValue::Int16(v) => write!(buf, "{}", v).unwrap(),
+        AnyValue::Int32(v) => write!(buf, "{}", v).unwrap(),
+        AnyValue::Int64(v) => write!(buf, "{}", v).unwrap(),
+        AnyValue::Float32(v) => write_float(buf, v),
+        AnyValue::Float64(v) => write_float(buf, v),
+        #[cfg(feature = "dtype-decimal")]
+        v @ AnyValue::Decimal(_, _) => write!(buf, "{}", v).unwrap(),
+        #[cfg(feature = "dtype-date")]
+        AnyValue::Date(v) => write_str(buf, &date32_to_date(v).format("%Y-%m-%d").to_string()),
+        #[cfg(feature = "dtype-datetime")]
+        AnyValue::Datetime(v, tu, tz) => {
+            // ISO 8601 with the precision of the time unit; time zone aware values are
+            // stored in UTC and written as such
+            let (ndt, fmt) = match tu {
+                TimeUnit::Nanoseconds => (timestamp_ns_to_datetime(v), "%Y-%m-%dT%H:%M:%S%.9f"),
+                TimeUnit::Microseconds => (timestamp_us_to_datetime(v), "%Y-%m-%dT%H:%M:%S%.6f"),
+                TimeUnit::Milliseconds => (timestamp_ms_to_datetime(v), "%Y-%m-%dT%H:%M:%S%.3f"),
+            };
+            let mut out = ndt.format(fmt).to_string();
+            if tz.is_some() {
+                out.push('Z');
+            }
+            write_str(buf, &out)
+        }
+        #[cfg(feature = "dtype-duration")]
+        AnyValue::Duration(v, _) => write!(buf, "{}", v).unwrap(),
+        #[cfg(feature = "dtype-time")]
+        AnyValue::Time(v) => write_str(buf, &time64ns_to_time(v).format("%T%.9f").to_string()),
+        #[cfg(feature = "dtype-categorical")]
+        AnyValue::Categorical(idx, rev) => write_str(buf, rev.get(idx)),
+        AnyValue::List(s) => {
+            buf.push(b'[');
+            for i in 0..s.len() {
+                if i > 0 {
+                    buf.push(b',');
+                }
+                serialize_value(buf, s.get(i));
+            }
+            buf.push(b']');
+        }
+        #[cfg(feature = "dtype-struct")]
+        AnyValue::Struct(values, fields) => {
+            buf.push(b'{');
+            for (i, (v, fld)) in values.into_iter().zip(fields).enumerate() {
+                if i > 0 {
+                    buf.push(b',');
+                }
+                write_str(buf, fld.name());
+                buf.push(b':');
+                serialize_value(buf, v);
+            }
+            buf.push(b'}');
+        }
+        #[allow(unreachable_patterns)]
+        v => write_str(buf, &v.to_string()),
+    }
+}
+
+fn serialize_column(s: &Series) -> Result<SerializedCells> {
+    let mut cells = SerializedCells::with_capacity(s.len());
+    let null = b"null";
+    macro_rules! numbers {
+        ($ca:expr) => {
+            cells.extend_primitive($ca, null, |buf, v| write!(buf, "{}", v).unwrap())
+        };
+    }
+    match s.dtype() {
+        DataType::Utf8 => {
+            let values = s.utf8()?.downcast_iter().flat_map(|arr| arr.iter());
+            cells.extend(values, null, write_str)
+        }
+        DataType::Boolean => {
+            let values = s.bool()?.downcast_iter().flat_map(|arr| arr.iter());
+            cells.extend(values, null, |buf, v| {
+                buf.extend_from_slice(if v { b"true" } else { b"false" })
+            })
+        }
+        DataType::UInt8 => numbers!(s.u8()?),
+        DataType::UInt16 => numbers!(s.u16()?),
+        DataType::UInt32 => numbers!(s.u32()?),
+        DataType::UInt64 => numbers!(s.u64()?),
+        DataType::Int8 => numbers!(s.i8()?),
+        DataType::Int16 => numbers!(s.i16()?),
+        DataType::Int32 => numbers!(s.i32()?),
+        DataType::Int64 => numbers!(s.i64()?),
+        DataType::Float32 => cells.extend_primitive(s.f32()?, null, write_float),
+        DataType::Float64 => cells.extend_primitive(s.f64()?, null, write_float),
+        // temporal and nested values are formatted from their `AnyValue`
+        _ => cells.extend((0..s.len()).map(|i| Some(s.get(i))), null, serialize_value),
+    }
+    Ok(cells)
+}
+
+fn serialize_rows(
+    buf: &mut Vec<u8>,
+    df: &DataFrame,
+    format: JsonFormat,
+    is_first_chunk: bool,
+) -> Result<()> {
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(serialize_column)
+        .collect::<Result<Vec<_>>>()?;
+    let names = df
+        .get_columns()
+        .iter()
+        .map(|s| serde_json::to_string(s.name()).unwrap())
+        .collect::<Vec<_>>();
+
+    for row in 0..df.height() {
+        if format == JsonFormat::Json && !(is_first_chunk && row == 0) {
+            buf.push(b',');
+        }
+        buf.push(b'{');
+        for (i, (cells, name)) in columns.iter().zip(&names).enumerate() {
+            if i > 0 {
+                buf.push(b',');
+            }
+            buf.extend_from_slice(name.as_bytes());
+            buf.push(b':');
+            buf.extend_from_slice(cells.get(row));
+        }
+        buf.push(b'}');
+        if format == JsonFormat::JsonLines {
+            buf.push(b'\n');
+        }
+    }
+    Ok(())
+}
+
+/// Serialize a column as a named array.
+fn serialize_named_column(s: &Series) -> Result<Vec<u8>> {
+    let cells = serialize_column(s)?;
+    let mut buf = Vec::with_capacity(cells.size() + cells.len() + s.name().len() + 4);
+    write_str(&mut buf, s.name());
+    buf.extend_from_slice(b":[");
+    for i in 0..cells.len() {
+        if i > 0 {
+            buf.push(b',');
+        }
+        buf.extend_from_slice(cells.get(i));
+    }
+    buf.push(b']');
+    Ok(buf)
+}
+
+/// Write `df` in the given `format`. The row formats are serialized in parallel in chunks of
+/// `chunk_size` rows.
+pub(crate) fn write<W: Write>(
+    writer: &mut W,
+    df: &DataFrame,
+    chunk_size: usize,
+    format: JsonFormat,
+) -> Result<()> {
+    if format == JsonFormat::JsonColumns {
+        let buffers = POOL.install(|| {
+            df.get_columns()
+                .par_iter()
+                .map(serialize_named_column)
+                .collect::<Result<Vec<_>>>()
+        })?;
+        writer.write_all(b"{")?;
+        for (i, buf) in buffers.iter().enumerate() {
+            if i > 0 {
+                writer.write_all(b",")?;
+            }
+            writer.write_all(buf)?;
+        }
+        writer.write_all(b"}")?;
+        return Ok(());
+    }
+
+    if format == JsonFormat::Json {
+        writer.write_all(b"[")?;
+    }
+    write_chunked(writer, df, chunk_size, |chunk, offset| {
+        let mut buf = Vec::with_capacity(chunk.height() * chunk.width() * 16);
+        serialize_rows(&mut buf, chunk, format, offset == 0)?;
+        Ok(buf)
+    })?;
+    if format == JsonFormat::Json {
+        writer.write_all(b"]")?;
+    }
+    Ok(())
+}
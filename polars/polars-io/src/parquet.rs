@@ -14,9 +14,14 @@
 //! }
 //! ```
 //!
-use super::{finish_reader, ArrowReader, ArrowResult, RecordBatch};
+use super::{finish_reader, ArrowReader, ArrowResult, BatchedReader, RecordBatch};
+#[cfg(feature = "lazy")]
+use crate::predicates::{BatchStats, ColumnStats};
 use crate::prelude::*;
 use crate::{PhysicalIoExpr, ScanAggregation};
+use arrow::array::{ArrayRef, StructArray};
+use arrow::bitmap::Bitmap;
+use arrow::compute::{aggregate, cast};
 use arrow::datatypes::PhysicalType;
 use arrow::error::ArrowError;
 use arrow::io::parquet::write::{array_to_pages, DynIter, DynStreamingIterator, Encoding};
@@ -24,17 +29,44 @@ use arrow::io::parquet::{
     read,
     write::{self, *},
 };
+use polars_arrow::utils::combine_validities;
+use polars_core::datatypes::PlHashMap;
 use polars_core::prelude::*;
+use polars_core::POOL;
 use rayon::prelude::*;
 use std::collections::VecDeque;
 use std::io::{Read, Seek, Write};
+#[cfg(feature = "lazy")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{sync_channel, SyncSender};
 use std::sync::Arc;
+use std::thread::JoinHandle;
+
+/// How the [`ParquetReader`] decodes a file on the thread pool.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ParallelStrategy {
+    /// Decode the file sequentially.
+    None,
+    /// Decode the columns of a row group in parallel, one row group at a time.
+    Columns,
+    /// Decode multiple row groups in parallel.
+    RowGroups,
+    /// Decode row groups in parallel if the file has more than one, otherwise its columns.
+    Auto,
+}
+
+impl Default for ParallelStrategy {
+    fn default() -> Self {
+        ParallelStrategy::Auto
+    }
+}
 
 /// Read Apache parquet format into a DataFrame.
 pub struct ParquetReader<R: Read + Seek> {
     reader: R,
     rechunk: bool,
     stop_after_n_rows: Option<usize>,
+    parallel: ParallelStrategy,
 }
 
 impl<R> ParquetReader<R>
@@ -49,23 +81,42 @@ where
         aggregate: Option<&[ScanAggregation]>,
         projection: Option<&[usize]>,
     ) -> Result<DataFrame> {
-        let rechunk = self.rechunk;
-
-        let reader = read::RecordReader::try_new(
-            &mut self.reader,
-            projection.map(|x| x.to_vec()),
-            self.stop_after_n_rows,
-            None,
-            None,
-        )?;
+        let pruned = Arc::new(AtomicUsize::new(0));
+        let mut n_row_groups = 0;
+        let groups_filter = match &predicate {
+            Some(predicate) if predicate.as_stats_evaluator().is_some() => {
+                let metadata = read::read_metadata(&mut self.reader)?;
+                let schema = read::get_schema(&metadata)?;
+                n_row_groups = metadata.row_groups.len();
+                let predicate = predicate.clone();
+                let pruned = pruned.clone();
+                let filter: read::GroupFilter = Arc::new(move |_, md: &read::RowGroupMetaData| {
+                    let stats = row_group_stats(&schema, md);
+                    // if the statistics cannot be evaluated we must read the row group
+                    let read = predicate
+                        .as_stats_evaluator()
+                        .unwrap()
+                        .should_read(&stats)
+                        .unwrap_or(true);
+                    if !read {
+                        pruned.fetch_add(1, Ordering::Relaxed);
+                    }
+                    read
+                });
+                Some(filter)
+            }
+            _ => None,
+        };
 
-        finish_reader(
-            reader,
-            rechunk,
-            self.stop_after_n_rows,
-            predicate,
-            aggregate,
-        )
+        let df = self.read_impl(projection, groups_filter, predicate, aggregate)?;
+        if n_row_groups > 0 && std::env::var("POLARS_VERBOSE").is_ok() {
+            eprintln!(
+                "parquet scan: {} of {} row groups pruned by statistics",
+                pruned.load(Ordering::Relaxed),
+                n_row_groups
+            );
+        }
+        Ok(df)
     }
 
     /// Stop parsing when `n` rows are parsed. By settings this parameter the csv will be parsed
@@ -75,12 +126,315 @@ where
         self
     }
 
+    /// Set the [`ParallelStrategy`] used to decode the file. Defaults to `Auto`.
+    pub fn read_parallel(mut self, parallel: ParallelStrategy) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Read the file one row group at a time, only keeping the columns at the `projection`
+    /// indexes.
+    pub fn batched(
+        self,
+        projection: Option<Vec<usize>>,
+    ) -> Result<BatchedReader<read::RecordReader<R>>> {
+        let reader = read::RecordReader::try_new(
+            self.reader,
+            projection,
+            self.stop_after_n_rows,
+            None,
+            None,
+        )?;
+        Ok(BatchedReader::new(reader, self.stop_after_n_rows))
+    }
+
     pub fn schema(mut self) -> Result<Schema> {
         let metadata = read::read_metadata(&mut self.reader)?;
 
         let schema = read::get_schema(&metadata)?;
         Ok(schema.into())
     }
+
+    fn read_impl(
+        mut self,
+        projection: Option<&[usize]>,
+        groups_filter: Option<read::GroupFilter>,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        aggregate: Option<&[ScanAggregation]>,
+    ) -> Result<DataFrame> {
+        let rechunk = self.rechunk;
+        let stop_after_n_rows = self.stop_after_n_rows;
+
+        if self.parallel != ParallelStrategy::None {
+            let metadata = read::read_metadata(&mut self.reader)?;
+            let schema = read::get_schema(&metadata)?;
+            // nested fields span multiple parquet columns; the `RecordReader` decodes those
+            if metadata.schema().num_columns() == schema.fields().len() {
+                let reader = ParallelReader::new(
+                    &mut self.reader,
+                    metadata,
+                    schema,
+                    projection,
+                    groups_filter,
+                    stop_after_n_rows,
+                    self.parallel,
+                );
+                return finish_reader(reader, rechunk, stop_after_n_rows, predicate, aggregate);
+            }
+        }
+
+        let reader = read::RecordReader::try_new(
+            &mut self.reader,
+            projection.map(|x| x.to_vec()),
+            stop_after_n_rows,
+            groups_filter,
+            None,
+        )?;
+        finish_reader(reader, rechunk, stop_after_n_rows, predicate, aggregate)
+    }
+}
+
+/// The compressed pages of a column chunk, read from the file but not yet decoded.
+struct CompressedColumn {
+    pages: Vec<CompressedPage>,
+    metadata: read::ColumnChunkMetaData,
+    data_type: ArrowDataType,
+}
+
+fn decode_column(column: CompressedColumn) -> ArrowResult<ArrayRef> {
+    let pages = column.pages.into_iter().map(Ok);
+    let mut pages = read::BasicDecompressor::new(pages, vec![]);
+    let array = read::page_iter_to_array(&mut pages, &column.metadata, column.data_type)?;
+    Ok(array.into())
+}
+
+fn decode_row_group(
+    columns: Vec<CompressedColumn>,
+    schema: Arc<ArrowSchema>,
+    parallel_columns: bool,
+) -> ArrowResult<RecordBatch> {
+    let arrays = if parallel_columns {
+        columns
+            .into_par_iter()
+            .map(decode_column)
+            .collect::<ArrowResult<Vec<_>>>()?
+    } else {
+        columns
+            .into_iter()
+            .map(decode_column)
+            .collect::<ArrowResult<Vec<_>>>()?
+    };
+    RecordBatch::try_new(schema, arrays)
+}
+
+/// Reads parquet row groups and decodes them on the thread pool.
+///
+/// Reading the compressed pages from the file is sequential, decompressing and decoding them is
+/// done in parallel. Batches are yielded in the order of the row groups in the file.
+struct ParallelReader<'a, R> {
+    reader: &'a mut R,
+    metadata: read::FileMetaData,
+    schema: Arc<ArrowSchema>,
+    projection: Vec<usize>,
+    /// The row groups that still have to be read.
+    row_groups: VecDeque<usize>,
+    decoded: VecDeque<RecordBatch>,
+    remaining_rows: Option<usize>,
+    strategy: ParallelStrategy,
+}
+
+impl<'a, R: Read + Seek> ParallelReader<'a, R> {
+    fn new(
+        reader: &'a mut R,
+        metadata: read::FileMetaData,
+        schema: ArrowSchema,
+        projection: Option<&[usize]>,
+        groups_filter: Option<read::GroupFilter>,
+        stop_after_n_rows: Option<usize>,
+        strategy: ParallelStrategy,
+    ) -> Self {
+        let projection = projection
+            .map(|p| p.to_vec())
+            .unwrap_or_else(|| (0..schema.fields().len()).collect());
+        let fields = projection
+            .iter()
+            .map(|&i| schema.fields()[i].clone())
+            .collect();
+        let schema = Arc::new(ArrowSchema::new(fields));
+
+        // select the row groups up front so that we don't read past `stop_after_n_rows`
+        let mut row_groups = VecDeque::with_capacity(metadata.row_groups.len());
+        let mut n_rows = 0;
+        for (i, md) in metadata.row_groups.iter().enumerate() {
+            if matches!(stop_after_n_rows, Some(n) if n_rows >= n) {
+                break;
+            }
+            if groups_filter.as_ref().map_or(true, |filter| filter(i, md)) {
+                n_rows += md.num_rows() as usize;
+                row_groups.push_back(i);
+            }
+        }
+        let strategy = match strategy {
+            ParallelStrategy::Auto if row_groups.len() > 1 => ParallelStrategy::RowGroups,
+            ParallelStrategy::Auto => ParallelStrategy::Columns,
+            strategy => strategy,
+        };
+
+        ParallelReader {
+            reader,
+            metadata,
+            schema,
+            projection,
+            row_groups,
+            decoded: VecDeque::new(),
+            remaining_rows: stop_after_n_rows,
+            strategy,
+        }
+    }
+
+    fn read_row_group(&mut self, row_group: usize) -> ArrowResult<Vec<CompressedColumn>> {
+        let reader = &mut *self.reader;
+        let md = &self.metadata.row_groups[row_group];
+        self.projection
+            .iter()
+            .zip(self.schema.fields())
+            .map(|(&column, field)| {
+                let metadata = md.column(column).clone();
+                let pages = read::get_page_iterator(&metadata, &mut *reader, None, vec![])?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok(CompressedColumn {
+                    pages,
+                    metadata,
+                    data_type: field.data_type().clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Read and decode the next row groups. With the `RowGroups` strategy, as many row groups as
+    /// there are threads are decoded at once.
+    fn decode_next(&mut self) -> ArrowResult<()> {
+        let n = match self.strategy {
+            ParallelStrategy::RowGroups => POOL.current_num_threads(),
+            _ => 1,
+        };
+        let row_groups = (0..n)
+            .filter_map(|_| self.row_groups.pop_front())
+            .collect::<Vec<_>>();
+        let compressed = row_groups
+            .into_iter()
+            .map(|row_group| self.read_row_group(row_group))
+            .collect::<ArrowResult<Vec<_>>>()?;
+
+        let schema = &self.schema;
+        let batches = match self.strategy {
+            ParallelStrategy::RowGroups => POOL.install(|| {
+                compressed
+                    .into_par_iter()
+                    .map(|columns| decode_row_group(columns, schema.clone(), false))
+                    .collect::<ArrowResult<Vec<_>>>()
+            })?,
+            _ => POOL.install(|| {
+                compressed
+                    .into_iter()
+                    .map(|columns| decode_row_group(columns, schema.clone(), true))
+                    .collect::<ArrowResult<Vec<_>>>()
+            })?,
+        };
+        self.decoded.extend(batches);
+        Ok(())
+    }
+}
+
+impl<'a, R: Read + Seek> ArrowReader for ParallelReader<'a, R> {
+    fn next_record_batch(&mut self) -> ArrowResult<Option<RecordBatch>> {
+        if self.decoded.is_empty() {
+            if self.row_groups.is_empty() {
+                return Ok(None);
+            }
+            self.decode_next()?;
+        }
+        let mut batch = self.decoded.pop_front().unwrap();
+
+        if let Some(remaining) = &mut self.remaining_rows {
+            if batch.num_rows() > *remaining {
+                let columns = batch
+                    .columns()
+                    .iter()
+                    .map(|arr| arr.slice(0, *remaining).into())
+                    .collect();
+                batch = RecordBatch::try_new(batch.schema().clone(), columns)?;
+            }
+            *remaining -= batch.num_rows();
+        }
+        Ok(Some(batch))
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        Arc::new(self.schema.as_ref().into())
+    }
+}
+
+/// Create a Series of length 1 from a statistics value, in the dtype of the column.
+#[cfg(feature = "lazy")]
+fn stats_value<T>(name: &str, dtype: &DataType, value: Option<T>) -> Option<Series>
+where
+    Series: NamedFrom<[T; 1], [T]>,
+{
+    let s = Series::new(name, [value?]);
+    s.cast(dtype).ok()
+}
+
+/// Convert the parquet statistics of a column chunk. Returns `None` for statistics we cannot
+/// use to prune row groups.
+#[cfg(feature = "lazy")]
+fn column_stats(
+    field: &ArrowField,
+    stats: &dyn read::statistics::Statistics,
+) -> Option<ColumnStats> {
+    let field: Field = field.into();
+    let name = field.name().as_str();
+    let dtype = field.data_type();
+
+    macro_rules! min_max {
+        ($stats_type:ty) => {
+            stats.as_any().downcast_ref::<$stats_type>().map(|stats| {
+                (
+                    stats_value(name, dtype, stats.min_value.clone()),
+                    stats_value(name, dtype, stats.max_value.clone()),
+                )
+            })
+        };
+    }
+    use read::statistics::{BooleanStatistics, PrimitiveStatistics, Utf8Statistics};
+    let (min, max) = min_max!(PrimitiveStatistics<i32>)
+        .or_else(|| min_max!(PrimitiveStatistics<i64>))
+        .or_else(|| min_max!(PrimitiveStatistics<f32>))
+        .or_else(|| min_max!(PrimitiveStatistics<f64>))
+        .or_else(|| min_max!(Utf8Statistics))
+        .or_else(|| min_max!(BooleanStatistics))?;
+    let null_count = stats.null_count().map(|n| n as usize);
+    Some(ColumnStats::new(field, null_count, min, max))
+}
+
+/// Collect the statistics of the (non-nested) columns of a row group.
+#[cfg(feature = "lazy")]
+fn row_group_stats(schema: &ArrowSchema, md: &read::RowGroupMetaData) -> BatchStats {
+    let stats = md
+        .columns()
+        .iter()
+        .filter_map(|column_md| {
+            let path = column_md.descriptor().path_in_schema();
+            if path.len() != 1 {
+                return None;
+            }
+            let field = schema.fields().iter().find(|fld| fld.name() == &path[0])?;
+            let stats = column_md.statistics()?.ok()?;
+            let stats = read::statistics::deserialize_statistics(stats.as_ref()).ok()?;
+            column_stats(field, stats.as_ref())
+        })
+        .collect();
+    BatchStats::new(md.num_rows() as usize, stats)
 }
 
 impl<R: Read + Seek> ArrowReader for read::RecordReader<R> {
@@ -102,6 +456,7 @@ where
             reader,
             rechunk: false,
             stop_after_n_rows: None,
+            parallel: Default::default(),
         }
     }
 
@@ -110,17 +465,8 @@ where
         self
     }
 
-    fn finish(mut self) -> Result<DataFrame> {
-        let rechunk = self.rechunk;
-
-        let reader = read::RecordReader::try_new(
-            &mut self.reader,
-            None,
-            self.stop_after_n_rows,
-            None,
-            None,
-        )?;
-        finish_reader(reader, rechunk, self.stop_after_n_rows, None, None)
+    fn finish(self) -> Result<DataFrame> {
+        self.read_impl(None, None, None, None)
     }
 }
 
@@ -152,14 +498,77 @@ impl FallibleStreamingIterator for Bla {
     }
 }
 
+/// Collect the leaf arrays of (nested) struct arrays in the depth first order
+/// of the columns in the parquet schema. Only the validity of the leaves is written,
+/// so the fields of a null struct row are written as nulls.
+fn flatten_struct_leaves(array: &ArrayRef, validity: Option<&Bitmap>, leaves: &mut Vec<ArrayRef>) {
+    match array.as_any().downcast_ref::<StructArray>() {
+        Some(arr) => {
+            let validity = combine_validities(validity, arr.validity());
+            arr.values()
+                .iter()
+                .for_each(|values| flatten_struct_leaves(values, validity.as_ref(), leaves))
+        }
+        None => match validity {
+            Some(validity) => {
+                let validity = combine_validities(Some(validity), array.validity());
+                leaves.push(Arc::from(array.with_validity(validity)))
+            }
+            None => leaves.push(array.clone()),
+        },
+    }
+}
+
+/// Target size of the row groups written by the [`ParquetWriter`].
+#[derive(Copy, Clone, Debug)]
+pub enum RowGroupSize {
+    /// Number of rows per row group.
+    Rows(usize),
+    /// Approximate number of (uncompressed) bytes per row group.
+    Bytes(usize),
+}
+
+/// Make the array of a column match the dictionary encoding chosen for that column.
+fn set_dictionary_encoding(array: &ArrayRef, dictionary: Option<bool>) -> ArrowResult<ArrayRef> {
+    let array = match (dictionary, array.data_type()) {
+        (Some(true), ArrowDataType::Utf8 | ArrowDataType::LargeUtf8) => {
+            let dtype = ArrowDataType::Dictionary(
+                Box::new(ArrowDataType::UInt32),
+                Box::new(array.data_type().clone()),
+            );
+            cast::cast(array.as_ref(), &dtype)?.into()
+        }
+        (Some(false), ArrowDataType::Dictionary(_, values)) => {
+            cast::cast(array.as_ref(), values)?.into()
+        }
+        _ => array.clone(),
+    };
+    Ok(array)
+}
+
 /// Write a DataFrame to parquet format
 ///
 /// # Example
 ///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+/// use std::fs::File;
 ///
+/// fn example(df: &DataFrame) -> Result<()> {
+///     let file = File::create("example.parquet").expect("could not create file");
+///     ParquetWriter::new(file)
+///         .with_statistics(true)
+///         .with_row_group_size(Some(RowGroupSize::Rows(512 * 512)))
+///         .finish(df)
+/// }
+/// ```
 pub struct ParquetWriter<W> {
     writer: W,
     compression: write::Compression,
+    statistics: bool,
+    row_group_size: Option<RowGroupSize>,
+    dictionary: PlHashMap<String, bool>,
 }
 
 pub use write::Compression;
@@ -176,52 +585,168 @@ where
         ParquetWriter {
             writer,
             compression: write::Compression::Snappy,
+            statistics: false,
+            row_group_size: None,
+            dictionary: PlHashMap::default(),
         }
     }
 
     /// Set the compression used. Defaults to `Snappy`.
+    ///
+    /// The parquet backend compresses with the default level of every codec; compression
+    /// levels cannot be set.
     pub fn with_compression(mut self, compression: write::Compression) -> Self {
         self.compression = compression;
         self
     }
 
+    /// Write the min, max and null count of every column chunk, so that readers can skip row
+    /// groups. Defaults to `false`.
+    pub fn with_statistics(mut self, statistics: bool) -> Self {
+        self.statistics = statistics;
+        self
+    }
+
+    /// Set the target size of the row groups. If `None` (the default), every chunk of the
+    /// DataFrame is written as a row group.
+    pub fn with_row_group_size(mut self, size: Option<RowGroupSize>) -> Self {
+        self.row_group_size = size;
+        self
+    }
+
+    /// Choose whether the column `name` is dictionary encoded. By default only categorical
+    /// columns are. A categorical column that is not dictionary encoded is written as utf8.
+    pub fn with_dictionary_encoding(mut self, name: &str, dictionary: bool) -> Self {
+        self.dictionary.insert(name.to_string(), dictionary);
+        self
+    }
+
+    /// Split a DataFrame into the row groups that will be written.
+    fn row_groups(row_group_size: Option<RowGroupSize>, df: &DataFrame) -> Result<Vec<DataFrame>> {
+        let height = df.height();
+        let rows_per_group = match row_group_size {
+            None => return Ok(vec![df.clone()]),
+            Some(RowGroupSize::Rows(rows)) => rows,
+            Some(RowGroupSize::Bytes(bytes)) => {
+                let df_bytes = df
+                    .get_columns()
+                    .iter()
+                    .flat_map(|s| s.chunks())
+                    .map(|arr| aggregate::estimated_bytes_size(arr.as_ref()))
+                    .sum::<usize>();
+                let row_bytes = std::cmp::max(df_bytes / std::cmp::max(height, 1), 1);
+                bytes / row_bytes
+            }
+        };
+        if rows_per_group == 0 {
+            return Err(PolarsError::ValueError(
+                "row group size should be at least one row".into(),
+            ));
+        }
+        let groups = (0..std::cmp::max(height, 1))
+            .step_by(rows_per_group)
+            .map(|offset| {
+                let mut df = df.slice(offset as i64, rows_per_group);
+                df.as_single_chunk();
+                df
+            })
+            .collect();
+        Ok(groups)
+    }
+
     /// Write the given DataFrame in the the writer `W`.
-    pub fn finish(mut self, df: &DataFrame) -> Result<()> {
-        let fields = df.schema().to_arrow().fields().clone();
-        let rb_iter = df.iter_record_batches();
+    pub fn finish(self, df: &DataFrame) -> Result<()> {
+        self.write_batches(&df.schema(), std::iter::once(df.clone()))
+    }
+
+    /// Write DataFrames with the given schema one after the other. A DataFrame is only taken
+    /// from `batches` when the previous row groups are written.
+    fn write_batches<I>(mut self, schema: &Schema, batches: I) -> Result<()>
+    where
+        I: Iterator<Item = DataFrame>,
+    {
+        let dictionary = schema
+            .fields()
+            .iter()
+            .map(|fld| {
+                let dictionary = self.dictionary.get(fld.name()).copied();
+                match (dictionary, fld.data_type()) {
+                    (None, _) | (_, DataType::Utf8) | (_, DataType::Categorical) => Ok(dictionary),
+                    (Some(_), dtype) => Err(PolarsError::InvalidOperation(
+                        format!(
+                            "cannot choose the dictionary encoding of column {} of type {:?}",
+                            fld.name(),
+                            dtype
+                        )
+                        .into(),
+                    )),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let fields = schema
+            .to_arrow()
+            .fields()
+            .iter()
+            .zip(&dictionary)
+            .map(
+                |(field, dictionary)| match (dictionary, field.data_type()) {
+                    (Some(false), ArrowDataType::Dictionary(_, values)) => {
+                        ArrowField::new(field.name(), (**values).clone(), field.is_nullable())
+                    }
+                    _ => field.clone(),
+                },
+            )
+            .collect();
+
+        // errors of splitting the batches are stored, so that they are returned after the
+        // writer has consumed the iterator
+        let row_group_size = self.row_group_size;
+        let mut split_error = None;
+        let rb_iter = batches
+            .flat_map(|df| match Self::row_groups(row_group_size, &df) {
+                Ok(row_groups) => row_groups,
+                Err(e) => {
+                    split_error = Some(e);
+                    vec![]
+                }
+            })
+            .flat_map(|df| df.iter_record_batches().collect::<Vec<_>>());
 
         let options = write::WriteOptions {
-            write_statistics: false,
+            write_statistics: self.statistics,
             compression: self.compression,
             version: write::Version::V2,
         };
         let schema = ArrowSchema::new(fields);
         let parquet_schema = write::to_parquet_schema(&schema)?;
-        let encodings = schema
-            .fields()
-            .iter()
-            .map(|field| match field.data_type().to_physical_type() {
-                // delta encoding
-                // Not yet supported by pyarrow
-                // PhysicalType::LargeUtf8 => Encoding::DeltaLengthByteArray,
-                // dictionaries are kept dict-encoded
-                PhysicalType::Dictionary(_) => Encoding::RleDictionary,
-                // remaining is plain
-                _ => Encoding::Plain,
-            })
-            .collect::<Vec<_>>();
 
         // clone is needed because parquet schema is moved into `write_file`
         let parquet_schema_iter = parquet_schema.clone();
         let row_group_iter = rb_iter.map(|batch| {
-            let columns = batch
-                .columns()
+            // the parquet columns are the leaves of the schema, so struct columns
+            // are written as one column per (nested) field
+            let mut leaves = Vec::with_capacity(parquet_schema_iter.columns().len());
+            for (array, dictionary) in batch.columns().iter().zip(&dictionary) {
+                let array = set_dictionary_encoding(array, *dictionary)?;
+                flatten_struct_leaves(&array, None, &mut leaves);
+            }
+
+            let columns = leaves
                 .par_iter()
                 .zip(parquet_schema_iter.columns().par_iter())
-                .zip(encodings.par_iter())
-                .map(|((array, descriptor), encoding)| {
+                .map(|(array, descriptor)| {
+                    let encoding = match array.data_type().to_physical_type() {
+                        // delta encoding
+                        // Not yet supported by pyarrow
+                        // PhysicalType::LargeUtf8 => Encoding::DeltaLengthByteArray,
+                        // dictionaries are kept dict-encoded
+                        PhysicalType::Dictionary(_) => Encoding::RleDictionary,
+                        // remaining is plain
+                        _ => Encoding::Plain,
+                    };
                     let encoded_pages =
-                        array_to_pages(array.as_ref(), descriptor.clone(), options, *encoding)?;
+                        array_to_pages(array.as_ref(), descriptor.clone(), options, encoding)?;
                     encoded_pages
                         .map(|page| {
                             compress(page?, vec![], options.compression).map_err(|x| x.into())
@@ -238,17 +763,100 @@ where
             ArrowResult::Ok(row_group)
         });
 
-        write::write_file(
+        let written = write::write_file(
             &mut self.writer,
             row_group_iter,
             &schema,
             parquet_schema,
             options,
             None,
-        )?;
+        );
+        match split_error {
+            Some(e) => Err(e),
+            None => {
+                written?;
+                Ok(())
+            }
+        }
+    }
+}
 
+impl<W> ParquetWriter<W>
+where
+    W: Write + Seek + Send + 'static,
+{
+    /// Write DataFrames with the given schema one after the other, e.g. as they are produced
+    /// by a query. The row groups are encoded and written on a separate thread.
+    pub fn batched(self, schema: &Schema) -> Result<BatchedParquetWriter> {
+        // a single batch is buffered, so that the next batch can be produced while the
+        // previous one is written
+        let (sender, receiver) = sync_channel(1);
+        let writer_schema = schema.clone();
+        let handle =
+            std::thread::spawn(move || self.write_batches(&writer_schema, receiver.into_iter()));
+        Ok(BatchedParquetWriter {
+            sender,
+            handle: Some(handle),
+            schema: schema.clone(),
+        })
+    }
+}
+
+/// Writes DataFrames with the same schema incrementally to a parquet file.
+///
+/// Created by [`ParquetWriter::batched`].
+pub struct BatchedParquetWriter {
+    sender: SyncSender<DataFrame>,
+    handle: Option<JoinHandle<Result<()>>>,
+    schema: Schema,
+}
+
+impl BatchedParquetWriter {
+    /// Write the rows of `df`. The schema must be the schema the writer was created with.
+    pub fn write_batch(&mut self, df: &DataFrame) -> Result<()> {
+        if df.schema() != self.schema {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "cannot write a batch with schema {:?} to a parquet writer with schema {:?}",
+                    df.schema(),
+                    self.schema
+                )
+                .into(),
+            ));
+        }
+        if df.height() == 0 {
+            return Ok(());
+        }
+        if self.sender.send(df.clone()).is_err() {
+            // the writer thread only hangs up if it failed
+            return match self.join() {
+                Err(e) => Err(e),
+                Ok(()) => Err(PolarsError::ComputeError(
+                    "the parquet writer has stopped".into(),
+                )),
+            };
+        }
         Ok(())
     }
+
+    fn join(&mut self) -> Result<()> {
+        match self.handle.take() {
+            Some(handle) => handle.join().map_err(|_| {
+                PolarsError::ComputeError("the parquet writer thread panicked".into())
+            })?,
+            None => Err(PolarsError::ComputeError(
+                "the parquet writer has stopped".into(),
+            )),
+        }
+    }
+
+    /// Write the footer of the file once all batches are written.
+    pub fn finish(mut self) -> Result<()> {
+        let (sender, _) = sync_channel(0);
+        // hang up, so that the writer thread writes the footer
+        drop(std::mem::replace(&mut self.sender, sender));
+        self.join()
+    }
 }
 
 #[cfg(test)]
@@ -273,20 +881,209 @@ mod test {
     fn test_parquet_datetime_round_trip() -> Result<()> {
         use std::io::{Cursor, Seek, SeekFrom};
 
+        for tu in [
+            TimeUnit::Milliseconds,
+            TimeUnit::Microseconds,
+            TimeUnit::Nanoseconds,
+        ] {
+            let mut f = Cursor::new(vec![]);
+
+            let mut df = df![
+                "datetime" => [Some(191845729i64), Some(89107598), None, Some(3158971092)]
+            ]?;
+
+            let dtype = DataType::Datetime(tu, None);
+            df.may_apply("datetime", |s| s.cast(&dtype))?;
+
+            ParquetWriter::new(&mut f).finish(&df)?;
+
+            f.seek(SeekFrom::Start(0))?;
+
+            // the time unit of the file is kept, no lossy cast to milliseconds
+            let read = ParquetReader::new(f).finish()?;
+            assert_eq!(read.column("datetime")?.dtype(), &dtype);
+            assert!(read.frame_equal_missing(&df));
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-datetime", feature = "parquet"))]
+    fn test_parquet_time_zone_round_trip() -> Result<()> {
+        use std::io::{Cursor, Seek, SeekFrom};
+
+        let mut f = Cursor::new(vec![]);
+
+        let dtype = DataType::Datetime(TimeUnit::Milliseconds, Some("America/New_York".into()));
+        let mut df = df![
+            "datetime" => [Some(191845729i64), None, Some(3158971092)]
+        ]?;
+        df.may_apply("datetime", |s| s.cast(&dtype))?;
+
+        ParquetWriter::new(&mut f).finish(&df)?;
+
+        f.seek(SeekFrom::Start(0))?;
+
+        let read = ParquetReader::new(f).finish()?;
+        assert_eq!(read.column("datetime")?.dtype(), &dtype);
+        assert!(read.frame_equal_missing(&df));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-duration", feature = "parquet"))]
+    fn test_parquet_duration_round_trip() -> Result<()> {
+        use std::io::{Cursor, Seek, SeekFrom};
+
         let mut f = Cursor::new(vec![]);
 
         let mut df = df![
-            "datetime" => [Some(191845729i64), Some(89107598), None, Some(3158971092)]
+            "duration" => [Some(86_400_000i64), Some(-1500), None, Some(3158971092)]
         ]?;
 
-        df.may_apply("datetime", |s| s.cast(&DataType::Datetime))?;
+        let dtype = DataType::Duration(TimeUnit::Milliseconds);
+        df.may_apply("duration", |s| s.cast(&dtype))?;
+
+        ParquetWriter::new(&mut f).finish(&df)?;
+
+        f.seek(SeekFrom::Start(0))?;
+
+        let read = ParquetReader::new(f).finish()?;
+        assert_eq!(read.column("duration")?.dtype(), &dtype);
+        assert!(read.frame_equal_missing(&df));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-struct", feature = "parquet"))]
+    fn test_parquet_struct_round_trip() -> Result<()> {
+        use std::io::{Cursor, Seek, SeekFrom};
+
+        let mut f = Cursor::new(vec![]);
+
+        let a = Series::new("a", &[Some(1i64), None, Some(3)]);
+        let b = Series::new("b", &[Some("foo"), Some("bar"), None]);
+        let s = StructChunked::new("struct", &[a, b])?.into_series();
+        let df = DataFrame::new(vec![Series::new("idx", &[0u32, 1, 2]), s])?;
+
+        ParquetWriter::new(&mut f).finish(&df)?;
+
+        f.seek(SeekFrom::Start(0))?;
+
+        let read = ParquetReader::new(f).finish()?;
+        assert_eq!(read.column("struct")?.dtype(), df.column("struct")?.dtype());
+        assert!(read.frame_equal_missing(&df));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-binary", feature = "parquet"))]
+    fn test_parquet_binary_round_trip() -> Result<()> {
+        use std::io::{Cursor, Seek, SeekFrom};
+
+        let mut f = Cursor::new(vec![]);
+
+        let ca = BinaryChunked::new_from_opt_slice(
+            "bytes",
+            &[Some(&b"foo"[..]), None, Some(&b"\x00\xff"[..])],
+        );
+        let df = DataFrame::new(vec![ca.into_series()])?;
+
+        ParquetWriter::new(&mut f).finish(&df)?;
+
+        f.seek(SeekFrom::Start(0))?;
+
+        let read = ParquetReader::new(f).finish()?;
+        assert_eq!(read.column("bytes")?.dtype(), &DataType::Binary);
+        assert!(read.frame_equal_missing(&df));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "dtype-decimal", feature = "parquet"))]
+    fn test_parquet_decimal_round_trip() -> Result<()> {
+        use std::io::{Cursor, Seek, SeekFrom};
+
+        let mut f = Cursor::new(vec![]);
+
+        let ca = DecimalChunked::new_from_opt_slice("price", &[Some(1999), None, Some(-5)], 10, 2)?;
+        let df = DataFrame::new(vec![ca.into_series()])?;
 
         ParquetWriter::new(&mut f).finish(&df)?;
 
         f.seek(SeekFrom::Start(0))?;
 
         let read = ParquetReader::new(f).finish()?;
+        assert_eq!(read.column("price")?.dtype(), &DataType::Decimal(10, 2));
         assert!(read.frame_equal_missing(&df));
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "parquet")]
+    fn test_parquet_writer_options() -> Result<()> {
+        use arrow::io::parquet::read::read_metadata;
+        use std::io::{Cursor, Seek, SeekFrom};
+
+        let mut f = Cursor::new(vec![]);
+
+        let df = df![
+            "a" => (0..10i64).collect::<Vec<_>>(),
+            "b" => ["x", "y", "x", "x", "y", "x", "y", "y", "x", "x"]
+        ]?;
+
+        ParquetWriter::new(&mut f)
+            .with_statistics(true)
+            .with_row_group_size(Some(RowGroupSize::Rows(4)))
+            .with_dictionary_encoding("b", true)
+            .finish(&df)?;
+
+        f.seek(SeekFrom::Start(0))?;
+        assert_eq!(read_metadata(&mut f)?.row_groups.len(), 3);
+
+        f.seek(SeekFrom::Start(0))?;
+        let read = ParquetReader::new(f).finish()?;
+        assert_eq!(read.column("b")?.dtype(), &DataType::Utf8);
+        assert!(read.frame_equal(&df));
+
+        let result = ParquetWriter::new(Cursor::new(vec![]))
+            .with_dictionary_encoding("a", true)
+            .finish(&df);
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_read_parallel() -> Result<()> {
+        use std::io::Cursor;
+
+        let df = df![
+            "a" => (0..100i32).collect::<Vec<_>>(),
+            "b" => (0..100).map(|i| format!("s{}", i)).collect::<Vec<_>>()
+        ]?;
+        let mut f = Cursor::new(vec![]);
+        ParquetWriter::new(&mut f)
+            .with_row_group_size(Some(RowGroupSize::Rows(7)))
+            .finish(&df)?;
+        let buf = f.into_inner();
+
+        for strategy in [
+            ParallelStrategy::None,
+            ParallelStrategy::Columns,
+            ParallelStrategy::RowGroups,
+            ParallelStrategy::Auto,
+        ] {
+            let read = ParquetReader::new(Cursor::new(&buf))
+                .read_parallel(strategy)
+                .finish()?;
+            assert!(read.frame_equal(&df));
+
+            let read = ParquetReader::new(Cursor::new(&buf))
+                .read_parallel(strategy)
+                .with_stop_after_n_rows(Some(10))
+                .finish()?;
+            assert!(read.frame_equal(&df.head(Some(10))));
+        }
+        Ok(())
+    }
 }
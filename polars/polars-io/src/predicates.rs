@@ -0,0 +1,74 @@
+//! Statistics of batches of rows, used to skip batches that cannot satisfy a predicate.
+use polars_core::prelude::*;
+
+/// The statistics of a single column in a batch of rows, e.g. a parquet row group.
+#[derive(Debug)]
+pub struct ColumnStats {
+    field: Field,
+    null_count: Option<usize>,
+    min_value: Option<Series>,
+    max_value: Option<Series>,
+}
+
+impl ColumnStats {
+    pub fn new(
+        field: Field,
+        null_count: Option<usize>,
+        min_value: Option<Series>,
+        max_value: Option<Series>,
+    ) -> Self {
+        Self {
+            field,
+            null_count,
+            min_value,
+            max_value,
+        }
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        self.field.data_type()
+    }
+
+    pub fn null_count(&self) -> Option<usize> {
+        self.null_count
+    }
+
+    /// The minimum value as a Series of length 1.
+    pub fn to_min(&self) -> Option<&Series> {
+        self.min_value.as_ref()
+    }
+
+    /// The maximum value as a Series of length 1.
+    pub fn to_max(&self) -> Option<&Series> {
+        self.max_value.as_ref()
+    }
+}
+
+/// The statistics of the columns of a batch of rows.
+#[derive(Debug)]
+pub struct BatchStats {
+    num_rows: usize,
+    stats: Vec<ColumnStats>,
+}
+
+impl BatchStats {
+    pub fn new(num_rows: usize, stats: Vec<ColumnStats>) -> Self {
+        Self { num_rows, stats }
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The statistics of `column`. Returns `None` if no statistics were written for it.
+    pub fn get_stats(&self, column: &str) -> Option<&ColumnStats> {
+        self.stats.iter().find(|stats| stats.field.name() == column)
+    }
+}
+
+/// Decide from the statistics of a batch whether the batch may contain rows that satisfy
+/// a predicate.
+pub trait StatsEvaluator {
+    /// Returns `false` only if no row of the batch can satisfy the predicate.
+    fn should_read(&self, stats: &BatchStats) -> Result<bool>;
+}
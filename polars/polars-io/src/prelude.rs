@@ -1,11 +1,17 @@
 pub use crate::{SerReader, SerWriter};
 
+#[cfg(feature = "avro")]
+pub use crate::avro::*;
 #[cfg(feature = "csv-file")]
 pub use crate::csv::*;
 #[cfg(feature = "ipc")]
 pub use crate::ipc::*;
+#[cfg(feature = "ipc")]
+pub use crate::ipc_stream::*;
 #[cfg(feature = "json")]
 pub use crate::json::*;
+#[cfg(feature = "json")]
+pub use crate::ndjson_core::ndjson::JsonLineReader;
 #[cfg(feature = "parquet")]
 pub use crate::parquet::*;
 
@@ -1,4 +1,10 @@
 use dirs::home_dir;
+#[cfg(any(feature = "csv-file", feature = "json"))]
+use polars_core::{prelude::*, POOL};
+#[cfg(any(feature = "csv-file", feature = "json"))]
+use rayon::prelude::*;
+#[cfg(any(feature = "csv-file", feature = "json"))]
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
 pub fn resolve_homedir(path: &Path) -> PathBuf {
@@ -12,6 +18,106 @@ pub fn resolve_homedir(path: &Path) -> PathBuf {
     path.into()
 }
 
+/// Serialize the rows of `df` in parallel and write them in order. Every thread serializes
+/// `chunk_size` rows (at least one) at once with `serialize_chunk`, which gets the rows and the
+/// offset of the first row.
+#[cfg(any(feature = "csv-file", feature = "json"))]
+pub(crate) fn write_chunked<W, F>(
+    writer: &mut W,
+    df: &DataFrame,
+    chunk_size: usize,
+    serialize_chunk: F,
+) -> Result<()>
+where
+    W: Write,
+    F: Fn(&DataFrame, usize) -> Result<Vec<u8>> + Send + Sync,
+{
+    let chunk_size = chunk_size.max(1);
+    let len = df.height();
+    let n_threads = POOL.current_num_threads();
+    let rows_per_iter = n_threads * chunk_size;
+
+    let mut n_rows_finished = 0;
+    while n_rows_finished < len {
+        let buffers = POOL.install(|| {
+            (0..n_threads)
+                .into_par_iter()
+                .map(|thread_no| {
+                    let offset = n_rows_finished + thread_no * chunk_size;
+                    if offset >= len {
+                        return Ok(vec![]);
+                    }
+                    serialize_chunk(&df.slice(offset as i64, chunk_size), offset)
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+        for buf in buffers {
+            writer.write_all(&buf)?;
+        }
+        n_rows_finished += rows_per_iter;
+    }
+    Ok(())
+}
+
+/// The serialized values of a column, stored back to back. The values are serialized from the
+/// typed arrays of the column, so that a row can then be assembled from the cells of its columns.
+#[cfg(any(feature = "csv-file", feature = "json"))]
+pub(crate) struct SerializedCells {
+    buf: Vec<u8>,
+    ends: Vec<usize>,
+}
+
+#[cfg(any(feature = "csv-file", feature = "json"))]
+impl SerializedCells {
+    pub(crate) fn with_capacity(n_cells: usize) -> Self {
+        SerializedCells {
+            buf: Vec::with_capacity(n_cells * 8),
+            ends: Vec::with_capacity(n_cells),
+        }
+    }
+
+    /// Serialize the values with `serialize` and the nulls as `null`.
+    pub(crate) fn extend<T, I, F>(&mut self, values: I, null: &[u8], mut serialize: F)
+    where
+        I: Iterator<Item = Option<T>>,
+        F: FnMut(&mut Vec<u8>, T),
+    {
+        for opt_v in values {
+            match opt_v {
+                Some(v) => serialize(&mut self.buf, v),
+                None => self.buf.extend_from_slice(null),
+            }
+            self.ends.push(self.buf.len());
+        }
+    }
+
+    pub(crate) fn extend_primitive<T, F>(&mut self, ca: &ChunkedArray<T>, null: &[u8], serialize: F)
+    where
+        T: PolarsNumericType,
+        F: FnMut(&mut Vec<u8>, T::Native),
+    {
+        let values = ca
+            .downcast_iter()
+            .flat_map(|arr| arr.iter().map(|opt_v| opt_v.copied()));
+        self.extend(values, null, serialize)
+    }
+
+    #[cfg(feature = "json")]
+    pub(crate) fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// The number of bytes of all cells.
+    pub(crate) fn size(&self) -> usize {
+        self.buf.len()
+    }
+
+    pub(crate) fn get(&self, i: usize) -> &[u8] {
+        let start = if i == 0 { 0 } else { self.ends[i - 1] };
+        &self.buf[start..self.ends[i]]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::resolve_homedir;
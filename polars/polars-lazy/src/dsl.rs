@@ -19,6 +19,8 @@ use polars_core::frame::select::Selection;
 #[cfg(feature = "diff")]
 use polars_core::series::ops::NullBehavior;
 use polars_core::utils::get_supertype;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
 
 /// A wrapper trait for any closure `Fn(Vec<Series>) -> Result<Series>`
 pub trait SeriesUdf: Send + Sync {
@@ -61,6 +63,7 @@ impl Debug for dyn SeriesBinaryUdf {
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 /// Wrapper type that indicates that the inner type is not equal to anything
 pub struct NoEq<T>(T);
 
@@ -147,6 +150,15 @@ impl GetOutput {
     }
 }
 
+/// The output field of a time zone operation: the input `Datetime` with its time zone replaced.
+#[cfg(feature = "timezones")]
+fn with_time_zone(tz: Option<TimeZone>) -> GetOutput {
+    GetOutput::map_field(move |fld| match fld.data_type() {
+        DataType::Datetime(tu, _) => Field::new(fld.name(), DataType::Datetime(*tu, tz.clone())),
+        _ => fld.clone(),
+    })
+}
+
 impl<F> FunctionOutputField for F
 where
     F: Fn(&Schema, Context, &[Field]) -> Field + Send + Sync,
@@ -157,6 +169,7 @@ where
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum ApplyOptions {
     /// Collect groups to a list and apply the function over the groups.
     /// This can be important in aggregation context.
@@ -168,6 +181,7 @@ pub enum ApplyOptions {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct WindowOptions {
     /// Explode the aggregated list and just do a hstack instead of a join
     /// this requires the groups to be sorted to make any sense
@@ -175,6 +189,7 @@ pub struct WindowOptions {
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct FunctionOptions {
     /// Collect groups to a list and apply the function over the groups.
     /// This can be important in aggregation context.
@@ -197,6 +212,7 @@ pub struct FunctionOptions {
 }
 
 #[derive(PartialEq, Clone)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum AggExpr {
     Min(Box<Expr>),
     Max(Box<Expr>),
@@ -244,6 +260,7 @@ impl From<AggExpr> for Expr {
 
 /// Queries consists of multiple expressions.
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum Expr {
     Alias(Box<Expr>, Arc<String>),
     Column(Arc<String>),
@@ -288,8 +305,16 @@ pub enum Expr {
         /// function arguments
         input: Vec<Expr>,
         /// function to apply
+        #[cfg_attr(
+            feature = "serde-lazy",
+            serde(with = "crate::logical_plan::serialize::udf")
+        )]
         function: NoEq<Arc<dyn SeriesUdf>>,
         /// output dtype of the function
+        #[cfg_attr(
+            feature = "serde-lazy",
+            serde(with = "crate::logical_plan::serialize::udf")
+        )]
         output_type: GetOutput,
         options: FunctionOptions,
     },
@@ -323,8 +348,16 @@ pub enum Expr {
     BinaryFunction {
         input_a: Box<Expr>,
         input_b: Box<Expr>,
+        #[cfg_attr(
+            feature = "serde-lazy",
+            serde(with = "crate::logical_plan::serialize::udf")
+        )]
         function: NoEq<Arc<dyn SeriesBinaryUdf>>,
         /// Delays output type evaluation until input schema is known.
+        #[cfg_attr(
+            feature = "serde-lazy",
+            serde(with = "crate::logical_plan::serialize::udf")
+        )]
         output_field: NoEq<Arc<dyn BinaryUdfOutputField>>,
     },
     /// Can be used in a select statement to exclude a column from selection
@@ -449,6 +482,7 @@ impl fmt::Debug for Expr {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum Operator {
     Eq,
     NotEq,
@@ -1316,6 +1350,35 @@ impl Expr {
         self.map(function, GetOutput::from_type(DataType::UInt32))
     }
 
+    /// Convert a time zone aware Datetime to another time zone. The instants stay the same.
+    #[cfg(feature = "timezones")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timezones")))]
+    pub fn convert_time_zone(self, tz: &str) -> Expr {
+        let tz = tz.to_string();
+        let output_tz = Some(tz.clone());
+        let function = move |s: Series| {
+            s.datetime()?
+                .convert_time_zone(&tz)
+                .map(|ca| ca.into_series())
+        };
+        self.map(function, with_time_zone(output_tz))
+    }
+
+    /// Replace the time zone of a Datetime, keeping the wall clock time.
+    /// `None` makes the Datetime naive.
+    #[cfg(feature = "timezones")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timezones")))]
+    pub fn replace_time_zone(self, tz: Option<&str>) -> Expr {
+        let tz = tz.map(|tz| tz.to_string());
+        let output_tz = tz.clone();
+        let function = move |s: Series| {
+            s.datetime()?
+                .replace_time_zone(tz.as_deref())
+                .map(|ca| ca.into_series())
+        };
+        self.map(function, with_time_zone(output_tz))
+    }
+
     /// Sort this column by the ordering of another column.
     /// Can also be used in a groupby context to sort the groups.
     pub fn sort_by(self, by: Expr, reverse: bool) -> Expr {
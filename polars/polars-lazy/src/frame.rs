@@ -1,5 +1,10 @@
 //! Lazy variant of a [DataFrame](polars_core::frame::DataFrame).
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "csv-file",
+    feature = "ipc",
+    feature = "json"
+))]
 use polars_core::datatypes::PlHashMap;
 use polars_core::frame::hash_join::JoinType;
 use polars_core::prelude::*;
@@ -8,27 +13,57 @@ use polars_core::toggle_string_cache;
 use std::sync::Arc;
 
 use crate::logical_plan::optimizer::aggregate_pushdown::AggregatePushdown;
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "csv-file",
+    feature = "ipc",
+    feature = "json"
+))]
 use crate::logical_plan::optimizer::aggregate_scan_projections::AggScanProjection;
+use crate::logical_plan::optimizer::common_subplan::CommonSubplanElimination;
 use crate::logical_plan::optimizer::simplify_expr::SimplifyExprRule;
 use crate::logical_plan::optimizer::stack_opt::{OptimizationRule, StackOptimizer};
 use crate::logical_plan::optimizer::{
     predicate_pushdown::PredicatePushDown, projection_pushdown::ProjectionPushDown,
 };
+#[cfg(feature = "ipc")]
+use crate::logical_plan::IpcScanOptions;
+#[cfg(feature = "json")]
+use crate::logical_plan::NdJsonScanOptions;
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv-file"))]
+use crate::physical_plan::sink::{self, SinkWriter};
 use crate::physical_plan::state::ExecutionState;
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+use crate::physical_plan::streaming::insert_streaming_nodes;
+#[cfg(any(
+    feature = "parquet",
+    feature = "csv-file",
+    feature = "ipc",
+    feature = "json"
+))]
 use crate::prelude::aggregate_scan_projections::agg_projection;
 use crate::prelude::drop_nulls::ReplaceDropNulls;
 use crate::prelude::fast_projection::FastProjection;
 use crate::prelude::simplify_expr::SimplifyBooleanRule;
 use crate::utils::{combine_predicates_expr, expr_to_root_column_names};
 use crate::{logical_plan::FETCH_ROWS, prelude::*};
-use polars_io::csv::NullValues;
+use polars_io::csv::{CsvEncoding, NullValues};
+#[cfg(feature = "csv-file")]
+use polars_io::csv::{CsvWriter, SerializeOptions};
+#[cfg(feature = "ipc")]
+use polars_io::ipc::IpcWriter;
+#[cfg(feature = "parquet")]
+use polars_io::parquet::{Compression as ParquetCompression, ParquetWriter, RowGroupSize};
+#[cfg(any(feature = "ipc", feature = "csv-file"))]
+use polars_io::SerWriter;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
+#[cfg(any(feature = "parquet", feature = "csv-file", feature = "ipc"))]
+use std::path::PathBuf;
 
 #[derive(Clone)]
 #[cfg(feature = "csv-file")]
 pub struct LazyCsvReader<'a> {
-    path: String,
+    paths: Vec<PathBuf>,
     delimiter: u8,
     has_header: bool,
     ignore_errors: bool,
@@ -41,13 +76,22 @@ pub struct LazyCsvReader<'a> {
     comment_char: Option<u8>,
     quote_char: Option<u8>,
     null_values: Option<NullValues>,
+    encoding: CsvEncoding,
 }
 
 #[cfg(feature = "csv-file")]
 impl<'a> LazyCsvReader<'a> {
+    /// Scan a CSV file. The path may be a glob pattern, in which case all matching files are
+    /// scanned as one.
     pub fn new(path: String) -> Self {
+        Self::new_paths(vec![path.into()])
+    }
+
+    /// Scan multiple CSV files as one. The schemas of the files are unified: a column that
+    /// doesn't exist in every file is null for the files that don't have it.
+    pub fn new_paths(paths: Vec<PathBuf>) -> Self {
         LazyCsvReader {
-            path,
+            paths,
             delimiter: b',',
             has_header: true,
             ignore_errors: false,
@@ -60,6 +104,7 @@ impl<'a> LazyCsvReader<'a> {
             comment_char: None,
             quote_char: Some(b'"'),
             null_values: None,
+            encoding: CsvEncoding::LossyUtf8,
         }
     }
 
@@ -125,6 +170,12 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Set the encoding of the files. Defaults to [`CsvEncoding::LossyUtf8`].
+    pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
     /// Cache the DataFrame after reading.
     pub fn with_cache(mut self, cache: bool) -> Self {
         self.cache = cache;
@@ -137,9 +188,11 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
-    pub fn finish(self) -> LazyFrame {
+    /// Create the LazyFrame. Fails if the paths match no file or if the schemas of the files
+    /// cannot be read or combined.
+    pub fn finish(self) -> Result<LazyFrame> {
         let mut lf: LazyFrame = LogicalPlanBuilder::scan_csv(
-            self.path,
+            self.paths,
             self.delimiter,
             self.has_header,
             self.ignore_errors,
@@ -152,15 +205,99 @@ impl<'a> LazyCsvReader<'a> {
             self.comment_char,
             self.quote_char,
             self.null_values,
-        )
+            self.encoding,
+        )?
         .build()
         .into();
         lf.opt_state.agg_scan_projection = true;
-        lf
+        Ok(lf)
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg(feature = "ipc")]
+pub struct ScanArgsIpc {
+    pub stop_after_n_rows: Option<usize>,
+    pub cache: bool,
+    /// Read the file through a memory map instead of the file handle. The arrays are still
+    /// copied out of the mapped bytes.
+    pub memmap: bool,
+}
+
+#[cfg(feature = "ipc")]
+impl Default for ScanArgsIpc {
+    fn default() -> Self {
+        Self {
+            stop_after_n_rows: None,
+            cache: true,
+            memmap: true,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg(feature = "json")]
+pub struct ScanArgsNdJson {
+    /// Number of lines used to infer the schema. If `None`, all lines are used.
+    pub infer_schema_length: Option<usize>,
+    pub stop_after_n_rows: Option<usize>,
+    pub cache: bool,
+    /// Read values that do not match the type of their column as null.
+    pub ignore_errors: bool,
+}
+
+#[cfg(feature = "json")]
+impl Default for ScanArgsNdJson {
+    fn default() -> Self {
+        Self {
+            infer_schema_length: Some(100),
+            stop_after_n_rows: None,
+            cache: true,
+            ignore_errors: false,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+#[cfg(feature = "parquet")]
+pub struct SinkArgsParquet {
+    pub compression: ParquetCompression,
+    /// Write the min, max and null count of every column chunk.
+    pub statistics: bool,
+    /// Target size of the row groups. If `None`, every batch is written as a row group.
+    pub row_group_size: Option<RowGroupSize>,
+}
+
+#[cfg(feature = "parquet")]
+impl Default for SinkArgsParquet {
+    fn default() -> Self {
+        Self {
+            compression: ParquetCompression::Snappy,
+            statistics: false,
+            row_group_size: None,
+        }
     }
 }
 
 #[derive(Clone, Debug)]
+#[cfg(feature = "csv-file")]
+pub struct SinkArgsCsv {
+    pub has_header: bool,
+    pub options: SerializeOptions,
+}
+
+#[cfg(feature = "csv-file")]
+impl Default for SinkArgsCsv {
+    fn default() -> Self {
+        Self {
+            has_header: true,
+            options: SerializeOptions::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct JoinOptions {
     pub allow_parallel: bool,
     pub force_parallel: bool,
@@ -224,6 +361,14 @@ pub struct OptState {
     pub aggregate_pushdown: bool,
     pub global_string_cache: bool,
     pub join_pruning: bool,
+    /// Execute identical subplans only once
+    pub common_subplan_elimination: bool,
+    /// Run the parts of the query that support it with the streaming engine
+    pub streaming: bool,
+    /// The number of bytes the state of a streaming sort or aggregation may take before it is
+    /// spilled to disk. Defaults to `POLARS_STREAMING_MEMORY_BUDGET`, or 1 GiB. Only used with
+    /// the `spill` feature.
+    pub memory_budget: Option<usize>,
 }
 
 impl Default for OptState {
@@ -235,9 +380,12 @@ impl Default for OptState {
             simplify_expr: true,
             global_string_cache: true,
             join_pruning: true,
+            common_subplan_elimination: true,
             // will be toggled by a scan operation such as csv scan or parquet scan
             agg_scan_projection: false,
             aggregate_pushdown: false,
+            streaming: false,
+            memory_budget: None,
         }
     }
 }
@@ -252,14 +400,78 @@ impl LazyFrame {
         logical_plan.schema().clone()
     }
 
-    /// Create a LazyFrame directly from a parquet scan.
+    /// Create a LazyFrame directly from a parquet scan. The path may be a glob pattern, in which
+    /// case all matching files are scanned as one.
+    #[cfg(feature = "parquet")]
+    pub fn new_from_parquet(
+        path: String,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+    ) -> Result<Self> {
+        Self::new_from_parquet_files(vec![path.into()], stop_after_n_rows, cache)
+    }
+
+    /// Create a LazyFrame from a scan of multiple parquet files. The files are read in parallel
+    /// and their schemas are unified.
     #[cfg(feature = "parquet")]
-    pub fn new_from_parquet(path: String, stop_after_n_rows: Option<usize>, cache: bool) -> Self {
-        let mut lf: LazyFrame = LogicalPlanBuilder::scan_parquet(path, stop_after_n_rows, cache)
+    pub fn new_from_parquet_files(
+        paths: Vec<PathBuf>,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+    ) -> Result<Self> {
+        let mut lf: LazyFrame = LogicalPlanBuilder::scan_parquet(paths, stop_after_n_rows, cache)?
+            .build()
+            .into();
+        lf.opt_state.agg_scan_projection = true;
+        Ok(lf)
+    }
+
+    /// Create a LazyFrame from a hive partitioned parquet dataset, e.g.
+    /// `root/year=2021/month=10/part-0.parquet`. The `key=value` directories become columns,
+    /// and filters on those columns skip the directories that don't match.
+    #[cfg(feature = "parquet")]
+    pub fn new_from_parquet_dataset(
+        root: String,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+    ) -> Result<Self> {
+        let mut lf: LazyFrame =
+            LogicalPlanBuilder::scan_parquet_dataset(root, stop_after_n_rows, cache)?
+                .build()
+                .into();
+        lf.opt_state.agg_scan_projection = true;
+        Ok(lf)
+    }
+
+    /// Create a LazyFrame directly from an Arrow IPC file scan.
+    #[cfg(feature = "ipc")]
+    pub fn scan_ipc(path: String, args: ScanArgsIpc) -> Result<Self> {
+        let options = IpcScanOptions {
+            stop_after_n_rows: args.stop_after_n_rows,
+            with_columns: None,
+            cache: args.cache,
+            memmap: args.memmap,
+        };
+        let mut lf: LazyFrame = LogicalPlanBuilder::scan_ipc(path, options)?.build().into();
+        lf.opt_state.agg_scan_projection = true;
+        Ok(lf)
+    }
+
+    /// Create a LazyFrame directly from a newline delimited JSON scan.
+    #[cfg(feature = "json")]
+    pub fn scan_ndjson(path: String, args: ScanArgsNdJson) -> Result<Self> {
+        let options = NdJsonScanOptions {
+            infer_schema_length: args.infer_schema_length,
+            stop_after_n_rows: args.stop_after_n_rows,
+            with_columns: None,
+            cache: args.cache,
+            ignore_errors: args.ignore_errors,
+        };
+        let mut lf: LazyFrame = LogicalPlanBuilder::scan_ndjson(path, options)?
             .build()
             .into();
         lf.opt_state.agg_scan_projection = true;
-        lf
+        Ok(lf)
     }
 
     /// Get a dot language representation of the LogicalPlan.
@@ -346,6 +558,26 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle common subplan elimination optimization.
+    pub fn with_common_subplan_elimination(mut self, toggle: bool) -> Self {
+        self.opt_state.common_subplan_elimination = toggle;
+        self
+    }
+
+    /// Run the parts of the query that support it with the streaming engine.
+    pub fn with_streaming(mut self, toggle: bool) -> Self {
+        self.opt_state.streaming = toggle;
+        self
+    }
+
+    /// Spill the state of a streaming sort or aggregation to disk once it exceeds `budget`
+    /// bytes. Only the streaming engine spills, the in-memory engine ignores the budget.
+    #[cfg(feature = "spill")]
+    pub fn with_memory_budget(mut self, budget: usize) -> Self {
+        self.opt_state.memory_budget = Some(budget);
+        self
+    }
+
     /// Describe the logical plan.
     pub fn describe_plan(&self) -> String {
         self.logical_plan.describe()
@@ -547,8 +779,14 @@ impl LazyFrame {
         let projection_pushdown = self.opt_state.projection_pushdown;
         let type_coercion = self.opt_state.type_coercion;
         let simplify_expr = self.opt_state.simplify_expr;
-
-        #[cfg(any(feature = "parquet", feature = "csv-file"))]
+        let common_subplan_elimination = self.opt_state.common_subplan_elimination;
+
+        #[cfg(any(
+            feature = "parquet",
+            feature = "csv-file",
+            feature = "ipc",
+            feature = "json"
+        ))]
         let agg_scan_projection = self.opt_state.agg_scan_projection;
         let aggregate_pushdown = self.opt_state.aggregate_pushdown;
 
@@ -582,6 +820,12 @@ impl LazyFrame {
             lp_arena.replace(lp_top, alp);
         }
 
+        // after the pushdowns, so that they cannot push different projections or predicates
+        // into subplans that share a cache
+        if common_subplan_elimination {
+            CommonSubplanElimination {}.optimize(lp_top, lp_arena, expr_arena);
+        }
+
         if type_coercion {
             rules.push(Box::new(TypeCoercionRule {}))
         }
@@ -594,7 +838,12 @@ impl LazyFrame {
             rules.push(Box::new(AggregatePushdown::new()))
         }
 
-        #[cfg(any(feature = "parquet", feature = "csv-file"))]
+        #[cfg(any(
+            feature = "parquet",
+            feature = "csv-file",
+            feature = "ipc",
+            feature = "json"
+        ))]
         if agg_scan_projection {
             // scan the LP to aggregate all the column used in scans
             // these columns will be added to the state of the AggScanProjection rule
@@ -653,9 +902,14 @@ impl LazyFrame {
     pub fn collect(self) -> Result<DataFrame> {
         #[cfg(feature = "dtype-categorical")]
         let use_string_cache = self.opt_state.global_string_cache;
+        let streaming = self.opt_state.streaming;
+        let memory_budget = self.opt_state.memory_budget;
         let mut expr_arena = Arena::with_capacity(256);
         let mut lp_arena = Arena::with_capacity(128);
         let lp_top = self.optimize(&mut lp_arena, &mut expr_arena)?;
+        if streaming {
+            insert_streaming_nodes(lp_top, &mut lp_arena, &mut expr_arena, memory_budget)?;
+        }
 
         // if string cache was already set, we skip this and global settings are respected
         #[cfg(feature = "dtype-categorical")]
@@ -675,6 +929,114 @@ impl LazyFrame {
         out
     }
 
+    /// Execute the query with the streaming engine and collect the result.
+    ///
+    /// Scans followed by filters, projections, `with_columns` and inner and left joins are
+    /// processed in morsels, on all threads. They end in a groupby, a sort or the collection of
+    /// the result. Only the state of the groupby and the sort, and the right side of the joins are
+    /// held in memory. The parts of the query that are not supported, e.g. a groupby with a
+    /// `first` aggregation, run on the default in-memory engine.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example() -> Result<DataFrame> {
+    ///     LazyCsvReader::new("reddit.csv".into())
+    ///         .finish()?
+    ///         .filter(col("comment_karma").gt(lit(0)))
+    ///         .groupby([col("name")])
+    ///         .agg([col("link_karma").sum(), col("comment_karma").mean()])
+    ///         .collect_streaming()
+    /// }
+    /// ```
+    pub fn collect_streaming(self) -> Result<DataFrame> {
+        self.with_streaming(true).collect()
+    }
+
+    /// Optimize the query and write its result with the writer created by `create_writer`.
+    #[cfg(any(feature = "parquet", feature = "ipc", feature = "csv-file"))]
+    fn sink<F>(self, create_writer: F) -> Result<()>
+    where
+        F: FnOnce(&Schema) -> Result<Box<dyn SinkWriter>>,
+    {
+        #[cfg(feature = "dtype-categorical")]
+        let use_string_cache = self.opt_state.global_string_cache;
+        let mut expr_arena = Arena::with_capacity(256);
+        let mut lp_arena = Arena::with_capacity(128);
+        let lp_top = self.optimize(&mut lp_arena, &mut expr_arena)?;
+
+        #[cfg(feature = "dtype-categorical")]
+        if use_string_cache {
+            toggle_string_cache(use_string_cache);
+        }
+        let out = sink::sink(lp_top, &mut lp_arena, &mut expr_arena, create_writer);
+        #[cfg(feature = "dtype-categorical")]
+        if use_string_cache {
+            toggle_string_cache(!use_string_cache);
+        }
+        out
+    }
+
+    /// Execute the query and write the result to a parquet file.
+    ///
+    /// A scan of a single file followed by filters, projections and `with_columns` of row-wise
+    /// expressions is executed batch by batch, so the result is never held in memory at once.
+    /// Other queries are collected before they are written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example() -> Result<()> {
+    ///     LazyCsvReader::new("reddit.csv".into())
+    ///         .finish()?
+    ///         .filter(col("comment_karma").gt(lit(0)))
+    ///         .sink_parquet("reddit.parquet".into(), Default::default())
+    /// }
+    /// ```
+    #[cfg(feature = "parquet")]
+    pub fn sink_parquet(self, path: PathBuf, args: SinkArgsParquet) -> Result<()> {
+        self.sink(move |schema| {
+            let file = std::fs::File::create(&path)?;
+            let writer = ParquetWriter::new(file)
+                .with_compression(args.compression)
+                .with_statistics(args.statistics)
+                .with_row_group_size(args.row_group_size)
+                .batched(schema)?;
+            Ok(Box::new(writer) as Box<dyn SinkWriter>)
+        })
+    }
+
+    /// Execute the query and write the result to an Arrow IPC file. Like
+    /// [`sink_parquet`](LazyFrame::sink_parquet), simple queries are executed batch by batch.
+    #[cfg(feature = "ipc")]
+    pub fn sink_ipc(self, path: PathBuf) -> Result<()> {
+        self.sink(move |schema| {
+            let file = std::fs::File::create(&path)?;
+            let writer = IpcWriter::new(file).batched(schema)?;
+            Ok(Box::new(writer) as Box<dyn SinkWriter>)
+        })
+    }
+
+    /// Execute the query and write the result to a csv file. Like
+    /// [`sink_parquet`](LazyFrame::sink_parquet), simple queries are executed batch by batch.
+    #[cfg(feature = "csv-file")]
+    pub fn sink_csv(self, path: PathBuf, args: SinkArgsCsv) -> Result<()> {
+        self.sink(move |schema| {
+            let file = std::fs::File::create(&path)?;
+            let writer = CsvWriter::new(file)
+                .has_header(args.has_header)
+                .with_serialize_options(args.options)
+                .batched(schema)?;
+            Ok(Box::new(writer) as Box<dyn SinkWriter>)
+        })
+    }
+
     /// Filter by some predicate expression.
     ///
     /// # Example
@@ -6,6 +6,32 @@ use polars_core::prelude::*;
 use polars_core::utils::{get_supertype, Arena, Node};
 use std::sync::Arc;
 
+/// Output type of an addition or subtraction between temporal types where at least one side
+/// is not a plain date/datetime, e.g. `datetime - datetime = duration`.
+#[cfg(feature = "dtype-duration")]
+pub(crate) fn temporal_arithmetic_type(
+    op: Operator,
+    left: &DataType,
+    right: &DataType,
+) -> Option<DataType> {
+    use DataType::*;
+    match (op, left, right) {
+        // both sides are cast to their supertype, dates are subtracted as millisecond datetimes
+        (Operator::Minus, Date | Datetime(_, _), Date | Datetime(_, _)) => {
+            match get_supertype(left, right).ok()? {
+                Datetime(tu, _) => Some(Duration(tu)),
+                _ => Some(Duration(TimeUnit::Milliseconds)),
+            }
+        }
+        (Operator::Minus | Operator::Plus, Datetime(tu, tz), Duration(_))
+        | (Operator::Plus, Duration(_), Datetime(tu, tz)) => Some(Datetime(*tu, tz.clone())),
+        (Operator::Minus | Operator::Plus, Date, Duration(tu))
+        | (Operator::Plus, Duration(tu), Date) => Some(Datetime(*tu, None)),
+        (Operator::Minus | Operator::Plus, Duration(tu), Duration(_)) => Some(Duration(*tu)),
+        _ => None,
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum AAggExpr {
     Min(Node),
@@ -160,6 +186,12 @@ impl AExpr {
                     | Operator::LtEq
                     | Operator::GtEq
                     | Operator::Or => DataType::Boolean,
+                    #[cfg(feature = "dtype-duration")]
+                    Operator::Minus | Operator::Plus
+                        if temporal_arithmetic_type(*op, &left_type, &right_type).is_some() =>
+                    {
+                        temporal_arithmetic_type(*op, &left_type, &right_type).unwrap()
+                    }
                     _ => get_supertype(&left_type, &right_type)?,
                 };
 
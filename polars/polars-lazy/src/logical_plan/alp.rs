@@ -1,3 +1,9 @@
+#[cfg(feature = "parquet")]
+use crate::logical_plan::HivePartitions;
+#[cfg(feature = "ipc")]
+use crate::logical_plan::IpcScanOptions;
+#[cfg(feature = "json")]
+use crate::logical_plan::NdJsonScanOptions;
 use crate::logical_plan::{det_melt_schema, Context, CsvParserOptions};
 use crate::prelude::*;
 use crate::utils::{aexprs_to_schema, PushNode};
@@ -5,7 +11,12 @@ use ahash::RandomState;
 use polars_core::prelude::*;
 use polars_core::utils::{Arena, Node};
 use std::collections::HashSet;
-#[cfg(any(feature = "csv-file", feature = "parquet"))]
+#[cfg(any(
+    feature = "csv-file",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -29,7 +40,7 @@ pub enum ALogicalPlan {
     },
     #[cfg(feature = "csv-file")]
     CsvScan {
-        path: PathBuf,
+        paths: Arc<Vec<PathBuf>>,
         schema: SchemaRef,
         options: CsvParserOptions,
         predicate: Option<Node>,
@@ -37,13 +48,30 @@ pub enum ALogicalPlan {
     },
     #[cfg(feature = "parquet")]
     ParquetScan {
-        path: PathBuf,
+        paths: Arc<Vec<PathBuf>>,
         schema: SchemaRef,
         with_columns: Option<Vec<String>>,
         predicate: Option<Node>,
         aggregate: Vec<Node>,
         stop_after_n_rows: Option<usize>,
         cache: bool,
+        partitions: Option<Arc<HivePartitions>>,
+    },
+    #[cfg(feature = "ipc")]
+    IpcScan {
+        path: PathBuf,
+        schema: SchemaRef,
+        options: IpcScanOptions,
+        predicate: Option<Node>,
+        aggregate: Vec<Node>,
+    },
+    #[cfg(feature = "json")]
+    NdJsonScan {
+        path: PathBuf,
+        schema: SchemaRef,
+        options: NdJsonScanOptions,
+        predicate: Option<Node>,
+        aggregate: Vec<Node>,
     },
     DataFrameScan {
         df: Arc<DataFrame>,
@@ -72,6 +100,7 @@ pub enum ALogicalPlan {
     },
     Cache {
         input: Node,
+        id: usize,
     },
     Aggregate {
         input: Node,
@@ -125,11 +154,15 @@ impl ALogicalPlan {
     pub(crate) fn schema<'a>(&'a self, arena: &'a Arena<ALogicalPlan>) -> &'a Schema {
         use ALogicalPlan::*;
         match self {
-            Cache { input } => arena.get(*input).schema(arena),
+            Cache { input, .. } => arena.get(*input).schema(arena),
             Sort { input, .. } => arena.get(*input).schema(arena),
             Explode { input, .. } => arena.get(*input).schema(arena),
             #[cfg(feature = "parquet")]
             ParquetScan { schema, .. } => schema,
+            #[cfg(feature = "ipc")]
+            IpcScan { schema, .. } => schema,
+            #[cfg(feature = "json")]
+            NdJsonScan { schema, .. } => schema,
             DataFrameScan { schema, .. } => schema,
             Selection { input, .. } => arena.get(*input).schema(arena),
             #[cfg(feature = "csv-file")]
@@ -162,17 +195,42 @@ impl ALogicalPlan {
     ) -> bool {
         use crate::logical_plan::iterator::ArenaLpIter;
         use std::fs::canonicalize;
+        use std::path::Path;
+
+        // a path that cannot be resolved is never considered the same file
+        let same_file = |a: &Path, b: &Path| {
+            matches!(
+                (canonicalize(a), canonicalize(b)),
+                (Ok(a), Ok(b)) if a == b
+            )
+        };
 
         let cmp = |(node_left, node_right)| {
             use ALogicalPlan::*;
             match (lp_arena.get(node_left), lp_arena.get(node_right)) {
                 #[cfg(feature = "csv-file")]
-                (CsvScan { path: path_a, .. }, CsvScan { path: path_b, .. }) => {
-                    canonicalize(path_a).unwrap() == canonicalize(path_b).unwrap()
+                (CsvScan { paths: paths_a, .. }, CsvScan { paths: paths_b, .. }) => {
+                    paths_a.len() == paths_b.len()
+                        && paths_a
+                            .iter()
+                            .zip(paths_b.iter())
+                            .all(|(a, b)| same_file(a, b))
                 }
                 #[cfg(feature = "parquet")]
-                (ParquetScan { path: path_a, .. }, ParquetScan { path: path_b, .. }) => {
-                    canonicalize(path_a).unwrap() == canonicalize(path_b).unwrap()
+                (ParquetScan { paths: paths_a, .. }, ParquetScan { paths: paths_b, .. }) => {
+                    paths_a.len() == paths_b.len()
+                        && paths_a
+                            .iter()
+                            .zip(paths_b.iter())
+                            .all(|(a, b)| same_file(a, b))
+                }
+                #[cfg(feature = "ipc")]
+                (IpcScan { path: path_a, .. }, IpcScan { path: path_b, .. }) => {
+                    same_file(path_a, path_b)
+                }
+                #[cfg(feature = "json")]
+                (NdJsonScan { path: path_a, .. }, NdJsonScan { path: path_b, .. }) => {
+                    same_file(path_a, path_b)
                 }
                 (DataFrameScan { df: df_a, .. }, DataFrameScan { df: df_b, .. }) => {
                     df_a.ptr_equal(df_b)
@@ -295,7 +353,10 @@ impl ALogicalPlan {
                 input: inputs[0],
                 columns: columns.clone(),
             },
-            Cache { .. } => Cache { input: inputs[0] },
+            Cache { id, .. } => Cache {
+                input: inputs[0],
+                id: *id,
+            },
             Distinct {
                 maintain_order,
                 subset,
@@ -312,12 +373,13 @@ impl ALogicalPlan {
             },
             #[cfg(feature = "parquet")]
             ParquetScan {
-                path,
+                paths,
                 schema,
                 with_columns,
                 predicate,
                 stop_after_n_rows,
                 cache,
+                partitions,
                 ..
             } => {
                 let mut new_predicate = None;
@@ -326,18 +388,19 @@ impl ALogicalPlan {
                 }
 
                 ParquetScan {
-                    path: path.clone(),
+                    paths: paths.clone(),
                     schema: schema.clone(),
                     with_columns: with_columns.clone(),
                     predicate: new_predicate,
                     aggregate: exprs,
                     stop_after_n_rows: *stop_after_n_rows,
                     cache: *cache,
+                    partitions: partitions.clone(),
                 }
             }
             #[cfg(feature = "csv-file")]
             CsvScan {
-                path,
+                paths,
                 schema,
                 predicate,
                 options,
@@ -348,6 +411,46 @@ impl ALogicalPlan {
                     new_predicate = exprs.pop()
                 }
                 CsvScan {
+                    paths: paths.clone(),
+                    schema: schema.clone(),
+                    options: options.clone(),
+                    predicate: new_predicate,
+                    aggregate: exprs,
+                }
+            }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                predicate,
+                options,
+                ..
+            } => {
+                let mut new_predicate = None;
+                if predicate.is_some() {
+                    new_predicate = exprs.pop()
+                }
+                IpcScan {
+                    path: path.clone(),
+                    schema: schema.clone(),
+                    options: options.clone(),
+                    predicate: new_predicate,
+                    aggregate: exprs,
+                }
+            }
+            #[cfg(feature = "json")]
+            NdJsonScan {
+                path,
+                schema,
+                predicate,
+                options,
+                ..
+            } => {
+                let mut new_predicate = None;
+                if predicate.is_some() {
+                    new_predicate = exprs.pop()
+                }
+                NdJsonScan {
                     path: path.clone(),
                     schema: schema.clone(),
                     options: options.clone(),
@@ -440,6 +543,28 @@ impl ALogicalPlan {
                     container.push(*node)
                 }
             }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                predicate,
+                aggregate,
+                ..
+            } => {
+                container.extend_from_slice(aggregate);
+                if let Some(node) = predicate {
+                    container.push(*node)
+                }
+            }
+            #[cfg(feature = "json")]
+            NdJsonScan {
+                predicate,
+                aggregate,
+                ..
+            } => {
+                container.extend_from_slice(aggregate);
+                if let Some(node) = predicate {
+                    container.push(*node)
+                }
+            }
             DataFrameScan {
                 projection,
                 selection,
@@ -496,6 +621,10 @@ impl ALogicalPlan {
             ParquetScan { .. } => return,
             #[cfg(feature = "csv-file")]
             CsvScan { .. } => return,
+            #[cfg(feature = "ipc")]
+            IpcScan { .. } => return,
+            #[cfg(feature = "json")]
+            NdJsonScan { .. } => return,
             DataFrameScan { .. } => return,
         };
         container.push_node(input)
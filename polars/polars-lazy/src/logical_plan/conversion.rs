@@ -180,13 +180,13 @@ pub(crate) fn to_alp(
         }
         #[cfg(feature = "csv-file")]
         LogicalPlan::CsvScan {
-            path,
+            paths,
             schema,
             options,
             predicate,
             aggregate,
         } => ALogicalPlan::CsvScan {
-            path,
+            paths,
             schema,
             options,
             predicate: predicate.map(|expr| to_aexpr(expr, expr_arena)),
@@ -197,15 +197,16 @@ pub(crate) fn to_alp(
         },
         #[cfg(feature = "parquet")]
         LogicalPlan::ParquetScan {
-            path,
+            paths,
             schema,
             with_columns,
             predicate,
             aggregate,
             stop_after_n_rows,
             cache,
+            partitions,
         } => ALogicalPlan::ParquetScan {
-            path,
+            paths,
             schema,
             with_columns,
             predicate: predicate.map(|expr| to_aexpr(expr, expr_arena)),
@@ -215,6 +216,41 @@ pub(crate) fn to_alp(
                 .collect(),
             stop_after_n_rows,
             cache,
+            partitions,
+        },
+        #[cfg(feature = "ipc")]
+        LogicalPlan::IpcScan {
+            path,
+            schema,
+            options,
+            predicate,
+            aggregate,
+        } => ALogicalPlan::IpcScan {
+            path,
+            schema,
+            options,
+            predicate: predicate.map(|expr| to_aexpr(expr, expr_arena)),
+            aggregate: aggregate
+                .into_iter()
+                .map(|expr| to_aexpr(expr, expr_arena))
+                .collect(),
+        },
+        #[cfg(feature = "json")]
+        LogicalPlan::NdJsonScan {
+            path,
+            schema,
+            options,
+            predicate,
+            aggregate,
+        } => ALogicalPlan::NdJsonScan {
+            path,
+            schema,
+            options,
+            predicate: predicate.map(|expr| to_aexpr(expr, expr_arena)),
+            aggregate: aggregate
+                .into_iter()
+                .map(|expr| to_aexpr(expr, expr_arena))
+                .collect(),
         },
         LogicalPlan::DataFrameScan {
             df,
@@ -274,9 +310,9 @@ pub(crate) fn to_alp(
             let input = to_alp(*input, expr_arena, lp_arena);
             ALogicalPlan::Explode { input, columns }
         }
-        LogicalPlan::Cache { input } => {
+        LogicalPlan::Cache { input, id } => {
             let input = to_alp(*input, expr_arena, lp_arena);
-            ALogicalPlan::Cache { input }
+            ALogicalPlan::Cache { input, id }
         }
         LogicalPlan::Aggregate {
             input,
@@ -622,13 +658,13 @@ pub(crate) fn node_to_lp(
         }
         #[cfg(feature = "csv-file")]
         ALogicalPlan::CsvScan {
-            path,
+            paths,
             schema,
             options,
             predicate,
             aggregate,
         } => LogicalPlan::CsvScan {
-            path,
+            paths,
             schema,
             options,
             predicate: predicate.map(|n| node_to_exp(n, expr_arena)),
@@ -636,21 +672,51 @@ pub(crate) fn node_to_lp(
         },
         #[cfg(feature = "parquet")]
         ALogicalPlan::ParquetScan {
-            path,
+            paths,
             schema,
             with_columns,
             predicate,
             aggregate,
             stop_after_n_rows,
             cache,
+            partitions,
         } => LogicalPlan::ParquetScan {
-            path,
+            paths,
             schema,
             with_columns,
             predicate: predicate.map(|n| node_to_exp(n, expr_arena)),
             aggregate: nodes_to_exprs(&aggregate, expr_arena),
             stop_after_n_rows,
             cache,
+            partitions,
+        },
+        #[cfg(feature = "ipc")]
+        ALogicalPlan::IpcScan {
+            path,
+            schema,
+            options,
+            predicate,
+            aggregate,
+        } => LogicalPlan::IpcScan {
+            path,
+            schema,
+            options,
+            predicate: predicate.map(|n| node_to_exp(n, expr_arena)),
+            aggregate: nodes_to_exprs(&aggregate, expr_arena),
+        },
+        #[cfg(feature = "json")]
+        ALogicalPlan::NdJsonScan {
+            path,
+            schema,
+            options,
+            predicate,
+            aggregate,
+        } => LogicalPlan::NdJsonScan {
+            path,
+            schema,
+            options,
+            predicate: predicate.map(|n| node_to_exp(n, expr_arena)),
+            aggregate: nodes_to_exprs(&aggregate, expr_arena),
         },
         ALogicalPlan::DataFrameScan {
             df,
@@ -707,9 +773,9 @@ pub(crate) fn node_to_lp(
             let input = Box::new(node_to_lp(input, expr_arena, lp_arena));
             LogicalPlan::Explode { input, columns }
         }
-        ALogicalPlan::Cache { input } => {
+        ALogicalPlan::Cache { input, id } => {
             let input = Box::new(node_to_lp(input, expr_arena, lp_arena));
-            LogicalPlan::Cache { input }
+            LogicalPlan::Cache { input, id }
         }
         ALogicalPlan::Aggregate {
             input,
@@ -0,0 +1,208 @@
+//! Discovery of hive partitioned datasets, e.g. `root/year=2021/month=10/part-0.parquet`.
+use polars_core::prelude::*;
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Directory name hive uses for a null partition value.
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// The partition values of the files of a hive partitioned dataset. Row `i` holds the values of
+/// the `key=value` directories of the `i`-th file of the scan.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub struct HivePartitions {
+    values: DataFrame,
+}
+
+impl HivePartitions {
+    pub(crate) fn schema(&self) -> Schema {
+        self.values.schema()
+    }
+
+    /// The partition values of all files, one row per file.
+    pub(crate) fn values(&self) -> &DataFrame {
+        &self.values
+    }
+
+    /// Only keep the partitions of the files selected by `mask`.
+    pub(crate) fn filter(&self, mask: &BooleanChunked) -> Result<Self> {
+        Ok(HivePartitions {
+            values: self.values.filter(mask)?,
+        })
+    }
+
+    /// Add the partition values of the `idx`-th file as constant columns to the DataFrame read
+    /// from that file.
+    pub(crate) fn add_columns(&self, idx: usize, df: &mut DataFrame) -> Result<()> {
+        let height = df.height();
+        let columns = self
+            .values
+            .get_columns()
+            .iter()
+            .map(|s| s.slice(idx as i64, 1).expand_at_index(0, height))
+            .collect::<Vec<_>>();
+        df.hstack_mut(&columns)?;
+        Ok(())
+    }
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .map(|name| {
+            let name = name.to_string_lossy();
+            name.starts_with('.') || name.starts_with('_')
+        })
+        .unwrap_or(false)
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        // skip files like `_SUCCESS` and `.crc` files written next to the data
+        if is_hidden(&path) {
+            continue;
+        }
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Parse the `key=value` directories between `root` and the file.
+fn parse_partitions(root: &Path, file: &Path) -> Result<Vec<(String, Option<String>)>> {
+    let relative = file.strip_prefix(root).unwrap();
+    let n_dirs = relative.components().count() - 1;
+    relative
+        .components()
+        .take(n_dirs)
+        .map(|dir| {
+            let dir = dir.as_os_str().to_string_lossy();
+            match dir.split_once('=') {
+                Some((key, value)) => {
+                    let value = if value == HIVE_DEFAULT_PARTITION {
+                        None
+                    } else {
+                        Some(value.to_string())
+                    };
+                    Ok((key.to_string(), value))
+                }
+                None => Err(PolarsError::ComputeError(
+                    format!(
+                        "directory {:?} of {:?} is not a 'key=value' partition",
+                        dir, file
+                    )
+                    .into(),
+                )),
+            }
+        })
+        .collect()
+}
+
+/// Create the partition column of `key`. Integer and float values are parsed, everything else
+/// stays a string.
+fn partition_column(key: &str, values: &[Option<String>]) -> Series {
+    let ints = values
+        .iter()
+        .map(|opt_v| opt_v.as_ref().map(|v| v.parse::<i64>()).transpose())
+        .collect::<std::result::Result<Vec<_>, _>>();
+    if let Ok(ints) = ints {
+        return Series::new(key, &ints);
+    }
+    let floats = values
+        .iter()
+        .map(|opt_v| opt_v.as_ref().map(|v| v.parse::<f64>()).transpose())
+        .collect::<std::result::Result<Vec<_>, _>>();
+    if let Ok(floats) = floats {
+        return Series::new(key, &floats);
+    }
+    let strings = values.iter().map(|v| v.as_deref()).collect::<Vec<_>>();
+    Series::new(key, &strings)
+}
+
+/// Find all data files under `root` and their partition values. The files are sorted by path.
+pub(crate) fn discover(root: &Path) -> Result<(Vec<PathBuf>, HivePartitions)> {
+    let mut files = vec![];
+    collect_files(root, &mut files)?;
+    if files.is_empty() {
+        return Err(PolarsError::NoData(
+            format!("no files found in {:?}", root).into(),
+        ));
+    }
+    files.sort();
+
+    let mut keys: Option<Vec<String>> = None;
+    let mut values: Vec<Vec<Option<String>>> = vec![];
+    for file in &files {
+        let partitions = parse_partitions(root, file)?;
+        let file_keys = partitions.iter().map(|(key, _)| key.clone()).collect();
+        match &keys {
+            Some(keys) if keys != &file_keys => {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "partitions of {:?} don't match the partitions {:?} of the dataset",
+                        file, keys
+                    )
+                    .into(),
+                ))
+            }
+            Some(_) => {}
+            None => {
+                values = vec![Vec::with_capacity(files.len()); partitions.len()];
+                keys = Some(file_keys);
+            }
+        }
+        for (column, (_, value)) in values.iter_mut().zip(partitions) {
+            column.push(value)
+        }
+    }
+
+    let columns = keys
+        .unwrap()
+        .iter()
+        .zip(&values)
+        .map(|(key, values)| partition_column(key, values))
+        .collect();
+    Ok((
+        files,
+        HivePartitions {
+            values: DataFrame::new(columns)?,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_discover_partitions() -> Result<()> {
+        let root = std::env::temp_dir().join("polars_test_discover_partitions");
+        for (year, month) in [("2021", "9"), ("2021", "10"), (HIVE_DEFAULT_PARTITION, "1")] {
+            let dir = root
+                .join(format!("year={}", year))
+                .join(format!("month={}", month));
+            std::fs::create_dir_all(&dir)?;
+            std::fs::write(dir.join("part-0.parquet"), "")?;
+        }
+        std::fs::write(root.join("_SUCCESS"), "")?;
+
+        let (files, partitions) = discover(&root)?;
+        assert_eq!(files.len(), 3);
+        let values = partitions.values();
+        assert_eq!(values.get_column_names(), &["year", "month"]);
+        // sorted by path
+        assert_eq!(
+            Vec::from(values.column("year")?.i64()?),
+            &[Some(2021), Some(2021), None]
+        );
+        assert_eq!(
+            Vec::from(values.column("month")?.i64()?),
+            &[Some(10), Some(9), Some(1)]
+        );
+        Ok(())
+    }
+}
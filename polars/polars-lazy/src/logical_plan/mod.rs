@@ -1,9 +1,15 @@
 use std::collections::HashSet;
-#[cfg(any(feature = "csv-file", feature = "parquet"))]
+#[cfg(any(
+    feature = "csv-file",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
 use std::path::PathBuf;
 use std::{
     cell::Cell,
     fmt::{self, Debug, Formatter, Write},
+    sync::atomic::{AtomicUsize, Ordering},
     sync::Arc,
 };
 
@@ -16,8 +22,14 @@ use polars_core::prelude::*;
 use polars_core::utils::chrono::NaiveDateTime;
 #[cfg(feature = "csv-file")]
 use polars_io::csv_core::utils::infer_file_schema;
+#[cfg(feature = "ipc")]
+use polars_io::ipc::IpcReader;
+#[cfg(feature = "json")]
+use polars_io::ndjson_core::ndjson::infer_schema as infer_ndjson_schema;
 #[cfg(feature = "parquet")]
-use polars_io::{parquet::ParquetReader, SerReader};
+use polars_io::parquet::ParquetReader;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+use polars_io::SerReader;
 
 use crate::logical_plan::LogicalPlan::DataFrameScan;
 use crate::utils::{
@@ -25,20 +37,36 @@ use crate::utils::{
     rename_expr_root_name,
 };
 use crate::{prelude::*, utils};
-use polars_io::csv::NullValues;
-use polars_io::csv_core::utils::get_reader_bytes;
+use polars_io::csv::{CsvEncoding, NullValues};
+use polars_io::csv_core::utils::{get_reader_bytes, transcode_to_utf8};
+#[cfg(feature = "serde-lazy")]
+use serde::{Deserialize, Serialize};
 
 pub(crate) mod aexpr;
 pub(crate) mod alp;
 pub(crate) mod conversion;
+#[cfg(feature = "parquet")]
+pub(crate) mod hive;
 pub(crate) mod iterator;
 pub(crate) mod optimizer;
 mod projection;
+#[cfg(feature = "serde-lazy")]
+pub(crate) mod serialize;
 use projection::*;
 
+#[cfg(feature = "parquet")]
+pub use hive::HivePartitions;
+
 // Will be set/ unset in the fetch operation to communicate overwriting the number of rows to scan.
 thread_local! {pub(crate) static FETCH_ROWS: Cell<Option<usize>> = Cell::new(None)}
 
+static CACHE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Get an id for a new `Cache` node.
+pub(crate) fn next_cache_id() -> usize {
+    CACHE_ID.fetch_add(1, Ordering::Relaxed)
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum Context {
     /// Any operation that is done on groups
@@ -67,6 +95,7 @@ impl Debug for dyn DataFrameUdf {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum LiteralValue {
     Null,
     /// A binary true or false.
@@ -103,6 +132,7 @@ pub enum LiteralValue {
         data_type: DataType,
     },
     #[cfg(all(feature = "temporal", feature = "dtype-datetime"))]
+    #[cfg_attr(feature = "serde-lazy", serde(with = "serialize::datetime"))]
     DateTime(NaiveDateTime),
     Series(NoEq<Series>),
 }
@@ -129,14 +159,15 @@ impl LiteralValue {
             LiteralValue::Utf8(_) => DataType::Utf8,
             LiteralValue::Range { data_type, .. } => data_type.clone(),
             #[cfg(all(feature = "temporal", feature = "dtype-datetime"))]
-            LiteralValue::DateTime(_) => DataType::Datetime,
+            LiteralValue::DateTime(_) => DataType::Datetime(TimeUnit::Milliseconds, None),
             LiteralValue::Series(s) => s.dtype().clone(),
             LiteralValue::Null => DataType::Null,
         }
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct CsvParserOptions {
     pub(crate) delimiter: u8,
     pub(crate) comment_char: Option<u8>,
@@ -149,10 +180,33 @@ pub struct CsvParserOptions {
     pub(crate) ignore_errors: bool,
     pub(crate) cache: bool,
     pub(crate) null_values: Option<NullValues>,
+    pub(crate) encoding: CsvEncoding,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+#[cfg(feature = "ipc")]
+pub struct IpcScanOptions {
+    pub(crate) stop_after_n_rows: Option<usize>,
+    pub(crate) with_columns: Option<Vec<String>>,
+    pub(crate) cache: bool,
+    pub(crate) memmap: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+#[cfg(feature = "json")]
+pub struct NdJsonScanOptions {
+    pub(crate) infer_schema_length: Option<usize>,
+    pub(crate) stop_after_n_rows: Option<usize>,
+    pub(crate) with_columns: Option<Vec<String>>,
+    pub(crate) cache: bool,
+    pub(crate) ignore_errors: bool,
 }
 
 // https://stackoverflow.com/questions/1031076/what-are-projection-and-selection
 #[derive(Clone)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum LogicalPlan {
     /// Filter on a boolean mask
     Selection {
@@ -160,11 +214,19 @@ pub enum LogicalPlan {
         predicate: Expr,
     },
     /// Cache the input at this point in the LP
-    Cache { input: Box<LogicalPlan> },
-    /// Scan a CSV file
+    Cache {
+        input: Box<LogicalPlan>,
+        /// Caches with the same id share the cached result
+        #[cfg_attr(
+            feature = "serde-lazy",
+            serde(deserialize_with = "serialize::cache_id::deserialize")
+        )]
+        id: usize,
+    },
+    /// Scan one or more CSV files
     #[cfg(feature = "csv-file")]
     CsvScan {
-        path: PathBuf,
+        paths: Arc<Vec<PathBuf>>,
         schema: SchemaRef,
         options: CsvParserOptions,
         /// Filters at the scan level
@@ -174,15 +236,37 @@ pub enum LogicalPlan {
     },
     #[cfg(feature = "parquet")]
     #[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
-    /// Scan a Parquet file
+    /// Scan one or more Parquet files
     ParquetScan {
-        path: PathBuf,
+        paths: Arc<Vec<PathBuf>>,
         schema: SchemaRef,
         with_columns: Option<Vec<String>>,
         predicate: Option<Expr>,
         aggregate: Vec<Expr>,
         stop_after_n_rows: Option<usize>,
         cache: bool,
+        /// Partition values of a hive partitioned dataset
+        partitions: Option<Arc<HivePartitions>>,
+    },
+    #[cfg(feature = "ipc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
+    /// Scan an Arrow IPC file
+    IpcScan {
+        path: PathBuf,
+        schema: SchemaRef,
+        options: IpcScanOptions,
+        predicate: Option<Expr>,
+        aggregate: Vec<Expr>,
+    },
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    /// Scan a newline delimited JSON file
+    NdJsonScan {
+        path: PathBuf,
+        schema: SchemaRef,
+        options: NdJsonScanOptions,
+        predicate: Option<Expr>,
+        aggregate: Vec<Expr>,
     },
     // we keep track of the projection and selection as it is cheaper to first project and then filter
     /// In memory DataFrame
@@ -211,6 +295,7 @@ pub enum LogicalPlan {
         keys: Arc<Vec<Expr>>,
         aggs: Vec<Expr>,
         schema: SchemaRef,
+        #[cfg_attr(feature = "serde-lazy", serde(with = "serialize::opt_udf"))]
         apply: Option<Arc<dyn DataFrameUdf>>,
         maintain_order: bool,
     },
@@ -262,6 +347,7 @@ pub enum LogicalPlan {
     /// A User Defined Function
     Udf {
         input: Box<LogicalPlan>,
+        #[cfg_attr(feature = "serde-lazy", serde(with = "serialize::udf"))]
         function: Arc<dyn DataFrameUdf>,
         ///  allow predicate pushdown optimizations
         predicate_pd: bool,
@@ -288,10 +374,10 @@ impl fmt::Debug for LogicalPlan {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use LogicalPlan::*;
         match self {
-            Cache { input } => write!(f, "CACHE {:?}", input),
+            Cache { input, .. } => write!(f, "CACHE {:?}", input),
             #[cfg(feature = "parquet")]
             ParquetScan {
-                path,
+                paths,
                 schema,
                 with_columns,
                 predicate,
@@ -305,6 +391,50 @@ impl fmt::Debug for LogicalPlan {
                 write!(
                     f,
                     "PARQUET SCAN {}; PROJECT {}/{} COLUMNS; SELECTION: {:?}",
+                    fmt_paths(paths),
+                    n_columns,
+                    total_columns,
+                    predicate
+                )
+            }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                options,
+                predicate,
+                ..
+            } => {
+                let total_columns = schema.fields().len();
+                let mut n_columns = "*".to_string();
+                if let Some(columns) = &options.with_columns {
+                    n_columns = format!("{}", columns.len());
+                }
+                write!(
+                    f,
+                    "IPC SCAN {}; PROJECT {}/{} COLUMNS; SELECTION: {:?}",
+                    path.to_string_lossy(),
+                    n_columns,
+                    total_columns,
+                    predicate
+                )
+            }
+            #[cfg(feature = "json")]
+            NdJsonScan {
+                path,
+                schema,
+                options,
+                predicate,
+                ..
+            } => {
+                let total_columns = schema.fields().len();
+                let mut n_columns = "*".to_string();
+                if let Some(columns) = &options.with_columns {
+                    n_columns = format!("{}", columns.len());
+                }
+                write!(
+                    f,
+                    "NDJSON SCAN {}; PROJECT {}/{} COLUMNS; SELECTION: {:?}",
                     path.to_string_lossy(),
                     n_columns,
                     total_columns,
@@ -319,7 +449,7 @@ impl fmt::Debug for LogicalPlan {
             }
             #[cfg(feature = "csv-file")]
             CsvScan {
-                path,
+                paths,
                 options,
                 schema,
                 predicate,
@@ -333,7 +463,7 @@ impl fmt::Debug for LogicalPlan {
                 write!(
                     f,
                     "CSV SCAN {}; PROJECT {}/{} COLUMNS; SELECTION: {:?}",
-                    path.to_string_lossy(),
+                    fmt_paths(paths),
                     n_columns,
                     total_columns,
                     predicate
@@ -418,6 +548,16 @@ impl fmt::Debug for LogicalPlan {
     }
 }
 
+#[cfg(any(feature = "parquet", feature = "csv-file"))]
+fn fmt_paths(paths: &[PathBuf]) -> String {
+    match paths {
+        // a hive partitioned scan may have all its files pruned
+        [] => "no files".to_string(),
+        [path] => path.to_string_lossy().into_owned(),
+        [path, rest @ ..] => format!("{} and {} other files", path.to_string_lossy(), rest.len()),
+    }
+}
+
 fn fmt_predicate(predicate: Option<&Expr>) -> String {
     if let Some(predicate) = predicate {
         let n = 25;
@@ -464,7 +604,7 @@ impl LogicalPlan {
         use LogicalPlan::*;
         let (branch, id) = id;
         match self {
-            Cache { input } => {
+            Cache { input, .. } => {
                 let current_node = format!("CACHE [{:?}]", (branch, id));
                 self.write_dot(acc_str, prev_node, &current_node, id)?;
                 input.dot(acc_str, (branch, id + 1), &current_node)
@@ -477,7 +617,7 @@ impl LogicalPlan {
             }
             #[cfg(feature = "csv-file")]
             CsvScan {
-                path,
+                paths,
                 options,
                 schema,
                 predicate,
@@ -492,7 +632,7 @@ impl LogicalPlan {
 
                 let current_node = format!(
                     "CSV SCAN {};\nπ {}/{};\nσ {}\n[{:?}]",
-                    path.to_string_lossy(),
+                    fmt_paths(paths),
                     n_columns,
                     total_columns,
                     pred,
@@ -622,7 +762,7 @@ impl LogicalPlan {
             }
             #[cfg(feature = "parquet")]
             ParquetScan {
-                path,
+                paths,
                 schema,
                 with_columns,
                 predicate,
@@ -637,6 +777,66 @@ impl LogicalPlan {
                 let pred = fmt_predicate(predicate.as_ref());
                 let current_node = format!(
                     "PARQUET SCAN {};\nπ {}/{};\nσ {} [{:?}]",
+                    fmt_paths(paths),
+                    n_columns,
+                    total_columns,
+                    pred,
+                    (branch, id)
+                );
+                if id == 0 {
+                    self.write_dot(acc_str, prev_node, &current_node, id)?;
+                    write!(acc_str, "\"{}\"", current_node)
+                } else {
+                    self.write_dot(acc_str, prev_node, &current_node, id)
+                }
+            }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                options,
+                predicate,
+                ..
+            } => {
+                let total_columns = schema.fields().len();
+                let mut n_columns = "*".to_string();
+                if let Some(columns) = &options.with_columns {
+                    n_columns = format!("{}", columns.len());
+                }
+
+                let pred = fmt_predicate(predicate.as_ref());
+                let current_node = format!(
+                    "IPC SCAN {};\nπ {}/{};\nσ {} [{:?}]",
+                    path.to_string_lossy(),
+                    n_columns,
+                    total_columns,
+                    pred,
+                    (branch, id)
+                );
+                if id == 0 {
+                    self.write_dot(acc_str, prev_node, &current_node, id)?;
+                    write!(acc_str, "\"{}\"", current_node)
+                } else {
+                    self.write_dot(acc_str, prev_node, &current_node, id)
+                }
+            }
+            #[cfg(feature = "json")]
+            NdJsonScan {
+                path,
+                schema,
+                options,
+                predicate,
+                ..
+            } => {
+                let total_columns = schema.fields().len();
+                let mut n_columns = "*".to_string();
+                if let Some(columns) = &options.with_columns {
+                    n_columns = format!("{}", columns.len());
+                }
+
+                let pred = fmt_predicate(predicate.as_ref());
+                let current_node = format!(
+                    "NDJSON SCAN {};\nπ {}/{};\nσ {} [{:?}]",
                     path.to_string_lossy(),
                     n_columns,
                     total_columns,
@@ -686,11 +886,15 @@ impl LogicalPlan {
     pub(crate) fn schema(&self) -> &SchemaRef {
         use LogicalPlan::*;
         match self {
-            Cache { input } => input.schema(),
+            Cache { input, .. } => input.schema(),
             Sort { input, .. } => input.schema(),
             Explode { input, .. } => input.schema(),
             #[cfg(feature = "parquet")]
             ParquetScan { schema, .. } => schema,
+            #[cfg(feature = "ipc")]
+            IpcScan { schema, .. } => schema,
+            #[cfg(feature = "json")]
+            NdJsonScan { schema, .. } => schema,
             DataFrameScan { schema, .. } => schema,
             Selection { input, .. } => input.schema(),
             #[cfg(feature = "csv-file")]
@@ -729,35 +933,114 @@ pub(crate) fn prepare_projection(exprs: Vec<Expr>, schema: &Schema) -> (Vec<Expr
 impl LogicalPlanBuilder {
     #[cfg(feature = "parquet")]
     #[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
-    pub fn scan_parquet<P: Into<PathBuf>>(
-        path: P,
+    pub fn scan_parquet(
+        paths: Vec<PathBuf>,
         stop_after_n_rows: Option<usize>,
         cache: bool,
-    ) -> Self {
-        let path = path.into();
-        let file = std::fs::File::open(&path).expect("could not open file");
-        let schema = Arc::new(
-            ParquetReader::new(file)
-                .schema()
-                .expect("could not get parquet schema"),
-        );
+    ) -> Result<Self> {
+        let paths = utils::expand_paths(&paths)?;
+        let schemas = paths
+            .iter()
+            .map(|path| {
+                let file = std::fs::File::open(path)?;
+                ParquetReader::new(file).schema()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let schema = Arc::new(utils::unify_schemas(&schemas)?);
 
-        LogicalPlan::ParquetScan {
-            path,
+        Ok(LogicalPlan::ParquetScan {
+            paths: Arc::new(paths),
             schema,
             stop_after_n_rows,
             with_columns: None,
             predicate: None,
             aggregate: vec![],
             cache,
+            partitions: None,
         }
-        .into()
+        .into())
+    }
+
+    /// Scan a hive partitioned dataset. The schema is taken from the first file, and the
+    /// `key=value` directories are added as columns.
+    #[cfg(feature = "parquet")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
+    pub fn scan_parquet_dataset<P: Into<PathBuf>>(
+        root: P,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+    ) -> Result<Self> {
+        let (paths, partitions) = hive::discover(&root.into())?;
+        let file = std::fs::File::open(&paths[0])?;
+        let mut schema = ParquetReader::new(file).schema()?;
+        for field in partitions.schema().fields() {
+            if schema.column_with_name(field.name()).is_some() {
+                return Err(PolarsError::ValueError(
+                    format!(
+                        "partition column {} also exists in the files of the dataset",
+                        field.name()
+                    )
+                    .into(),
+                ));
+            }
+            schema.fields_mut().push(field.clone());
+        }
+
+        Ok(LogicalPlan::ParquetScan {
+            paths: Arc::new(paths),
+            schema: Arc::new(schema),
+            stop_after_n_rows,
+            with_columns: None,
+            predicate: None,
+            aggregate: vec![],
+            cache,
+            partitions: Some(Arc::new(partitions)),
+        }
+        .into())
+    }
+
+    #[cfg(feature = "ipc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
+    pub fn scan_ipc<P: Into<PathBuf>>(path: P, options: IpcScanOptions) -> Result<Self> {
+        let path = path.into();
+        let file = std::fs::File::open(&path)?;
+        let schema = Arc::new(IpcReader::new(file).schema()?);
+
+        Ok(LogicalPlan::IpcScan {
+            path,
+            schema,
+            predicate: None,
+            aggregate: vec![],
+            options,
+        }
+        .into())
+    }
+
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub fn scan_ndjson<P: Into<PathBuf>>(path: P, options: NdJsonScanOptions) -> Result<Self> {
+        let path = path.into();
+        let mut file = std::fs::File::open(&path)?;
+        let reader_bytes = get_reader_bytes(&mut file)?;
+        let schema = Arc::new(infer_ndjson_schema(
+            &reader_bytes,
+            options.infer_schema_length,
+        )?);
+
+        Ok(LogicalPlan::NdJsonScan {
+            path,
+            schema,
+            predicate: None,
+            aggregate: vec![],
+            options,
+        }
+        .into())
     }
 
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "csv-file")]
-    pub fn scan_csv<P: Into<PathBuf>>(
-        path: P,
+    pub fn scan_csv(
+        paths: Vec<PathBuf>,
         delimiter: u8,
         has_header: bool,
         ignore_errors: bool,
@@ -770,27 +1053,44 @@ impl LogicalPlanBuilder {
         comment_char: Option<u8>,
         quote_char: Option<u8>,
         null_values: Option<NullValues>,
-    ) -> Self {
-        let path = path.into();
-        let mut file = std::fs::File::open(&path).expect("could not open file");
-        let reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
-
-        let schema = schema.unwrap_or_else(|| {
-            let (schema, _) = infer_file_schema(
-                &reader_bytes,
-                delimiter,
-                Some(100),
-                has_header,
-                schema_overwrite,
-                &mut skip_rows,
-                comment_char,
-                quote_char,
-            )
-            .expect("could not read schema");
-            Arc::new(schema)
-        });
-        LogicalPlan::CsvScan {
-            path,
+        encoding: CsvEncoding,
+    ) -> Result<Self> {
+        let paths = utils::expand_paths(&paths)?;
+
+        let schema = match schema {
+            Some(schema) => schema,
+            None => {
+                let initial_skip_rows = skip_rows;
+                let schemas = paths
+                    .iter()
+                    .enumerate()
+                    .map(|(i, path)| {
+                        let mut file = std::fs::File::open(path)?;
+                        let reader_bytes = get_reader_bytes(&mut file)?;
+                        let reader_bytes = transcode_to_utf8(reader_bytes, encoding);
+                        let mut file_skip_rows = initial_skip_rows;
+                        let (schema, _) = infer_file_schema(
+                            &reader_bytes,
+                            delimiter,
+                            Some(100),
+                            has_header,
+                            schema_overwrite,
+                            &mut file_skip_rows,
+                            comment_char,
+                            quote_char,
+                        )?;
+                        // the leading comment lines of the first file are skipped by the scan
+                        if i == 0 {
+                            skip_rows = file_skip_rows;
+                        }
+                        Ok(schema)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Arc::new(utils::unify_schemas(&schemas)?)
+            }
+        };
+        Ok(LogicalPlan::CsvScan {
+            paths: Arc::new(paths),
             schema,
             options: CsvParserOptions {
                 has_header,
@@ -804,16 +1104,18 @@ impl LogicalPlanBuilder {
                 comment_char,
                 quote_char,
                 null_values,
+                encoding,
             },
             predicate: None,
             aggregate: vec![],
         }
-        .into()
+        .into())
     }
 
     pub fn cache(self) -> Self {
         LogicalPlan::Cache {
             input: Box::new(self.0),
+            id: next_cache_id(),
         }
         .into()
     }
@@ -84,7 +84,12 @@ impl OptimizationRule for AggregatePushdown {
                 schema,
             } => self.pushdown_projection(node, expr, input, schema, lp_arena, expr_arena),
             // todo! hstack should pushown not dependent columns
-            Join { .. } | Aggregate { .. } | HStack { .. } | DataFrameScan { .. } => {
+            // the input of a cache may be shared with other parts of the plan
+            Join { .. }
+            | Aggregate { .. }
+            | HStack { .. }
+            | DataFrameScan { .. }
+            | Cache { .. } => {
                 if self.accumulated_projections.is_empty() {
                     lp_arena.replace(node, lp);
                     None
@@ -114,7 +119,7 @@ impl OptimizationRule for AggregatePushdown {
             }
             #[cfg(feature = "csv-file")]
             CsvScan {
-                path,
+                paths,
                 schema,
                 options,
                 predicate,
@@ -124,7 +129,7 @@ impl OptimizationRule for AggregatePushdown {
                     lp_arena.replace(
                         node,
                         CsvScan {
-                            path,
+                            paths,
                             schema,
                             options,
                             predicate,
@@ -136,7 +141,7 @@ impl OptimizationRule for AggregatePushdown {
                 false => {
                     let aggregate: Vec<_> = self.process_nodes();
                     Some(ALogicalPlan::CsvScan {
-                        path,
+                        paths,
                         schema,
                         options,
                         predicate,
@@ -146,25 +151,27 @@ impl OptimizationRule for AggregatePushdown {
             },
             #[cfg(feature = "parquet")]
             ParquetScan {
-                path,
+                paths,
                 schema,
                 with_columns,
                 predicate,
                 aggregate,
                 stop_after_n_rows,
                 cache,
+                partitions,
             } => match self.accumulated_projections.is_empty() {
                 true => {
                     lp_arena.replace(
                         node,
                         ParquetScan {
-                            path,
+                            paths,
                             schema,
                             with_columns,
                             predicate,
                             aggregate,
                             stop_after_n_rows,
                             cache,
+                            partitions,
                         },
                     );
                     None
@@ -172,13 +179,78 @@ impl OptimizationRule for AggregatePushdown {
                 false => {
                     let aggregate = self.process_nodes();
                     Some(ALogicalPlan::ParquetScan {
-                        path,
+                        paths,
                         schema,
                         with_columns,
                         predicate,
                         aggregate,
                         stop_after_n_rows,
                         cache,
+                        partitions,
+                    })
+                }
+            },
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                options,
+                predicate,
+                aggregate,
+            } => match self.accumulated_projections.is_empty() {
+                true => {
+                    lp_arena.replace(
+                        node,
+                        IpcScan {
+                            path,
+                            schema,
+                            options,
+                            predicate,
+                            aggregate,
+                        },
+                    );
+                    None
+                }
+                false => {
+                    let aggregate = self.process_nodes();
+                    Some(ALogicalPlan::IpcScan {
+                        path,
+                        schema,
+                        options,
+                        predicate,
+                        aggregate,
+                    })
+                }
+            },
+            #[cfg(feature = "json")]
+            NdJsonScan {
+                path,
+                schema,
+                options,
+                predicate,
+                aggregate,
+            } => match self.accumulated_projections.is_empty() {
+                true => {
+                    lp_arena.replace(
+                        node,
+                        NdJsonScan {
+                            path,
+                            schema,
+                            options,
+                            predicate,
+                            aggregate,
+                        },
+                    );
+                    None
+                }
+                false => {
+                    let aggregate = self.process_nodes();
+                    Some(ALogicalPlan::NdJsonScan {
+                        path,
+                        schema,
+                        options,
+                        predicate,
+                        aggregate,
                     })
                 }
             },
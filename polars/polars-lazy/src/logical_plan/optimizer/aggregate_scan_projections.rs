@@ -27,14 +27,24 @@ pub(crate) fn agg_projection(
     use ALogicalPlan::*;
     match lp_arena.get(root) {
         #[cfg(feature = "csv-file")]
-        CsvScan { path, options, .. } => {
-            process_with_columns(path, &options.with_columns, columns);
+        CsvScan { paths, options, .. } => {
+            process_with_columns(&paths[0], &options.with_columns, columns);
         }
         #[cfg(feature = "parquet")]
         ParquetScan {
-            path, with_columns, ..
-        } => {
-            process_with_columns(path, with_columns, columns);
+            paths,
+            with_columns,
+            ..
+        } if !paths.is_empty() => {
+            process_with_columns(&paths[0], with_columns, columns);
+        }
+        #[cfg(feature = "ipc")]
+        IpcScan { path, options, .. } => {
+            process_with_columns(path, &options.with_columns, columns);
+        }
+        #[cfg(feature = "json")]
+        NdJsonScan { path, options, .. } => {
+            process_with_columns(path, &options.with_columns, columns);
         }
         DataFrameScan { .. } => (),
         lp => {
@@ -91,47 +101,51 @@ impl OptimizationRule for AggScanProjection {
         let lp = lp_arena.get_mut(node);
         match lp {
             #[cfg(feature = "parquet")]
-            ALogicalPlan::ParquetScan { .. } => {
+            // all files of a hive partitioned scan may have been pruned
+            ALogicalPlan::ParquetScan { paths, .. } if !paths.is_empty() => {
                 let lp = std::mem::take(lp);
                 if let ALogicalPlan::ParquetScan {
-                    path,
+                    paths,
                     schema,
                     predicate,
                     aggregate,
                     with_columns,
                     stop_after_n_rows,
                     cache,
+                    partitions,
                 } = lp
                 {
                     let new_with_columns = self
                         .columns
-                        .get(&path)
+                        .get(&paths[0])
                         .map(|agg| agg.iter().cloned().collect());
                     // prevent infinite loop
                     if with_columns == new_with_columns {
                         let lp = ALogicalPlan::ParquetScan {
-                            path,
+                            paths,
                             schema,
                             predicate,
                             aggregate,
                             with_columns,
                             stop_after_n_rows,
                             cache,
+                            partitions,
                         };
                         lp_arena.replace(node, lp);
                         return None;
                     }
 
                     let lp = ALogicalPlan::ParquetScan {
-                        path: path.clone(),
+                        paths: paths.clone(),
                         schema,
                         with_columns: new_with_columns,
                         predicate,
                         aggregate,
                         stop_after_n_rows,
                         cache,
+                        partitions,
                     };
-                    Some(self.finish_rewrite(lp, expr_arena, lp_arena, &path, with_columns))
+                    Some(self.finish_rewrite(lp, expr_arena, lp_arena, &paths[0], with_columns))
                 } else {
                     unreachable!()
                 }
@@ -140,7 +154,7 @@ impl OptimizationRule for AggScanProjection {
             ALogicalPlan::CsvScan { .. } => {
                 let lp = std::mem::take(lp);
                 if let ALogicalPlan::CsvScan {
-                    path,
+                    paths,
                     schema,
                     mut options,
                     predicate,
@@ -149,11 +163,11 @@ impl OptimizationRule for AggScanProjection {
                 {
                     let new_with_columns = self
                         .columns
-                        .get(&path)
+                        .get(&paths[0])
                         .map(|agg| agg.iter().cloned().collect());
                     if options.with_columns == new_with_columns {
                         let lp = ALogicalPlan::CsvScan {
-                            path,
+                            paths,
                             schema,
                             options,
                             predicate,
@@ -164,13 +178,101 @@ impl OptimizationRule for AggScanProjection {
                     }
                     options.with_columns = new_with_columns;
                     let lp = ALogicalPlan::CsvScan {
-                        path: path.clone(),
+                        paths: paths.clone(),
                         schema,
                         options: options.clone(),
                         predicate,
                         aggregate,
                     };
-                    Some(self.finish_rewrite(lp, expr_arena, lp_arena, &path, options.with_columns))
+                    Some(self.finish_rewrite(
+                        lp,
+                        expr_arena,
+                        lp_arena,
+                        &paths[0],
+                        options.with_columns,
+                    ))
+                } else {
+                    unreachable!()
+                }
+            }
+            #[cfg(feature = "ipc")]
+            ALogicalPlan::IpcScan { .. } => {
+                let lp = std::mem::take(lp);
+                if let ALogicalPlan::IpcScan {
+                    path,
+                    schema,
+                    mut options,
+                    predicate,
+                    aggregate,
+                } = lp
+                {
+                    let new_with_columns = self
+                        .columns
+                        .get(&path)
+                        .map(|agg| agg.iter().cloned().collect());
+                    // prevent infinite loop
+                    if options.with_columns == new_with_columns {
+                        let lp = ALogicalPlan::IpcScan {
+                            path,
+                            schema,
+                            options,
+                            predicate,
+                            aggregate,
+                        };
+                        lp_arena.replace(node, lp);
+                        return None;
+                    }
+                    let with_columns =
+                        std::mem::replace(&mut options.with_columns, new_with_columns);
+                    let lp = ALogicalPlan::IpcScan {
+                        path: path.clone(),
+                        schema,
+                        options,
+                        predicate,
+                        aggregate,
+                    };
+                    Some(self.finish_rewrite(lp, expr_arena, lp_arena, &path, with_columns))
+                } else {
+                    unreachable!()
+                }
+            }
+            #[cfg(feature = "json")]
+            ALogicalPlan::NdJsonScan { .. } => {
+                let lp = std::mem::take(lp);
+                if let ALogicalPlan::NdJsonScan {
+                    path,
+                    schema,
+                    mut options,
+                    predicate,
+                    aggregate,
+                } = lp
+                {
+                    let new_with_columns = self
+                        .columns
+                        .get(&path)
+                        .map(|agg| agg.iter().cloned().collect());
+                    // prevent infinite loop
+                    if options.with_columns == new_with_columns {
+                        let lp = ALogicalPlan::NdJsonScan {
+                            path,
+                            schema,
+                            options,
+                            predicate,
+                            aggregate,
+                        };
+                        lp_arena.replace(node, lp);
+                        return None;
+                    }
+                    let with_columns =
+                        std::mem::replace(&mut options.with_columns, new_with_columns);
+                    let lp = ALogicalPlan::NdJsonScan {
+                        path: path.clone(),
+                        schema,
+                        options,
+                        predicate,
+                        aggregate,
+                    };
+                    Some(self.finish_rewrite(lp, expr_arena, lp_arena, &path, with_columns))
                 } else {
                     unreachable!()
                 }
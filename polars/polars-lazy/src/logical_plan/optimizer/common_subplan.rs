@@ -0,0 +1,438 @@
+use crate::logical_plan::next_cache_id;
+use crate::prelude::*;
+use polars_core::prelude::*;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// Finds subplans that occur more than once in the logical plan, e.g. when the same `LazyFrame`
+/// is joined with itself, and wraps them in `Cache` nodes with the same id. Only the first of
+/// them that is executed computes the result, the others read it from the cache.
+pub(crate) struct CommonSubplanElimination {}
+
+impl CommonSubplanElimination {
+    pub(crate) fn optimize(
+        &self,
+        root: Node,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &Arena<AExpr>,
+    ) {
+        // nodes in pre-order, so that the largest duplicated subplans are found first
+        let mut nodes = Vec::with_capacity(lp_arena.len());
+        let mut hashes = PlHashMap::with_capacity(lp_arena.len());
+        subplan_hash(root, lp_arena, &mut nodes, &mut hashes);
+
+        let mut by_hash: PlHashMap<u64, Vec<Node>> = PlHashMap::with_capacity(nodes.len());
+        for node in &nodes {
+            by_hash.entry(hashes[node]).or_default().push(*node);
+        }
+
+        // nodes in subplans that are already cached
+        let mut done = PlHashSet::with_capacity(nodes.len());
+        for node in nodes {
+            if done.contains(&node) || !can_cache(lp_arena.get(node)) {
+                continue;
+            }
+            let duplicates = by_hash[&hashes[&node]]
+                .iter()
+                .copied()
+                .filter(|other| {
+                    *other != node
+                        && !done.contains(other)
+                        && subplan_eq(node, *other, lp_arena, expr_arena)
+                })
+                .collect::<Vec<_>>();
+            if duplicates.is_empty() {
+                continue;
+            }
+
+            for duplicate in &duplicates {
+                mark_subplan(*duplicate, lp_arena, &mut done);
+            }
+            match lp_arena.get(node) {
+                // identical caches only need to share the id
+                ALogicalPlan::Cache { id, .. } => {
+                    let id = *id;
+                    for duplicate in duplicates {
+                        if let ALogicalPlan::Cache { id: other, .. } = lp_arena.get_mut(duplicate) {
+                            *other = id
+                        }
+                    }
+                }
+                _ => {
+                    let id = next_cache_id();
+                    for node in std::iter::once(node).chain(duplicates) {
+                        let input = lp_arena.add(lp_arena.take(node));
+                        lp_arena.replace(node, ALogicalPlan::Cache { input, id });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Scans of in-memory `DataFrame`s are cheap and are not cached.
+fn can_cache(lp: &ALogicalPlan) -> bool {
+    !matches!(
+        lp,
+        ALogicalPlan::DataFrameScan {
+            projection: None,
+            selection: None,
+            ..
+        }
+    )
+}
+
+/// A hash of the structure of the subplan. Equal subplans have equal hashes.
+fn subplan_hash(
+    node: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    nodes: &mut Vec<Node>,
+    hashes: &mut PlHashMap<Node, u64>,
+) -> u64 {
+    nodes.push(node);
+    let lp = lp_arena.get(node);
+    let mut hasher = DefaultHasher::new();
+    std::mem::discriminant(lp).hash(&mut hasher);
+    lp.get_exprs().len().hash(&mut hasher);
+    for input in lp.get_inputs() {
+        subplan_hash(input, lp_arena, nodes, hashes).hash(&mut hasher);
+    }
+    let hash = hasher.finish();
+    hashes.insert(node, hash);
+    hash
+}
+
+fn mark_subplan(node: Node, lp_arena: &Arena<ALogicalPlan>, done: &mut PlHashSet<Node>) {
+    done.insert(node);
+    for input in lp_arena.get(node).get_inputs() {
+        mark_subplan(input, lp_arena, done);
+    }
+}
+
+/// Check if two subplans produce the same result.
+fn subplan_eq(
+    left: Node,
+    right: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    if left == right {
+        return true;
+    }
+    let (lp_l, lp_r) = (lp_arena.get(left), lp_arena.get(right));
+    let (exprs_l, exprs_r) = (lp_l.get_exprs(), lp_r.get_exprs());
+    let (inputs_l, inputs_r) = (lp_l.get_inputs(), lp_r.get_inputs());
+
+    node_eq(lp_l, lp_r, expr_arena)
+        && exprs_eq(&exprs_l, &exprs_r, expr_arena)
+        && inputs_l.len() == inputs_r.len()
+        && inputs_l
+            .iter()
+            .zip(&inputs_r)
+            .all(|(l, r)| subplan_eq(*l, *r, lp_arena, expr_arena))
+}
+
+fn exprs_eq(left: &[Node], right: &[Node], expr_arena: &Arena<AExpr>) -> bool {
+    left.len() == right.len()
+        // expressions with closures are never equal
+        && left
+            .iter()
+            .zip(right)
+            .all(|(l, r)| node_to_exp(*l, expr_arena) == node_to_exp(*r, expr_arena))
+}
+
+fn ptr_eq<T: ?Sized>(l: &Arc<T>, r: &Arc<T>) -> bool {
+    Arc::as_ptr(l) as *const u8 == Arc::as_ptr(r) as *const u8
+}
+
+/// Check if the nodes are equal, ignoring their inputs and the expressions returned by
+/// `get_exprs`.
+fn node_eq(left: &ALogicalPlan, right: &ALogicalPlan, expr_arena: &Arena<AExpr>) -> bool {
+    use ALogicalPlan::*;
+    match (left, right) {
+        (
+            Melt {
+                id_vars: id_l,
+                value_vars: value_l,
+                ..
+            },
+            Melt {
+                id_vars: id_r,
+                value_vars: value_r,
+                ..
+            },
+        ) => id_l == id_r && value_l == value_r,
+        (
+            Slice {
+                offset: offset_l,
+                len: len_l,
+                ..
+            },
+            Slice {
+                offset: offset_r,
+                len: len_r,
+                ..
+            },
+        ) => offset_l == offset_r && len_l == len_r,
+        (Selection { .. }, Selection { .. }) => true,
+        #[cfg(feature = "csv-file")]
+        (
+            CsvScan {
+                paths: paths_l,
+                options: options_l,
+                predicate: predicate_l,
+                ..
+            },
+            CsvScan {
+                paths: paths_r,
+                options: options_r,
+                predicate: predicate_r,
+                ..
+            },
+        ) => {
+            paths_l == paths_r
+                && options_l == options_r
+                && predicate_l.is_some() == predicate_r.is_some()
+        }
+        #[cfg(feature = "parquet")]
+        (
+            ParquetScan {
+                paths: paths_l,
+                with_columns: columns_l,
+                predicate: predicate_l,
+                stop_after_n_rows: n_rows_l,
+                cache: cache_l,
+                partitions: partitions_l,
+                ..
+            },
+            ParquetScan {
+                paths: paths_r,
+                with_columns: columns_r,
+                predicate: predicate_r,
+                stop_after_n_rows: n_rows_r,
+                cache: cache_r,
+                partitions: partitions_r,
+                ..
+            },
+        ) => {
+            paths_l == paths_r
+                && columns_l == columns_r
+                && predicate_l.is_some() == predicate_r.is_some()
+                && n_rows_l == n_rows_r
+                && cache_l == cache_r
+                && match (partitions_l, partitions_r) {
+                    (Some(l), Some(r)) => ptr_eq(l, r),
+                    (l, r) => l.is_none() && r.is_none(),
+                }
+        }
+        #[cfg(feature = "ipc")]
+        (
+            IpcScan {
+                path: path_l,
+                options: options_l,
+                predicate: predicate_l,
+                ..
+            },
+            IpcScan {
+                path: path_r,
+                options: options_r,
+                predicate: predicate_r,
+                ..
+            },
+        ) => {
+            path_l == path_r
+                && options_l == options_r
+                && predicate_l.is_some() == predicate_r.is_some()
+        }
+        #[cfg(feature = "json")]
+        (
+            NdJsonScan {
+                path: path_l,
+                options: options_l,
+                predicate: predicate_l,
+                ..
+            },
+            NdJsonScan {
+                path: path_r,
+                options: options_r,
+                predicate: predicate_r,
+                ..
+            },
+        ) => {
+            path_l == path_r
+                && options_l == options_r
+                && predicate_l.is_some() == predicate_r.is_some()
+        }
+        (
+            DataFrameScan {
+                df: df_l,
+                projection: projection_l,
+                selection: selection_l,
+                ..
+            },
+            DataFrameScan {
+                df: df_r,
+                projection: projection_r,
+                selection: selection_r,
+                ..
+            },
+        ) => {
+            ptr_eq(df_l, df_r)
+                && projection_l.as_ref().map(|p| p.len()) == projection_r.as_ref().map(|p| p.len())
+                && selection_l.is_some() == selection_r.is_some()
+        }
+        (Projection { schema: l, .. }, Projection { schema: r, .. })
+        | (LocalProjection { schema: l, .. }, LocalProjection { schema: r, .. })
+        | (HStack { schema: l, .. }, HStack { schema: r, .. }) => l == r,
+        (
+            Sort {
+                by_column: by_l,
+                reverse: reverse_l,
+                ..
+            },
+            Sort {
+                by_column: by_r,
+                reverse: reverse_r,
+                ..
+            },
+        ) => reverse_l == reverse_r && exprs_eq(by_l, by_r, expr_arena),
+        (Explode { columns: l, .. }, Explode { columns: r, .. }) => l == r,
+        (Cache { .. }, Cache { .. }) => true,
+        (
+            Aggregate {
+                keys: keys_l,
+                schema: schema_l,
+                apply: apply_l,
+                maintain_order: maintain_order_l,
+                ..
+            },
+            Aggregate {
+                keys: keys_r,
+                schema: schema_r,
+                apply: apply_r,
+                maintain_order: maintain_order_r,
+                ..
+            },
+        ) => {
+            keys_l.len() == keys_r.len()
+                && schema_l == schema_r
+                && maintain_order_l == maintain_order_r
+                && match (apply_l, apply_r) {
+                    (Some(l), Some(r)) => ptr_eq(l, r),
+                    (l, r) => l.is_none() && r.is_none(),
+                }
+        }
+        (
+            Join {
+                schema: schema_l,
+                left_on: left_on_l,
+                options: options_l,
+                ..
+            },
+            Join {
+                schema: schema_r,
+                left_on: left_on_r,
+                options: options_r,
+                ..
+            },
+        ) => schema_l == schema_r && left_on_l.len() == left_on_r.len() && options_l == options_r,
+        (
+            Distinct {
+                maintain_order: maintain_order_l,
+                subset: subset_l,
+                ..
+            },
+            Distinct {
+                maintain_order: maintain_order_r,
+                subset: subset_r,
+                ..
+            },
+        ) => maintain_order_l == maintain_order_r && subset_l == subset_r,
+        (
+            Udf {
+                function: function_l,
+                predicate_pd: predicate_pd_l,
+                projection_pd: projection_pd_l,
+                schema: schema_l,
+                ..
+            },
+            Udf {
+                function: function_r,
+                predicate_pd: predicate_pd_r,
+                projection_pd: projection_pd_r,
+                schema: schema_r,
+                ..
+            },
+        ) => {
+            ptr_eq(function_l, function_r)
+                && predicate_pd_l == predicate_pd_r
+                && projection_pd_l == projection_pd_r
+                && schema_l == schema_r
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use polars_core::df;
+
+    fn count_caches(node: Node, lp_arena: &Arena<ALogicalPlan>) -> usize {
+        let lp = lp_arena.get(node);
+        let own = matches!(lp, ALogicalPlan::Cache { .. }) as usize;
+        own + lp
+            .get_inputs()
+            .into_iter()
+            .map(|input| count_caches(input, lp_arena))
+            .sum::<usize>()
+    }
+
+    #[test]
+    fn test_common_subplan_elimination() -> Result<()> {
+        let df = df![
+            "a" => [1, 2, 3],
+            "b" => [1, 1, 2]
+        ]?;
+        let filtered = df.lazy().filter(col("a").gt(lit(1)));
+        let lf = filtered.clone().groupby([col("b")]).agg([col("a").sum()]);
+
+        let (root, expr_arena, mut lp_arena) =
+            lf.clone().left_join(lf, col("b"), col("b")).into_alp();
+        CommonSubplanElimination {}.optimize(root, &mut lp_arena, &expr_arena);
+        assert_eq!(count_caches(root, &lp_arena), 2);
+        // both sides read the same cache
+        let inputs = lp_arena.get(root).get_inputs();
+        assert!(matches!(
+            (lp_arena.get(inputs[0]), lp_arena.get(inputs[1])),
+            (
+                ALogicalPlan::Cache { id: l, .. },
+                ALogicalPlan::Cache { id: r, .. }
+            ) if l == r
+        ));
+
+        // the aggregations differ, only the filter is shared
+        let left = filtered.clone().groupby([col("b")]).agg([col("a").sum()]);
+        let right = filtered.clone().groupby([col("b")]).agg([col("a").max()]);
+        let (root, expr_arena, mut lp_arena) = left.left_join(right, col("b"), col("b")).into_alp();
+        CommonSubplanElimination {}.optimize(root, &mut lp_arena, &expr_arena);
+        assert_eq!(count_caches(root, &lp_arena), 2);
+        let inputs = lp_arena.get(root).get_inputs();
+        assert!(matches!(
+            lp_arena.get(inputs[0]),
+            ALogicalPlan::Aggregate { .. }
+        ));
+
+        // sorts by different columns are not shared
+        let (root, expr_arena, mut lp_arena) = filtered
+            .clone()
+            .sort("a", false)
+            .left_join(filtered.sort("b", false), col("b"), col("b"))
+            .into_alp();
+        CommonSubplanElimination {}.optimize(root, &mut lp_arena, &expr_arena);
+        let inputs = lp_arena.get(root).get_inputs();
+        assert!(matches!(lp_arena.get(inputs[0]), ALogicalPlan::Sort { .. }));
+        assert!(matches!(lp_arena.get(inputs[1]), ALogicalPlan::Sort { .. }));
+        Ok(())
+    }
+}
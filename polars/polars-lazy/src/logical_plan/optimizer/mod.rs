@@ -2,8 +2,14 @@ use crate::prelude::*;
 use polars_core::{datatypes::PlHashMap, prelude::*};
 
 pub(crate) mod aggregate_pushdown;
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "csv-file",
+    feature = "ipc",
+    feature = "json"
+))]
 pub(crate) mod aggregate_scan_projections;
+pub(crate) mod common_subplan;
 pub(crate) mod drop_nulls;
 pub(crate) mod fast_projection;
 #[cfg(feature = "private")]
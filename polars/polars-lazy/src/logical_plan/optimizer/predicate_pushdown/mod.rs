@@ -178,30 +178,40 @@ impl PredicatePushDown {
             }
             #[cfg(feature = "parquet")]
             ParquetScan {
-                path,
+                paths,
                 schema,
                 with_columns,
                 predicate,
                 aggregate,
                 stop_after_n_rows,
                 cache,
+                partitions,
             } => {
+                let (paths, partitions) = match partitions {
+                    Some(partitions) => {
+                        let (paths, partitions) =
+                            prune_partitions(paths, partitions, &acc_predicates, expr_arena)?;
+                        (paths, Some(partitions))
+                    }
+                    None => (paths, None),
+                };
                 let predicate = predicate_at_scan(acc_predicates, predicate, expr_arena);
 
                 let lp = ParquetScan {
-                    path,
+                    paths,
                     schema,
                     with_columns,
                     predicate,
                     aggregate,
                     stop_after_n_rows,
                     cache,
+                    partitions,
                 };
                 Ok(lp)
             }
             #[cfg(feature = "csv-file")]
             CsvScan {
-                path,
+                paths,
                 schema,
                 options,
                 predicate,
@@ -210,6 +220,44 @@ impl PredicatePushDown {
                 let predicate = predicate_at_scan(acc_predicates, predicate, expr_arena);
 
                 let lp = CsvScan {
+                    paths,
+                    schema,
+                    options,
+                    predicate,
+                    aggregate,
+                };
+                Ok(lp)
+            }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                options,
+                predicate,
+                aggregate,
+            } => {
+                let predicate = predicate_at_scan(acc_predicates, predicate, expr_arena);
+
+                let lp = IpcScan {
+                    path,
+                    schema,
+                    options,
+                    predicate,
+                    aggregate,
+                };
+                Ok(lp)
+            }
+            #[cfg(feature = "json")]
+            NdJsonScan {
+                path,
+                schema,
+                options,
+                predicate,
+                aggregate,
+            } => {
+                let predicate = predicate_at_scan(acc_predicates, predicate, expr_arena);
+
+                let lp = NdJsonScan {
                     path,
                     schema,
                     options,
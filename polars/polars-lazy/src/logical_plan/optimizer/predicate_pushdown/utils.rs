@@ -1,5 +1,9 @@
 use crate::logical_plan::iterator::ArenaExprIter;
 use crate::logical_plan::Context;
+#[cfg(feature = "parquet")]
+use crate::logical_plan::HivePartitions;
+#[cfg(feature = "parquet")]
+use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use crate::utils::{
     aexpr_to_root_column_name, aexpr_to_root_names, check_input_node, has_aexpr,
@@ -7,6 +11,8 @@ use crate::utils::{
 };
 use polars_core::datatypes::PlHashMap;
 use polars_core::prelude::*;
+#[cfg(feature = "parquet")]
+use std::path::PathBuf;
 
 trait Dsl {
     fn and(self, right: Node, arena: &mut Arena<AExpr>) -> Node;
@@ -77,6 +83,52 @@ pub(super) fn predicate_at_scan(
     }
 }
 
+/// Evaluate the predicates that only refer to partition columns on the partition values of a
+/// hive partitioned scan, and drop the files that cannot match before any of them is opened.
+#[cfg(feature = "parquet")]
+pub(super) fn prune_partitions(
+    paths: Arc<Vec<PathBuf>>,
+    partitions: Arc<HivePartitions>,
+    acc_predicates: &PlHashMap<Arc<String>, Node>,
+    expr_arena: &mut Arena<AExpr>,
+) -> Result<(Arc<Vec<PathBuf>>, Arc<HivePartitions>)> {
+    let schema = partitions.schema();
+    let planner = DefaultPlanner::default();
+    let state = ExecutionState::new();
+
+    let mut mask: Option<BooleanChunked> = None;
+    for node in acc_predicates.values() {
+        let roots = aexpr_to_root_names(*node, expr_arena);
+        if roots.is_empty()
+            || roots
+                .iter()
+                .any(|name| schema.column_with_name(name).is_none())
+        {
+            continue;
+        }
+        let phys_expr = planner.create_physical_expr(*node, Context::Default, expr_arena)?;
+        let file_mask = phys_expr.evaluate(partitions.values(), &state)?;
+        let file_mask = file_mask.bool()?;
+        mask = Some(match mask {
+            Some(mask) => &mask & file_mask,
+            None => file_mask.clone(),
+        });
+    }
+
+    match mask {
+        Some(mask) => {
+            let paths = paths
+                .iter()
+                .zip(&mask)
+                .filter(|(_, keep)| *keep == Some(true))
+                .map(|(path, _)| path.clone())
+                .collect();
+            Ok((Arc::new(paths), Arc::new(partitions.filter(&mask)?)))
+        }
+        None => Ok((paths, partitions)),
+    }
+}
+
 /// Determine the hashmap key by combining all the root column names of a predicate
 pub(super) fn roots_to_key(roots: &[Arc<String>]) -> Arc<String> {
     if roots.len() == 1 {
@@ -11,7 +11,12 @@ fn init_set() -> PlHashSet<Arc<String>> {
 }
 
 /// utility function to get names of the columns needed in projection at scan level
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "csv-file",
+    feature = "ipc",
+    feature = "json"
+))]
 fn get_scan_columns(
     acc_projections: &mut Vec<Node>,
     expr_arena: &Arena<AExpr>,
@@ -296,29 +301,31 @@ impl ProjectionPushDown {
             }
             #[cfg(feature = "parquet")]
             ParquetScan {
-                path,
+                paths,
                 schema,
                 predicate,
                 aggregate,
                 stop_after_n_rows,
                 cache,
+                partitions,
                 ..
             } => {
                 let with_columns = get_scan_columns(&mut acc_projections, expr_arena);
                 let lp = ParquetScan {
-                    path,
+                    paths,
                     schema,
                     with_columns,
                     predicate,
                     aggregate,
                     stop_after_n_rows,
                     cache,
+                    partitions,
                 };
                 Ok(lp)
             }
             #[cfg(feature = "csv-file")]
             CsvScan {
-                path,
+                paths,
                 schema,
                 mut options,
                 predicate,
@@ -327,6 +334,42 @@ impl ProjectionPushDown {
             } => {
                 options.with_columns = get_scan_columns(&mut acc_projections, expr_arena);
                 let lp = CsvScan {
+                    paths,
+                    schema,
+                    options,
+                    predicate,
+                    aggregate,
+                };
+                Ok(lp)
+            }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                mut options,
+                predicate,
+                aggregate,
+            } => {
+                options.with_columns = get_scan_columns(&mut acc_projections, expr_arena);
+                let lp = IpcScan {
+                    path,
+                    schema,
+                    options,
+                    predicate,
+                    aggregate,
+                };
+                Ok(lp)
+            }
+            #[cfg(feature = "json")]
+            NdJsonScan {
+                path,
+                schema,
+                mut options,
+                predicate,
+                aggregate,
+            } => {
+                options.with_columns = get_scan_columns(&mut acc_projections, expr_arena);
+                let lp = NdJsonScan {
                     path,
                     schema,
                     options,
@@ -137,7 +137,20 @@ impl OptimizationRule for TypeCoercionRule {
                         && type_right == DataType::Utf8)
                         || (type_left == DataType::Utf8 && type_right == DataType::Categorical));
 
-                    if type_left == type_right || compare_cat_to_string {
+                    // arithmetic with durations is dispatched by the series kernels, not by a supertype cast
+                    #[cfg(feature = "dtype-duration")]
+                    let duration_arithmetic = (matches!(type_left, DataType::Duration(_))
+                        || matches!(type_right, DataType::Duration(_)))
+                        && crate::logical_plan::aexpr::temporal_arithmetic_type(
+                            op,
+                            &type_left,
+                            &type_right,
+                        )
+                        .is_some();
+                    #[cfg(not(feature = "dtype-duration"))]
+                    let duration_arithmetic = false;
+
+                    if type_left == type_right || compare_cat_to_string || duration_arithmetic {
                         None
                     } else {
                         let mut st = get_supertype(&type_left, &type_right)
@@ -0,0 +1,144 @@
+//! Serialization of [`LogicalPlan`]s and [`Expr`]essions.
+//!
+//! Plans and expressions can be written to JSON or to a compact binary form (bincode). Closures,
+//! e.g. of `map`, `apply` or a user defined function, cannot be serialized. Plans and expressions
+//! that hold them return an error instead.
+use crate::prelude::*;
+use polars_core::prelude::*;
+
+const UDF_ERR: &str =
+    "closures, e.g. of 'map', 'apply' or a user defined function, cannot be serialized";
+
+fn to_compute_err(e: impl std::fmt::Display) -> PolarsError {
+    PolarsError::ComputeError(format!("{}", e).into())
+}
+
+/// (De)serialization of a closure. Always fails.
+pub(crate) mod udf {
+    use serde::{de, ser, Deserializer, Serializer};
+
+    pub(crate) fn serialize<T, S: Serializer>(_: &T, _: S) -> Result<S::Ok, S::Error> {
+        Err(ser::Error::custom(super::UDF_ERR))
+    }
+
+    pub(crate) fn deserialize<'de, T, D: Deserializer<'de>>(_: D) -> Result<T, D::Error> {
+        Err(de::Error::custom(super::UDF_ERR))
+    }
+}
+
+/// (De)serialization of an optional closure. Only succeeds if there is no closure.
+pub(crate) mod opt_udf {
+    use serde::{de, ser, Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<T, S: Serializer>(f: &Option<T>, s: S) -> Result<S::Ok, S::Error> {
+        match f {
+            None => s.serialize_none(),
+            Some(_) => Err(ser::Error::custom(super::UDF_ERR)),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, T, D: Deserializer<'de>>(d: D) -> Result<Option<T>, D::Error> {
+        match Option::<()>::deserialize(d)? {
+            None => Ok(None),
+            Some(()) => Err(de::Error::custom(super::UDF_ERR)),
+        }
+    }
+}
+
+/// A datetime is stored as the seconds since the UNIX epoch and the nanoseconds within that second.
+#[cfg(all(feature = "temporal", feature = "dtype-datetime"))]
+pub(crate) mod datetime {
+    use polars_core::utils::chrono::NaiveDateTime;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(v: &NaiveDateTime, s: S) -> Result<S::Ok, S::Error> {
+        (v.timestamp(), v.timestamp_subsec_nanos()).serialize(s)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<NaiveDateTime, D::Error> {
+        let (secs, nanos) = <(i64, u32)>::deserialize(d)?;
+        NaiveDateTime::from_timestamp_opt(secs, nanos)
+            .ok_or_else(|| de::Error::custom("datetime out of range"))
+    }
+}
+
+/// Deserialization of the id of a `Cache` node.
+///
+/// Ids are only unique within this process, so a deserialized id is replaced by a fresh one.
+/// Caches that shared an id in the serialized plan share the same fresh id.
+pub(crate) mod cache_id {
+    use crate::logical_plan::next_cache_id;
+    use polars_core::prelude::PlHashMap;
+    use serde::{Deserialize, Deserializer};
+    use std::cell::RefCell;
+
+    thread_local! {static CACHE_IDS: RefCell<PlHashMap<usize, usize>> = RefCell::new(PlHashMap::new())}
+
+    /// Forget the ids mapped so far, so that the next plan gets its own fresh ids.
+    pub(crate) fn reset() {
+        CACHE_IDS.with(|ids| ids.borrow_mut().clear())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<usize, D::Error> {
+        let id = usize::deserialize(d)?;
+        Ok(CACHE_IDS.with(|ids| *ids.borrow_mut().entry(id).or_insert_with(next_cache_id)))
+    }
+}
+
+fn with_fresh_cache_ids<T>(f: impl FnOnce() -> T) -> T {
+    cache_id::reset();
+    let out = f();
+    cache_id::reset();
+    out
+}
+
+macro_rules! impl_serialize {
+    ($type:ty) => {
+        impl $type {
+            /// Serialize to a JSON string.
+            pub fn to_json(&self) -> Result<String> {
+                serde_json::to_string(self).map_err(to_compute_err)
+            }
+
+            /// Deserialize from a JSON string.
+            pub fn from_json(json: &str) -> Result<Self> {
+                with_fresh_cache_ids(|| serde_json::from_str(json)).map_err(to_compute_err)
+            }
+
+            /// Serialize to the compact binary form.
+            pub fn to_bytes(&self) -> Result<Vec<u8>> {
+                bincode::serialize(self).map_err(to_compute_err)
+            }
+
+            /// Deserialize from the compact binary form.
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+                with_fresh_cache_ids(|| bincode::deserialize(bytes)).map_err(to_compute_err)
+            }
+        }
+    };
+}
+
+impl_serialize!(LogicalPlan);
+impl_serialize!(Expr);
+
+impl LazyFrame {
+    /// Serialize the query plan to a JSON string.
+    pub fn to_json(&self) -> Result<String> {
+        self.logical_plan.to_json()
+    }
+
+    /// Deserialize a query plan from a JSON string.
+    pub fn from_json(json: &str) -> Result<Self> {
+        LogicalPlan::from_json(json).map(LazyFrame::from)
+    }
+
+    /// Serialize the query plan to the compact binary form.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.logical_plan.to_bytes()
+    }
+
+    /// Deserialize a query plan from the compact binary form.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        LogicalPlan::from_bytes(bytes).map(LazyFrame::from)
+    }
+}
@@ -33,7 +33,7 @@ impl GroupByExec {
     }
 }
 
-fn groupby_helper(
+pub(crate) fn groupby_helper(
     df: DataFrame,
     keys: Vec<Series>,
     aggs: &[Arc<dyn PhysicalExpr>],
@@ -1,68 +1,192 @@
 use super::*;
 use crate::logical_plan::CsvParserOptions;
+#[cfg(feature = "parquet")]
+use crate::logical_plan::HivePartitions;
+#[cfg(feature = "ipc")]
+use crate::logical_plan::IpcScanOptions;
+#[cfg(feature = "json")]
+use crate::logical_plan::NdJsonScanOptions;
 use crate::utils::try_path_to_str;
+#[cfg(any(feature = "parquet", feature = "csv-file"))]
+use polars_core::utils::accumulate_dataframes_vertical;
+#[cfg(feature = "csv-file")]
+use polars_io::csv_core::utils::{get_reader_bytes, infer_file_schema, transcode_to_utf8};
 use polars_io::prelude::*;
-use polars_io::{csv::CsvEncoding, ScanAggregation};
+use polars_io::ScanAggregation;
 use std::mem;
+#[cfg(any(feature = "parquet", feature = "csv-file"))]
+use std::path::Path;
+
+/// Key under which the result of a scan of `paths` is cached.
+#[cfg(any(feature = "parquet", feature = "csv-file"))]
+fn scan_cache_key(paths: &[PathBuf], predicate: Option<&Arc<dyn PhysicalExpr>>) -> Result<String> {
+    let paths = paths
+        .iter()
+        .map(|path| try_path_to_str(path))
+        .collect::<Result<Vec<_>>>()?
+        .join(",");
+    Ok(match predicate {
+        Some(predicate) => format!("{}{:?}", paths, predicate.as_expression()),
+        None => paths,
+    })
+}
+
+/// The projected columns that are present in one file of a multi-file scan. If the file has none
+/// of them, its first column is read so that the number of rows is still known.
+#[cfg(any(feature = "parquet", feature = "csv-file"))]
+fn file_projection(
+    with_columns: &[String],
+    file_schema: &Schema,
+    path: &Path,
+) -> Result<Vec<String>> {
+    let mut columns: Vec<_> = with_columns
+        .iter()
+        .filter(|name| file_schema.column_with_name(name).is_some())
+        .cloned()
+        .collect();
+    if columns.is_empty() {
+        let first = file_schema.fields().first().ok_or_else(|| {
+            PolarsError::NoData(format!("the file {:?} of the scan has no columns", path).into())
+        })?;
+        columns.push(first.name().clone())
+    }
+    Ok(columns)
+}
+
+/// Whether one file of a multi-file scan has all the columns of the scan, so that the
+/// predicate can be pushed into its reader.
+#[cfg(any(feature = "parquet", feature = "csv-file"))]
+fn has_all_columns(file_schema: &Schema, schema: &Schema) -> bool {
+    schema
+        .fields()
+        .iter()
+        .all(|fld| file_schema.column_with_name(fld.name()).is_some())
+}
+
+/// Give the DataFrame read from one file of a multi-file scan the unified schema of the scan.
+/// Columns are cast to the unified dtype and columns that the file doesn't have are filled with nulls.
+#[cfg(any(feature = "parquet", feature = "csv-file"))]
+fn align_to_schema(
+    df: DataFrame,
+    schema: &Schema,
+    with_columns: Option<&[String]>,
+) -> Result<DataFrame> {
+    let height = df.height();
+    let columns = schema
+        .fields()
+        .iter()
+        .filter(|fld| with_columns.map_or(true, |columns| columns.contains(fld.name())))
+        .map(|fld| match df.column(fld.name()) {
+            Ok(s) if s.dtype() == fld.data_type() => Ok(s.clone()),
+            Ok(s) => s.cast(fld.data_type()),
+            Err(_) => UInt32Chunked::full_null(fld.name(), height)
+                .into_series()
+                .cast(fld.data_type()),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    DataFrame::new(columns)
+}
+
+#[cfg(any(feature = "parquet", feature = "csv-file"))]
+fn apply_predicate(
+    df: DataFrame,
+    predicate: Option<&Arc<dyn PhysicalIoExpr>>,
+) -> Result<DataFrame> {
+    match predicate {
+        Some(predicate) => {
+            let s = predicate.evaluate(&df)?;
+            let mask = s.bool().map_err(|_| {
+                PolarsError::ComputeError("filter predicate was not of type boolean".into())
+            })?;
+            df.filter(mask)
+        }
+        None => Ok(df),
+    }
+}
+
+/// Concatenate the DataFrames of the files of a multi-file scan. The row limit and the
+/// aggregations apply to all files together, so they are determined after concatenation.
+#[cfg(any(feature = "parquet", feature = "csv-file"))]
+fn finish_multi_file_scan(
+    dfs: Vec<DataFrame>,
+    stop_after_n_rows: Option<usize>,
+    aggregate: &[ScanAggregation],
+) -> Result<DataFrame> {
+    let mut df = accumulate_dataframes_vertical(dfs)?;
+    if stop_after_n_rows.is_some() {
+        df = df.head(stop_after_n_rows);
+    }
+    if !aggregate.is_empty() {
+        let columns = aggregate
+            .iter()
+            .map(|agg| agg.finish(&df))
+            .collect::<Result<_>>()?;
+        df = DataFrame::new_no_checks(columns);
+    }
+    Ok(df)
+}
 
 #[cfg(feature = "parquet")]
 pub struct ParquetExec {
-    path: PathBuf,
+    paths: Arc<Vec<PathBuf>>,
     schema: SchemaRef,
     with_columns: Option<Vec<String>>,
     predicate: Option<Arc<dyn PhysicalExpr>>,
     aggregate: Vec<ScanAggregation>,
     stop_after_n_rows: Option<usize>,
     cache: bool,
+    partitions: Option<Arc<HivePartitions>>,
 }
 
 #[cfg(feature = "parquet")]
 impl ParquetExec {
     pub(crate) fn new(
-        path: PathBuf,
+        paths: Arc<Vec<PathBuf>>,
         schema: SchemaRef,
         with_columns: Option<Vec<String>>,
         predicate: Option<Arc<dyn PhysicalExpr>>,
         aggregate: Vec<ScanAggregation>,
         stop_after_n_rows: Option<usize>,
         cache: bool,
+        partitions: Option<Arc<HivePartitions>>,
     ) -> Self {
         ParquetExec {
-            path,
+            paths,
             schema,
             with_columns,
             predicate,
             aggregate,
             stop_after_n_rows,
             cache,
+            partitions,
         }
     }
+
+    fn read_file(
+        path: &Path,
+        stop_after_n_rows: Option<usize>,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        aggregate: Option<&[ScanAggregation]>,
+        projection: Option<&[usize]>,
+    ) -> Result<DataFrame> {
+        let file = std::fs::File::open(path)?;
+        ParquetReader::new(file)
+            .with_stop_after_n_rows(stop_after_n_rows)
+            .finish_with_scan_ops(predicate, aggregate, projection)
+    }
 }
 
 #[cfg(feature = "parquet")]
 impl Executor for ParquetExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
-        let path_str = try_path_to_str(&self.path)?;
-        let cache_key = match &self.predicate {
-            Some(predicate) => format!("{}{:?}", path_str, predicate.as_expression()),
-            None => path_str.to_string(),
-        };
+        let cache_key = scan_cache_key(&self.paths, self.predicate.as_ref())?;
         if let Some(df) = state.cache_hit(&cache_key) {
             return Ok(df);
         }
         // cache miss
-        let file = std::fs::File::open(&self.path).unwrap();
-
         let with_columns = mem::take(&mut self.with_columns);
         let schema = mem::take(&mut self.schema);
 
-        let projection: Option<Vec<_>> = with_columns.map(|with_columns| {
-            with_columns
-                .iter()
-                .map(|name| schema.column_with_name(name).unwrap().0)
-                .collect()
-        });
-
         let stop_after_n_rows = set_n_rows(self.stop_after_n_rows);
         let aggregate = if self.aggregate.is_empty() {
             None
@@ -74,19 +198,189 @@ impl Executor for ParquetExec {
             .clone()
             .map(|expr| Arc::new(PhysicalIoHelper { expr }) as Arc<dyn PhysicalIoExpr>);
 
-        let df = ParquetReader::new(file)
-            .with_stop_after_n_rows(stop_after_n_rows)
-            .finish_with_scan_ops(
+        let df = if self.paths.len() == 1 && self.partitions.is_none() {
+            let projection: Option<Vec<_>> = with_columns.map(|with_columns| {
+                with_columns
+                    .iter()
+                    .map(|name| schema.column_with_name(name).unwrap().0)
+                    .collect()
+            });
+            Self::read_file(
+                &self.paths[0],
+                stop_after_n_rows,
                 predicate,
                 aggregate,
-                projection.as_ref().map(|v| v.as_ref()),
-            )?;
+                projection.as_deref(),
+            )?
+        } else {
+            let mut dfs = POOL.install(|| {
+                self.paths
+                    .par_iter()
+                    .enumerate()
+                    .map(|(idx, path)| {
+                        let file_schema =
+                            ParquetReader::new(std::fs::File::open(path)?).schema()?;
+                        // the predicate can only be pushed into the reader if no column is missing
+                        let complete = has_all_columns(&file_schema, &schema);
+                        let projection = with_columns
+                            .as_ref()
+                            .map(|columns| {
+                                file_projection(columns, &file_schema, path)?
+                                    .iter()
+                                    .map(|name| file_schema.index_of(name))
+                                    .collect::<Result<Vec<_>>>()
+                            })
+                            .transpose()?;
+                        let mut df = Self::read_file(
+                            path,
+                            stop_after_n_rows,
+                            predicate.clone().filter(|_| complete),
+                            None,
+                            projection.as_deref(),
+                        )?;
+                        if let Some(partitions) = &self.partitions {
+                            partitions.add_columns(idx, &mut df)?;
+                        }
+                        let df = align_to_schema(df, &schema, with_columns.as_deref())?;
+                        apply_predicate(df, predicate.as_ref().filter(|_| !complete))
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })?;
+            // all files may be pruned by their partition values
+            if dfs.is_empty() {
+                let df = DataFrame::new_no_checks(vec![]);
+                dfs.push(align_to_schema(df, &schema, with_columns.as_deref())?);
+            }
+            finish_multi_file_scan(dfs, stop_after_n_rows, &self.aggregate)?
+        };
 
         if self.cache {
             state.store_cache(cache_key, df.clone())
         }
         if std::env::var(POLARS_VERBOSE).is_ok() {
-            println!("parquet {:?} read", self.path);
+            println!("parquet {:?} read", self.paths);
+        }
+
+        Ok(df)
+    }
+}
+
+#[cfg(feature = "ipc")]
+pub struct IpcExec {
+    pub(crate) path: PathBuf,
+    pub(crate) schema: SchemaRef,
+    pub(crate) options: IpcScanOptions,
+    pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
+    pub(crate) aggregate: Vec<ScanAggregation>,
+}
+
+#[cfg(feature = "ipc")]
+impl Executor for IpcExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        let path_str = try_path_to_str(&self.path)?;
+        let cache_key = match &self.predicate {
+            Some(predicate) => format!("{}{:?}", path_str, predicate.as_expression()),
+            None => path_str.to_string(),
+        };
+        if self.options.cache {
+            if let Some(df) = state.cache_hit(&cache_key) {
+                return Ok(df);
+            }
+        }
+        // cache miss
+        let file = std::fs::File::open(&self.path)?;
+
+        let projection: Option<Vec<_>> = mem::take(&mut self.options.with_columns)
+            .map(|with_columns| {
+                with_columns
+                    .iter()
+                    .map(|name| self.schema.index_of(name))
+                    .collect::<Result<_>>()
+            })
+            .transpose()?;
+
+        let stop_after_n_rows = set_n_rows(self.options.stop_after_n_rows);
+        let aggregate = if self.aggregate.is_empty() {
+            None
+        } else {
+            Some(self.aggregate.as_slice())
+        };
+        let predicate = self
+            .predicate
+            .clone()
+            .map(|expr| Arc::new(PhysicalIoHelper { expr }) as Arc<dyn PhysicalIoExpr>);
+
+        let reader = IpcReader::new(file).with_stop_after_n_rows(stop_after_n_rows);
+        let df = if self.options.memmap {
+            reader
+                .memory_mapped()?
+                .finish_with_scan_ops(predicate, aggregate, projection)?
+        } else {
+            reader.finish_with_scan_ops(predicate, aggregate, projection)?
+        };
+
+        if self.options.cache {
+            state.store_cache(cache_key, df.clone())
+        }
+        if std::env::var(POLARS_VERBOSE).is_ok() {
+            println!("ipc {:?} read", self.path);
+        }
+
+        Ok(df)
+    }
+}
+
+#[cfg(feature = "json")]
+pub struct NdJsonExec {
+    pub(crate) path: PathBuf,
+    pub(crate) schema: SchemaRef,
+    pub(crate) options: NdJsonScanOptions,
+    pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
+    pub(crate) aggregate: Vec<ScanAggregation>,
+}
+
+#[cfg(feature = "json")]
+impl Executor for NdJsonExec {
+    fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
+        let path_str = try_path_to_str(&self.path)?;
+        let cache_key = match &self.predicate {
+            Some(predicate) => format!("{}{:?}", path_str, predicate.as_expression()),
+            None => path_str.to_string(),
+        };
+        if self.options.cache {
+            if let Some(df) = state.cache_hit(&cache_key) {
+                return Ok(df);
+            }
+        }
+        // cache miss
+        let file = std::fs::File::open(&self.path)?;
+
+        let with_columns = mem::take(&mut self.options.with_columns);
+        let stop_after_n_rows = set_n_rows(self.options.stop_after_n_rows);
+        let aggregate = if self.aggregate.is_empty() {
+            None
+        } else {
+            Some(self.aggregate.as_slice())
+        };
+        let predicate = self
+            .predicate
+            .clone()
+            .map(|expr| Arc::new(PhysicalIoHelper { expr }) as Arc<dyn PhysicalIoExpr>);
+
+        let df = JsonLineReader::new(file)
+            .with_schema(&self.schema)
+            .with_stop_after_n_rows(stop_after_n_rows)
+            .with_columns(with_columns)
+            .with_ignore_errors(self.options.ignore_errors)
+            .with_predicate(predicate)
+            .with_aggregate(aggregate)
+            .finish()?;
+
+        if self.options.cache {
+            state.store_cache(cache_key, df.clone())
+        }
+        if std::env::var(POLARS_VERBOSE).is_ok() {
+            println!("ndjson {:?} read", self.path);
         }
 
         Ok(df)
@@ -95,21 +389,69 @@ impl Executor for ParquetExec {
 
 #[cfg(feature = "csv-file")]
 pub struct CsvExec {
-    pub path: PathBuf,
+    pub paths: Arc<Vec<PathBuf>>,
     pub schema: SchemaRef,
     pub options: CsvParserOptions,
     pub predicate: Option<Arc<dyn PhysicalExpr>>,
     pub aggregate: Vec<ScanAggregation>,
 }
 
+#[cfg(feature = "csv-file")]
+impl CsvExec {
+    #[allow(clippy::too_many_arguments)]
+    fn read_file(
+        &self,
+        path: &Path,
+        schema: &Schema,
+        skip_rows: usize,
+        with_columns: Option<Vec<String>>,
+        stop_after_n_rows: Option<usize>,
+        predicate: Option<Arc<dyn PhysicalIoExpr>>,
+        aggregate: Option<&[ScanAggregation]>,
+    ) -> Result<DataFrame> {
+        CsvReader::from_path(path)?
+            .has_header(self.options.has_header)
+            .with_schema(schema)
+            .with_delimiter(self.options.delimiter)
+            .with_ignore_parser_errors(self.options.ignore_errors)
+            .with_skip_rows(skip_rows)
+            .with_stop_after_n_rows(stop_after_n_rows)
+            .with_columns(with_columns)
+            .low_memory(self.options.low_memory)
+            .with_null_values(self.options.null_values.clone())
+            .with_predicate(predicate)
+            .with_aggregate(aggregate)
+            .with_encoding(self.options.encoding)
+            .with_comment_char(self.options.comment_char)
+            .with_quote_char(self.options.quote_char)
+            .finish()
+    }
+
+    /// Infer the schema of one file of a multi-file scan. The dtypes are taken from the unified
+    /// schema, so that every file is parsed to the same types.
+    fn file_schema(&self, path: &Path) -> Result<(Schema, usize)> {
+        let mut file = std::fs::File::open(path)?;
+        let reader_bytes = get_reader_bytes(&mut file)?;
+        let reader_bytes = transcode_to_utf8(reader_bytes, self.options.encoding);
+        let mut skip_rows = self.options.skip_rows;
+        let (schema, _) = infer_file_schema(
+            &reader_bytes,
+            self.options.delimiter,
+            Some(100),
+            self.options.has_header,
+            Some(&self.schema),
+            &mut skip_rows,
+            self.options.comment_char,
+            self.options.quote_char,
+        )?;
+        Ok((schema, skip_rows))
+    }
+}
+
 #[cfg(feature = "csv-file")]
 impl Executor for CsvExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
-        let path_str = try_path_to_str(&self.path)?;
-        let state_key = match &self.predicate {
-            Some(predicate) => format!("{}{:?}", path_str, predicate.as_expression()),
-            None => path_str.to_string(),
-        };
+        let state_key = scan_cache_key(&self.paths, self.predicate.as_ref())?;
         if self.options.cache {
             if let Some(df) = state.cache_hit(&state_key) {
                 return Ok(df);
@@ -140,29 +482,50 @@ impl Executor for CsvExec {
             Some(self.aggregate.as_slice())
         };
 
-        let df = CsvReader::from_path(&self.path)
-            .unwrap()
-            .has_header(self.options.has_header)
-            .with_schema(&self.schema)
-            .with_delimiter(self.options.delimiter)
-            .with_ignore_parser_errors(self.options.ignore_errors)
-            .with_skip_rows(self.options.skip_rows)
-            .with_stop_after_n_rows(stop_after_n_rows)
-            .with_columns(with_columns)
-            .low_memory(self.options.low_memory)
-            .with_null_values(self.options.null_values.clone())
-            .with_predicate(predicate)
-            .with_aggregate(aggregate)
-            .with_encoding(CsvEncoding::LossyUtf8)
-            .with_comment_char(self.options.comment_char)
-            .with_quote_char(self.options.quote_char)
-            .finish()?;
+        let df = if self.paths.len() == 1 {
+            self.read_file(
+                &self.paths[0],
+                &self.schema,
+                self.options.skip_rows,
+                with_columns,
+                stop_after_n_rows,
+                predicate,
+                aggregate,
+            )?
+        } else {
+            let dfs = POOL.install(|| {
+                self.paths
+                    .par_iter()
+                    .map(|path| {
+                        let (file_schema, skip_rows) = self.file_schema(path)?;
+                        // the predicate can only be pushed into the reader if no column is missing
+                        let complete = has_all_columns(&file_schema, &self.schema);
+                        let projection = with_columns
+                            .as_ref()
+                            .map(|columns| file_projection(columns, &file_schema, path))
+                            .transpose()?;
+                        let df = self.read_file(
+                            path,
+                            &file_schema,
+                            skip_rows,
+                            projection,
+                            stop_after_n_rows,
+                            predicate.clone().filter(|_| complete),
+                            None,
+                        )?;
+                        let df = align_to_schema(df, &self.schema, with_columns.as_deref())?;
+                        apply_predicate(df, predicate.as_ref().filter(|_| !complete))
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })?;
+            finish_multi_file_scan(dfs, stop_after_n_rows, &self.aggregate)?
+        };
 
         if self.options.cache {
             state.store_cache(state_key, df.clone());
         }
         if std::env::var(POLARS_VERBOSE).is_ok() {
-            println!("csv {:?} read", self.path);
+            println!("csv {:?} read", self.paths);
         }
 
         Ok(df)
@@ -8,29 +8,36 @@ pub(crate) struct SortExec {
     pub(crate) reverse: Vec<bool>,
 }
 
+/// Evaluate the sort expressions on `df` and return the names of the columns to sort by.
+pub(crate) fn add_sort_columns(
+    df: &mut DataFrame,
+    by_column: &[Arc<dyn PhysicalExpr>],
+    state: &ExecutionState,
+) -> Result<Vec<String>> {
+    let by_columns = by_column
+        .iter()
+        .map(|e| e.evaluate(df, state))
+        .collect::<Result<Vec<_>>>()?;
+    let mut column_names = Vec::with_capacity(by_columns.len());
+    // replace the columns in the DataFrame with the expressions
+    // for col("foo") this is redundant
+    // for col("foo").reverse() this is not
+    for column in by_columns {
+        let name = column.name();
+        column_names.push(name.to_string());
+        // if error, expression create a new named column and we must add it to the DataFrame
+        // if ok, we have replaced the column with the expression eval
+        if df.apply(name, |_| column.clone()).is_err() {
+            df.hstack(&[column])?;
+        }
+    }
+    Ok(column_names)
+}
+
 impl Executor for SortExec {
     fn execute(&mut self, state: &ExecutionState) -> Result<DataFrame> {
         let mut df = self.input.execute(state)?;
-
-        let by_columns = self
-            .by_column
-            .iter()
-            .map(|e| e.evaluate(&df, state))
-            .collect::<Result<Vec<_>>>()?;
-        let mut column_names = Vec::with_capacity(by_columns.len());
-        // replace the columns in the DataFrame with the expressions
-        // for col("foo") this is redundant
-        // for col("foo").reverse() this is not
-        for column in by_columns {
-            let name = column.name();
-            column_names.push(name.to_string());
-            // if error, expression create a new named column and we must add it to the DataFrame
-            // if ok, we have replaced the column with the expression eval
-            if df.apply(name, |_| column.clone()).is_err() {
-                df.hstack(&[column])?;
-            }
-        }
-
+        let column_names = add_sort_columns(&mut df, &self.by_column, state)?;
         df.sort(&column_names, std::mem::take(&mut self.reverse))
     }
 }
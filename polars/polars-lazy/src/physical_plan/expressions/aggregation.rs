@@ -83,11 +83,11 @@ impl PhysicalAggregation for AggregationExpr {
                 Ok(rename_option_series(agg_s, &new_name))
             }
             GroupByMethod::Mean => {
-                let agg_s = ac.flat().into_owned().agg_mean(ac.groups());
+                let agg_s = ac.flat().into_owned().try_agg_mean(ac.groups())?;
                 Ok(rename_option_series(agg_s, &new_name))
             }
             GroupByMethod::Sum => {
-                let agg_s = ac.flat().into_owned().agg_sum(ac.groups());
+                let agg_s = ac.flat().into_owned().try_agg_sum(ac.groups())?;
                 Ok(rename_option_series(agg_s, &new_name))
             }
             GroupByMethod::Count => {
@@ -156,7 +156,7 @@ impl PhysicalAggregation for AggregationExpr {
             GroupByMethod::Mean => {
                 let series = self.expr.evaluate(df, state)?;
                 let mut new_name = fmt_groupby_column(series.name(), self.agg_type);
-                let agg_s = series.agg_sum(groups);
+                let agg_s = series.try_agg_sum(groups)?;
 
                 // If the aggregation is successful,
                 // we also count the valid values (len - null count)
@@ -201,8 +201,8 @@ impl PhysicalAggregation for AggregationExpr {
                 let count = final_df.column(&count_name).unwrap();
 
                 let (agg_count, agg_s) =
-                    POOL.join(|| count.agg_sum(groups), || series.agg_sum(groups));
-                let agg_s = agg_s.map(|agg_s| &agg_s / &agg_count.unwrap());
+                    POOL.join(|| count.agg_sum(groups), || series.try_agg_sum(groups));
+                let agg_s = agg_s?.map(|agg_s| &agg_s / &agg_count.unwrap());
                 Ok(rename_option_series(agg_s, &new_name))
             }
             GroupByMethod::List => {
@@ -2,7 +2,11 @@ use crate::physical_plan::state::ExecutionState;
 use crate::physical_plan::PhysicalAggregation;
 use crate::prelude::*;
 use polars_core::frame::groupby::GroupTuples;
+#[cfg(feature = "parquet")]
+use polars_core::utils::get_supertype;
 use polars_core::{prelude::*, POOL};
+#[cfg(feature = "parquet")]
+use polars_io::predicates::{BatchStats, StatsEvaluator};
 use std::sync::Arc;
 
 pub struct BinaryExpr {
@@ -44,7 +48,7 @@ pub(crate) fn apply_operator(left: &Series, right: &Series, op: Operator) -> Res
         Operator::TrueDivide => {
             use DataType::*;
             match left.dtype() {
-                Date | Datetime | Float32 | Float64 => Ok(left / right),
+                Date | Datetime(_, _) | Float32 | Float64 => Ok(left / right),
                 _ => Ok(&left.cast(&Float64)? / &right.cast(&Float64)?),
             }
         }
@@ -105,6 +109,11 @@ impl PhysicalExpr for BinaryExpr {
     fn as_agg_expr(&self) -> Result<&dyn PhysicalAggregation> {
         Ok(self)
     }
+
+    #[cfg(feature = "parquet")]
+    fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
+        Some(self)
+    }
 }
 
 impl PhysicalAggregation for BinaryExpr {
@@ -139,3 +148,83 @@ impl PhysicalAggregation for BinaryExpr {
         }
     }
 }
+
+#[cfg(feature = "parquet")]
+fn stats_should_read(expr: &Arc<dyn PhysicalExpr>, stats: &BatchStats) -> Result<bool> {
+    match expr.as_stats_evaluator() {
+        Some(evaluator) => evaluator.should_read(stats),
+        // we don't know anything about this expression
+        None => Ok(true),
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl BinaryExpr {
+    /// Check if `column <op> literal` can be true for a value in the range of the statistics.
+    fn should_read_comparison(&self, stats: &BatchStats) -> Result<bool> {
+        let (column, literal, op) = match (self.left.as_expression(), self.right.as_expression()) {
+            (Expr::Column(name), Expr::Literal(_)) => (name, &self.right, self.op),
+            // `literal <op> column`; swap the operands
+            (Expr::Literal(_), Expr::Column(name)) => {
+                let op = match self.op {
+                    Operator::Gt => Operator::Lt,
+                    Operator::GtEq => Operator::LtEq,
+                    Operator::Lt => Operator::Gt,
+                    Operator::LtEq => Operator::GtEq,
+                    op => op,
+                };
+                (name, &self.left, op)
+            }
+            _ => return Ok(true),
+        };
+        let (min, max) = match stats.get_stats(column) {
+            Some(col_stats) => match (col_stats.to_min(), col_stats.to_max()) {
+                (Some(min), Some(max)) => (min, max),
+                _ => return Ok(true),
+            },
+            None => return Ok(true),
+        };
+        let literal =
+            literal.evaluate(&DataFrame::new_no_checks(vec![]), &ExecutionState::new())?;
+        // compare in the supertype, casting the literal to the column type could truncate it
+        let dtype = match get_supertype(min.dtype(), literal.dtype()) {
+            Ok(dtype) => dtype,
+            Err(_) => return Ok(true),
+        };
+        let (min, max, literal) = (min.cast(&dtype)?, max.cast(&dtype)?, literal.cast(&dtype)?);
+
+        let holds = |s: BooleanChunked| s.get(0).unwrap_or(false);
+        let read = match op {
+            Operator::Gt => holds(ChunkCompare::<&Series>::gt(&max, &literal)),
+            Operator::GtEq => holds(ChunkCompare::<&Series>::gt_eq(&max, &literal)),
+            Operator::Lt => holds(ChunkCompare::<&Series>::lt(&min, &literal)),
+            Operator::LtEq => holds(ChunkCompare::<&Series>::lt_eq(&min, &literal)),
+            Operator::Eq => {
+                holds(ChunkCompare::<&Series>::lt_eq(&min, &literal))
+                    && holds(ChunkCompare::<&Series>::gt_eq(&max, &literal))
+            }
+            // only skip if all values are equal to the literal
+            Operator::NotEq => {
+                !(holds(ChunkCompare::<&Series>::eq(&min, &literal))
+                    && holds(ChunkCompare::<&Series>::eq(&max, &literal)))
+            }
+            _ => true,
+        };
+        Ok(read)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl StatsEvaluator for BinaryExpr {
+    fn should_read(&self, stats: &BatchStats) -> Result<bool> {
+        match self.op {
+            Operator::And => {
+                Ok(stats_should_read(&self.left, stats)? && stats_should_read(&self.right, stats)?)
+            }
+            Operator::Or => {
+                Ok(stats_should_read(&self.left, stats)? || stats_should_read(&self.right, stats)?)
+            }
+            _ => self.should_read_comparison(stats),
+        }
+    }
+}
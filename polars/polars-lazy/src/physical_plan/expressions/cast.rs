@@ -30,9 +30,9 @@ impl CastExpr {
                         .into_series())
                 }
                 #[cfg(feature = "dtype-datetime")]
-                DataType::Datetime => {
+                DataType::Datetime(tu, tz) => {
                     return Ok(Int64Chunked::full_null(input.name(), input.len())
-                        .into_date()
+                        .into_datetime(*tu, tz.clone())
                         .into_series())
                 }
                 _ => {}
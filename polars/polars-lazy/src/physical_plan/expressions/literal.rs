@@ -120,7 +120,7 @@ impl PhysicalExpr for LiteralExpr {
             Null => Field::new(name, DataType::Null),
             Range { data_type, .. } => Field::new(name, data_type.clone()),
             #[cfg(all(feature = "temporal", feature = "dtype-datetime"))]
-            DateTime(_) => Field::new(name, DataType::Datetime),
+            DateTime(_) => Field::new(name, DataType::Datetime(TimeUnit::Milliseconds, None)),
             Series(s) => s.field().into_owned(),
         };
         Ok(field)
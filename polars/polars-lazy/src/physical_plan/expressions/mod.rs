@@ -23,6 +23,8 @@ use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 use polars_core::frame::groupby::GroupTuples;
 use polars_core::prelude::*;
+#[cfg(feature = "parquet")]
+use polars_io::predicates::StatsEvaluator;
 use polars_io::PhysicalIoExpr;
 use std::borrow::Cow;
 
@@ -294,6 +296,12 @@ pub trait PhysicalExpr: Send + Sync {
             format!("{:?} is not an agg expression", e).into(),
         ))
     }
+
+    /// Can this expression be evaluated on the statistics of a batch, e.g. a parquet row group?
+    #[cfg(feature = "parquet")]
+    fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
+        None
+    }
 }
 
 /// Wrapper struct that allow us to use a PhysicalExpr in polars-io.
@@ -307,6 +315,11 @@ impl PhysicalIoExpr for PhysicalIoHelper {
     fn evaluate(&self, df: &DataFrame) -> Result<Series> {
         self.expr.evaluate(df, &Default::default())
     }
+
+    #[cfg(feature = "parquet")]
+    fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
+        self.expr.as_stats_evaluator()
+    }
 }
 
 pub trait PhysicalAggregation: Send + Sync {
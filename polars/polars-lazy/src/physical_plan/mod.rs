@@ -1,7 +1,10 @@
 pub mod executors;
 pub mod expressions;
 pub mod planner;
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv-file"))]
+pub(crate) mod sink;
 pub(crate) mod state;
+pub(crate) mod streaming;
 
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
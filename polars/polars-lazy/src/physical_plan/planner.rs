@@ -4,18 +4,28 @@ use crate::prelude::shift::ShiftExpr;
 use crate::prelude::*;
 use crate::{
     logical_plan::iterator::ArenaExprIter,
-    utils::{aexpr_to_root_names, aexpr_to_root_nodes, agg_source_paths, has_aexpr},
+    utils::{aexpr_to_root_names, aexpr_to_root_nodes, agg_cache_ids, agg_source_paths, has_aexpr},
 };
 use ahash::RandomState;
 use itertools::Itertools;
 use polars_core::prelude::*;
 use polars_core::{frame::groupby::GroupByMethod, utils::parallel_op_series};
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "csv-file",
+    feature = "ipc",
+    feature = "json"
+))]
 use polars_io::ScanAggregation;
 use std::collections::HashSet;
 use std::sync::Arc;
 
-#[cfg(any(feature = "parquet", feature = "csv-file"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "csv-file",
+    feature = "ipc",
+    feature = "json"
+))]
 fn aggregate_expr_to_scan_agg(
     aggregate: Vec<Node>,
     expr_arena: &mut Arena<AExpr>,
@@ -119,7 +129,7 @@ impl DefaultPlanner {
             }
             #[cfg(feature = "csv-file")]
             CsvScan {
-                path,
+                paths,
                 schema,
                 options,
                 predicate,
@@ -130,7 +140,7 @@ impl DefaultPlanner {
                     .map_or(Ok(None), |v| v.map(Some))?;
                 let aggregate = aggregate_expr_to_scan_agg(aggregate, expr_arena);
                 Ok(Box::new(CsvExec {
-                    path,
+                    paths,
                     schema,
                     options,
                     predicate,
@@ -139,13 +149,14 @@ impl DefaultPlanner {
             }
             #[cfg(feature = "parquet")]
             ParquetScan {
-                path,
+                paths,
                 schema,
                 with_columns,
                 predicate,
                 aggregate,
                 stop_after_n_rows,
                 cache,
+                partitions,
             } => {
                 let predicate = predicate
                     .map(|pred| self.create_physical_expr(pred, Context::Default, expr_arena))
@@ -153,15 +164,56 @@ impl DefaultPlanner {
 
                 let aggregate = aggregate_expr_to_scan_agg(aggregate, expr_arena);
                 Ok(Box::new(ParquetExec::new(
-                    path,
+                    paths,
                     schema,
                     with_columns,
                     predicate,
                     aggregate,
                     stop_after_n_rows,
                     cache,
+                    partitions,
                 )))
             }
+            #[cfg(feature = "ipc")]
+            IpcScan {
+                path,
+                schema,
+                options,
+                predicate,
+                aggregate,
+            } => {
+                let predicate = predicate
+                    .map(|pred| self.create_physical_expr(pred, Context::Default, expr_arena))
+                    .map_or(Ok(None), |v| v.map(Some))?;
+                let aggregate = aggregate_expr_to_scan_agg(aggregate, expr_arena);
+                Ok(Box::new(IpcExec {
+                    path,
+                    schema,
+                    options,
+                    predicate,
+                    aggregate,
+                }))
+            }
+            #[cfg(feature = "json")]
+            NdJsonScan {
+                path,
+                schema,
+                options,
+                predicate,
+                aggregate,
+            } => {
+                let predicate = predicate
+                    .map(|pred| self.create_physical_expr(pred, Context::Default, expr_arena))
+                    .map_or(Ok(None), |v| v.map(Some))?;
+                let aggregate = aggregate_expr_to_scan_agg(aggregate, expr_arena);
+                Ok(Box::new(NdJsonExec {
+                    path,
+                    schema,
+                    options,
+                    predicate,
+                    aggregate,
+                }))
+            }
             Projection { expr, input, .. } => {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
                 let phys_expr =
@@ -208,18 +260,8 @@ impl DefaultPlanner {
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
                 Ok(Box::new(ExplodeExec { input, columns }))
             }
-            Cache { input } => {
-                let fields = lp_arena.get(input).schema(lp_arena).fields();
-                // todo! fix the unique constraint in the schema. Probably in projection pushdown at joins
-                let mut unique =
-                    HashSet::with_capacity_and_hasher(fields.len(), RandomState::default());
-                // assumption of 80 characters per column name
-                let mut key = String::with_capacity(fields.len() * 80);
-                for field in fields {
-                    if unique.insert(field.name()) {
-                        key.push_str(field.name())
-                    }
-                }
+            Cache { input, id } => {
+                let key = format!("cache_{}", id);
                 let input = self.create_initial_physical_plan(input, lp_arena, expr_arena)?;
                 Ok(Box::new(CacheExec { key, input }))
             }
@@ -347,10 +389,16 @@ impl DefaultPlanner {
                     let mut sources_right =
                         HashSet::with_capacity_and_hasher(16, RandomState::default());
                     agg_source_paths(input_right, &mut sources_right, lp_arena);
+                    // a cache on both sides would be computed twice in parallel
+                    let mut caches_left = PlHashSet::with_capacity(4);
+                    agg_cache_ids(input_left, &mut caches_left, lp_arena);
+                    let mut caches_right = PlHashSet::with_capacity(4);
+                    agg_cache_ids(input_right, &mut caches_right, lp_arena);
                     sources_left
                         .intersection(&sources_right)
                         .collect_vec()
                         .is_empty()
+                        && caches_left.is_disjoint(&caches_right)
                 } else {
                     false
                 };
@@ -586,7 +634,7 @@ impl DefaultPlanner {
                             Context::Default => {
                                 let function = NoEq::new(Arc::new(move |s: &mut [Series]| {
                                     let s = std::mem::take(&mut s[0]);
-                                    parallel_op_series(|s| Ok(s.sum_as_series()), s, None)
+                                    parallel_op_series(|s| s.try_sum_as_series(), s, None)
                                 })
                                     as Arc<dyn SeriesUdf>);
                                 Ok(Arc::new(ApplyExpr {
@@ -650,7 +698,7 @@ impl DefaultPlanner {
                                 let function = NoEq::new(Arc::new(move |s: &mut [Series]| {
                                     let s = std::mem::take(&mut s[0]);
                                     let len = s.len() as f64;
-                                    parallel_op_series(|s| Ok(s.sum_as_series()), s, None)
+                                    parallel_op_series(|s| s.try_sum_as_series(), s, None)
                                         .map(|s| s.cast(&DataType::Float64).unwrap() / len)
                                 })
                                     as Arc<dyn SeriesUdf>);
@@ -0,0 +1,202 @@
+//! Execute a query batch by batch and write every batch to a file, so that the result never has
+//! to be held in memory.
+//!
+//! A query is executed in batches if it is a scan of a single file or of an in memory DataFrame,
+//! followed by filters, projections and `with_columns` of expressions that only combine values
+//! of the same row. Functions, e.g. of `map`, may look at the whole column, so queries that
+//! use them are collected too. Every other query is collected before it is written.
+use crate::logical_plan::Context;
+use crate::physical_plan::state::ExecutionState;
+use crate::physical_plan::streaming::source::{
+    is_batched_source, read_batches, take_scan_operators,
+};
+use crate::physical_plan::streaming::{
+    is_elementwise, is_elementwise_projection, morsel_size, BatchExec,
+};
+use crate::prelude::*;
+use polars_core::prelude::*;
+use polars_io::prelude::*;
+use std::sync::{Arc, Mutex};
+
+/// A writer that writes the batches of a query to a file.
+pub(crate) trait SinkWriter {
+    fn write_batch(&mut self, df: &DataFrame) -> Result<()>;
+
+    /// Finish the file once all batches are written.
+    fn finish(self: Box<Self>) -> Result<()>;
+}
+
+#[cfg(feature = "parquet")]
+impl SinkWriter for BatchedParquetWriter {
+    fn write_batch(&mut self, df: &DataFrame) -> Result<()> {
+        BatchedParquetWriter::write_batch(self, df)
+    }
+
+    fn finish(self: Box<Self>) -> Result<()> {
+        BatchedParquetWriter::finish(*self)
+    }
+}
+
+#[cfg(feature = "ipc")]
+impl<W: std::io::Write> SinkWriter for BatchedIpcWriter<W> {
+    fn write_batch(&mut self, df: &DataFrame) -> Result<()> {
+        BatchedIpcWriter::write_batch(self, df)
+    }
+
+    fn finish(self: Box<Self>) -> Result<()> {
+        BatchedIpcWriter::finish(*self)
+    }
+}
+
+#[cfg(feature = "csv-file")]
+impl<W: std::io::Write> SinkWriter for BatchedCsvWriter<W> {
+    fn write_batch(&mut self, df: &DataFrame) -> Result<()> {
+        BatchedCsvWriter::write_batch(self, df)
+    }
+
+    fn finish(self: Box<Self>) -> Result<()> {
+        BatchedCsvWriter::finish(*self)
+    }
+}
+
+/// The operators between the root of the query and its scan, from the root down, and the
+/// scan. Returns `None` if the query cannot be executed in batches.
+fn batched_plan(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<(Vec<Node>, Node)> {
+    use ALogicalPlan::*;
+    let mut operators = vec![];
+    let mut node = root;
+    loop {
+        let lp = lp_arena.get(node);
+        let input = match lp {
+            Selection { input, predicate } if is_elementwise(*predicate, expr_arena) => *input,
+            Projection { input, expr, .. } | LocalProjection { input, expr, .. }
+                if is_elementwise_projection(expr, expr_arena) =>
+            {
+                *input
+            }
+            HStack { input, exprs, .. } if exprs.iter().all(|&e| is_elementwise(e, expr_arena)) => {
+                *input
+            }
+            lp if is_batched_source(lp, expr_arena) => return Some((operators, node)),
+            _ => return None,
+        };
+        operators.push(node);
+        node = input;
+    }
+}
+
+/// Pushes batches through the operators of the query and writes the results.
+struct BatchSink<F> {
+    executor: Box<dyn Executor>,
+    batch: Arc<Mutex<Option<DataFrame>>>,
+    state: ExecutionState,
+    create_writer: Option<F>,
+    writer: Option<Box<dyn SinkWriter>>,
+}
+
+impl<F> BatchSink<F>
+where
+    F: FnOnce(&Schema) -> Result<Box<dyn SinkWriter>>,
+{
+    fn push(&mut self, df: DataFrame) -> Result<()> {
+        *self.batch.lock().unwrap() = Some(df);
+        let df = self.executor.execute(&self.state)?;
+        self.state.clear_expr_cache();
+        // the writer is created with the schema of the first result, as the dtypes of the
+        // executed operators may be wider than the dtypes of the logical plan
+        if self.writer.is_none() {
+            let create_writer = self.create_writer.take().unwrap();
+            self.writer = Some(create_writer(&df.schema())?);
+        }
+        self.writer.as_mut().unwrap().write_batch(&df)
+    }
+
+    fn finish(mut self, schema: &Schema) -> Result<()> {
+        let writer = match self.writer.take() {
+            Some(writer) => writer,
+            // the scan had no rows
+            None => (self.create_writer.take().unwrap())(schema)?,
+        };
+        writer.finish()
+    }
+}
+
+/// Execute the query and write its result with the writer created by `create_writer`.
+pub(crate) fn sink<F>(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    create_writer: F,
+) -> Result<()>
+where
+    F: FnOnce(&Schema) -> Result<Box<dyn SinkWriter>>,
+{
+    let planner = DefaultPlanner::default();
+    let state = ExecutionState::new();
+
+    let (operators, source) = match batched_plan(root, lp_arena, expr_arena) {
+        Some(plan) => plan,
+        None => {
+            if std::env::var("POLARS_VERBOSE").is_ok() {
+                eprintln!("sink: the query cannot be executed in batches and is collected first");
+            }
+            let mut executor = planner.create_physical_plan(root, lp_arena, expr_arena)?;
+            let df = executor.execute(&state)?;
+            let mut writer = create_writer(&df.schema())?;
+            writer.write_batch(&df)?;
+            return writer.finish();
+        }
+    };
+    let schema = lp_arena.get(root).schema(lp_arena).clone();
+
+    // rebuild the operators on top of an executor that produces the current batch
+    let batch = Arc::new(Mutex::new(None));
+    let mut executor: Box<dyn Executor> = Box::new(BatchExec {
+        batch: batch.clone(),
+    });
+    let mut source = lp_arena.take(source);
+    let (projection, predicate) = take_scan_operators(&mut source);
+    if let Some(projection) = projection {
+        let exprs =
+            planner.create_physical_expressions(&projection, Context::Default, expr_arena)?;
+        executor = Box::new(ProjectionExec::new(executor, exprs));
+    }
+    if let Some(predicate) = predicate {
+        let predicate = planner.create_physical_expr(predicate, Context::Default, expr_arena)?;
+        executor = Box::new(FilterExec::new(predicate, executor));
+    }
+    for node in operators.into_iter().rev() {
+        executor = match lp_arena.take(node) {
+            ALogicalPlan::Selection { predicate, .. } => {
+                let predicate =
+                    planner.create_physical_expr(predicate, Context::Default, expr_arena)?;
+                Box::new(FilterExec::new(predicate, executor))
+            }
+            ALogicalPlan::Projection { expr, .. } | ALogicalPlan::LocalProjection { expr, .. } => {
+                let exprs =
+                    planner.create_physical_expressions(&expr, Context::Default, expr_arena)?;
+                Box::new(ProjectionExec::new(executor, exprs))
+            }
+            ALogicalPlan::HStack { exprs, .. } => {
+                let exprs =
+                    planner.create_physical_expressions(&exprs, Context::Default, expr_arena)?;
+                Box::new(StackExec::new(executor, exprs))
+            }
+            _ => unreachable!(),
+        };
+    }
+
+    let mut sink = BatchSink {
+        executor,
+        batch,
+        state,
+        create_writer: Some(create_writer),
+        writer: None,
+    };
+    read_batches(source, morsel_size(), &mut |df| sink.push(df))?;
+    sink.finish(&schema)
+}
@@ -0,0 +1,398 @@
+//! A morsel-driven streaming engine.
+//!
+//! A pipeline reads its scan in morsels of a few thousand rows and pushes every morsel through
+//! a chain of [operators](operators::Operator) on the thread pool. The results flow into a
+//! [sink](sinks::Sink) that produces the result of the pipeline. Filters, projections,
+//! `with_columns` and the left side of inner and left joins are operators; hash aggregations
+//! and sorts are sinks. A pipeline without such a sink collects its morsels in order.
+//!
+//! Only the state of the sink is held in memory, e.g. about one row per group for an
+//! aggregation. The right side of a join is materialized and sorts hold their input. With the
+//! `spill` feature, sorts and aggregations whose state exceeds the memory budget spill it to
+//! disk, see [`spill`]. The in-memory executors never spill.
+//!
+//! Pipelines are inserted into the optimized logical plan as `Udf` nodes. The nodes they do not
+//! cover are executed by the in-memory executors, which may in turn contain pipelines.
+mod operators;
+mod sinks;
+pub(crate) mod source;
+#[cfg(feature = "spill")]
+mod spill;
+
+use crate::logical_plan::{Context, FETCH_ROWS};
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use crate::utils::has_aexpr;
+use operators::*;
+use polars_core::export::arrow::array::{new_empty_array, ArrayRef};
+use polars_core::export::arrow::compute::aggregate::estimated_bytes_size;
+use polars_core::{prelude::*, POOL};
+use rayon::prelude::*;
+use sinks::*;
+use source::{is_batched_source, read_batches, take_scan_operators};
+use std::sync::Mutex;
+
+/// Number of rows of the morsels read from the scans. Can be set with the
+/// `POLARS_STREAMING_CHUNK_SIZE` environment variable.
+pub(crate) fn morsel_size() -> usize {
+    std::env::var("POLARS_STREAMING_CHUNK_SIZE")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(50_000)
+}
+
+/// Estimate of the memory held by the columns of `df`.
+pub(crate) fn estimated_size(df: &DataFrame) -> usize {
+    df.get_columns()
+        .iter()
+        .flat_map(|s| s.chunks().iter())
+        .map(|arr| estimated_bytes_size(arr.as_ref()))
+        .sum()
+}
+
+/// Whether every output row of the expression only depends on the same input row, so that
+/// evaluating it per batch gives the same rows as evaluating it on all rows at once.
+pub(crate) fn is_elementwise(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    !has_aexpr(node, expr_arena, |e| match e {
+        AExpr::Alias(_, _)
+        | AExpr::Column(_)
+        | AExpr::BinaryExpr { .. }
+        | AExpr::Not(_)
+        | AExpr::IsNotNull(_)
+        | AExpr::IsNull(_)
+        | AExpr::Cast { .. }
+        | AExpr::Ternary { .. } => false,
+        AExpr::Literal(lv) => matches!(lv, LiteralValue::Series(_) | LiteralValue::Range { .. }),
+        // the function of a `map` gets the whole column, e.g. `cumsum` or `rank`, and nothing
+        // tells whether it only looks at single rows
+        _ => true,
+    })
+}
+
+/// A projection of literals only produces a single row, whatever the number of input rows.
+pub(crate) fn is_elementwise_projection(exprs: &[Node], expr_arena: &Arena<AExpr>) -> bool {
+    exprs.iter().all(|&e| {
+        is_elementwise(e, expr_arena) && has_aexpr(e, expr_arena, |e| matches!(e, AExpr::Column(_)))
+    })
+}
+
+/// Produces the batch that is currently pushed through the in-memory executors built on top of
+/// it.
+pub(crate) struct BatchExec {
+    pub(crate) batch: Arc<Mutex<Option<DataFrame>>>,
+}
+
+impl Executor for BatchExec {
+    fn execute(&mut self, _state: &ExecutionState) -> Result<DataFrame> {
+        Ok(self
+            .batch
+            .lock()
+            .unwrap()
+            .take()
+            .expect("a batch is set before the operators are executed"))
+    }
+}
+
+/// A DataFrame without rows with the given schema.
+pub(crate) fn empty_df(schema: &Schema) -> DataFrame {
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|fld| {
+            let arr = ArrayRef::from(new_empty_array(fld.data_type().to_arrow()));
+            Series::try_from((fld.name().as_str(), arr)).unwrap()
+        })
+        .collect();
+    DataFrame::new_no_checks(columns)
+}
+
+/// A morsel and its position in the scan.
+pub(crate) struct DataChunk {
+    pub(crate) chunk_index: usize,
+    pub(crate) data: DataFrame,
+}
+
+pub(crate) struct Pipeline {
+    /// Taken by the first run. The operators and the sink keep state across the morsels of a
+    /// run, so a pipeline only runs once.
+    source: Option<ALogicalPlan>,
+    operators: Vec<Box<dyn Operator>>,
+    sink: Box<dyn Sink>,
+}
+
+impl Pipeline {
+    fn run(&mut self) -> Result<DataFrame> {
+        let source = self.source.take().ok_or_else(|| {
+            PolarsError::ComputeError("a streaming pipeline can only be executed once".into())
+        })?;
+        let state = ExecutionState::new();
+        if state.verbose {
+            eprintln!(
+                "run streaming pipeline with {} operators",
+                self.operators.len()
+            );
+        }
+        for op in &mut self.operators {
+            op.prepare(&state)?;
+        }
+
+        let operators = &self.operators;
+        let sink = &*self.sink;
+        let n_threads = POOL.current_num_threads();
+        let mut morsels = Vec::with_capacity(n_threads);
+        let mut chunk_index = 0;
+        // every thread gets a morsel, so at most `n_threads` morsels are in flight
+        read_batches(source, morsel_size(), &mut |data| {
+            morsels.push(DataChunk { chunk_index, data });
+            chunk_index += 1;
+            if morsels.len() == n_threads {
+                process_morsels(std::mem::take(&mut morsels), operators, sink)?;
+            }
+            Ok(())
+        })?;
+        process_morsels(morsels, operators, sink)?;
+        self.sink.finalize()
+    }
+}
+
+fn process_morsels(
+    morsels: Vec<DataChunk>,
+    operators: &[Box<dyn Operator>],
+    sink: &dyn Sink,
+) -> Result<()> {
+    POOL.install(|| {
+        morsels.into_par_iter().try_for_each(|chunk| {
+            let state = ExecutionState::new();
+            let mut data = chunk.data;
+            for op in operators {
+                data = op.execute(data, &state)?;
+            }
+            sink.sink(DataChunk {
+                chunk_index: chunk.chunk_index,
+                data,
+            })
+        })
+    })
+}
+
+/// Runs the pipeline when the in-memory executors reach the `Udf` node it replaced.
+struct PipelineUdf(Mutex<Pipeline>);
+
+impl DataFrameUdf for PipelineUdf {
+    fn call_udf(&self, _df: DataFrame) -> Result<DataFrame> {
+        self.0.lock().unwrap().run()
+    }
+}
+
+/// The nodes of the logical plan that make up a pipeline.
+struct PipelineNodes {
+    /// An aggregation or sort that ends the pipeline.
+    sink: Option<Node>,
+    /// The operators from the top of the pipeline down.
+    operators: Vec<Node>,
+    source: Node,
+}
+
+fn is_streamable_join(options: &JoinOptions) -> bool {
+    matches!(options.how, JoinType::Inner | JoinType::Left)
+        && options.asof_by_left.is_empty()
+        && options.asof_by_right.is_empty()
+}
+
+fn pipeline_nodes(
+    root: Node,
+    lp_arena: &Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<PipelineNodes> {
+    use ALogicalPlan::*;
+    let (sink, mut node) = match lp_arena.get(root) {
+        Aggregate {
+            input,
+            keys,
+            aggs,
+            apply: None,
+            maintain_order: false,
+            ..
+        } if is_streamable_groupby(keys, aggs, expr_arena) => (Some(root), *input),
+        Sort { input, .. } => (Some(root), *input),
+        _ => (None, root),
+    };
+
+    let mut operators = vec![];
+    loop {
+        let input = match lp_arena.get(node) {
+            Selection { input, predicate } if is_elementwise(*predicate, expr_arena) => *input,
+            Projection { input, expr, .. } | LocalProjection { input, expr, .. }
+                if is_elementwise_projection(expr, expr_arena) =>
+            {
+                *input
+            }
+            HStack { input, exprs, .. } if exprs.iter().all(|&e| is_elementwise(e, expr_arena)) => {
+                *input
+            }
+            Join {
+                input_left,
+                options,
+                ..
+            } if is_streamable_join(options) => *input_left,
+            lp if is_batched_source(lp, expr_arena) => break,
+            _ => return None,
+        };
+        operators.push(node);
+        node = input;
+    }
+    // a bare scan is not worth a pipeline
+    if sink.is_none() && operators.is_empty() {
+        return None;
+    }
+    Some(PipelineNodes {
+        sink,
+        operators,
+        source: node,
+    })
+}
+
+fn create_pipeline(
+    nodes: PipelineNodes,
+    schema: Schema,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    memory_budget: Option<usize>,
+) -> Result<Pipeline> {
+    let planner = DefaultPlanner {};
+    let mut operators: Vec<Box<dyn Operator>> = vec![];
+
+    let mut source = lp_arena.take(nodes.source);
+    let (projection, predicate) = take_scan_operators(&mut source);
+    if let Some(projection) = projection {
+        let exprs =
+            planner.create_physical_expressions(&projection, Context::Default, expr_arena)?;
+        operators.push(Box::new(ProjectionOperator { exprs }));
+    }
+    if let Some(predicate) = predicate {
+        let predicate = planner.create_physical_expr(predicate, Context::Default, expr_arena)?;
+        operators.push(Box::new(FilterOperator { predicate }));
+    }
+
+    for node in nodes.operators.into_iter().rev() {
+        let op: Box<dyn Operator> = match lp_arena.take(node) {
+            ALogicalPlan::Selection { predicate, .. } => {
+                let predicate =
+                    planner.create_physical_expr(predicate, Context::Default, expr_arena)?;
+                Box::new(FilterOperator { predicate })
+            }
+            ALogicalPlan::Projection { expr, .. } | ALogicalPlan::LocalProjection { expr, .. } => {
+                let exprs =
+                    planner.create_physical_expressions(&expr, Context::Default, expr_arena)?;
+                Box::new(ProjectionOperator { exprs })
+            }
+            ALogicalPlan::HStack { exprs, .. } => {
+                let exprs =
+                    planner.create_physical_expressions(&exprs, Context::Default, expr_arena)?;
+                Box::new(HStackOperator { exprs })
+            }
+            ALogicalPlan::Join {
+                input_right,
+                left_on,
+                right_on,
+                options,
+                ..
+            } => {
+                // the right side is executed in memory, but may contain pipelines itself
+                insert_streaming_nodes(input_right, lp_arena, expr_arena, memory_budget)?;
+                let right =
+                    planner.create_initial_physical_plan(input_right, lp_arena, expr_arena)?;
+                let left_on =
+                    planner.create_physical_expressions(&left_on, Context::Default, expr_arena)?;
+                let right_on =
+                    planner.create_physical_expressions(&right_on, Context::Default, expr_arena)?;
+                Box::new(JoinOperator::new(
+                    right,
+                    left_on,
+                    right_on,
+                    options.how,
+                    options.suffix,
+                ))
+            }
+            _ => unreachable!(),
+        };
+        operators.push(op);
+    }
+
+    let sink: Box<dyn Sink> = match nodes.sink.map(|node| lp_arena.take(node)) {
+        Some(ALogicalPlan::Aggregate { keys, aggs, .. }) => Box::new(GroupBySink::new(
+            &keys,
+            &aggs,
+            schema,
+            expr_arena,
+            memory_budget,
+        )?),
+        Some(ALogicalPlan::Sort {
+            by_column, reverse, ..
+        }) => {
+            let can_spill = by_column.iter().all(|&e| is_elementwise(e, expr_arena));
+            let by_column =
+                planner.create_physical_expressions(&by_column, Context::Default, expr_arena)?;
+            Box::new(SortSink::new(
+                schema,
+                by_column,
+                reverse,
+                can_spill,
+                memory_budget,
+            ))
+        }
+        None => Box::new(OrderedSink::new(schema)),
+        _ => unreachable!(),
+    };
+
+    Ok(Pipeline {
+        source: Some(source),
+        operators,
+        sink,
+    })
+}
+
+/// Replace the parts of the plan that can be streamed by pipelines. The sinks spill their state
+/// once it exceeds `memory_budget` bytes.
+pub(crate) fn insert_streaming_nodes(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    memory_budget: Option<usize>,
+) -> Result<()> {
+    // the scans of the pipelines don't know about `fetch`
+    if FETCH_ROWS.with(|fetch_rows| fetch_rows.get()).is_some() {
+        return Ok(());
+    }
+
+    match pipeline_nodes(root, lp_arena, expr_arena) {
+        Some(nodes) => {
+            let schema = lp_arena.get(root).schema(lp_arena).clone();
+            let pipeline =
+                create_pipeline(nodes, schema.clone(), lp_arena, expr_arena, memory_budget)?;
+            // the pipeline has no input, the `Udf` gets an empty DataFrame
+            let input = lp_arena.add(ALogicalPlan::DataFrameScan {
+                df: Arc::new(DataFrame::new_no_checks(vec![])),
+                schema: Arc::new(Schema::new(vec![])),
+                projection: None,
+                selection: None,
+            });
+            lp_arena.replace(
+                root,
+                ALogicalPlan::Udf {
+                    input,
+                    function: Arc::new(PipelineUdf(Mutex::new(pipeline))),
+                    predicate_pd: false,
+                    projection_pd: false,
+                    schema: Some(Arc::new(schema)),
+                },
+            );
+        }
+        None => {
+            for input in lp_arena.get(root).get_inputs() {
+                insert_streaming_nodes(input, lp_arena, expr_arena, memory_budget)?;
+            }
+        }
+    }
+    Ok(())
+}
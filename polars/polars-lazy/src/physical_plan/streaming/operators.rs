@@ -0,0 +1,125 @@
+//! Operators transform a morsel into a new morsel without looking at the other morsels.
+use crate::physical_plan::executors::evaluate_physical_expressions;
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use polars_core::prelude::*;
+
+pub(crate) trait Operator: Send + Sync {
+    /// Called once before the first morsel is executed.
+    fn prepare(&mut self, _state: &ExecutionState) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called from the worker threads for every morsel.
+    fn execute(&self, chunk: DataFrame, state: &ExecutionState) -> Result<DataFrame>;
+}
+
+pub(crate) struct FilterOperator {
+    pub(crate) predicate: Arc<dyn PhysicalExpr>,
+}
+
+impl Operator for FilterOperator {
+    fn execute(&self, chunk: DataFrame, state: &ExecutionState) -> Result<DataFrame> {
+        let s = self.predicate.evaluate(&chunk, state)?;
+        chunk.filter(s.bool()?)
+    }
+}
+
+pub(crate) struct ProjectionOperator {
+    pub(crate) exprs: Vec<Arc<dyn PhysicalExpr>>,
+}
+
+impl Operator for ProjectionOperator {
+    fn execute(&self, chunk: DataFrame, state: &ExecutionState) -> Result<DataFrame> {
+        evaluate_physical_expressions(&chunk, &self.exprs, state)
+    }
+}
+
+pub(crate) struct HStackOperator {
+    pub(crate) exprs: Vec<Arc<dyn PhysicalExpr>>,
+}
+
+impl Operator for HStackOperator {
+    fn execute(&self, mut chunk: DataFrame, state: &ExecutionState) -> Result<DataFrame> {
+        let height = chunk.height();
+        let columns = self
+            .exprs
+            .iter()
+            .map(|expr| {
+                expr.evaluate(&chunk, state).map(|series| {
+                    // literals are broadcasted to the height of the morsel
+                    if series.len() == 1 && height > 1 {
+                        series.expand_at_index(0, height)
+                    } else {
+                        series
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for s in columns {
+            let name = s.name().to_string();
+            chunk.replace_or_add(&name, s)?;
+        }
+        Ok(chunk)
+    }
+}
+
+/// Probes the morsels of the left side of an inner or left join against the right side. The
+/// right side is executed in memory before the first morsel arrives.
+pub(crate) struct JoinOperator {
+    right_input: Option<Box<dyn Executor>>,
+    right: DataFrame,
+    left_on: Vec<Arc<dyn PhysicalExpr>>,
+    right_on: Vec<Arc<dyn PhysicalExpr>>,
+    right_names: Vec<String>,
+    how: JoinType,
+    suffix: Option<String>,
+}
+
+impl JoinOperator {
+    pub(crate) fn new(
+        right_input: Box<dyn Executor>,
+        left_on: Vec<Arc<dyn PhysicalExpr>>,
+        right_on: Vec<Arc<dyn PhysicalExpr>>,
+        how: JoinType,
+        suffix: Option<String>,
+    ) -> Self {
+        JoinOperator {
+            right_input: Some(right_input),
+            right: DataFrame::new_no_checks(vec![]),
+            left_on,
+            right_on,
+            right_names: vec![],
+            how,
+            suffix,
+        }
+    }
+}
+
+impl Operator for JoinOperator {
+    fn prepare(&mut self, state: &ExecutionState) -> Result<()> {
+        let right = self.right_input.take().unwrap().execute(state)?;
+        self.right_names = self
+            .right_on
+            .iter()
+            .map(|e| e.evaluate(&right, state).map(|s| s.name().to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        self.right = right;
+        Ok(())
+    }
+
+    fn execute(&self, chunk: DataFrame, state: &ExecutionState) -> Result<DataFrame> {
+        let left_names = self
+            .left_on
+            .iter()
+            .map(|e| e.evaluate(&chunk, state).map(|s| s.name().to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        chunk.join(
+            &self.right,
+            &left_names,
+            &self.right_names,
+            self.how,
+            self.suffix.clone(),
+        )
+    }
+}
@@ -0,0 +1,439 @@
+//! Sinks end a pipeline. They receive the morsels in any order and produce the result of the
+//! pipeline once all morsels are sunk.
+#[cfg(feature = "spill")]
+use super::spill::*;
+use super::{empty_df, estimated_size, DataChunk};
+use crate::logical_plan::Context;
+use crate::physical_plan::executors::groupby::groupby_helper;
+use crate::physical_plan::executors::sort::add_sort_columns;
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use std::sync::Mutex;
+
+/// Number of partially aggregated rows at which they are aggregated again.
+const COMBINE_THRESHOLD: usize = 1_000_000;
+
+pub(crate) trait Sink: Send + Sync {
+    /// Called from the worker threads with the result of every morsel.
+    fn sink(&self, chunk: DataChunk) -> Result<()>;
+
+    /// Produce the result once all morsels are sunk.
+    fn finalize(&self) -> Result<DataFrame>;
+}
+
+/// Collects the morsels and concatenates them in the order of the source.
+pub(crate) struct OrderedSink {
+    chunks: Mutex<Vec<DataChunk>>,
+    schema: Schema,
+}
+
+impl OrderedSink {
+    pub(crate) fn new(schema: Schema) -> Self {
+        OrderedSink {
+            chunks: Mutex::new(vec![]),
+            schema,
+        }
+    }
+}
+
+impl Sink for OrderedSink {
+    fn sink(&self, chunk: DataChunk) -> Result<()> {
+        self.chunks.lock().unwrap().push(chunk);
+        Ok(())
+    }
+
+    fn finalize(&self) -> Result<DataFrame> {
+        let mut chunks = std::mem::take(&mut *self.chunks.lock().unwrap());
+        if chunks.is_empty() {
+            return Ok(empty_df(&self.schema));
+        }
+        chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+        let mut df = accumulate_dataframes_vertical(chunks.into_iter().map(|chunk| chunk.data))?;
+        df.rechunk();
+        Ok(df)
+    }
+}
+
+/// Sorts its input. The input is collected in memory and sorted like the `SortExec` once all
+/// morsels are sunk. With the `spill` feature, input that exceeds the memory budget is sorted in
+/// runs that are spilled to disk and merged.
+pub(crate) struct SortSink {
+    input: OrderedSink,
+    by_column: Vec<Arc<dyn PhysicalExpr>>,
+    reverse: Vec<bool>,
+    #[cfg(feature = "spill")]
+    spill: Option<ExternalSort>,
+}
+
+impl SortSink {
+    /// Spilling sorts every run on its own, so it is only done if `can_spill`, i.e. if the sort
+    /// expressions are row-wise. Runs are spilled once the input exceeds `memory_budget` bytes.
+    #[cfg_attr(not(feature = "spill"), allow(unused_variables))]
+    pub(crate) fn new(
+        schema: Schema,
+        by_column: Vec<Arc<dyn PhysicalExpr>>,
+        reverse: Vec<bool>,
+        can_spill: bool,
+        memory_budget: Option<usize>,
+    ) -> Self {
+        SortSink {
+            input: OrderedSink::new(schema),
+            by_column,
+            reverse,
+            #[cfg(feature = "spill")]
+            spill: if can_spill {
+                Some(ExternalSort::new(memory_budget))
+            } else {
+                None
+            },
+        }
+    }
+}
+
+impl Sink for SortSink {
+    fn sink(&self, chunk: DataChunk) -> Result<()> {
+        #[cfg(feature = "spill")]
+        if let Some(spill) = &self.spill {
+            let must_spill = spill.buffer(&chunk.data);
+            self.input.sink(chunk)?;
+            if must_spill {
+                let df = self.input.finalize()?;
+                spill.spill_run(df, &self.by_column, &self.reverse)?;
+            }
+            return Ok(());
+        }
+        self.input.sink(chunk)
+    }
+
+    fn finalize(&self) -> Result<DataFrame> {
+        let mut df = self.input.finalize()?;
+        #[cfg(feature = "spill")]
+        if let Some(spill) = self.spill.as_ref().filter(|spill| spill.is_spilled()) {
+            spill.spill_run(df, &self.by_column, &self.reverse)?;
+            return spill.merge(&self.reverse);
+        }
+        let names = add_sort_columns(&mut df, &self.by_column, &ExecutionState::new())?;
+        df.sort(&names, self.reverse.clone())
+    }
+}
+
+/// How an output column of the aggregation is computed from the partial aggregates.
+enum AggOutput {
+    Partial {
+        name: String,
+        partial: String,
+    },
+    Mean {
+        name: String,
+        sum: String,
+        count: String,
+    },
+}
+
+#[derive(Default)]
+struct Partials {
+    dfs: Vec<DataFrame>,
+    n_rows: usize,
+    threshold: usize,
+    /// Estimated size in bytes of `dfs`.
+    size: usize,
+}
+
+/// A hash aggregation that aggregates every morsel and aggregates the partial results again
+/// whenever they grow too large, so that only about one row per group is held in memory.
+///
+/// Only `min`, `max`, `sum`, `mean` and `count` of row-wise expressions are supported, see
+/// [`is_streamable_groupby`].
+///
+/// With the `spill` feature, partial aggregates that exceed the memory budget are spilled to
+/// disk in hash partitions of the keys. The partitions are then aggregated one by one.
+pub(crate) struct GroupBySink {
+    keys: Vec<Arc<dyn PhysicalExpr>>,
+    partial_aggs: Vec<Arc<dyn PhysicalExpr>>,
+    combine_keys: Vec<Arc<dyn PhysicalExpr>>,
+    combine_aggs: Vec<Arc<dyn PhysicalExpr>>,
+    key_names: Vec<String>,
+    outputs: Vec<AggOutput>,
+    schema: Schema,
+    partials: Mutex<Partials>,
+    #[cfg(feature = "spill")]
+    spill: SpilledPartitions,
+}
+
+fn strip_alias(node: Node, expr_arena: &Arena<AExpr>) -> Node {
+    match expr_arena.get(node) {
+        AExpr::Alias(input, _) => strip_alias(*input, expr_arena),
+        _ => node,
+    }
+}
+
+/// Whether the aggregation can be computed from partial aggregations of the morsels.
+pub(crate) fn is_streamable_groupby(
+    keys: &[Node],
+    aggs: &[Node],
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    !keys.is_empty()
+        && keys.iter().all(|&k| super::is_elementwise(k, expr_arena))
+        && aggs
+            .iter()
+            .all(|&agg| match expr_arena.get(strip_alias(agg, expr_arena)) {
+                AExpr::Agg(
+                    AAggExpr::Min(input)
+                    | AAggExpr::Max(input)
+                    | AAggExpr::Sum(input)
+                    | AAggExpr::Mean(input)
+                    | AAggExpr::Count(input),
+                ) => super::is_elementwise(*input, expr_arena),
+                _ => false,
+            })
+}
+
+impl GroupBySink {
+    /// The partial aggregates are spilled once they exceed `memory_budget` bytes.
+    #[cfg_attr(not(feature = "spill"), allow(unused_variables))]
+    pub(crate) fn new(
+        keys: &[Node],
+        aggs: &[Node],
+        schema: Schema,
+        expr_arena: &mut Arena<AExpr>,
+        memory_budget: Option<usize>,
+    ) -> Result<Self> {
+        let planner = DefaultPlanner {};
+        let fields = schema.fields();
+        let key_names = fields[..keys.len()]
+            .iter()
+            .map(|fld| fld.name().clone())
+            .collect::<Vec<_>>();
+
+        let mut partial_aggs = Vec::with_capacity(aggs.len());
+        let mut combine_aggs = Vec::with_capacity(aggs.len());
+        let mut outputs = Vec::with_capacity(aggs.len());
+        for (i, (&agg, fld)) in aggs.iter().zip(&fields[keys.len()..]).enumerate() {
+            let name = fld.name().clone();
+            let agg = strip_alias(agg, expr_arena);
+            let agg_expr = match expr_arena.get(agg) {
+                AExpr::Agg(agg_expr) => agg_expr.clone(),
+                _ => unreachable!(),
+            };
+            match agg_expr {
+                AAggExpr::Mean(input) => {
+                    // the mean is the sum of the partial sums divided by the sum of the
+                    // partial counts of the valid values
+                    let sum = format!("__POLARS_PART_{}_SUM", i);
+                    let count = format!("__POLARS_PART_{}_COUNT", i);
+                    let values = expr_arena.add(AExpr::Cast {
+                        expr: input,
+                        data_type: DataType::Float64,
+                        strict: false,
+                    });
+                    let valid = expr_arena.add(AExpr::IsNotNull(input));
+                    let valid = expr_arena.add(AExpr::Cast {
+                        expr: valid,
+                        data_type: DataType::Float64,
+                        strict: false,
+                    });
+                    for (input, part) in [(values, &sum), (valid, &count)] {
+                        let partial = expr_arena.add(AExpr::Agg(AAggExpr::Sum(input)));
+                        partial_aggs.push(alias(partial, part, expr_arena));
+                        let column = expr_arena.add(AExpr::Column(Arc::new(part.clone())));
+                        let combine = expr_arena.add(AExpr::Agg(AAggExpr::Sum(column)));
+                        combine_aggs.push(alias(combine, part, expr_arena));
+                    }
+                    outputs.push(AggOutput::Mean { name, sum, count })
+                }
+                agg_expr => {
+                    let partial = format!("__POLARS_PART_{}", i);
+                    partial_aggs.push(alias(agg, &partial, expr_arena));
+
+                    let column = expr_arena.add(AExpr::Column(Arc::new(partial.clone())));
+                    let combine = match agg_expr {
+                        AAggExpr::Min(_) => AAggExpr::Min(column),
+                        AAggExpr::Max(_) => AAggExpr::Max(column),
+                        // the count of a group is the sum of its partial counts
+                        AAggExpr::Sum(_) | AAggExpr::Count(_) => AAggExpr::Sum(column),
+                        _ => unreachable!(),
+                    };
+                    let combine = expr_arena.add(AExpr::Agg(combine));
+                    combine_aggs.push(alias(combine, &partial, expr_arena));
+                    outputs.push(AggOutput::Partial { name, partial })
+                }
+            }
+        }
+
+        let combine_keys = key_names
+            .iter()
+            .map(|name| expr_arena.add(AExpr::Column(Arc::new(name.clone()))))
+            .collect::<Vec<_>>();
+
+        Ok(GroupBySink {
+            keys: planner.create_physical_expressions(keys, Context::Default, expr_arena)?,
+            partial_aggs: planner.create_physical_expressions(
+                &partial_aggs,
+                Context::Aggregation,
+                expr_arena,
+            )?,
+            combine_keys: planner.create_physical_expressions(
+                &combine_keys,
+                Context::Default,
+                expr_arena,
+            )?,
+            combine_aggs: planner.create_physical_expressions(
+                &combine_aggs,
+                Context::Aggregation,
+                expr_arena,
+            )?,
+            key_names,
+            outputs,
+            schema,
+            partials: Mutex::new(Partials {
+                threshold: COMBINE_THRESHOLD,
+                ..Default::default()
+            }),
+            #[cfg(feature = "spill")]
+            spill: SpilledPartitions::new(memory_budget),
+        })
+    }
+
+    fn combine(&self, dfs: Vec<DataFrame>) -> Result<DataFrame> {
+        let df = accumulate_dataframes_vertical(dfs)?;
+        let state = ExecutionState::new();
+        let keys = self
+            .combine_keys
+            .iter()
+            .map(|e| e.evaluate(&df, &state))
+            .collect::<Result<_>>()?;
+        groupby_helper(df, keys, &self.combine_aggs, None, &state, false)
+    }
+
+    #[cfg(feature = "spill")]
+    fn exceeds_budget(&self, size: usize) -> bool {
+        size > self.spill.budget
+    }
+
+    #[cfg(not(feature = "spill"))]
+    fn exceeds_budget(&self, _size: usize) -> bool {
+        false
+    }
+
+    /// Compute the output columns from the combined partial aggregates.
+    fn finish(&self, df: DataFrame) -> Result<DataFrame> {
+        let mut columns = self
+            .key_names
+            .iter()
+            .map(|name| df.column(name).map(|s| s.clone()))
+            .collect::<Result<Vec<_>>>()?;
+        for output in &self.outputs {
+            // like the in-memory groupby, aggregations that are not defined for the dtype of
+            // their input, e.g. the sum of strings, are left out
+            let s = match output {
+                AggOutput::Partial { name, partial } => df.column(partial).ok().map(|s| {
+                    let mut s = s.clone();
+                    s.rename(name);
+                    s
+                }),
+                AggOutput::Mean { name, sum, count } => {
+                    match (df.column(sum), df.column(count)) {
+                        (Ok(sum), Ok(count)) => {
+                            let count = count.f64()?;
+                            // a group without valid values has a null mean
+                            let mean = (sum.f64()? / count).set(&count.eq(0.0), None)?;
+                            // like the in-memory mean, e.g. Float32 for a Float32 input
+                            let dtype = self.schema.field_with_name(name)?.data_type();
+                            let mut s = mean.into_series().cast(dtype)?;
+                            s.rename(name);
+                            Some(s)
+                        }
+                        _ => None,
+                    }
+                }
+            };
+            columns.extend(s);
+        }
+        Ok(DataFrame::new_no_checks(columns))
+    }
+}
+
+fn alias(node: Node, name: &str, expr_arena: &mut Arena<AExpr>) -> Node {
+    expr_arena.add(AExpr::Alias(node, Arc::new(name.to_string())))
+}
+
+impl Sink for GroupBySink {
+    fn sink(&self, chunk: DataChunk) -> Result<()> {
+        let df = chunk.data;
+        if df.height() == 0 {
+            return Ok(());
+        }
+        let state = ExecutionState::new();
+        let keys = self
+            .keys
+            .iter()
+            .map(|e| e.evaluate(&df, &state))
+            .collect::<Result<_>>()?;
+        let partial = groupby_helper(df, keys, &self.partial_aggs, None, &state, false)?;
+
+        let to_combine = {
+            let mut partials = self.partials.lock().unwrap();
+            partials.n_rows += partial.height();
+            partials.size += estimated_size(&partial);
+            partials.dfs.push(partial);
+            if partials.n_rows > partials.threshold || self.exceeds_budget(partials.size) {
+                partials.n_rows = 0;
+                partials.size = 0;
+                Some(std::mem::take(&mut partials.dfs))
+            } else {
+                None
+            }
+        };
+        // the lock is not held while combining, so the other threads can continue to sink
+        if let Some(dfs) = to_combine {
+            let combined = self.combine(dfs)?;
+            let size = estimated_size(&combined);
+            // keeping partial aggregates that fill most of the budget would only trigger
+            // another combine on the next morsel
+            #[cfg(feature = "spill")]
+            if self.exceeds_budget(size / 2) {
+                return self.spill.spill(&combined, &self.key_names);
+            }
+            let mut partials = self.partials.lock().unwrap();
+            // with many groups, combining does not reduce the number of rows much
+            partials.threshold = std::cmp::max(partials.threshold, 2 * combined.height());
+            partials.n_rows += combined.height();
+            partials.size += size;
+            partials.dfs.push(combined);
+        }
+        Ok(())
+    }
+
+    fn finalize(&self) -> Result<DataFrame> {
+        let mut dfs = std::mem::take(&mut self.partials.lock().unwrap().dfs);
+        #[cfg(feature = "spill")]
+        if self.spill.is_spilled() {
+            if !dfs.is_empty() {
+                self.spill.spill(&self.combine(dfs)?, &self.key_names)?;
+            }
+            let mut out = Vec::with_capacity(self.spill.n_partitions());
+            for partition in 0..self.spill.n_partitions() {
+                let mut dfs = self.spill.read_partition(partition)?;
+                // every spilled file holds a single partial aggregate per group
+                let df = match dfs.len() {
+                    0 => continue,
+                    1 => dfs.pop().unwrap(),
+                    _ => self.combine(dfs)?,
+                };
+                out.push(self.finish(df)?);
+            }
+            let mut df = accumulate_dataframes_vertical(out)?;
+            df.rechunk();
+            return Ok(df);
+        }
+        let df = match dfs.len() {
+            0 => return Ok(empty_df(&self.schema)),
+            1 => dfs.pop().unwrap(),
+            _ => self.combine(dfs)?,
+        };
+        self.finish(df)
+    }
+}
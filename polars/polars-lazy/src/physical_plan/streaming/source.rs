@@ -0,0 +1,146 @@
+//! The scans that produce their rows in morsels.
+use super::is_elementwise;
+use super::is_elementwise_projection;
+use crate::prelude::*;
+use polars_core::prelude::*;
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "csv-file"))]
+use polars_io::prelude::*;
+
+/// Whether the scan can produce its rows in batches.
+pub(crate) fn is_batched_source(lp: &ALogicalPlan, expr_arena: &Arena<AExpr>) -> bool {
+    use ALogicalPlan::*;
+    let predicate = match lp {
+        #[cfg(feature = "csv-file")]
+        CsvScan {
+            paths,
+            predicate,
+            aggregate,
+            ..
+        } if paths.len() == 1 && aggregate.is_empty() => predicate,
+        #[cfg(feature = "parquet")]
+        ParquetScan {
+            paths,
+            predicate,
+            aggregate,
+            partitions: None,
+            ..
+        } if paths.len() == 1 && aggregate.is_empty() => predicate,
+        #[cfg(feature = "ipc")]
+        IpcScan {
+            predicate,
+            aggregate,
+            ..
+        } if aggregate.is_empty() => predicate,
+        DataFrameScan {
+            projection,
+            selection,
+            ..
+        } => {
+            if let Some(projection) = projection {
+                if !is_elementwise_projection(projection, expr_arena) {
+                    return false;
+                }
+            }
+            selection
+        }
+        _ => return false,
+    };
+    predicate.map_or(true, |predicate| is_elementwise(predicate, expr_arena))
+}
+
+/// Take the projection and the predicate that were pushed down into the scan. They are applied
+/// to every batch, the projection before the predicate like the `DataFrameExec` does.
+pub(crate) fn take_scan_operators(source: &mut ALogicalPlan) -> (Option<Vec<Node>>, Option<Node>) {
+    match source {
+        ALogicalPlan::DataFrameScan {
+            projection,
+            selection,
+            ..
+        } => (projection.take(), selection.take()),
+        #[cfg(feature = "csv-file")]
+        ALogicalPlan::CsvScan { predicate, .. } => (None, predicate.take()),
+        #[cfg(feature = "parquet")]
+        ALogicalPlan::ParquetScan { predicate, .. } => (None, predicate.take()),
+        #[cfg(feature = "ipc")]
+        ALogicalPlan::IpcScan { predicate, .. } => (None, predicate.take()),
+        _ => unreachable!(),
+    }
+}
+
+/// Read the scan in batches of `batch_size` rows and call `on_batch` with every batch, in the
+/// order of the rows.
+pub(crate) fn read_batches(
+    source: ALogicalPlan,
+    batch_size: usize,
+    on_batch: &mut dyn FnMut(DataFrame) -> Result<()>,
+) -> Result<()> {
+    use ALogicalPlan::*;
+    match source {
+        #[cfg(feature = "csv-file")]
+        CsvScan {
+            paths,
+            schema,
+            options,
+            ..
+        } => {
+            let with_columns = options.with_columns.filter(|columns| !columns.is_empty());
+            let mut reader = CsvReader::from_path(&paths[0])?
+                .has_header(options.has_header)
+                .with_schema(&schema)
+                .with_delimiter(options.delimiter)
+                .with_ignore_parser_errors(options.ignore_errors)
+                .with_skip_rows(options.skip_rows)
+                .with_stop_after_n_rows(options.stop_after_n_rows)
+                .with_columns(with_columns)
+                .low_memory(options.low_memory)
+                .with_null_values(options.null_values)
+                .with_encoding(options.encoding)
+                .with_comment_char(options.comment_char)
+                .with_quote_char(options.quote_char);
+            for df in reader.batched(batch_size)? {
+                on_batch(df?)?;
+            }
+        }
+        #[cfg(feature = "parquet")]
+        ParquetScan {
+            paths,
+            schema,
+            with_columns,
+            stop_after_n_rows,
+            ..
+        } => {
+            let projection = with_columns
+                .map(|columns| {
+                    columns
+                        .iter()
+                        .map(|name| schema.index_of(name))
+                        .collect::<Result<Vec<_>>>()
+                })
+                .transpose()?;
+            let file = std::fs::File::open(&paths[0])?;
+            let reader = ParquetReader::new(file).with_stop_after_n_rows(stop_after_n_rows);
+            for df in reader.batched(projection)? {
+                on_batch(df?)?;
+            }
+        }
+        #[cfg(feature = "ipc")]
+        IpcScan { path, options, .. } => {
+            let file = std::fs::File::open(&path)?;
+            let reader = IpcReader::new(file)
+                .with_stop_after_n_rows(options.stop_after_n_rows)
+                .with_columns(options.with_columns);
+            for df in reader.batched()? {
+                on_batch(df?)?;
+            }
+        }
+        DataFrameScan { df, .. } => {
+            let mut offset = 0;
+            while offset < df.height() {
+                on_batch(df.slice(offset as i64, batch_size))?;
+                offset += batch_size;
+            }
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
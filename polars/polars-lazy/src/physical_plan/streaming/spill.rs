@@ -0,0 +1,254 @@
+//! Spilling of the state of the sinks to IPC files when it exceeds the memory budget.
+//!
+//! Sorts spill sorted runs that are merged once all morsels are sunk. Aggregations spill their
+//! partial aggregates hash partitioned by the keys, so that every partition can be aggregated
+//! on its own.
+use super::estimated_size;
+use crate::physical_plan::executors::sort::add_sort_columns;
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+use ahash::RandomState;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_io::prelude::*;
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Number of partitions the partial aggregates are spilled to.
+const N_PARTITIONS: usize = 32;
+/// Marks the last row of every batch of a sorted run during the merge.
+const RUN_COLUMN: &str = "__POLARS_SORT_RUN";
+
+static SPILL_DIR_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// The number of bytes the state of a sink may take before it is spilled, unless a budget is
+/// passed to the sink. Can be set with the `POLARS_STREAMING_MEMORY_BUDGET` environment variable.
+pub(crate) fn memory_budget() -> usize {
+    std::env::var("POLARS_STREAMING_MEMORY_BUDGET")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(1 << 30)
+}
+
+/// A directory for the files of a single sink. It is created in `POLARS_TEMP_DIR`, or the
+/// temporary directory of the OS, and removed with all its files when dropped.
+struct SpillDir {
+    path: PathBuf,
+    n_files: AtomicUsize,
+}
+
+impl SpillDir {
+    fn new() -> Self {
+        let base = std::env::var_os("POLARS_TEMP_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(std::env::temp_dir);
+        let path = base.join(format!(
+            "polars-spill-{}-{}",
+            std::process::id(),
+            SPILL_DIR_COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        SpillDir {
+            path,
+            n_files: AtomicUsize::new(0),
+        }
+    }
+
+    /// Write `df` to a new file in batches of `batch_size` rows.
+    fn write(&self, df: &DataFrame, batch_size: usize) -> Result<PathBuf> {
+        std::fs::create_dir_all(&self.path)?;
+        let path = self.path.join(format!(
+            "{}.ipc",
+            self.n_files.fetch_add(1, Ordering::Relaxed)
+        ));
+        let file = File::create(&path)?;
+        let mut writer = IpcWriter::new(file).batched(&df.schema())?;
+        let mut offset = 0;
+        while offset < df.height() {
+            writer.write_batch(&df.slice(offset as i64, batch_size))?;
+            offset += batch_size;
+        }
+        writer.finish()?;
+        Ok(path)
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Sorts that do not fit in memory. The input is sorted in runs that are spilled and merged
+/// once all morsels are sunk.
+pub(crate) struct ExternalSort {
+    dir: SpillDir,
+    budget: usize,
+    buffered: Mutex<usize>,
+    runs: Mutex<Vec<PathBuf>>,
+    /// The names of the sort columns in the runs.
+    names: Mutex<Vec<String>>,
+}
+
+impl ExternalSort {
+    pub(crate) fn new(budget: Option<usize>) -> Self {
+        ExternalSort {
+            dir: SpillDir::new(),
+            budget: budget.unwrap_or_else(memory_budget),
+            buffered: Mutex::new(0),
+            runs: Mutex::new(vec![]),
+            names: Mutex::new(vec![]),
+        }
+    }
+
+    /// Account for a buffered morsel. Returns true if the buffered morsels have to be spilled.
+    pub(crate) fn buffer(&self, df: &DataFrame) -> bool {
+        let mut buffered = self.buffered.lock().unwrap();
+        *buffered += estimated_size(df);
+        if *buffered > self.budget {
+            *buffered = 0;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn is_spilled(&self) -> bool {
+        !self.runs.lock().unwrap().is_empty()
+    }
+
+    /// Sort `df` and spill it as a run.
+    pub(crate) fn spill_run(
+        &self,
+        mut df: DataFrame,
+        by_column: &[Arc<dyn PhysicalExpr>],
+        reverse: &[bool],
+    ) -> Result<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+        let names = add_sort_columns(&mut df, by_column, &ExecutionState::new())?;
+        let df = df.sort(&names, reverse.to_vec())?;
+        let path = self.dir.write(&df, super::morsel_size())?;
+        self.runs.lock().unwrap().push(path);
+        *self.names.lock().unwrap() = names;
+        Ok(())
+    }
+
+    /// k-way merge of the spilled runs.
+    ///
+    /// Every run is read one batch at a time. Rows up to the smallest last row of the current
+    /// batches are in their final position, as the rows that are not read yet are larger. Those
+    /// rows are emitted and only the run whose batch is exhausted is read further.
+    pub(crate) fn merge(&self, reverse: &[bool]) -> Result<DataFrame> {
+        let runs = std::mem::take(&mut *self.runs.lock().unwrap());
+        let mut readers = runs
+            .iter()
+            .map(|path| IpcReader::new(File::open(path)?).batched())
+            .collect::<Result<Vec<_>>>()?;
+
+        let names = std::mem::take(&mut *self.names.lock().unwrap());
+        let mut out = vec![];
+        let mut carry: Option<DataFrame> = None;
+        let mut to_read = (0..readers.len()).collect::<Vec<_>>();
+        loop {
+            let mut dfs = carry.take().into_iter().collect::<Vec<_>>();
+            for run in to_read.drain(..) {
+                let batch = readers[run]
+                    .find(|batch| batch.as_ref().map_or(true, |df| df.height() > 0))
+                    .transpose()?;
+                if let Some(mut batch) = batch {
+                    let mut last = vec![None; batch.height()];
+                    last[batch.height() - 1] = Some(run as u32);
+                    batch.with_column(UInt32Chunked::new_from_opt_slice(RUN_COLUMN, &last))?;
+                    dfs.push(batch);
+                }
+            }
+            if dfs.is_empty() {
+                break;
+            }
+            let df = accumulate_dataframes_vertical(dfs)?;
+            let df = df.sort(&names, reverse.to_vec())?;
+            let first_last = df
+                .column(RUN_COLUMN)?
+                .u32()?
+                .into_iter()
+                .enumerate()
+                .find_map(|(i, run)| run.map(|run| (i, run as usize)));
+            match first_last {
+                Some((i, run)) => {
+                    carry = Some(df.slice(i as i64 + 1, df.height()));
+                    out.push(df.slice(0, i + 1));
+                    to_read.push(run);
+                }
+                // all runs are exhausted
+                None => {
+                    out.push(df);
+                    break;
+                }
+            }
+        }
+        let mut df = accumulate_dataframes_vertical(out)?;
+        df.drop_in_place(RUN_COLUMN)?;
+        df.rechunk();
+        Ok(df)
+    }
+}
+
+/// Partial aggregates that do not fit in memory. They are spilled in partitions by the hash
+/// of their keys, so that all partial aggregates of a group are in the same partition.
+pub(crate) struct SpilledPartitions {
+    dir: SpillDir,
+    pub(crate) budget: usize,
+    partitions: Mutex<Vec<Vec<PathBuf>>>,
+}
+
+impl SpilledPartitions {
+    pub(crate) fn new(budget: Option<usize>) -> Self {
+        SpilledPartitions {
+            dir: SpillDir::new(),
+            budget: budget.unwrap_or_else(memory_budget),
+            partitions: Mutex::new(vec![vec![]; N_PARTITIONS]),
+        }
+    }
+
+    pub(crate) fn is_spilled(&self) -> bool {
+        self.partitions
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|files| !files.is_empty())
+    }
+
+    pub(crate) fn n_partitions(&self) -> usize {
+        N_PARTITIONS
+    }
+
+    /// Partition `df` by the hash of the `keys` columns and spill the partitions.
+    pub(crate) fn spill(&self, df: &DataFrame, keys: &[String]) -> Result<()> {
+        // the seeds are fixed so that a key is always spilled to the same partition
+        let random_state = RandomState::with_seeds(0, 1, 2, 3);
+        let hashes = df
+            .select(keys.iter().map(|name| name.as_str()).collect::<Vec<_>>())?
+            .hash_rows(Some(random_state))?
+            .apply(|h| h % N_PARTITIONS as u64);
+        for partition in 0..N_PARTITIONS {
+            let part = df.filter(&hashes.eq(partition as u64))?;
+            if part.height() > 0 {
+                let path = self.dir.write(&part, part.height())?;
+                self.partitions.lock().unwrap()[partition].push(path);
+            }
+        }
+        Ok(())
+    }
+
+    /// Read the spilled partial aggregates of a partition.
+    pub(crate) fn read_partition(&self, partition: usize) -> Result<Vec<DataFrame>> {
+        let files = std::mem::take(&mut self.partitions.lock().unwrap()[partition]);
+        files
+            .iter()
+            .map(|path| IpcReader::new(File::open(path)?).finish())
+            .collect()
+    }
+}
@@ -12,6 +12,10 @@ pub use crate::{
 
 #[cfg(feature = "csv-file")]
 pub(crate) use crate::physical_plan::executors::scan::CsvExec;
+#[cfg(feature = "ipc")]
+pub(crate) use crate::physical_plan::executors::scan::IpcExec;
+#[cfg(feature = "json")]
+pub(crate) use crate::physical_plan::executors::scan::NdJsonExec;
 #[cfg(feature = "parquet")]
 pub(crate) use crate::physical_plan::executors::scan::ParquetExec;
 
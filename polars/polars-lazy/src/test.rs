@@ -12,7 +12,7 @@ use std::iter::FromIterator;
 
 fn scan_foods_csv() -> LazyFrame {
     let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods1.csv";
-    LazyCsvReader::new(path.to_string()).finish()
+    LazyCsvReader::new(path.to_string()).finish().unwrap()
 }
 
 pub(crate) fn fruits_cars() -> DataFrame {
@@ -981,7 +981,9 @@ fn test_lazy_groupby_cast() {
     let _out = df
         .lazy()
         .groupby([col("a")])
-        .agg([col("b").mean().cast(DataType::Datetime)])
+        .agg([col("b")
+            .mean()
+            .cast(DataType::Datetime(TimeUnit::Milliseconds, None))])
         .collect()
         .unwrap();
 }
@@ -1999,3 +2001,512 @@ pub fn test_select_by_dtypes() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_scan_ipc() -> Result<()> {
+    use polars_io::prelude::*;
+
+    let path = std::env::temp_dir().join("polars_test_scan_ipc.ipc");
+    let df = fruits_cars();
+    IpcWriter::new(std::fs::File::create(&path)?).finish(&df)?;
+    let path = path.to_string_lossy().to_string();
+
+    for memmap in [true, false] {
+        let args = ScanArgsIpc {
+            memmap,
+            ..Default::default()
+        };
+        let out = LazyFrame::scan_ipc(path.clone(), args)?
+            .filter(col("fruits").eq(lit("banana")))
+            .select([col("B"), col("A")])
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["B", "A"]);
+        assert_eq!(
+            Vec::from(out.column("A")?.i32()?),
+            &[Some(1), Some(2), Some(5)]
+        );
+    }
+
+    let args = ScanArgsIpc {
+        stop_after_n_rows: Some(2),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_ipc(path.clone(), args)?.collect()?;
+    assert_eq!(out.shape(), (2, 4));
+
+    let out = LazyFrame::scan_ipc(path, Default::default())?
+        .select([col("cars")])
+        .fetch(3)?;
+    assert_eq!(out.shape(), (3, 1));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_scan_ndjson() -> Result<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_ndjson.ndjson");
+    std::fs::write(
+        &path,
+        r#"{"level":"info", "code":1, "latency":0.5}
+{"level":"warn", "code":2, "latency":1.5}
+{"level":"info", "code":3, "latency":null}
+{"level":"error", "code":4, "latency":2.0}
+"#,
+    )?;
+    let path = path.to_string_lossy().to_string();
+
+    let out = LazyFrame::scan_ndjson(path.clone(), Default::default())?
+        .filter(col("level").eq(lit("info")))
+        .select([col("code")])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["code"]);
+    assert_eq!(Vec::from(out.column("code")?.i64()?), &[Some(1), Some(3)]);
+
+    let args = ScanArgsNdJson {
+        stop_after_n_rows: Some(2),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_ndjson(path, args)?
+        .select([col("latency").sum()])
+        .collect()?;
+    assert_eq!(out.column("latency")?.f64()?.get(0), Some(2.0));
+    Ok(())
+}
+
+#[test]
+fn test_scan_csv_glob() -> Result<()> {
+    let dir = std::env::temp_dir().join("polars_test_scan_csv_glob");
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("0.csv"), "a,b\n1,x\n2,y\n")?;
+    std::fs::write(dir.join("1.csv"), "a,b\n3,z\n")?;
+    std::fs::write(dir.join("2.csv"), "a,c\n4.5,1\n")?;
+    let pattern = dir.join("*.csv").to_string_lossy().to_string();
+
+    let lf = LazyCsvReader::new(pattern.clone()).finish()?;
+    let schema = lf.schema();
+    assert_eq!(
+        schema.fields().iter().map(|f| f.name()).collect::<Vec<_>>(),
+        &["a", "b", "c"]
+    );
+    assert_eq!(schema.field_with_name("a")?.data_type(), &DataType::Float64);
+
+    let out = lf
+        .filter(col("a").gt(lit(1.5)))
+        .select([col("a"), col("b"), col("c")])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.f64()?),
+        &[Some(2.0), Some(3.0), Some(4.5)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b")?.utf8()?),
+        &[Some("y"), Some("z"), None]
+    );
+    assert_eq!(Vec::from(out.column("c")?.i64()?), &[None, None, Some(1)]);
+
+    let out = LazyCsvReader::new(pattern)
+        .finish()?
+        .select([col("a").sum()])
+        .collect()?;
+    assert_eq!(out.column("a")?.f64()?.get(0), Some(10.5));
+
+    let pattern = dir.join("*.missing").to_string_lossy().to_string();
+    assert!(LazyCsvReader::new(pattern).finish().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_scan_parquet_dataset() -> Result<()> {
+    use polars_io::prelude::*;
+
+    let root = std::env::temp_dir().join("polars_test_scan_parquet_dataset");
+    for (year, values) in [(2020, [1i32, 2]), (2021, [3, 4])] {
+        let dir = root.join(format!("year={}", year));
+        std::fs::create_dir_all(&dir)?;
+        let df = df!["a" => values]?;
+        let mut f = std::fs::File::create(dir.join("part-0.parquet"))?;
+        ParquetWriter::new(&mut f).finish(&df)?;
+    }
+    // this directory is pruned, so its invalid file is never opened
+    let dir = root.join("year=2022");
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("part-0.parquet"), "not parquet")?;
+
+    let root = root.to_string_lossy().to_string();
+    let out = LazyFrame::new_from_parquet_dataset(root, None, false)?
+        .filter(col("year").lt(lit(2022i64)))
+        .filter(col("a").gt(lit(1)))
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["a", "year"]);
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(2), Some(3), Some(4)]
+    );
+    assert_eq!(
+        Vec::from(out.column("year")?.i64()?),
+        &[Some(2020), Some(2021), Some(2021)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_scan_parquet_prune_row_groups() -> Result<()> {
+    use polars_io::prelude::*;
+
+    let path = std::env::temp_dir().join("polars_test_scan_parquet_prune_row_groups.parquet");
+    let df = df![
+        "a" => (0..10).collect::<Vec<i32>>(),
+        "b" => ["a", "a", "a", "b", "b", "b", "c", "c", "c", "d"]
+    ]?;
+    let mut f = std::fs::File::create(&path)?;
+    ParquetWriter::new(&mut f)
+        .with_statistics(true)
+        .with_row_group_size(Some(RowGroupSize::Rows(3)))
+        .finish(&df)?;
+    let path = path.to_string_lossy().to_string();
+
+    let out = LazyFrame::new_from_parquet(path.clone(), None, false)?
+        .filter(col("a").gt(lit(4)).and(lit("d").neq(col("b"))))
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(5), Some(6), Some(7), Some(8)]
+    );
+
+    // no row group can match
+    let out = LazyFrame::new_from_parquet(path.clone(), None, false)?
+        .filter(col("a").gt(lit(20)).or(col("b").eq(lit("z"))))
+        .collect()?;
+    assert_eq!(out.shape(), (0, 2));
+    assert_eq!(out.column("b")?.dtype(), &DataType::Utf8);
+
+    // a float literal must not be truncated to the integer type of the column
+    let out = LazyFrame::new_from_parquet(path.clone(), None, false)?
+        .filter(col("a").lt(lit(3.5)))
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(0), Some(1), Some(2), Some(3)]
+    );
+    let out = LazyFrame::new_from_parquet(path, None, false)?
+        .filter(col("a").gt(lit(8.5)))
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(9)]);
+    Ok(())
+}
+
+#[test]
+fn test_scan_csv_utf16() -> Result<()> {
+    use polars_io::csv::CsvEncoding;
+
+    let path = std::env::temp_dir().join("polars_test_scan_csv_utf16.csv");
+    let mut bytes = vec![0xFF, 0xFE];
+    for unit in "name,n\ncafé,1\nnaïve,2\n".encode_utf16() {
+        bytes.extend_from_slice(&unit.to_le_bytes());
+    }
+    std::fs::write(&path, bytes)?;
+
+    let out = LazyCsvReader::new(path.to_string_lossy().to_string())
+        .with_encoding(CsvEncoding::Utf16)
+        .finish()?
+        .filter(col("n").gt(lit(1)))
+        .collect()?;
+    assert_eq!(Vec::from(out.column("name")?.utf8()?), &[Some("naïve")]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "ipc"))]
+fn test_sink() -> Result<()> {
+    use polars_io::prelude::*;
+
+    let query = || {
+        scan_foods_csv()
+            .filter(col("calories").gt(lit(100)))
+            .with_column((col("fats_g") * lit(2.0)).alias("fats_2"))
+            .select([col("category"), col("fats_2")])
+    };
+    let expected = query().collect()?;
+    let dir = std::env::temp_dir();
+
+    let path = dir.join("polars_test_sink.parquet");
+    query().sink_parquet(path.clone(), Default::default())?;
+    let out = ParquetReader::new(std::fs::File::open(&path)?).finish()?;
+    assert!(out.frame_equal(&expected));
+
+    let path = dir.join("polars_test_sink.ipc");
+    query().sink_ipc(path.clone())?;
+    let out = IpcReader::new(std::fs::File::open(&path)?).finish()?;
+    assert!(out.frame_equal(&expected));
+
+    let path = dir.join("polars_test_sink.csv");
+    query().sink_csv(path.clone(), Default::default())?;
+    let out = CsvReader::from_path(&path)?.finish()?;
+    assert!(out.frame_equal(&expected));
+
+    // a sort needs all rows, so the query is collected before it is written
+    let path = dir.join("polars_test_sink_sorted.ipc");
+    query().sort("fats_2", false).sink_ipc(path.clone())?;
+    let out = IpcReader::new(std::fs::File::open(&path)?).finish()?;
+    assert!(out.frame_equal(&expected.sort("fats_2", false)?));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "ipc", feature = "cum_agg"))]
+fn test_sink_cumsum() -> Result<()> {
+    use polars_io::prelude::*;
+
+    // more rows than fit in a single batch
+    let n = 120_000;
+    let df = df!["a" => (0..n).map(|i| i % 3).collect::<Vec<i64>>()]?;
+    let query = || {
+        df.clone()
+            .lazy()
+            .with_column(col("a").cumsum(false).alias("cumsum"))
+    };
+    let path = std::env::temp_dir().join("polars_test_sink_cumsum.ipc");
+    query().sink_ipc(path.clone())?;
+    let out = IpcReader::new(std::fs::File::open(&path)?).finish()?;
+    assert!(out.frame_equal(&query().collect()?));
+    Ok(())
+}
+
+#[test]
+fn test_streaming() -> Result<()> {
+    // large enough for several morsels
+    let n = 120_000;
+    let df = df![
+        "a" => (0..n).map(|i| i % 7).collect::<Vec<i32>>(),
+        "b" => (0..n).map(|i| if i % 5 == 0 { None } else { Some(i as f64) }).collect::<Vec<_>>()
+    ]?;
+    let right = df![
+        "a" => [0, 1, 2, 3],
+        "label" => ["zero", "one", "two", "three"]
+    ]?;
+    let query = || {
+        df.clone()
+            .lazy()
+            .filter(col("b").gt(lit(10.0)))
+            .with_column((col("b") * lit(2.0)).alias("b2"))
+            .inner_join(right.clone().lazy(), col("a"), col("a"))
+    };
+
+    let groupby = || {
+        query().groupby([col("label")]).agg([
+            col("b").sum(),
+            col("b2").min().alias("b2_min"),
+            col("b").max().alias("b_max"),
+            col("b").mean().alias("b_mean"),
+            col("b").count().alias("n"),
+        ])
+    };
+    let expected = groupby().collect()?.sort("label", false)?;
+    let out = groupby().collect_streaming()?.sort("label", false)?;
+    assert!(out.frame_equal_missing(&expected));
+
+    let sorted = || query().sort("b", true);
+    assert!(sorted()
+        .collect_streaming()?
+        .frame_equal_missing(&sorted().collect()?));
+
+    // `first` depends on the order of the rows, the groupby runs in memory
+    let first = || query().groupby([col("a")]).agg([col("b").first()]);
+    let expected = first().collect()?.sort("a", false)?;
+    let out = first().collect_streaming()?.sort("a", false)?;
+    assert!(out.frame_equal_missing(&expected));
+
+    // a filter that removes all rows
+    let empty = query()
+        .filter(col("a").gt(lit(100)))
+        .groupby([col("label")])
+        .agg([col("b").sum()])
+        .collect_streaming()?;
+    assert_eq!(empty.shape(), (0, 2));
+
+    // the mean keeps the float type of its input
+    let df = df![
+        "a" => [1, 1, 2],
+        "b" => [1.0f32, 2.0, 3.0]
+    ]?;
+    let mean = || df.clone().lazy().groupby([col("a")]).agg([col("b").mean()]);
+    let out = mean().collect_streaming()?.sort("a", false)?;
+    assert_eq!(out.column("b")?.dtype(), &DataType::Float32);
+    assert!(out.frame_equal(&mean().collect()?.sort("a", false)?));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "spill")]
+fn test_streaming_spill() -> Result<()> {
+    // every morsel exceeds the budget, so all partial aggregates and sorted runs are spilled
+    let budget = 1000;
+    let n = 120_000;
+    let df = df![
+        "a" => (0..n).map(|i| i % 30_000).collect::<Vec<i32>>(),
+        "b" => (0..n).map(|i| ((i * 7919) % n) as f64).collect::<Vec<_>>()
+    ]?;
+
+    let groupby = || {
+        df.clone().lazy().groupby([col("a")]).agg([
+            col("b").sum(),
+            col("b").mean().alias("b_mean"),
+            col("b").count().alias("n"),
+        ])
+    };
+    let expected = groupby().collect()?.sort("a", false)?;
+    let out = groupby()
+        .with_memory_budget(budget)
+        .collect_streaming()?
+        .sort("a", false)?;
+    assert!(out.frame_equal_missing(&expected));
+
+    // the values of "b" are unique, so the order of the merged runs is defined
+    let sorted = || df.clone().lazy().sort("b", true);
+    let out = sorted().with_memory_budget(budget).collect_streaming()?;
+    assert!(out.frame_equal(&sorted().collect()?));
+    Ok(())
+}
+
+#[test]
+fn test_common_subplan_elimination() -> Result<()> {
+    let lf = fruits_cars()
+        .lazy()
+        .filter(col("A").gt(lit(1)))
+        .select([col("fruits"), col("B")]);
+    let q = lf.clone().left_join(lf, col("fruits"), col("fruits"));
+    assert!(q.describe_optimized_plan()?.contains("CACHE"));
+
+    let out = q
+        .clone()
+        .collect()?
+        .sort(["fruits", "B", "B_right"], false)?;
+    let expected = q
+        .with_common_subplan_elimination(false)
+        .collect()?
+        .sort(["fruits", "B", "B_right"], false)?;
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_common_subplan_elimination_different_sort_keys() -> Result<()> {
+    let lf = fruits_cars().lazy().filter(col("A").gt(lit(1)));
+    let q = lf
+        .clone()
+        .sort("A", false)
+        .select([col("fruits"), col("B")])
+        .with_row_count("idx")
+        .left_join(
+            lf.sort("B", false)
+                .select([col("B").alias("right_b")])
+                .with_row_count("idx"),
+            col("idx"),
+            col("idx"),
+        );
+
+    let out = q.clone().collect()?;
+    let expected = q.with_common_subplan_elimination(false).collect()?;
+    assert!(out.frame_equal(&expected));
+    assert_eq!(
+        Vec::from(out.column("right_b")?.i32()?),
+        &[Some(1), Some(2), Some(3), Some(4)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "serde-lazy")]
+fn test_serde_lazy() -> Result<()> {
+    let prices = df![
+        "fruits" => ["banana", "apple"],
+        "price" => [2, 3]
+    ]?;
+    let q = fruits_cars()
+        .lazy()
+        .filter(col("A").gt(lit(1)))
+        .left_join(prices.lazy(), col("fruits"), col("fruits"))
+        .groupby([col("fruits")])
+        .agg([col("B").sum(), (col("A") * col("price")).max().alias("max")])
+        .sort("fruits", false);
+    let expected = q.clone().collect()?;
+    let out = LazyFrame::from_json(&q.to_json()?)?.collect()?;
+    assert!(out.frame_equal(&expected));
+    let out = LazyFrame::from_bytes(&q.to_bytes()?)?.collect()?;
+    assert!(out.frame_equal(&expected));
+
+    let q = scan_foods_csv()
+        .filter(col("calories").gt(lit(50)))
+        .select([col("category"), col("fats_g")]);
+    let expected = q.clone().collect()?;
+    let out = LazyFrame::from_bytes(&q.to_bytes()?)?.collect()?;
+    assert!(out.frame_equal(&expected));
+
+    let expr = when(col("A").gt(lit(2))).then(col("B")).otherwise(lit(0));
+    assert_eq!(Expr::from_json(&expr.to_json()?)?, expr);
+
+    // deserialized caches get fresh ids, but caches that shared an id keep sharing it
+    let cached = fruits_cars().lazy().cache();
+    let q = cached.clone().left_join(cached, col("A"), col("A"));
+    let cache_ids = |lf: &LazyFrame| match &lf.logical_plan {
+        LogicalPlan::Join {
+            input_left,
+            input_right,
+            ..
+        } => match (&**input_left, &**input_right) {
+            (LogicalPlan::Cache { id: l, .. }, LogicalPlan::Cache { id: r, .. }) => (*l, *r),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    let (id, _) = cache_ids(&q);
+    let json = q.to_json()?;
+    let (l1, r1) = cache_ids(&LazyFrame::from_json(&json)?);
+    let (l2, r2) = cache_ids(&LazyFrame::from_json(&json)?);
+    assert_eq!(l1, r1);
+    assert_eq!(l2, r2);
+    assert_ne!(l1, id);
+    assert_ne!(l1, l2);
+
+    // closures cannot be serialized
+    let expr = col("A").map(Ok, GetOutput::same_type());
+    assert!(expr.to_json().is_err());
+    assert!(fruits_cars().lazy().select([expr]).to_bytes().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "serde-lazy",
+    feature = "dtype-i8",
+    feature = "dtype-u8",
+    feature = "dtype-i16",
+    feature = "dtype-u16",
+    feature = "dtype-categorical"
+))]
+fn test_serde_lazy_dtypes() -> Result<()> {
+    let df = DataFrame::new(vec![
+        Series::new("i8", &[Some(1i8), None]),
+        Series::new("u8", &[Some(1u8), None]),
+        Series::new("i16", &[Some(1i16), None]),
+        Series::new("u16", &[Some(1u16), None]),
+        Series::new("cat", &[Some("a"), None]).cast(&DataType::Categorical)?,
+    ])?;
+    // the frame of the scan and the literal keep their dtypes
+    let q = df
+        .clone()
+        .lazy()
+        .with_column(lit(Series::new("lit", &[2i8, 3])).alias("lit"));
+    let expected = q.clone().collect()?;
+    for out in vec![
+        LazyFrame::from_json(&q.to_json()?)?.collect()?,
+        LazyFrame::from_bytes(&q.to_bytes()?)?.collect()?,
+    ] {
+        assert_eq!(out.dtypes(), expected.dtypes());
+        assert!(out.frame_equal_missing(&expected));
+    }
+    Ok(())
+}
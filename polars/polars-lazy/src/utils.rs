@@ -3,6 +3,8 @@ use crate::logical_plan::Context;
 use crate::prelude::*;
 use ahash::RandomState;
 use polars_core::prelude::*;
+#[cfg(any(feature = "parquet", feature = "csv-file"))]
+use polars_core::utils::get_supertype;
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -67,6 +69,10 @@ pub(crate) fn is_scan(plan: &ALogicalPlan) -> bool {
         ALogicalPlan::DataFrameScan { .. } => true,
         #[cfg(feature = "parquet")]
         ALogicalPlan::ParquetScan { .. } => true,
+        #[cfg(feature = "ipc")]
+        ALogicalPlan::IpcScan { .. } => true,
+        #[cfg(feature = "json")]
+        ALogicalPlan::NdJsonScan { .. } => true,
         _ => false,
     }
 }
@@ -243,11 +249,23 @@ pub(crate) fn agg_source_paths(
         use ALogicalPlan::*;
         match lp {
             #[cfg(feature = "csv-file")]
-            CsvScan { path, .. } => {
-                paths.insert(path.clone());
+            CsvScan {
+                paths: scan_paths, ..
+            } => {
+                paths.extend(scan_paths.iter().cloned());
             }
             #[cfg(feature = "parquet")]
-            ParquetScan { path, .. } => {
+            ParquetScan {
+                paths: scan_paths, ..
+            } => {
+                paths.extend(scan_paths.iter().cloned());
+            }
+            #[cfg(feature = "ipc")]
+            IpcScan { path, .. } => {
+                paths.insert(path.clone());
+            }
+            #[cfg(feature = "json")]
+            NdJsonScan { path, .. } => {
                 paths.insert(path.clone());
             }
             _ => {}
@@ -255,6 +273,75 @@ pub(crate) fn agg_source_paths(
     })
 }
 
+/// Collect the ids of the caches in the logical plan.
+pub(crate) fn agg_cache_ids(
+    root_lp: Node,
+    ids: &mut PlHashSet<usize>,
+    lp_arena: &Arena<ALogicalPlan>,
+) {
+    lp_arena.iter(root_lp).for_each(|(_, lp)| {
+        if let ALogicalPlan::Cache { id, .. } = lp {
+            ids.insert(*id);
+        }
+    })
+}
+
+/// Expand the glob patterns in `paths`. Matches of a single pattern are sorted so that
+/// the files of a scan are always read in the same order.
+#[cfg(any(feature = "parquet", feature = "csv-file"))]
+pub(crate) fn expand_paths(paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut out = Vec::with_capacity(paths.len());
+    for path in paths {
+        let pattern = path.to_string_lossy();
+        if !pattern.contains(&['*', '?', '['][..]) {
+            out.push(path.clone());
+            continue;
+        }
+        let glob_paths = glob::glob(&pattern).map_err(|e| {
+            PolarsError::ValueError(format!("invalid glob pattern {}: {}", pattern, e).into())
+        })?;
+        let mut matches = glob_paths
+            .map(|res| res.map_err(|e| PolarsError::Io(e.into_error())))
+            .collect::<Result<Vec<_>>>()?;
+        if matches.is_empty() {
+            return Err(PolarsError::NotFound(format!(
+                "no files found matching {}",
+                pattern
+            )));
+        }
+        matches.sort();
+        out.extend(matches);
+    }
+    if out.is_empty() {
+        return Err(PolarsError::NoData("no files given to scan".into()));
+    }
+    Ok(out)
+}
+
+/// Combine the schemas of the files of a multi-file scan. Columns are ordered by their first
+/// appearance, and a column whose type differs between files gets the supertype of those types.
+#[cfg(any(feature = "parquet", feature = "csv-file"))]
+pub(crate) fn unify_schemas<'a, I>(schemas: I) -> Result<Schema>
+where
+    I: IntoIterator<Item = &'a Schema>,
+{
+    let mut fields: Vec<Field> = vec![];
+    for schema in schemas {
+        for field in schema.fields() {
+            match fields.iter_mut().find(|fld| fld.name() == field.name()) {
+                Some(fld) => {
+                    if fld.data_type() != field.data_type() {
+                        let dtype = get_supertype(fld.data_type(), field.data_type())?;
+                        fld.coerce(dtype);
+                    }
+                }
+                None => fields.push(field.clone()),
+            }
+        }
+    }
+    Ok(Schema::new(fields))
+}
+
 pub(crate) fn try_path_to_str(path: &Path) -> Result<&str> {
     path.to_str().ok_or_else(|| {
         PolarsError::ComputeError(format!("Non-UTF8 file path: {}", path.to_string_lossy()).into())
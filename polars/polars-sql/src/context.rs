@@ -0,0 +1,488 @@
+use crate::sql_expr::{contains_aggregation, SqlExprVisitor, TableColumns};
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use sqlparser::ast::{
+    BinaryOperator, Expr as SqlExpr, JoinConstraint, JoinOperator, Offset, OrderByExpr, Query,
+    Select, SelectItem, SetExpr, Statement, TableFactor, TableWithJoins, Value as SqlValue,
+};
+use sqlparser::dialect::GenericDialect;
+use sqlparser::parser::Parser;
+
+fn unsupported(what: &str) -> PolarsError {
+    PolarsError::InvalidOperation(format!("{} is not supported in SQL", what).into())
+}
+
+/// The tables SQL queries are executed against.
+///
+/// Queries are translated to a [`LazyFrame`] on the registered tables, so they are optimized like
+/// any other lazy query.
+#[derive(Clone, Default)]
+pub struct SQLContext {
+    tables: PlHashMap<String, LazyFrame>,
+}
+
+impl SQLContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a `LazyFrame` as a table. A `DataFrame` is registered with `df.lazy()`.
+    pub fn register(&mut self, name: &str, lf: LazyFrame) {
+        self.tables.insert(name.to_string(), lf);
+    }
+
+    /// Remove a table.
+    pub fn unregister(&mut self, name: &str) {
+        self.tables.remove(name);
+    }
+
+    /// Translate a `SELECT` query to a `LazyFrame`.
+    pub fn execute(&self, query: &str) -> Result<LazyFrame> {
+        let statements = Parser::parse_sql(&GenericDialect {}, query)
+            .map_err(|e| PolarsError::ComputeError(format!("{}", e).into()))?;
+        match statements.as_slice() {
+            [Statement::Query(query)] => self.execute_query(query),
+            _ => Err(unsupported("anything but a single SELECT statement")),
+        }
+    }
+
+    fn execute_query(&self, query: &Query) -> Result<LazyFrame> {
+        if query.with.is_some() {
+            return Err(unsupported("WITH"));
+        }
+        let select = match &query.body {
+            SetExpr::Select(select) => select,
+            _ => return Err(unsupported("a query that is not a SELECT")),
+        };
+        let lf = self.execute_select(select, &query.order_by)?;
+
+        let limit = query.limit.as_ref().map(parse_usize).transpose()?;
+        match (&query.offset, limit) {
+            (Some(Offset { value, .. }), limit) => {
+                let offset = parse_usize(value)?;
+                Ok(lf.slice(offset as i64, limit.unwrap_or(usize::MAX)))
+            }
+            (None, Some(limit)) => Ok(lf.limit(limit)),
+            (None, None) => Ok(lf),
+        }
+    }
+
+    fn execute_select(&self, select: &Select, order_by: &[OrderByExpr]) -> Result<LazyFrame> {
+        let (mut lf, tables) = match select.from.as_slice() {
+            [from] => self.execute_from(from)?,
+            [] => return Err(unsupported("SELECT without FROM")),
+            _ => return Err(unsupported("FROM with multiple tables; use a JOIN")),
+        };
+        let schema = lf.schema();
+        let visitor = SqlExprVisitor {
+            first_column: schema.field(0).map_or("", |fld| fld.name()),
+            tables: &tables,
+        };
+
+        if let Some(selection) = &select.selection {
+            if contains_aggregation(selection) {
+                return Err(unsupported("an aggregation in WHERE; use HAVING"));
+            }
+            lf = lf.filter(visitor.parse_sql_expr(selection)?);
+        }
+
+        let is_aggregation = !select.group_by.is_empty()
+            || select.having.is_some()
+            || select.projection.iter().any(|item| match item {
+                SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => {
+                    contains_aggregation(expr)
+                }
+                _ => false,
+            });
+        lf = if is_aggregation {
+            // ORDER BY refers to the columns of the aggregated result
+            let lf = execute_aggregation(lf, select, &visitor)?;
+            let (by, reverse) = parse_order_by(order_by, |expr| visitor.parse_sql_expr(expr))?;
+            lf.sort_by_exprs(by, reverse)
+        } else {
+            let mut aliases = vec![];
+            let projection = select
+                .projection
+                .iter()
+                .map(|item| match item {
+                    SelectItem::UnnamedExpr(expr) => visitor.parse_sql_expr(expr),
+                    SelectItem::ExprWithAlias { expr, alias } => {
+                        let expr = visitor.parse_sql_expr(expr)?;
+                        aliases.push((alias.value.as_str(), expr.clone()));
+                        Ok(expr.alias(&alias.value))
+                    }
+                    SelectItem::Wildcard => Ok(col("*")),
+                    SelectItem::QualifiedWildcard(_) => Err(unsupported("a qualified wildcard")),
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            // the input is sorted before the projection, so that ORDER BY can refer to columns
+            // that are not selected; aliases are replaced by the expressions they name
+            let (by, reverse) = parse_order_by(order_by, |expr| match expr {
+                SqlExpr::Identifier(ident) => match aliases.iter().find(|(a, _)| *a == ident.value)
+                {
+                    Some((_, expr)) => Ok(expr.clone()),
+                    None => visitor.parse_sql_expr(expr),
+                },
+                expr => visitor.parse_sql_expr(expr),
+            })?;
+            lf.sort_by_exprs(by, reverse).select(projection)
+        };
+
+        if select.distinct {
+            lf = lf.drop_duplicates(true, None);
+        }
+        Ok(lf)
+    }
+
+    fn get_table(&self, relation: &TableFactor) -> Result<(LazyFrame, String)> {
+        match relation {
+            TableFactor::Table { name, alias, .. } => {
+                let table_name = name.to_string();
+                let lf = self.tables.get(&table_name).cloned().ok_or_else(|| {
+                    PolarsError::NotFound(format!("table '{}' is not registered", table_name))
+                })?;
+                let name = alias.as_ref().map_or(table_name, |a| a.name.value.clone());
+                Ok((lf, name))
+            }
+            _ => Err(unsupported("a table that is not a registered table")),
+        }
+    }
+
+    /// Translate the tables of a `FROM` clause and join them. Also returns the names the columns
+    /// of the tables have in the joined frame.
+    fn execute_from(&self, from: &TableWithJoins) -> Result<(LazyFrame, TableColumns)> {
+        let (mut lf, name) = self.get_table(&from.relation)?;
+        let mut tables = TableColumns::new();
+        let columns = lf
+            .schema()
+            .fields()
+            .iter()
+            .map(|fld| (fld.name().clone(), fld.name().clone()))
+            .collect();
+        tables.insert(name, columns);
+
+        for join in &from.joins {
+            let (right, right_name) = self.get_table(&join.relation)?;
+            if tables.contains_key(&right_name) {
+                return Err(PolarsError::InvalidOperation(
+                    format!(
+                        "table '{}' is joined more than once; give it an alias",
+                        right_name
+                    )
+                    .into(),
+                ));
+            }
+            let (how, constraint) = match &join.join_operator {
+                JoinOperator::Inner(constraint) => (JoinType::Inner, constraint),
+                JoinOperator::LeftOuter(constraint) => (JoinType::Left, constraint),
+                JoinOperator::FullOuter(constraint) => (JoinType::Outer, constraint),
+                _ => return Err(unsupported("this kind of JOIN")),
+            };
+            let (left_on, right_on) = match constraint {
+                JoinConstraint::On(expr) => {
+                    let mut left_on = vec![];
+                    let mut right_on = vec![];
+                    let visitor = SqlExprVisitor {
+                        first_column: "",
+                        tables: &tables,
+                    };
+                    collect_join_keys(expr, &right_name, &visitor, &mut left_on, &mut right_on)?;
+                    (left_on, right_on)
+                }
+                JoinConstraint::Using(idents) => {
+                    let on = idents
+                        .iter()
+                        .map(|ident| col(&ident.value))
+                        .collect::<Vec<_>>();
+                    (on.clone(), on)
+                }
+                _ => return Err(unsupported("a JOIN without ON or USING")),
+            };
+
+            // the keys of the right table are dropped by the join, they take the values of the
+            // keys of the left table; other columns that are also in the left table get a suffix
+            let left_schema = lf.schema();
+            let columns = right
+                .schema()
+                .fields()
+                .iter()
+                .map(|fld| {
+                    let name = fld.name();
+                    let key = right_on
+                        .iter()
+                        .position(|key| matches!(key, Expr::Column(key) if &**key == name));
+                    let joined = match key.map(|idx| &left_on[idx]) {
+                        Some(Expr::Column(left)) => left.to_string(),
+                        _ if left_schema.field_with_name(name).is_ok() => {
+                            format!("{}_right", name)
+                        }
+                        _ => name.clone(),
+                    };
+                    (name.clone(), joined)
+                })
+                .collect();
+            tables.insert(right_name, columns);
+
+            lf = lf.join(right, left_on, right_on, how);
+        }
+        Ok((lf, tables))
+    }
+}
+
+/// The expressions and the `reverse` flags of an `ORDER BY`.
+fn parse_order_by(
+    order_by: &[OrderByExpr],
+    parse: impl Fn(&SqlExpr) -> Result<Expr>,
+) -> Result<(Vec<Expr>, Vec<bool>)> {
+    let mut by = Vec::with_capacity(order_by.len());
+    let mut reverse = Vec::with_capacity(order_by.len());
+    for OrderByExpr { expr, asc, .. } in order_by {
+        by.push(parse(expr)?);
+        reverse.push(!asc.unwrap_or(true));
+    }
+    Ok((by, reverse))
+}
+
+fn parse_usize(expr: &SqlExpr) -> Result<usize> {
+    match expr {
+        SqlExpr::Value(SqlValue::Number(s, _)) => s.parse().map_err(|_| {
+            PolarsError::ValueError(format!("expected a non-negative integer, got {}", s).into())
+        }),
+        _ => Err(unsupported("LIMIT or OFFSET that is not a number")),
+    }
+}
+
+/// Collect the keys of a join condition of the form `a.x = b.y AND a.z = b.w`. A column that is
+/// qualified with the name of the right table is a key of the right table, otherwise the left
+/// operand of an equality is a key of the left table. The keys of the left table are resolved
+/// against the tables joined so far.
+fn collect_join_keys(
+    expr: &SqlExpr,
+    right_name: &str,
+    visitor: &SqlExprVisitor,
+    left_on: &mut Vec<Expr>,
+    right_on: &mut Vec<Expr>,
+) -> Result<()> {
+    let is_right = |expr: &SqlExpr| match expr {
+        SqlExpr::CompoundIdentifier(idents) => {
+            idents.len() > 1 && idents[idents.len() - 2].value == right_name
+        }
+        _ => false,
+    };
+    let left_key = |expr: &SqlExpr| match expr {
+        SqlExpr::Identifier(ident) => visitor.resolve_column(std::slice::from_ref(ident)),
+        SqlExpr::CompoundIdentifier(idents) => visitor.resolve_column(idents),
+        _ => Err(unsupported("a JOIN condition on expressions")),
+    };
+    let right_key = |expr: &SqlExpr| match expr {
+        SqlExpr::Identifier(ident) => Ok(col(&ident.value)),
+        SqlExpr::CompoundIdentifier(idents) => Ok(col(&idents.last().unwrap().value)),
+        _ => Err(unsupported("a JOIN condition on expressions")),
+    };
+    match expr {
+        SqlExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            collect_join_keys(left, right_name, visitor, left_on, right_on)?;
+            collect_join_keys(right, right_name, visitor, left_on, right_on)
+        }
+        SqlExpr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        } => {
+            let (l, r) = if is_right(left) && !is_right(right) {
+                (right, left)
+            } else {
+                (left, right)
+            };
+            left_on.push(left_key(l)?);
+            right_on.push(right_key(r)?);
+            Ok(())
+        }
+        SqlExpr::Nested(expr) => collect_join_keys(expr, right_name, visitor, left_on, right_on),
+        _ => Err(unsupported(
+            "a JOIN condition that is not a conjunction of equalities",
+        )),
+    }
+}
+
+/// Translate a `SELECT` with aggregations. The groups are aggregated with the aggregations of
+/// the projection and of `HAVING`. The latter are filtered on and removed by a final projection,
+/// which also puts the columns in the order of the `SELECT`.
+fn execute_aggregation(
+    lf: LazyFrame,
+    select: &Select,
+    visitor: &SqlExprVisitor,
+) -> Result<LazyFrame> {
+    let keys = select
+        .group_by
+        .iter()
+        .map(|expr| visitor.parse_sql_expr(expr))
+        .collect::<Result<Vec<_>>>()?;
+
+    enum Output {
+        Key { idx: usize, alias: Option<String> },
+        Agg(usize),
+    }
+    let mut aggs = vec![];
+    let mut outputs = vec![];
+    for item in &select.projection {
+        let (expr, alias) = match item {
+            SelectItem::UnnamedExpr(expr) => (expr, None),
+            SelectItem::ExprWithAlias { expr, alias } => (expr, Some(alias.value.clone())),
+            _ => return Err(unsupported("a wildcard in an aggregation")),
+        };
+        let translated = visitor.parse_sql_expr(expr)?;
+        if contains_aggregation(expr) {
+            outputs.push(Output::Agg(aggs.len()));
+            aggs.push(match &alias {
+                Some(alias) => translated.alias(alias),
+                None => translated,
+            });
+        } else {
+            let idx = keys
+                .iter()
+                .position(|key| key == &translated)
+                .ok_or_else(|| {
+                    PolarsError::InvalidOperation(
+                        format!(
+                            "'{}' must appear in the GROUP BY clause or be used in an aggregation",
+                            expr
+                        )
+                        .into(),
+                    )
+                })?;
+            outputs.push(Output::Key { idx, alias });
+        }
+    }
+
+    let n_aggs = aggs.len();
+    let having = select
+        .having
+        .as_ref()
+        .map(|having| {
+            visitor.visit_expr(having, &mut |agg| {
+                let name = format!("__POLARS_HAVING_{}", aggs.len() - n_aggs);
+                aggs.push(agg.alias(&name));
+                col(&name)
+            })
+        })
+        .transpose()?;
+
+    let mut lf = if keys.is_empty() {
+        lf.select(aggs)
+    } else {
+        lf.groupby(&keys).agg(aggs)
+    };
+    if let Some(having) = having {
+        lf = lf.filter(having);
+    }
+
+    // the groupby outputs the keys followed by the aggregations
+    let schema = lf.schema();
+    let fields = schema.fields();
+    let projection = outputs
+        .into_iter()
+        .map(|output| match output {
+            Output::Key { idx, alias } => {
+                let column = col(fields[idx].name());
+                match alias {
+                    Some(alias) => column.alias(&alias),
+                    None => column,
+                }
+            }
+            Output::Agg(idx) => col(fields[keys.len() + idx].name()),
+        })
+        .collect::<Vec<_>>();
+    Ok(lf.select(projection))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use polars_core::df;
+
+    fn context() -> Result<SQLContext> {
+        let sales = df![
+            "store" => [1, 1, 2, 2, 3],
+            "product" => ["a", "b", "a", "c", "b"],
+            "amount" => [10.0, 20.0, 30.0, 40.0, 50.0]
+        ]?;
+        let stores = df![
+            "id" => [1, 2, 3],
+            "city" => ["Paris", "Berlin", "Paris"]
+        ]?;
+        let mut ctx = SQLContext::new();
+        ctx.register("sales", sales.lazy());
+        ctx.register("stores", stores.lazy());
+        Ok(ctx)
+    }
+
+    #[test]
+    fn test_sql_select() -> Result<()> {
+        let ctx = context()?;
+        let out = ctx
+            .execute(
+                "SELECT product, amount * 2 AS double FROM sales \
+                 WHERE amount > 15 AND product <> 'c' ORDER BY amount DESC LIMIT 2",
+            )?
+            .collect()?;
+        let expected = df![
+            "product" => ["b", "a"],
+            "double" => [100.0, 60.0]
+        ]?;
+        assert!(out.frame_equal(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sql_join_groupby() -> Result<()> {
+        let ctx = context()?;
+        let out = ctx
+            .execute(
+                "SELECT SUM(s.amount) AS total, st.city, COUNT(*) FROM sales AS s \
+                 JOIN stores AS st ON s.store = st.id \
+                 GROUP BY st.city HAVING MAX(s.amount) > 40 ORDER BY city",
+            )?
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["total", "city", "count"]);
+        assert_eq!(Vec::from(out.column("total")?.f64()?), &[Some(80.0)]);
+        assert_eq!(Vec::from(out.column("city")?.utf8()?), &[Some("Paris")]);
+        assert_eq!(Vec::from(out.column("count")?.u32()?), &[Some(3)]);
+
+        // columns that are neither grouped nor aggregated
+        let err = ctx.execute("SELECT product FROM sales GROUP BY store");
+        assert!(err.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sql_join_qualified_columns() -> Result<()> {
+        let mut ctx = SQLContext::new();
+        ctx.register("a", df!["k" => [1, 2], "v" => ["a1", "a2"]]?.lazy());
+        ctx.register("b", df!["k" => [2, 1], "v" => ["b2", "b1"]]?.lazy());
+
+        let out = ctx
+            .execute("SELECT a.v, b.v AS bv, b.k FROM a JOIN b ON a.k = b.k ORDER BY b.v")?
+            .collect()?;
+        let expected = df![
+            "v" => ["a1", "a2"],
+            "bv" => ["b1", "b2"],
+            "k" => [1, 2]
+        ]?;
+        assert!(out.frame_equal(&expected));
+
+        // `v` is a column of both tables
+        assert!(ctx.execute("SELECT v FROM a JOIN b ON a.k = b.k").is_err());
+        // the join key is the same column in both tables
+        let out = ctx
+            .execute("SELECT k FROM a JOIN b USING (k) WHERE b.v = 'b2'")?
+            .collect()?;
+        assert_eq!(Vec::from(out.column("k")?.i32()?), &[Some(2)]);
+        Ok(())
+    }
+}
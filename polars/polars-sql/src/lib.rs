@@ -0,0 +1,26 @@
+//! SQL frontend of Polars.
+//!
+//! `SELECT` queries on the tables registered in a [`SQLContext`] are translated to a
+//! [`LazyFrame`](polars_lazy::frame::LazyFrame), so they go through the same optimizations as
+//! queries written with the lazy DSL.
+//!
+//! The supported clauses are `SELECT [DISTINCT]`, `FROM` with `[INNER | LEFT | FULL] JOIN ... ON`
+//! or `USING`, `WHERE`, `GROUP BY`, `HAVING`, `ORDER BY`, `LIMIT` and `OFFSET`. The aggregations
+//! are `COUNT`, `SUM`, `MIN`, `MAX`, `AVG`, `MEDIAN`, `FIRST`, `LAST`, `STDDEV` and `VARIANCE`.
+//!
+//! ```rust
+//! use polars_core::prelude::*;
+//! use polars_lazy::prelude::*;
+//! use polars_sql::SQLContext;
+//!
+//! fn example(df: DataFrame) -> Result<DataFrame> {
+//!     let mut ctx = SQLContext::new();
+//!     ctx.register("df", df.lazy());
+//!     ctx.execute("SELECT a, SUM(b) AS total FROM df WHERE b > 0 GROUP BY a ORDER BY total")?
+//!         .collect()
+//! }
+//! ```
+mod context;
+mod sql_expr;
+
+pub use context::SQLContext;
@@ -0,0 +1,267 @@
+//! Translation of SQL expressions to polars [`Expr`]essions.
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use sqlparser::ast::{
+    BinaryOperator as SQLBinaryOperator, DataType as SQLDataType, Expr as SqlExpr, Function,
+    FunctionArg, Ident, UnaryOperator, Value as SqlValue,
+};
+
+/// The names of the SQL aggregate functions.
+const AGGREGATIONS: &[&str] = &[
+    "avg", "count", "first", "last", "max", "mean", "median", "min", "stddev", "sum", "variance",
+];
+
+fn unsupported(what: &str, expr: &dyn std::fmt::Display) -> PolarsError {
+    PolarsError::InvalidOperation(format!("{} '{}' is not supported in SQL", what, expr).into())
+}
+
+fn map_sql_dtype(data_type: &SQLDataType) -> Result<DataType> {
+    Ok(match data_type {
+        SQLDataType::Boolean => DataType::Boolean,
+        SQLDataType::SmallInt => DataType::Int32,
+        SQLDataType::Int => DataType::Int32,
+        SQLDataType::BigInt => DataType::Int64,
+        SQLDataType::Float(_) | SQLDataType::Real => DataType::Float32,
+        SQLDataType::Double => DataType::Float64,
+        SQLDataType::Char(_)
+        | SQLDataType::Varchar(_)
+        | SQLDataType::Text
+        | SQLDataType::String => DataType::Utf8,
+        dt => return Err(unsupported("data type", dt)),
+    })
+}
+
+fn map_sql_value(value: &SqlValue) -> Result<Expr> {
+    Ok(match value {
+        SqlValue::Boolean(b) => lit(*b),
+        SqlValue::Null => lit(Null {}),
+        SqlValue::Number(s, _) => {
+            // integers stay integers, everything else is parsed as a float
+            if let Ok(v) = s.parse::<i64>() {
+                lit(v)
+            } else {
+                s.parse::<f64>().map(lit).map_err(|_| {
+                    PolarsError::ComputeError(format!("cannot parse number '{}'", s).into())
+                })?
+            }
+        }
+        SqlValue::SingleQuotedString(s) => lit(s.as_str()),
+        v => return Err(unsupported("value", v)),
+    })
+}
+
+fn map_binary_op(left: Expr, op: &SQLBinaryOperator, right: Expr) -> Result<Expr> {
+    Ok(match op {
+        SQLBinaryOperator::Plus => left + right,
+        SQLBinaryOperator::Minus => left - right,
+        SQLBinaryOperator::Multiply => left * right,
+        SQLBinaryOperator::Divide => left / right,
+        SQLBinaryOperator::Modulus => binary_expr(left, Operator::Modulus, right),
+        SQLBinaryOperator::Eq => left.eq(right),
+        SQLBinaryOperator::NotEq => left.neq(right),
+        SQLBinaryOperator::Gt => left.gt(right),
+        SQLBinaryOperator::GtEq => left.gt_eq(right),
+        SQLBinaryOperator::Lt => left.lt(right),
+        SQLBinaryOperator::LtEq => left.lt_eq(right),
+        SQLBinaryOperator::And => left.and(right),
+        SQLBinaryOperator::Or => left.or(right),
+        SQLBinaryOperator::Xor => left.xor(right),
+        op => return Err(unsupported("operator", op)),
+    })
+}
+
+/// Whether the function is an aggregation.
+fn is_aggregation(function: &Function) -> bool {
+    let name = function.name.to_string().to_lowercase();
+    AGGREGATIONS.contains(&name.as_str())
+}
+
+/// Whether the SQL expression contains an aggregation.
+pub(crate) fn contains_aggregation(expr: &SqlExpr) -> bool {
+    match expr {
+        SqlExpr::Function(function) => is_aggregation(function),
+        SqlExpr::BinaryOp { left, right, .. } => {
+            contains_aggregation(left) || contains_aggregation(right)
+        }
+        SqlExpr::UnaryOp { expr, .. }
+        | SqlExpr::Nested(expr)
+        | SqlExpr::Cast { expr, .. }
+        | SqlExpr::IsNull(expr)
+        | SqlExpr::IsNotNull(expr) => contains_aggregation(expr),
+        SqlExpr::Between {
+            expr, low, high, ..
+        } => contains_aggregation(expr) || contains_aggregation(low) || contains_aggregation(high),
+        SqlExpr::InList { expr, list, .. } => {
+            contains_aggregation(expr) || list.iter().any(contains_aggregation)
+        }
+        _ => false,
+    }
+}
+
+/// The columns of the tables in the `FROM` clause of a query. Maps the name of a table to its
+/// columns, and every column to the name it has in the joined input.
+pub(crate) type TableColumns = PlHashMap<String, PlHashMap<String, String>>;
+
+/// Translates SQL expressions of a query. Qualified column names, e.g. `t.a`, are resolved against
+/// the tables of the query.
+pub(crate) struct SqlExprVisitor<'a> {
+    /// The first column of the input. `COUNT(*)` counts its values, including the nulls.
+    pub(crate) first_column: &'a str,
+    pub(crate) tables: &'a TableColumns,
+}
+
+impl SqlExprVisitor<'_> {
+    /// Resolve a column name, that may be qualified with the name of a table, to the name of the
+    /// column in the joined input.
+    pub(crate) fn resolve_column(&self, idents: &[Ident]) -> Result<Expr> {
+        match idents {
+            [column] => {
+                let mut names = self
+                    .tables
+                    .values()
+                    .filter_map(|columns| columns.get(&column.value))
+                    .collect::<Vec<_>>();
+                names.sort_unstable();
+                names.dedup();
+                match names.as_slice() {
+                    [name] => Ok(col(name)),
+                    // not a column of a table, e.g. an alias or an aggregation result
+                    [] => Ok(col(&column.value)),
+                    _ => Err(PolarsError::InvalidOperation(
+                        format!(
+                            "column '{}' is ambiguous; qualify it with a table name",
+                            column.value
+                        )
+                        .into(),
+                    )),
+                }
+            }
+            [.., table, column] => {
+                let columns = self.tables.get(&table.value).ok_or_else(|| {
+                    PolarsError::NotFound(format!("table '{}' is not in the FROM clause", table))
+                })?;
+                let name = columns.get(&column.value).ok_or_else(|| {
+                    PolarsError::NotFound(format!(
+                        "column '{}' is not in table '{}'",
+                        column, table
+                    ))
+                })?;
+                Ok(col(name))
+            }
+            [] => unreachable!(),
+        }
+    }
+
+    pub(crate) fn parse_sql_expr(&self, expr: &SqlExpr) -> Result<Expr> {
+        self.visit_expr(expr, &mut |agg| agg)
+    }
+
+    /// Translate `expr` and replace every aggregation in it with the result of
+    /// `on_aggregation`.
+    pub(crate) fn visit_expr(
+        &self,
+        expr: &SqlExpr,
+        on_aggregation: &mut dyn FnMut(Expr) -> Expr,
+    ) -> Result<Expr> {
+        let mut visit = |expr: &SqlExpr| self.visit_expr(expr, on_aggregation);
+        Ok(match expr {
+            SqlExpr::Identifier(ident) => self.resolve_column(std::slice::from_ref(ident))?,
+            SqlExpr::CompoundIdentifier(idents) => self.resolve_column(idents)?,
+            SqlExpr::Wildcard => col("*"),
+            SqlExpr::Value(value) => map_sql_value(value)?,
+            SqlExpr::Nested(expr) => visit(expr)?,
+            SqlExpr::BinaryOp { left, op, right } => {
+                let left = visit(left)?;
+                map_binary_op(left, op, visit(right)?)?
+            }
+            SqlExpr::UnaryOp { op, expr } => match (op, &**expr) {
+                (UnaryOperator::Minus, SqlExpr::Value(SqlValue::Number(s, long))) => {
+                    map_sql_value(&SqlValue::Number(format!("-{}", s), *long))?
+                }
+                (UnaryOperator::Minus, expr) => lit(0) - visit(expr)?,
+                (UnaryOperator::Plus, expr) => visit(expr)?,
+                (UnaryOperator::Not, expr) => visit(expr)?.not(),
+                (op, _) => return Err(unsupported("operator", op)),
+            },
+            SqlExpr::IsNull(expr) => visit(expr)?.is_null(),
+            SqlExpr::IsNotNull(expr) => visit(expr)?.is_not_null(),
+            SqlExpr::Cast { expr, data_type } => visit(expr)?.cast(map_sql_dtype(data_type)?),
+            SqlExpr::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => {
+                let expr = visit(expr)?;
+                let between = expr
+                    .clone()
+                    .gt_eq(visit(low)?)
+                    .and(expr.lt_eq(visit(high)?));
+                if *negated {
+                    between.not()
+                } else {
+                    between
+                }
+            }
+            SqlExpr::InList {
+                expr,
+                list,
+                negated,
+            } => {
+                let expr = visit(expr)?;
+                let mut is_in = lit(false);
+                for value in list {
+                    is_in = is_in.or(expr.clone().eq(visit(value)?));
+                }
+                if *negated {
+                    is_in.not()
+                } else {
+                    is_in
+                }
+            }
+            SqlExpr::Function(function) => on_aggregation(self.visit_function(function)?),
+            expr => return Err(unsupported("expression", expr)),
+        })
+    }
+
+    fn visit_function(&self, function: &Function) -> Result<Expr> {
+        let name = function.name.to_string().to_lowercase();
+        let arg = match function.args.as_slice() {
+            [FunctionArg::Unnamed(arg)] if is_aggregation(function) => arg,
+            _ => return Err(unsupported("function", function)),
+        };
+        // aggregations cannot be nested
+        if contains_aggregation(arg) {
+            return Err(unsupported("function", function));
+        }
+
+        if name == "count" {
+            return match (arg, function.distinct) {
+                (SqlExpr::Wildcard, false) => Ok(col(self.first_column).count().alias("count")),
+                // SQL only counts the valid values
+                (arg, false) => Ok(self
+                    .parse_sql_expr(arg)?
+                    .is_not_null()
+                    .cast(DataType::UInt32)
+                    .sum()),
+                (arg, true) => Ok(self.parse_sql_expr(arg)?.n_unique()),
+            };
+        }
+        if function.distinct {
+            return Err(unsupported("function", function));
+        }
+        let expr = self.parse_sql_expr(arg)?;
+        Ok(match name.as_str() {
+            "avg" | "mean" => expr.mean(),
+            "first" => expr.first(),
+            "last" => expr.last(),
+            "max" => expr.max(),
+            "median" => expr.median(),
+            "min" => expr.min(),
+            "stddev" => expr.std(),
+            "sum" => expr.sum(),
+            "variance" => expr.var(),
+            _ => unreachable!(),
+        })
+    }
+}
@@ -92,6 +92,7 @@
 //!
 //! * `lazy` - Lazy API
 //!     - `lazy_regex` - Use regexes in [column selection](crate::lazy::dsl::col)
+//!     - `sql` - Query `LazyFrame`s with SQL in a [`SQLContext`](crate::sql::SQLContext)
 //! * `random` - Generate arrays with randomly sampled values
 //! * `ndarray`- Convert from `DataFrame` to `ndarray`
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
@@ -101,12 +102,16 @@
 //! * Performance related:
 //!     - `simd` - SIMD operations _(nightly only)_
 //!     - `performant` - ~40% faster chunkedarray creation but may lead to unexpected panic if iterator incorrectly sets a size_hint
+//!     - `spill` - Spill sorts and aggregations of the streaming engine to disk when they exceed the memory budget
 //! * IO related:
 //!     - `serde` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!                 Can be used for JSON and more serde supported serialization formats.
+//!     - `serde-lazy` - Serialize `LogicalPlan`s and `Expr`essions to JSON or bincode, e.g. to send
+//!                 queries to another process. Closures, as in `map` and `apply`, cannot be serialized.
 //!     - `parquet` - Read Apache Parquet format
-//!     - `json` - JSON serialization
+//!     - `json` - JSON serialization and newline delimited JSON reading
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `avro` - Apache Avro format serialization with deflate and snappy compression
 //!     - `decompress` - Automatically infer compression of csv-files and decompress them.
 //!                      Supported compressions:
 //!                         * zip
@@ -122,6 +127,7 @@
 //!     - `groupby_list` - Allow groupby operation on keys of type List.
 //! * `Series` operations:
 //!     - `is_in` - [Check for membership in `Series`](crate::chunked_array::ops::IsIn)
+//!     - `timezones` - Time zone conversions of `Datetime` `Series`
 //!     - `zip_with` - [Zip two Series/ ChunkedArrays](crate::chunked_array::ops::ChunkZip)
 //!     - `round_series` - round underlying float types of `Series`.
 //!     - `repeat_by` - [Repeat element in an Array N times, where N is given by another array.
@@ -161,11 +167,15 @@
 //! | DateType                | dtype-date        |
 //! | DatetimeType            | dtype-datetime    |
 //! | TimeType                | dtype-time        |
+//! | DurationType            | dtype-duration    |
 //! | Int8Type                | dtype-i8          |
 //! | Int16Type               | dtype-i16         |
 //! | UInt8Type               | dtype-u8          |
 //! | UInt16Type              | dtype-u16         |
 //! | Categorical             | dtype-categorical |
+//! | StructChunked           | dtype-struct      |
+//! | BinaryChunked           | dtype-binary      |
+//! | DecimalChunked          | dtype-decimal     |
 //!
 //!
 //! Or you can choose on of the preconfigured pre-sets.
@@ -203,6 +213,8 @@
 //! * `POLARS_TABLE_WIDTH` -> width of the tables used during DataFrame formatting.
 //! * `POLARS_MAX_THREADS` -> maximum number of threads used to initialize thread pool (on startup).
 //! * `POLARS_VERBOSE` -> print logging info to stderr
+//! * `POLARS_STREAMING_MEMORY_BUDGET` -> bytes a streaming sort or aggregation may hold in memory before it spills to disk (`spill` feature).
+//! * `POLARS_TEMP_DIR` -> directory of the spilled files, defaults to the temporary directory of the OS.
 //!
 //! ## Compile for WASM
 //! To be able to pretty print a `DataFrame` in `wasm32-wasi` you need to patch the `prettytable-rs`
@@ -230,3 +242,5 @@ pub use polars_core::df;
 pub use polars_io as io;
 #[cfg(feature = "lazy")]
 pub use polars_lazy as lazy;
+#[cfg(feature = "sql")]
+pub use polars_sql as sql;
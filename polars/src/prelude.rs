@@ -4,3 +4,6 @@ pub use polars_io::prelude::*;
 
 #[cfg(feature = "lazy")]
 pub use polars_lazy::prelude::*;
+
+#[cfg(feature = "sql")]
+pub use polars_sql::SQLContext;
@@ -38,6 +38,7 @@ __all__ = [
     "Date",
     "Datetime",
     "Time",
+    "Duration",
     "Object",
     "Categorical",
     "DTYPES",
@@ -116,6 +117,10 @@ class Time(DataType):
     pass
 
 
+class Duration(DataType):
+    pass
+
+
 class Object(DataType):
     pass
 
@@ -144,6 +149,7 @@ DTYPES: tp.List[Type[DataType]] = [
     Time,
     Object,
     Categorical,
+    Duration,
 ]
 DTYPE_TO_FFINAME: Dict[Type[DataType], str] = {
     Int8: "i8",
@@ -164,6 +170,7 @@ DTYPE_TO_FFINAME: Dict[Type[DataType], str] = {
     Time: "time",
     Object: "object",
     Categorical: "categorical",
+    Duration: "duration",
 }
 
 
@@ -173,6 +180,8 @@ def date_like_to_physical(dtype: Type[DataType]) -> Type[DataType]:
         return Int32
     if dtype == Datetime:
         return Int64
+    if dtype == Duration:
+        return Int64
     return dtype
 
 
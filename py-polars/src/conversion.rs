@@ -141,8 +141,10 @@ impl IntoPy<PyObject> for Wrap<AnyValue<'_>> {
                 s.into_py(py)
             }
             AnyValue::Date(v) => v.into_py(py),
-            AnyValue::Datetime(v) => v.into_py(py),
+            // python expects milliseconds
+            AnyValue::Datetime(v, tu, _) => (v / (tu.ticks_per_second() / 1000)).into_py(py),
             AnyValue::Time(v) => v.into_py(py),
+            AnyValue::Duration(v, tu) => (v / (tu.ticks_per_second() / 1000)).into_py(py),
             AnyValue::List(v) => {
                 let pypolars = PyModule::import(py, "polars").expect("polars installed");
                 let pyseries = PySeries::new(v);
@@ -219,7 +221,7 @@ impl<'s> FromPyObject<'s> for Wrap<AnyValue<'s>> {
                 loc_tz.call_method0("timestamp")?;
                 // s to ms
                 let v = ts.extract::<f64>()? as i64;
-                Ok(AnyValue::Datetime(v * 1000).into())
+                Ok(AnyValue::Datetime(v * 1000, TimeUnit::Milliseconds, &None).into())
             }
             // unix
             #[cfg(not(target_arch = "windows"))]
@@ -232,7 +234,7 @@ impl<'s> FromPyObject<'s> for Wrap<AnyValue<'s>> {
                 let ts = dt.call_method0("timestamp")?;
                 // s to ms
                 let v = ts.extract::<f64>()? as i64;
-                Ok(AnyValue::Datetime(v * 1000).into())
+                Ok(AnyValue::Datetime(v * 1000, TimeUnit::Milliseconds, &None).into())
             }
         } else if ob.is_none() {
             Ok(AnyValue::Null.into())
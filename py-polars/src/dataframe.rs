@@ -917,7 +917,7 @@ impl PyDataFrame {
                     .into_date()
                     .into_series()
             }
-            Some(DataType::Datetime) => {
+            Some(DataType::Datetime(_, _)) => {
                 apply_lambda_with_primitive_out_type::<Int64Type>(df, py, lambda, 0, None)
                     .into_date()
                     .into_series()
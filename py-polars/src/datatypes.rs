@@ -23,6 +23,7 @@ pub enum PyDataType {
     Time,
     Object,
     Categorical,
+    Duration,
 }
 
 impl From<&DataType> for PyDataType {
@@ -43,10 +44,11 @@ impl From<&DataType> for PyDataType {
             DataType::Utf8 => Utf8,
             DataType::List(_) => List,
             DataType::Date => Date,
-            DataType::Datetime => Datetime,
+            DataType::Datetime(_, _) => Datetime,
             DataType::Time => Time,
             DataType::Object(_) => Object,
             DataType::Categorical => Categorical,
+            DataType::Duration(_) => Duration,
             DataType::Null => {
                 panic!("null not expected here")
             }
@@ -78,10 +80,11 @@ impl Into<DataType> for PyDataType {
             PyDataType::Utf8 => Utf8,
             PyDataType::List => List(DataType::Null.into()),
             PyDataType::Date => Date,
-            PyDataType::Datetime => Datetime,
+            PyDataType::Datetime => Datetime(TimeUnit::Milliseconds, None),
             PyDataType::Time => Time,
             PyDataType::Object => Object("object"),
             PyDataType::Categorical => Categorical,
+            PyDataType::Duration => Duration(TimeUnit::Milliseconds),
         }
     }
 }
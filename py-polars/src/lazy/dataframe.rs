@@ -99,7 +99,7 @@ impl PyLazyFrame {
         comment_char: Option<&str>,
         quote_char: Option<&str>,
         null_values: Option<Wrap<NullValues>>,
-    ) -> Self {
+    ) -> PyResult<Self> {
         let null_values = null_values.map(|w| w.0);
         let comment_char = comment_char.map(|s| s.as_bytes()[0]);
         let quote_char = quote_char.map(|s| s.as_bytes()[0]);
@@ -117,7 +117,7 @@ impl PyLazyFrame {
             Schema::new(fields)
         });
 
-        LazyCsvReader::new(path)
+        let lf = LazyCsvReader::new(path)
             .with_delimiter(delimiter)
             .has_header(has_header)
             .with_ignore_parser_errors(ignore_errors)
@@ -130,13 +130,20 @@ impl PyLazyFrame {
             .with_quote_char(quote_char)
             .with_null_values(null_values)
             .finish()
-            .into()
+            .map_err(PyPolarsEr::from)?;
+        Ok(lf.into())
     }
 
     #[staticmethod]
     #[cfg(feature = "parquet")]
-    pub fn new_from_parquet(path: String, stop_after_n_rows: Option<usize>, cache: bool) -> Self {
-        LazyFrame::new_from_parquet(path, stop_after_n_rows, cache).into()
+    pub fn new_from_parquet(
+        path: String,
+        stop_after_n_rows: Option<usize>,
+        cache: bool,
+    ) -> PyResult<Self> {
+        let lf = LazyFrame::new_from_parquet(path, stop_after_n_rows, cache)
+            .map_err(PyPolarsEr::from)?;
+        Ok(lf.into())
     }
 
     pub fn describe_plan(&self) -> String {
@@ -341,7 +341,10 @@ impl PyExpr {
         };
         self.clone()
             .inner
-            .map(function, GetOutput::from_type(DataType::Datetime))
+            .map(
+                function,
+                GetOutput::from_type(DataType::Datetime(TimeUnit::Milliseconds, None)),
+            )
             .into()
     }
 
@@ -640,7 +640,14 @@ impl PySeries {
             DataType::Float32 => PyList::new(python, series.f32().unwrap()),
             DataType::Float64 => PyList::new(python, series.f64().unwrap()),
             DataType::Date => PyList::new(python, &series.date().unwrap().0),
-            DataType::Datetime => PyList::new(python, &series.datetime().unwrap().0),
+            DataType::Datetime(_, _) => {
+                // python expects milliseconds
+                let ca = series
+                    .datetime()
+                    .unwrap()
+                    .cast_time_unit(TimeUnit::Milliseconds);
+                PyList::new(python, &ca.0)
+            }
             DataType::Object(_) => {
                 let v = PyList::empty(python);
                 for i in 0..series.len() {
@@ -859,7 +866,7 @@ impl PySeries {
                 )?;
                 ca.into_date().into_series()
             }
-            Some(DataType::Datetime) => {
+            Some(DataType::Datetime(_, _)) => {
                 let ca: Int64Chunked = apply_method_all_arrow_series!(
                     series,
                     apply_lambda_with_primitive_out_type,
@@ -1196,7 +1203,7 @@ impl PySeries {
         let rule = downsample_str_to_rule(rule, n)?;
         match self.series.dtype() {
             DataType::Date => Ok(self.series.date().unwrap().round(rule).into_series().into()),
-            DataType::Datetime => Ok(self
+            DataType::Datetime(_, _) => Ok(self
                 .series
                 .datetime()
                 .unwrap()
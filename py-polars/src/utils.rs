@@ -18,8 +18,9 @@ pub fn str_to_polarstype(s: &str) -> DataType {
         "<class 'polars.datatypes.Boolean'>" => DataType::Boolean,
         "<class 'polars.datatypes.Utf8'>" => DataType::Utf8,
         "<class 'polars.datatypes.Date'>" => DataType::Date,
-        "<class 'polars.datatypes.Datetime'>" => DataType::Datetime,
+        "<class 'polars.datatypes.Datetime'>" => DataType::Datetime(TimeUnit::Milliseconds, None),
         "<class 'polars.datatypes.Time'>" => DataType::Time,
+        "<class 'polars.datatypes.Duration'>" => DataType::Duration(TimeUnit::Milliseconds),
         "<class 'polars.datatypes.List'>" => DataType::List(DataType::Null.into()),
         "<class 'polars.datatypes.Categorical'>" => DataType::Categorical,
         "<class 'polars.datatypes.Object'>" => DataType::Object("object"),